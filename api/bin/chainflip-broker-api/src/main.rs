@@ -4,7 +4,10 @@ use cf_utilities::{
 };
 use chainflip_api::{
 	self, clean_foreign_chain_address,
-	primitives::{AccountRole, Asset, BasisPoints, BlockNumber, CcmChannelMetadata, ChannelId},
+	primitives::{
+		AccountRole, Asset, BasisPoints, BlockNumber, CcmChannelMetadata, ChannelId, DcaParameters,
+		SwapRefundParameters,
+	},
 	settings::StateChain,
 	BrokerApi, OperatorApi, StateChainApi,
 };
@@ -55,6 +58,8 @@ pub trait Rpc {
 		broker_commission_bps: BasisPoints,
 		channel_metadata: Option<CcmChannelMetadata>,
 		boost_fee: Option<BasisPoints>,
+		refund_parameters: Option<SwapRefundParameters<String>>,
+		dca_parameters: Option<DcaParameters>,
 	) -> RpcResult<BrokerSwapDepositAddress>;
 }
 
@@ -93,6 +98,8 @@ impl RpcServer for RpcServerImpl {
 		broker_commission_bps: BasisPoints,
 		channel_metadata: Option<CcmChannelMetadata>,
 		boost_fee: Option<BasisPoints>,
+		refund_parameters: Option<SwapRefundParameters<String>>,
+		dca_parameters: Option<DcaParameters>,
 	) -> RpcResult<BrokerSwapDepositAddress> {
 		Ok(self
 			.api
@@ -104,6 +111,14 @@ impl RpcServer for RpcServerImpl {
 				broker_commission_bps,
 				channel_metadata,
 				boost_fee,
+				refund_parameters
+					.map(|params| {
+						params.try_map_address(|refund_address| {
+							clean_foreign_chain_address(source_asset.into(), &refund_address)
+						})
+					})
+					.transpose()?,
+				dca_parameters,
 			)
 			.await
 			.map(BrokerSwapDepositAddress::from)?)
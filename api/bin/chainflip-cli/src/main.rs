@@ -71,6 +71,8 @@ async fn run_cli() -> Result<()> {
 							params.broker_commission,
 							None,
 							params.boost_fee,
+							None,
+							None,
 						)
 						.await?;
 					println!("Deposit Address: {address}");
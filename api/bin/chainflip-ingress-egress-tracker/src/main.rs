@@ -1,13 +1,18 @@
-use crate::store::RedisStore;
+use crate::store::{InMemoryStore, RedisStore, RocksDbStore};
 use chainflip_engine::settings::CfSettings;
 use clap::Parser;
 use futures::FutureExt;
-use settings::{DepositTrackerSettings, TrackerOptions};
+use jsonrpsee::server::ServerBuilder;
+use server::RpcServer;
+use settings::{DepositTrackerSettings, StoreBackend, TrackerOptions};
 use store::{Storable, Store};
+use tracing::log;
 use utilities::task_scope;
 
+mod server;
 mod settings;
 mod store;
+mod swap_status;
 mod utils;
 mod witnessing;
 
@@ -20,8 +25,35 @@ async fn start(
 		.try_init()
 		.expect("setting default subscriber failed");
 
-	let client = redis::Client::open(settings.redis_url.clone()).unwrap();
-	let store = RedisStore::new(client.get_multiplexed_tokio_connection().await?);
+	match settings.store {
+		StoreBackend::Redis => {
+			let client = redis::Client::open(settings.redis_url.clone()).unwrap();
+			let store = RedisStore::new(client.get_multiplexed_tokio_connection().await?);
+			start_with_store(scope, settings, store).await
+		},
+		StoreBackend::Rocksdb => {
+			let store = RocksDbStore::open(&settings.db_path)?;
+			start_with_store(scope, settings, store).await
+		},
+		StoreBackend::InMemory => start_with_store(scope, settings, InMemoryStore::default()).await,
+	}
+}
+
+async fn start_with_store<S: Store + Clone>(
+	scope: &task_scope::Scope<'_, anyhow::Error>,
+	settings: DepositTrackerSettings,
+	store: S,
+) -> anyhow::Result<()> {
+	let server = ServerBuilder::default()
+		.build(format!("0.0.0.0:{}", settings.status_rpc_port))
+		.await?;
+	let server_addr = server.local_addr()?;
+	let server = server.start(server::RpcServerImpl::new(store.clone()).into_rpc())?;
+	log::info!("Swap status server is listening on {server_addr}.");
+	scope.spawn(async move {
+		server.stopped().await;
+		Ok(())
+	});
 
 	let btc_network = witnessing::start(scope, settings.clone(), store.clone())
 		.await?
@@ -0,0 +1,67 @@
+use crate::{
+	store::Store,
+	swap_status::{self, ChannelSwapStatus},
+};
+use cf_primitives::{ChannelId, ForeignChain, SwapId};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+};
+
+#[rpc(server, namespace = "tracker")]
+pub trait Rpc {
+	#[method(name = "swap_status_by_channel_id")]
+	async fn swap_status_by_channel_id(
+		&self,
+		chain: ForeignChain,
+		channel_id: ChannelId,
+	) -> RpcResult<Option<ChannelSwapStatus>>;
+
+	#[method(name = "swap_status_by_deposit_address")]
+	async fn swap_status_by_deposit_address(
+		&self,
+		chain: ForeignChain,
+		deposit_address: String,
+	) -> RpcResult<Option<ChannelSwapStatus>>;
+
+	#[method(name = "swap_status_by_swap_id")]
+	async fn swap_status_by_swap_id(&self, swap_id: SwapId)
+		-> RpcResult<Option<ChannelSwapStatus>>;
+}
+
+pub struct RpcServerImpl<S> {
+	store: S,
+}
+
+impl<S> RpcServerImpl<S> {
+	pub fn new(store: S) -> Self {
+		Self { store }
+	}
+}
+
+#[async_trait]
+impl<S: Store + Clone> RpcServer for RpcServerImpl<S> {
+	async fn swap_status_by_channel_id(
+		&self,
+		chain: ForeignChain,
+		channel_id: ChannelId,
+	) -> RpcResult<Option<ChannelSwapStatus>> {
+		Ok(swap_status::status_by_channel_id(&mut self.store.clone(), chain, channel_id).await?)
+	}
+
+	async fn swap_status_by_deposit_address(
+		&self,
+		chain: ForeignChain,
+		deposit_address: String,
+	) -> RpcResult<Option<ChannelSwapStatus>> {
+		Ok(swap_status::status_by_deposit_address(&mut self.store.clone(), chain, &deposit_address)
+			.await?)
+	}
+
+	async fn swap_status_by_swap_id(
+		&self,
+		swap_id: SwapId,
+	) -> RpcResult<Option<ChannelSwapStatus>> {
+		Ok(swap_status::status_by_swap_id(&mut self.store.clone(), swap_id).await?)
+	}
+}
@@ -4,7 +4,7 @@ use chainflip_engine::settings::{
 use clap::Parser;
 use config::{Config, ConfigBuilder, ConfigError, Environment, Map, Source, Value};
 use serde::Deserialize;
-use std::{collections::HashMap, env};
+use std::{collections::HashMap, env, path::PathBuf};
 
 #[derive(Parser, Debug, Clone, Default)]
 #[clap(version = env!("SUBSTRATE_CLI_IMPL_VERSION"), version_short = 'v')]
@@ -25,8 +25,25 @@ pub struct TrackerOptions {
 	btc_username: Option<String>,
 	#[clap(long = "btc.rpc.basic_auth_password")]
 	btc_password: Option<String>,
+	#[clap(long = "store")]
+	store: Option<String>,
 	#[clap(long = "redis_url")]
 	redis_url: Option<String>,
+	#[clap(long = "db_path")]
+	db_path: Option<String>,
+	#[clap(long = "status_rpc_port")]
+	status_rpc_port: Option<u16>,
+}
+
+/// Where the tracker stores the witnessed information.
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StoreBackend {
+	Redis,
+	/// An on-disk database at `db_path`.
+	Rocksdb,
+	/// Nothing is retained across restarts.
+	InMemory,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -35,7 +52,10 @@ pub struct DepositTrackerSettings {
 	pub dot: WsHttpEndpoints,
 	pub state_chain_ws_endpoint: String,
 	pub btc: HttpBasicAuthEndpoint,
+	pub store: StoreBackend,
 	pub redis_url: String,
+	pub db_path: PathBuf,
+	pub status_rpc_port: u16,
 }
 
 impl CfSettings for DepositTrackerSettings {
@@ -67,7 +87,10 @@ impl CfSettings for DepositTrackerSettings {
 			.set_default("btc.http_endpoint", "http://127.0.0.1:8332")?
 			.set_default("btc.basic_auth_user", "flip")?
 			.set_default("btc.basic_auth_password", "flip")?
-			.set_default("redis_url", "http://127.0.0.1:6380")
+			.set_default("store", "redis")?
+			.set_default("redis_url", "http://127.0.0.1:6380")?
+			.set_default("db_path", "./tracker_db")?
+			.set_default("status_rpc_port", 13337)
 	}
 
 	fn validate_settings(
@@ -98,7 +121,10 @@ impl Source for TrackerOptions {
 		insert_command_line_option(&mut map, "btc.http_endpoint", &self.btc_endpoint);
 		insert_command_line_option(&mut map, "btc.basic_auth_user", &self.btc_username);
 		insert_command_line_option(&mut map, "btc.basic_auth_password", &self.btc_password);
+		insert_command_line_option(&mut map, "store", &self.store);
 		insert_command_line_option(&mut map, "redis_url", &self.redis_url);
+		insert_command_line_option(&mut map, "db_path", &self.db_path);
+		insert_command_line_option(&mut map, "status_rpc_port", &self.status_rpc_port);
 
 		Ok(map)
 	}
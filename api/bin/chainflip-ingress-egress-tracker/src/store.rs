@@ -1,14 +1,21 @@
 use async_trait::async_trait;
 use redis::{aio::MultiplexedConnection, AsyncCommands};
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 use std::time::Duration;
 
+mod embedded;
+
+pub use embedded::{InMemoryStore, RocksDbStore};
+
 #[async_trait]
 pub trait Store: Sync + Send + 'static {
 	type Output: Sync + Send + 'static;
 
 	async fn save_to_array<S: Storable>(&mut self, storable: &S) -> anyhow::Result<Self::Output>;
 	async fn save_singleton<S: Storable>(&mut self, storable: &S) -> anyhow::Result<Self::Output>;
+	/// Like `save_singleton`, but the value never expires.
+	async fn save_persistent<S: Storable>(&mut self, storable: &S) -> anyhow::Result<Self::Output>;
+	async fn load<T: DeserializeOwned + Send>(&mut self, key: &str) -> anyhow::Result<Option<T>>;
 }
 
 #[derive(Clone)]
@@ -45,6 +52,18 @@ impl Store for RedisStore {
 
 		Ok(())
 	}
+
+	async fn save_persistent<S: Storable>(&mut self, storable: &S) -> anyhow::Result<()> {
+		self.con.set(storable.get_key(), serde_json::to_string(storable)?).await?;
+
+		Ok(())
+	}
+
+	async fn load<T: DeserializeOwned + Send>(&mut self, key: &str) -> anyhow::Result<Option<T>> {
+		let value: Option<String> = self.con.get(key).await?;
+
+		Ok(value.map(|value| serde_json::from_str(&value)).transpose()?)
+	}
 }
 
 pub trait Storable: Serialize + Sized + Sync + 'static {
@@ -56,3 +75,65 @@ pub trait Storable: Serialize + Sized + Sync + 'static {
 		Self::DEFAULT_EXPIRY_DURATION
 	}
 }
+
+#[cfg(test)]
+pub mod test_utils {
+	use super::*;
+	use anyhow::anyhow;
+	use std::collections::HashMap;
+
+	#[derive(Default)]
+	pub struct MockStore {
+		pub storage: HashMap<String, serde_json::Value>,
+	}
+
+	#[async_trait]
+	impl Store for MockStore {
+		type Output = ();
+
+		async fn save_to_array<S: Storable>(
+			&mut self,
+			storable: &S,
+		) -> anyhow::Result<Self::Output> {
+			let key = storable.get_key();
+			let value = serde_json::to_value(storable)?;
+
+			let array = self.storage.entry(key).or_insert(serde_json::Value::Array(vec![]));
+
+			array.as_array_mut().ok_or(anyhow!("expect array"))?.push(value);
+
+			Ok(())
+		}
+
+		async fn save_singleton<S: Storable>(
+			&mut self,
+			storable: &S,
+		) -> anyhow::Result<Self::Output> {
+			let key = storable.get_key();
+
+			let value = serde_json::to_value(storable)?;
+
+			self.storage.insert(key, value);
+
+			Ok(())
+		}
+
+		async fn save_persistent<S: Storable>(
+			&mut self,
+			storable: &S,
+		) -> anyhow::Result<Self::Output> {
+			self.save_singleton(storable).await
+		}
+
+		async fn load<T: DeserializeOwned + Send>(
+			&mut self,
+			key: &str,
+		) -> anyhow::Result<Option<T>> {
+			Ok(self
+				.storage
+				.get(key)
+				.map(|value| serde_json::from_value(value.clone()))
+				.transpose()?)
+		}
+	}
+}
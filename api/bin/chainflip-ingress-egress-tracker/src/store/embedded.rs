@@ -0,0 +1,392 @@
+use super::{Storable, Store};
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use rocksdb::{ColumnFamily, IteratorMode, Options, WriteBatch, DB};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+	collections::{BTreeSet, HashMap},
+	path::Path,
+	sync::{Arc, Mutex},
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Column family of the RocksDB backend that indexes the keys of expiring entries by their expiry.
+const EXPIRIES_COLUMN: &str = "expiries";
+
+/// A value together with its expiry, as stored by backends that don't handle expiry themselves.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Entry {
+	value: serde_json::Value,
+	/// Seconds since the unix epoch, or `None` if the entry never expires.
+	expires_at: Option<u64>,
+}
+
+impl Entry {
+	fn is_expired(&self, now: u64) -> bool {
+		self.expires_at.is_some_and(|expires_at| expires_at <= now)
+	}
+}
+
+/// Storage for [EmbeddedStore]. Backends keep an index of the entries by expiry, so that expired
+/// entries can be removed without scanning all of them.
+pub trait EmbeddedBackend: Send + 'static {
+	fn get(&self, key: &str) -> anyhow::Result<Option<Entry>>;
+	/// Writes the entry, replacing any existing entry and its expiry.
+	fn put(&mut self, key: &str, entry: &Entry) -> anyhow::Result<()>;
+	fn remove(&mut self, key: &str) -> anyhow::Result<()>;
+	/// Removes all entries that expired at or before `now`.
+	fn remove_expired(&mut self, now: u64) -> anyhow::Result<()>;
+}
+
+#[derive(Default)]
+pub struct InMemoryBackend {
+	entries: HashMap<String, Entry>,
+	expiries: BTreeSet<(u64, String)>,
+}
+
+impl EmbeddedBackend for InMemoryBackend {
+	fn get(&self, key: &str) -> anyhow::Result<Option<Entry>> {
+		Ok(self.entries.get(key).cloned())
+	}
+
+	fn put(&mut self, key: &str, entry: &Entry) -> anyhow::Result<()> {
+		self.remove(key)?;
+		if let Some(expires_at) = entry.expires_at {
+			self.expiries.insert((expires_at, key.to_string()));
+		}
+		self.entries.insert(key.to_string(), entry.clone());
+		Ok(())
+	}
+
+	fn remove(&mut self, key: &str) -> anyhow::Result<()> {
+		if let Some(Entry { expires_at: Some(expires_at), .. }) = self.entries.remove(key) {
+			self.expiries.remove(&(expires_at, key.to_string()));
+		}
+		Ok(())
+	}
+
+	fn remove_expired(&mut self, now: u64) -> anyhow::Result<()> {
+		while let Some((expires_at, key)) = self.expiries.first().cloned() {
+			if expires_at > now {
+				break
+			}
+			self.expiries.pop_first();
+			self.entries.remove(&key);
+		}
+		Ok(())
+	}
+}
+
+pub struct RocksDbBackend(DB);
+
+impl RocksDbBackend {
+	pub fn open(db_path: &Path) -> anyhow::Result<Self> {
+		let mut options = Options::default();
+		options.create_if_missing(true);
+		options.create_missing_column_families(true);
+		Ok(Self(
+			DB::open_cf(&options, db_path, [EXPIRIES_COLUMN])
+				.with_context(|| format!("Failed to open database at: {}", db_path.display()))?,
+		))
+	}
+
+	fn expiries_column(&self) -> &ColumnFamily {
+		self.0.cf_handle(EXPIRIES_COLUMN).expect("expiries column must exist")
+	}
+
+	/// Keys of the expiry index sort by expiry, since the expiry is encoded big-endian.
+	fn expiry_key(expires_at: u64, key: &[u8]) -> Vec<u8> {
+		[&expires_at.to_be_bytes()[..], key].concat()
+	}
+
+	/// Adds the removal of the entry and its expiry to the batch.
+	fn remove_in_batch(&self, batch: &mut WriteBatch, key: &str) -> anyhow::Result<()> {
+		if let Some(Entry { expires_at: Some(expires_at), .. }) = self.get(key)? {
+			batch.delete_cf(self.expiries_column(), Self::expiry_key(expires_at, key.as_bytes()));
+		}
+		batch.delete(key);
+		Ok(())
+	}
+}
+
+impl EmbeddedBackend for RocksDbBackend {
+	fn get(&self, key: &str) -> anyhow::Result<Option<Entry>> {
+		Ok(self
+			.0
+			.get(key)?
+			.map(|data| serde_json::from_slice(&data))
+			.transpose()
+			.context("Deserialization failed")?)
+	}
+
+	fn put(&mut self, key: &str, entry: &Entry) -> anyhow::Result<()> {
+		let mut batch = WriteBatch::default();
+		self.remove_in_batch(&mut batch, key)?;
+		if let Some(expires_at) = entry.expires_at {
+			batch.put_cf(self.expiries_column(), Self::expiry_key(expires_at, key.as_bytes()), b"");
+		}
+		batch.put(key, serde_json::to_vec(entry)?);
+		self.0.write(batch).context("Failed to write data to database.")
+	}
+
+	fn remove(&mut self, key: &str) -> anyhow::Result<()> {
+		let mut batch = WriteBatch::default();
+		self.remove_in_batch(&mut batch, key)?;
+		self.0.write(batch).context("Failed to delete data from database.")
+	}
+
+	fn remove_expired(&mut self, now: u64) -> anyhow::Result<()> {
+		let mut batch = WriteBatch::default();
+		for item in self.0.iterator_cf(self.expiries_column(), IteratorMode::Start) {
+			let (expiry_key, _) = item?;
+			let (expires_at, key) = expiry_key.split_at(8);
+			if u64::from_be_bytes(expires_at.try_into().expect("split at 8 bytes")) > now {
+				break
+			}
+			batch.delete_cf(self.expiries_column(), &expiry_key);
+			batch.delete(key);
+		}
+		self.0.write(batch).context("Failed to delete expired data from database.")
+	}
+}
+
+/// A [Store] that runs in-process, so that the tracker can be run without a Redis server. All
+/// operations on the backend are serialised, which makes appending to arrays atomic as it is in
+/// Redis. They are run on tokio's blocking thread pool, since the backend may block on disk IO.
+pub struct EmbeddedStore<B> {
+	backend: Arc<Mutex<B>>,
+}
+
+pub type InMemoryStore = EmbeddedStore<InMemoryBackend>;
+pub type RocksDbStore = EmbeddedStore<RocksDbBackend>;
+
+impl<B> Clone for EmbeddedStore<B> {
+	fn clone(&self) -> Self {
+		Self { backend: self.backend.clone() }
+	}
+}
+
+impl<B: EmbeddedBackend> EmbeddedStore<B> {
+	pub fn new(backend: B) -> Self {
+		Self { backend: Arc::new(Mutex::new(backend)) }
+	}
+
+	/// Removes expired entries, then runs `f` with the backend and the current time.
+	async fn with_backend<R: Send + 'static>(
+		&self,
+		f: impl FnOnce(&mut B, u64) -> anyhow::Result<R> + Send + 'static,
+	) -> anyhow::Result<R> {
+		let backend = self.backend.clone();
+		tokio::task::spawn_blocking(move || {
+			let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+			let mut backend = backend.lock().expect("Lock should not be poisoned");
+			backend.remove_expired(now)?;
+			f(&mut backend, now)
+		})
+		.await
+		.context("Store task failed")?
+	}
+}
+
+impl<B: EmbeddedBackend + Default> Default for EmbeddedStore<B> {
+	fn default() -> Self {
+		Self::new(B::default())
+	}
+}
+
+impl RocksDbStore {
+	pub fn open(db_path: &Path) -> anyhow::Result<Self> {
+		Ok(Self::new(RocksDbBackend::open(db_path)?))
+	}
+}
+
+#[async_trait]
+impl<B: EmbeddedBackend> Store for EmbeddedStore<B> {
+	type Output = ();
+
+	async fn save_to_array<S: Storable>(&mut self, storable: &S) -> anyhow::Result<()> {
+		let key = storable.get_key();
+		let value = serde_json::to_value(storable)?;
+		let expiry = storable.get_expiry_duration().as_secs();
+		self.with_backend(move |backend, now| {
+			let mut values = match backend.get(&key)? {
+				Some(entry) => match entry.value {
+					serde_json::Value::Array(values) => values,
+					_ => bail!("Value at {key} is not an array"),
+				},
+				None => Vec::new(),
+			};
+			values.push(value);
+
+			backend.put(
+				&key,
+				&Entry { value: serde_json::Value::Array(values), expires_at: Some(now + expiry) },
+			)
+		})
+		.await
+	}
+
+	async fn save_singleton<S: Storable>(&mut self, storable: &S) -> anyhow::Result<()> {
+		let key = storable.get_key();
+		let value = serde_json::to_value(storable)?;
+		let expiry = storable.get_expiry_duration().as_secs();
+		self.with_backend(move |backend, now| {
+			backend.put(&key, &Entry { value, expires_at: Some(now + expiry) })
+		})
+		.await
+	}
+
+	async fn save_persistent<S: Storable>(&mut self, storable: &S) -> anyhow::Result<()> {
+		let key = storable.get_key();
+		let value = serde_json::to_value(storable)?;
+		self.with_backend(move |backend, _| backend.put(&key, &Entry { value, expires_at: None }))
+			.await
+	}
+
+	async fn load<T: DeserializeOwned + Send>(&mut self, key: &str) -> anyhow::Result<Option<T>> {
+		let key = key.to_string();
+		self.with_backend(move |backend, _| Ok(backend.get(&key)?))
+			.await?
+			.map(|entry| serde_json::from_value(entry.value))
+			.transpose()
+			.map_err(Into::into)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	#[derive(Serialize)]
+	struct TestStorable {
+		#[serde(skip_serializing)]
+		key: &'static str,
+		#[serde(skip_serializing)]
+		expiry: Duration,
+		value: u32,
+	}
+
+	impl Storable for TestStorable {
+		fn get_key(&self) -> String {
+			self.key.to_string()
+		}
+
+		fn get_expiry_duration(&self) -> Duration {
+			self.expiry
+		}
+	}
+
+	async fn test_store<B: EmbeddedBackend>(mut store: EmbeddedStore<B>) {
+		const LONG: Duration = Duration::from_secs(3600);
+
+		store
+			.save_to_array(&TestStorable { key: "array", expiry: LONG, value: 1 })
+			.await
+			.unwrap();
+		store
+			.save_to_array(&TestStorable { key: "array", expiry: LONG, value: 2 })
+			.await
+			.unwrap();
+		store
+			.save_singleton(&TestStorable { key: "singleton", expiry: LONG, value: 3 })
+			.await
+			.unwrap();
+		store
+			.save_persistent(&TestStorable { key: "persistent", expiry: Duration::ZERO, value: 4 })
+			.await
+			.unwrap();
+		store
+			.save_singleton(&TestStorable { key: "expired", expiry: Duration::ZERO, value: 5 })
+			.await
+			.unwrap();
+
+		assert_eq!(
+			store.load::<serde_json::Value>("array").await.unwrap(),
+			Some(serde_json::json!([{ "value": 1 }, { "value": 2 }]))
+		);
+		assert_eq!(
+			store.load::<serde_json::Value>("singleton").await.unwrap(),
+			Some(serde_json::json!({ "value": 3 }))
+		);
+		assert_eq!(
+			store.load::<serde_json::Value>("persistent").await.unwrap(),
+			Some(serde_json::json!({ "value": 4 }))
+		);
+		assert_eq!(store.load::<serde_json::Value>("expired").await.unwrap(), None);
+		assert_eq!(store.load::<serde_json::Value>("missing").await.unwrap(), None);
+
+		// Appending to an expired array starts a new one.
+		store
+			.save_to_array(&TestStorable { key: "array", expiry: Duration::ZERO, value: 6 })
+			.await
+			.unwrap();
+		store
+			.save_to_array(&TestStorable { key: "array", expiry: LONG, value: 7 })
+			.await
+			.unwrap();
+		assert_eq!(
+			store.load::<serde_json::Value>("array").await.unwrap(),
+			Some(serde_json::json!([{ "value": 7 }]))
+		);
+	}
+
+	#[tokio::test]
+	async fn test_in_memory_store() {
+		test_store(InMemoryStore::default()).await;
+	}
+
+	#[tokio::test]
+	async fn test_rocksdb_store() {
+		let directory = tempfile::TempDir::new().unwrap();
+		let db_path = directory.path().join("db");
+
+		let mut store = RocksDbStore::open(&db_path).unwrap();
+		store
+			.save_singleton(&TestStorable { key: "unread", expiry: Duration::ZERO, value: 1 })
+			.await
+			.unwrap();
+		drop(store);
+
+		test_store(RocksDbStore::open(&db_path).unwrap()).await;
+
+		// Expired entries are purged, even if they are never read.
+		let backend = RocksDbStore::open(&db_path).unwrap().backend;
+		let backend = backend.lock().unwrap();
+		assert_eq!(backend.get("unread").unwrap(), None);
+		assert!(backend.get("persistent").unwrap().is_some());
+	}
+
+	fn test_backend_expiry_index<B: EmbeddedBackend>(mut backend: B) {
+		let entry = |value: u32, expires_at: Option<u64>| Entry { value: value.into(), expires_at };
+
+		backend.put("a", &entry(1, Some(10))).unwrap();
+		backend.put("b", &entry(2, Some(20))).unwrap();
+		backend.put("c", &entry(3, Some(10))).unwrap();
+		// Replacing an entry replaces its expiry.
+		backend.put("c", &entry(4, None)).unwrap();
+		backend.put("b", &entry(5, Some(5))).unwrap();
+		backend.remove("a").unwrap();
+		backend.put("a", &entry(6, Some(30))).unwrap();
+
+		backend.remove_expired(9).unwrap();
+		assert_eq!(backend.get("b").unwrap(), None);
+		assert_eq!(backend.get("a").unwrap(), Some(entry(6, Some(30))));
+		assert_eq!(backend.get("c").unwrap(), Some(entry(4, None)));
+
+		backend.remove_expired(u64::MAX).unwrap();
+		assert_eq!(backend.get("a").unwrap(), None);
+		assert_eq!(backend.get("c").unwrap(), Some(entry(4, None)));
+	}
+
+	#[test]
+	fn test_in_memory_backend_expiry_index() {
+		test_backend_expiry_index(InMemoryBackend::default());
+	}
+
+	#[test]
+	fn test_rocksdb_backend_expiry_index() {
+		let directory = tempfile::TempDir::new().unwrap();
+		test_backend_expiry_index(RocksDbBackend::open(&directory.path().join("db")).unwrap());
+	}
+}
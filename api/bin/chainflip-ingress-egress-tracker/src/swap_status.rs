@@ -0,0 +1,966 @@
+use crate::{
+	store::{Storable, Store},
+	witnessing::state_chain::TransactionId,
+};
+use cf_chains::{dot::PolkadotAccountId, SwapOrigin};
+use cf_primitives::{Asset, BlockNumber, BroadcastId, ChannelId, EgressId, ForeignChain, SwapId};
+use pallet_cf_ingress_egress::DepositAction;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use state_chain_runtime::RuntimeEvent;
+use utilities::rpc::NumberOrHex;
+
+/// The lifecycle of every swap made through a single deposit channel, stitched together from
+/// State Chain events. Unlike the witness information, this is never expired.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ChannelSwapStatus {
+	pub chain: ForeignChain,
+	pub channel_id: ChannelId,
+	pub deposit_address: String,
+	pub source_asset: Asset,
+	pub destination_asset: Asset,
+	pub destination_address: String,
+	/// `None` if the channel was opened before the tracker started.
+	pub opened_at: Option<BlockNumber>,
+	pub swaps: Vec<SwapProgress>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SwapState {
+	Scheduled,
+	Executed,
+	EgressScheduled,
+	Broadcast,
+	Confirmed,
+	BroadcastAborted,
+}
+
+/// The progress of a single swap. All `*_at` fields are State Chain block numbers.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SwapProgress {
+	pub swap_id: SwapId,
+	pub state: SwapState,
+	pub deposit: SwapDeposit,
+	pub scheduled_at: BlockNumber,
+	pub execution: Option<SwapExecution>,
+	pub egress: Option<SwapEgress>,
+	pub broadcast: Option<SwapBroadcast>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SwapDeposit {
+	pub amount: NumberOrHex,
+	pub deposit_chain_block_height: u64,
+	/// Only known once the deposit has been processed by the ingress-egress pallet.
+	pub ingress_fee: Option<NumberOrHex>,
+	/// Set if the deposit was boosted, i.e. swapped before it was finalised on the deposit chain.
+	pub boost_fee: Option<NumberOrHex>,
+}
+
+/// For DCA swaps, the input and output are accumulated over all executed chunks.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SwapExecution {
+	pub swap_input: NumberOrHex,
+	pub swap_output: NumberOrHex,
+	pub executed_at: BlockNumber,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SwapEgress {
+	pub egress_id: EgressId,
+	pub amount: NumberOrHex,
+	pub fee: NumberOrHex,
+	/// Whether the swap input was refunded instead of the swap output being egressed.
+	pub refunded: bool,
+	pub scheduled_at: BlockNumber,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SwapBroadcast {
+	pub broadcast_id: BroadcastId,
+	pub requested_at: BlockNumber,
+	pub tx_out_id: Option<TransactionId>,
+	pub confirmed_at: Option<BlockNumber>,
+}
+
+impl ChannelSwapStatus {
+	fn channel_ref(&self) -> ChannelRef {
+		ChannelRef {
+			chain: self.chain,
+			channel_id: self.channel_id,
+			deposit_address: self.deposit_address.clone(),
+			opened_at: self.opened_at,
+		}
+	}
+}
+
+impl Storable for ChannelSwapStatus {
+	fn get_key(&self) -> String {
+		channel_key(&self.channel_ref())
+	}
+}
+
+/// Identifies a single opening of a deposit channel. Channel ids and deposit addresses are both
+/// reused, so the block the channel was opened at is needed to tell the openings apart.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+struct ChannelRef {
+	chain: ForeignChain,
+	channel_id: ChannelId,
+	deposit_address: String,
+	opened_at: Option<BlockNumber>,
+}
+
+/// Identifies a swap within the status of its deposit channel.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+struct SwapRef {
+	channel: ChannelRef,
+	swap_id: SwapId,
+}
+
+/// Points from a secondary key (channel id, deposit address, swap id, egress id, broadcast id)
+/// to the channel status it belongs to.
+#[derive(Serialize, Deserialize)]
+struct SwapStatusIndex<T> {
+	#[serde(skip)]
+	key: String,
+	target: T,
+}
+
+impl<T: Serialize + Sync + 'static> Storable for SwapStatusIndex<T> {
+	fn get_key(&self) -> String {
+		self.key.clone()
+	}
+}
+
+fn channel_key(
+	ChannelRef { chain, channel_id, deposit_address, opened_at }: &ChannelRef,
+) -> String {
+	let opened_at = opened_at.map_or_else(|| "unknown".to_string(), |block| block.to_string());
+	format!("swap_status:{chain}:channel:{channel_id}:{deposit_address}:{opened_at}")
+}
+
+fn channel_id_key(chain: ForeignChain, channel_id: ChannelId) -> String {
+	format!("swap_status:{chain}:channel_id:{channel_id}")
+}
+
+fn address_key(chain: ForeignChain, deposit_address: &str) -> String {
+	format!("swap_status:{chain}:address:{deposit_address}")
+}
+
+fn swap_key(swap_id: SwapId) -> String {
+	format!("swap_status:swap:{swap_id}")
+}
+
+fn egress_key((chain, egress_counter): EgressId) -> String {
+	format!("swap_status:{chain}:egress:{egress_counter}")
+}
+
+fn broadcast_key(chain: ForeignChain, broadcast_id: BroadcastId) -> String {
+	format!("swap_status:{chain}:broadcast:{broadcast_id}")
+}
+
+async fn save_index<S: Store, T: Serialize + Sync + 'static>(
+	store: &mut S,
+	key: String,
+	target: T,
+) -> anyhow::Result<()> {
+	store.save_persistent(&SwapStatusIndex { key, target }).await?;
+	Ok(())
+}
+
+async fn load_index<S: Store, T: DeserializeOwned + Send>(
+	store: &mut S,
+	key: &str,
+) -> anyhow::Result<Option<T>> {
+	Ok(store.load::<SwapStatusIndex<T>>(key).await?.map(|index| index.target))
+}
+
+async fn status_by_channel<S: Store>(
+	store: &mut S,
+	channel: &ChannelRef,
+) -> anyhow::Result<Option<ChannelSwapStatus>> {
+	store.load(&channel_key(channel)).await
+}
+
+/// Makes `channel` the opening that queries by channel id or deposit address resolve to.
+async fn save_latest_opening<S: Store>(store: &mut S, channel: &ChannelRef) -> anyhow::Result<()> {
+	save_index(store, channel_id_key(channel.chain, channel.channel_id), channel.clone()).await?;
+	save_index(store, address_key(channel.chain, &channel.deposit_address), channel.clone()).await
+}
+
+async fn update_swap<S: Store>(
+	store: &mut S,
+	swap: &SwapRef,
+	f: impl FnOnce(&mut SwapProgress) + Send,
+) -> anyhow::Result<()> {
+	if let Some(mut status) = status_by_channel(store, &swap.channel).await? {
+		if let Some(progress) = status.swaps.iter_mut().find(|p| p.swap_id == swap.swap_id) {
+			f(progress);
+			store.save_persistent(&status).await?;
+		}
+	}
+	Ok(())
+}
+
+async fn update_swap_by_id<S: Store>(
+	store: &mut S,
+	swap_id: SwapId,
+	f: impl FnOnce(&mut SwapProgress) + Send,
+) -> anyhow::Result<Option<SwapRef>> {
+	let swap = load_index::<_, SwapRef>(store, &swap_key(swap_id)).await?;
+	if let Some(swap) = &swap {
+		update_swap(store, swap, f).await?;
+	}
+	Ok(swap)
+}
+
+fn add_amounts(a: NumberOrHex, b: NumberOrHex) -> NumberOrHex {
+	u128::try_from(a)
+		.unwrap_or_default()
+		.saturating_add(u128::try_from(b).unwrap_or_default())
+		.into()
+}
+
+async fn schedule_egress<S: Store>(
+	store: &mut S,
+	swap_id: SwapId,
+	egress: SwapEgress,
+) -> anyhow::Result<()> {
+	let egress_id = egress.egress_id;
+	if let Some(swap) = update_swap_by_id(store, swap_id, |progress| {
+		progress.state = SwapState::EgressScheduled;
+		progress.egress = Some(egress);
+	})
+	.await?
+	{
+		save_index(store, egress_key(egress_id), swap).await?;
+	}
+	Ok(())
+}
+
+async fn request_broadcast<S: Store>(
+	store: &mut S,
+	chain: ForeignChain,
+	broadcast_id: BroadcastId,
+	egress_ids: Vec<EgressId>,
+	block_number: BlockNumber,
+) -> anyhow::Result<()> {
+	let mut swaps = Vec::new();
+	for egress_id in egress_ids {
+		if let Some(swap) = load_index::<_, SwapRef>(store, &egress_key(egress_id)).await? {
+			update_swap(store, &swap, |progress| {
+				progress.state = SwapState::Broadcast;
+				progress.broadcast = Some(SwapBroadcast {
+					broadcast_id,
+					requested_at: block_number,
+					tx_out_id: None,
+					confirmed_at: None,
+				});
+			})
+			.await?;
+			swaps.push(swap);
+		}
+	}
+	if !swaps.is_empty() {
+		save_index(store, broadcast_key(chain, broadcast_id), swaps).await?;
+	}
+	Ok(())
+}
+
+async fn complete_broadcast<S: Store>(
+	store: &mut S,
+	chain: ForeignChain,
+	broadcast_id: BroadcastId,
+	tx_out_id: Option<TransactionId>,
+	block_number: BlockNumber,
+) -> anyhow::Result<()> {
+	let swaps = load_index::<_, Vec<SwapRef>>(store, &broadcast_key(chain, broadcast_id))
+		.await?
+		.unwrap_or_default();
+	for swap in swaps {
+		let tx_out_id = tx_out_id.clone();
+		update_swap(store, &swap, move |progress| {
+			progress.state = if tx_out_id.is_some() {
+				SwapState::Confirmed
+			} else {
+				SwapState::BroadcastAborted
+			};
+			if let Some(broadcast) = progress.broadcast.as_mut() {
+				if tx_out_id.is_some() {
+					broadcast.confirmed_at = Some(block_number);
+				}
+				broadcast.tx_out_id = tx_out_id;
+			}
+		})
+		.await?;
+	}
+	Ok(())
+}
+
+/// Errors are logged rather than returned, so that a single failing store write only loses the
+/// update for that event instead of stopping the tracker.
+pub async fn handle_events<S: Store>(
+	events: impl IntoIterator<Item = RuntimeEvent>,
+	block_number: BlockNumber,
+	store: &mut S,
+) {
+	for event in events {
+		if let Err(err) = handle_event(event, block_number, store).await {
+			tracing::error!("Failed to update swap status at block {block_number}: {err:?}");
+		}
+	}
+}
+
+async fn handle_event<S: Store>(
+	event: RuntimeEvent,
+	block_number: BlockNumber,
+	store: &mut S,
+) -> anyhow::Result<()> {
+	use pallet_cf_broadcast::Event as BroadcastEvent;
+	use pallet_cf_ingress_egress::Event as IngressEgressEvent;
+	use pallet_cf_swapping::Event as SwappingEvent;
+	use RuntimeEvent::*;
+
+	match event {
+		Swapping(SwappingEvent::SwapDepositAddressReady {
+			deposit_address,
+			destination_address,
+			source_asset,
+			destination_asset,
+			channel_id,
+			..
+		}) => {
+			let status = ChannelSwapStatus {
+				chain: source_asset.into(),
+				channel_id,
+				deposit_address: deposit_address.to_string(),
+				source_asset,
+				destination_asset,
+				destination_address: destination_address.to_string(),
+				opened_at: Some(block_number),
+				swaps: Vec::new(),
+			};
+			let channel = status.channel_ref();
+			// Never overwrite an existing record, which may already hold swaps.
+			if status_by_channel(store, &channel).await?.is_none() {
+				store.save_persistent(&status).await?;
+			}
+			save_latest_opening(store, &channel).await?;
+		},
+		Swapping(SwappingEvent::SwapScheduled {
+			swap_id,
+			source_asset,
+			deposit_amount,
+			destination_asset,
+			destination_address,
+			origin: SwapOrigin::DepositChannel { deposit_address, channel_id, deposit_block_height },
+			..
+		}) => {
+			let chain = source_asset.into();
+			let deposit_address = deposit_address.to_string();
+			let latest_opening =
+				load_index::<_, ChannelRef>(store, &address_key(chain, &deposit_address))
+					.await?
+					.filter(|channel| channel.channel_id == channel_id);
+			let latest_status = match latest_opening {
+				Some(channel) => status_by_channel(store, &channel).await?,
+				None => None,
+			};
+			let mut status = match latest_status {
+				Some(status) => status,
+				// The channel was opened before the tracker started.
+				None => {
+					let channel =
+						ChannelRef { chain, channel_id, deposit_address, opened_at: None };
+					let status = match status_by_channel(store, &channel).await? {
+						Some(status) => status,
+						None => ChannelSwapStatus {
+							chain,
+							channel_id,
+							deposit_address: channel.deposit_address.clone(),
+							source_asset,
+							destination_asset,
+							destination_address: destination_address.to_string(),
+							opened_at: None,
+							swaps: Vec::new(),
+						},
+					};
+					save_latest_opening(store, &channel).await?;
+					status
+				},
+			};
+			status.swaps.push(SwapProgress {
+				swap_id,
+				state: SwapState::Scheduled,
+				deposit: SwapDeposit {
+					amount: deposit_amount.into(),
+					deposit_chain_block_height: deposit_block_height,
+					ingress_fee: None,
+					boost_fee: None,
+				},
+				scheduled_at: block_number,
+				execution: None,
+				egress: None,
+				broadcast: None,
+			});
+			save_index(
+				store,
+				swap_key(swap_id),
+				SwapRef { channel: status.channel_ref(), swap_id },
+			)
+			.await?;
+			store.save_persistent(&status).await?;
+		},
+		Swapping(SwappingEvent::SwapExecuted { swap_id, swap_input, swap_output, .. }) => {
+			update_swap_by_id(store, swap_id, |progress| {
+				progress.state = SwapState::Executed;
+				progress.execution = Some(match progress.execution.take() {
+					// A previous chunk of a DCA swap.
+					Some(execution) => SwapExecution {
+						swap_input: add_amounts(execution.swap_input, swap_input.into()),
+						swap_output: add_amounts(execution.swap_output, swap_output.into()),
+						executed_at: block_number,
+					},
+					None => SwapExecution {
+						swap_input: swap_input.into(),
+						swap_output: swap_output.into(),
+						executed_at: block_number,
+					},
+				});
+			})
+			.await?;
+		},
+		Swapping(SwappingEvent::SwapEgressScheduled {
+			swap_id, egress_id, amount, fee, ..
+		}) =>
+			schedule_egress(
+				store,
+				swap_id,
+				SwapEgress {
+					egress_id,
+					amount: amount.into(),
+					fee: fee.into(),
+					refunded: false,
+					scheduled_at: block_number,
+				},
+			)
+			.await?,
+		Swapping(SwappingEvent::SwapRefunded { swap_id, egress_id, amount, fee, .. }) =>
+			schedule_egress(
+				store,
+				swap_id,
+				SwapEgress {
+					egress_id,
+					amount: amount.into(),
+					fee: fee.into(),
+					refunded: true,
+					scheduled_at: block_number,
+				},
+			)
+			.await?,
+		EthereumIngressEgress(IngressEgressEvent::DepositReceived {
+			ingress_fee,
+			action:
+				DepositAction::Swap { swap_id } |
+				DepositAction::CcmTransfer { principal_swap_id: Some(swap_id), .. },
+			..
+		}) => {
+			update_swap_by_id(store, swap_id, |progress| {
+				progress.deposit.ingress_fee = Some(ingress_fee.into());
+			})
+			.await?;
+		},
+		PolkadotIngressEgress(IngressEgressEvent::DepositReceived {
+			ingress_fee,
+			action:
+				DepositAction::Swap { swap_id } |
+				DepositAction::CcmTransfer { principal_swap_id: Some(swap_id), .. },
+			..
+		}) => {
+			update_swap_by_id(store, swap_id, |progress| {
+				progress.deposit.ingress_fee = Some(ingress_fee.into());
+			})
+			.await?;
+		},
+		BitcoinIngressEgress(IngressEgressEvent::DepositReceived {
+			ingress_fee,
+			action:
+				DepositAction::Swap { swap_id } |
+				DepositAction::CcmTransfer { principal_swap_id: Some(swap_id), .. },
+			..
+		}) => {
+			update_swap_by_id(store, swap_id, |progress| {
+				progress.deposit.ingress_fee = Some(ingress_fee.into());
+			})
+			.await?;
+		},
+		EthereumIngressEgress(IngressEgressEvent::DepositBoosted {
+			ingress_fee,
+			boost_fee,
+			action:
+				DepositAction::Swap { swap_id } |
+				DepositAction::CcmTransfer { principal_swap_id: Some(swap_id), .. },
+			..
+		}) => {
+			update_swap_by_id(store, swap_id, |progress| {
+				progress.deposit.ingress_fee = Some(ingress_fee.into());
+				progress.deposit.boost_fee = Some(boost_fee.into());
+			})
+			.await?;
+		},
+		PolkadotIngressEgress(IngressEgressEvent::DepositBoosted {
+			ingress_fee,
+			boost_fee,
+			action:
+				DepositAction::Swap { swap_id } |
+				DepositAction::CcmTransfer { principal_swap_id: Some(swap_id), .. },
+			..
+		}) => {
+			update_swap_by_id(store, swap_id, |progress| {
+				progress.deposit.ingress_fee = Some(ingress_fee.into());
+				progress.deposit.boost_fee = Some(boost_fee.into());
+			})
+			.await?;
+		},
+		BitcoinIngressEgress(IngressEgressEvent::DepositBoosted {
+			ingress_fee,
+			boost_fee,
+			action:
+				DepositAction::Swap { swap_id } |
+				DepositAction::CcmTransfer { principal_swap_id: Some(swap_id), .. },
+			..
+		}) => {
+			update_swap_by_id(store, swap_id, |progress| {
+				progress.deposit.ingress_fee = Some(ingress_fee.into());
+				progress.deposit.boost_fee = Some(boost_fee.into());
+			})
+			.await?;
+		},
+		EthereumIngressEgress(IngressEgressEvent::BatchBroadcastRequested {
+			broadcast_id,
+			egress_ids,
+		}) =>
+			request_broadcast(store, ForeignChain::Ethereum, broadcast_id, egress_ids, block_number)
+				.await?,
+		PolkadotIngressEgress(IngressEgressEvent::BatchBroadcastRequested {
+			broadcast_id,
+			egress_ids,
+		}) =>
+			request_broadcast(store, ForeignChain::Polkadot, broadcast_id, egress_ids, block_number)
+				.await?,
+		BitcoinIngressEgress(IngressEgressEvent::BatchBroadcastRequested {
+			broadcast_id,
+			egress_ids,
+		}) =>
+			request_broadcast(store, ForeignChain::Bitcoin, broadcast_id, egress_ids, block_number)
+				.await?,
+		EthereumIngressEgress(IngressEgressEvent::CcmBroadcastRequested {
+			broadcast_id,
+			egress_id,
+		}) =>
+			request_broadcast(
+				store,
+				ForeignChain::Ethereum,
+				broadcast_id,
+				vec![egress_id],
+				block_number,
+			)
+			.await?,
+		EthereumBroadcaster(BroadcastEvent::BroadcastSuccess {
+			broadcast_id,
+			transaction_out_id,
+		}) =>
+			complete_broadcast(
+				store,
+				ForeignChain::Ethereum,
+				broadcast_id,
+				Some(TransactionId::Ethereum { signature: transaction_out_id }),
+				block_number,
+			)
+			.await?,
+		PolkadotBroadcaster(BroadcastEvent::BroadcastSuccess {
+			broadcast_id,
+			transaction_out_id,
+		}) =>
+			complete_broadcast(
+				store,
+				ForeignChain::Polkadot,
+				broadcast_id,
+				Some(TransactionId::Polkadot {
+					signature: format!("0x{}", hex::encode(transaction_out_id.aliased_ref())),
+				}),
+				block_number,
+			)
+			.await?,
+		BitcoinBroadcaster(BroadcastEvent::BroadcastSuccess {
+			broadcast_id,
+			transaction_out_id,
+		}) =>
+			complete_broadcast(
+				store,
+				ForeignChain::Bitcoin,
+				broadcast_id,
+				Some(TransactionId::Bitcoin {
+					hash: format!("0x{}", hex::encode(transaction_out_id)),
+				}),
+				block_number,
+			)
+			.await?,
+		EthereumBroadcaster(BroadcastEvent::BroadcastAborted { broadcast_id }) =>
+			complete_broadcast(store, ForeignChain::Ethereum, broadcast_id, None, block_number)
+				.await?,
+		PolkadotBroadcaster(BroadcastEvent::BroadcastAborted { broadcast_id }) =>
+			complete_broadcast(store, ForeignChain::Polkadot, broadcast_id, None, block_number)
+				.await?,
+		BitcoinBroadcaster(BroadcastEvent::BroadcastAborted { broadcast_id }) =>
+			complete_broadcast(store, ForeignChain::Bitcoin, broadcast_id, None, block_number)
+				.await?,
+		_ => {},
+	}
+
+	Ok(())
+}
+
+/// Channel ids can be reused, so this returns the status of the most recent channel with the
+/// given id.
+pub async fn status_by_channel_id<S: Store>(
+	store: &mut S,
+	chain: ForeignChain,
+	channel_id: ChannelId,
+) -> anyhow::Result<Option<ChannelSwapStatus>> {
+	match load_index::<_, ChannelRef>(store, &channel_id_key(chain, channel_id)).await? {
+		Some(channel) => status_by_channel(store, &channel).await,
+		None => Ok(None),
+	}
+}
+
+/// Deposit addresses are expected in the same format as the `deposit:` keys, except that
+/// Polkadot addresses may also be given in SS58. Since deposit addresses can be recycled, this
+/// returns the status of the most recent channel with the given address.
+pub async fn status_by_deposit_address<S: Store>(
+	store: &mut S,
+	chain: ForeignChain,
+	deposit_address: &str,
+) -> anyhow::Result<Option<ChannelSwapStatus>> {
+	let deposit_address = match chain {
+		ForeignChain::Bitcoin => deposit_address.to_string(),
+		ForeignChain::Polkadot => match deposit_address.parse::<PolkadotAccountId>() {
+			Ok(account_id) => format!("0x{}", hex::encode(account_id.aliased_ref())),
+			Err(_) => deposit_address.to_lowercase(),
+		},
+		ForeignChain::Ethereum => deposit_address.to_lowercase(),
+	};
+
+	match load_index::<_, ChannelRef>(store, &address_key(chain, &deposit_address)).await? {
+		Some(channel) => status_by_channel(store, &channel).await,
+		None => Ok(None),
+	}
+}
+
+pub async fn status_by_swap_id<S: Store>(
+	store: &mut S,
+	swap_id: SwapId,
+) -> anyhow::Result<Option<ChannelSwapStatus>> {
+	match load_index::<_, SwapRef>(store, &swap_key(swap_id)).await? {
+		Some(swap) => status_by_channel(store, &swap.channel).await,
+		None => Ok(None),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::store::test_utils::MockStore;
+	use cf_chains::{
+		address::EncodedAddress, evm::SchnorrVerificationComponents, ForeignChainAddress,
+	};
+	use pallet_cf_swapping::SwapType;
+	use sp_core::H160;
+
+	const SWAP_ID: SwapId = 7;
+	const CHANNEL_ID: ChannelId = 3;
+	const EGRESS_ID: EgressId = (ForeignChain::Ethereum, 11);
+	const BROADCAST_ID: BroadcastId = 5;
+	const DEPOSIT_ADDRESS: [u8; 20] = [0xab; 20];
+
+	fn deposit_address_ready(channel_id: ChannelId) -> RuntimeEvent {
+		RuntimeEvent::Swapping(pallet_cf_swapping::Event::SwapDepositAddressReady {
+			deposit_address: EncodedAddress::Eth(DEPOSIT_ADDRESS),
+			destination_address: EncodedAddress::Eth([2; 20]),
+			source_asset: Asset::Eth,
+			destination_asset: Asset::Flip,
+			channel_id,
+			broker_commission_rate: 0,
+			channel_metadata: None,
+			source_chain_expiry_block: 200,
+			boost_fee: 0,
+			refund_parameters: None,
+			dca_parameters: None,
+		})
+	}
+
+	fn swap_scheduled(swap_id: SwapId, channel_id: ChannelId) -> RuntimeEvent {
+		RuntimeEvent::Swapping(pallet_cf_swapping::Event::SwapScheduled {
+			swap_id,
+			source_asset: Asset::Eth,
+			deposit_amount: 1_000,
+			destination_asset: Asset::Flip,
+			destination_address: EncodedAddress::Eth([2; 20]),
+			origin: SwapOrigin::DepositChannel {
+				deposit_address: EncodedAddress::Eth(DEPOSIT_ADDRESS),
+				channel_id,
+				deposit_block_height: 100,
+			},
+			swap_type: SwapType::Swap(ForeignChainAddress::Eth(H160::from([2; 20]))),
+			broker_commission: None,
+			execute_at: 3,
+		})
+	}
+
+	#[allow(deprecated)]
+	fn swap_executed(chunk_index: u32, swap_input: u128, swap_output: u128) -> RuntimeEvent {
+		RuntimeEvent::Swapping(pallet_cf_swapping::Event::SwapExecuted {
+			swap_id: SWAP_ID,
+			source_asset: Asset::Eth,
+			deposit_amount: swap_input,
+			swap_input,
+			destination_asset: Asset::Flip,
+			egress_amount: swap_output,
+			swap_output,
+			intermediate_amount: None,
+			dca_chunk: Some(pallet_cf_swapping::DcaChunk {
+				index: chunk_index,
+				number_of_chunks: 2,
+			}),
+		})
+	}
+
+	#[tokio::test]
+	async fn test_swap_lifecycle_is_stitched_together() {
+		let mut store = MockStore::default();
+
+		handle_events([deposit_address_ready(CHANNEL_ID)], 1, &mut store).await;
+
+		handle_events(
+			[
+				swap_scheduled(SWAP_ID, CHANNEL_ID),
+				RuntimeEvent::EthereumIngressEgress(
+					pallet_cf_ingress_egress::Event::DepositReceived {
+						deposit_address: H160::from(DEPOSIT_ADDRESS),
+						asset: cf_chains::assets::eth::Asset::Eth,
+						amount: 1_010,
+						deposit_details: (),
+						ingress_fee: 10,
+						action: DepositAction::Swap { swap_id: SWAP_ID },
+					},
+				),
+			],
+			2,
+			&mut store,
+		)
+		.await;
+
+		let status = status_by_swap_id(&mut store, SWAP_ID).await.unwrap().unwrap();
+		assert_eq!(status.opened_at, Some(1));
+		assert_eq!(status.swaps.len(), 1);
+		assert_eq!(status.swaps[0].state, SwapState::Scheduled);
+		assert_eq!(status.swaps[0].deposit.ingress_fee, Some(10u128.into()));
+
+		// Two chunks of a DCA swap.
+		handle_events([swap_executed(0, 500, 2_000)], 3, &mut store).await;
+		handle_events(
+			[
+				swap_executed(1, 500, 1_900),
+				RuntimeEvent::Swapping(pallet_cf_swapping::Event::SwapEgressScheduled {
+					swap_id: SWAP_ID,
+					egress_id: EGRESS_ID,
+					asset: Asset::Flip,
+					amount: 3_800,
+					fee: 100,
+				}),
+			],
+			4,
+			&mut store,
+		)
+		.await;
+		handle_events(
+			[RuntimeEvent::EthereumIngressEgress(
+				pallet_cf_ingress_egress::Event::BatchBroadcastRequested {
+					broadcast_id: BROADCAST_ID,
+					egress_ids: vec![EGRESS_ID],
+				},
+			)],
+			5,
+			&mut store,
+		)
+		.await;
+
+		let status = status_by_channel_id(&mut store, ForeignChain::Ethereum, CHANNEL_ID)
+			.await
+			.unwrap()
+			.unwrap();
+		assert_eq!(status.swaps[0].state, SwapState::Broadcast);
+		let execution = status.swaps[0].execution.clone().unwrap();
+		assert_eq!(u128::try_from(execution.swap_input).unwrap(), 1_000);
+		assert_eq!(u128::try_from(execution.swap_output).unwrap(), 3_900);
+		assert_eq!(execution.executed_at, 4);
+
+		let tx_out_id = SchnorrVerificationComponents { s: [1; 32], k_times_g_address: [2; 20] };
+		handle_events(
+			[RuntimeEvent::EthereumBroadcaster(pallet_cf_broadcast::Event::BroadcastSuccess {
+				broadcast_id: BROADCAST_ID,
+				transaction_out_id: tx_out_id,
+			})],
+			6,
+			&mut store,
+		)
+		.await;
+
+		let status = status_by_deposit_address(
+			&mut store,
+			ForeignChain::Ethereum,
+			&format!("0x{}", hex::encode(DEPOSIT_ADDRESS).to_uppercase()),
+		)
+		.await
+		.unwrap()
+		.unwrap();
+		let swap = &status.swaps[0];
+		assert_eq!(swap.state, SwapState::Confirmed);
+		assert_eq!(
+			swap.egress,
+			Some(SwapEgress {
+				egress_id: EGRESS_ID,
+				amount: 3_800u128.into(),
+				fee: 100u128.into(),
+				refunded: false,
+				scheduled_at: 4,
+			})
+		);
+		assert_eq!(
+			swap.broadcast,
+			Some(SwapBroadcast {
+				broadcast_id: BROADCAST_ID,
+				requested_at: 5,
+				tx_out_id: Some(TransactionId::Ethereum { signature: tx_out_id }),
+				confirmed_at: Some(6),
+			})
+		);
+	}
+
+	#[tokio::test]
+	async fn test_swaps_for_channels_opened_before_startup_are_tracked() {
+		let mut store = MockStore::default();
+
+		handle_events([swap_scheduled(SWAP_ID, CHANNEL_ID)], 2, &mut store).await;
+
+		let status = status_by_deposit_address(
+			&mut store,
+			ForeignChain::Ethereum,
+			&format!("0x{}", hex::encode(DEPOSIT_ADDRESS)),
+		)
+		.await
+		.unwrap()
+		.unwrap();
+		assert_eq!(status.channel_id, CHANNEL_ID);
+		assert_eq!(status.opened_at, None);
+		assert_eq!(status.swaps[0].swap_id, SWAP_ID);
+
+		assert!(status_by_swap_id(&mut store, SWAP_ID + 1).await.unwrap().is_none());
+	}
+
+	#[tokio::test]
+	async fn test_reopened_channels_do_not_overwrite_previous_swaps() {
+		let mut store = MockStore::default();
+
+		handle_events([deposit_address_ready(CHANNEL_ID)], 1, &mut store).await;
+		handle_events(
+			[
+				swap_scheduled(SWAP_ID, CHANNEL_ID),
+				RuntimeEvent::Swapping(pallet_cf_swapping::Event::SwapEgressScheduled {
+					swap_id: SWAP_ID,
+					egress_id: EGRESS_ID,
+					asset: Asset::Flip,
+					amount: 3_800,
+					fee: 100,
+				}),
+			],
+			2,
+			&mut store,
+		)
+		.await;
+
+		// The same channel id and address are handed out again.
+		handle_events([deposit_address_ready(CHANNEL_ID)], 10, &mut store).await;
+
+		let status = status_by_channel_id(&mut store, ForeignChain::Ethereum, CHANNEL_ID)
+			.await
+			.unwrap()
+			.unwrap();
+		assert_eq!(status.opened_at, Some(10));
+		assert!(status.swaps.is_empty());
+
+		// Egresses of the previous opening still update its own record.
+		handle_events(
+			[
+				RuntimeEvent::EthereumIngressEgress(
+					pallet_cf_ingress_egress::Event::BatchBroadcastRequested {
+						broadcast_id: BROADCAST_ID,
+						egress_ids: vec![EGRESS_ID],
+					},
+				),
+				swap_scheduled(SWAP_ID + 1, CHANNEL_ID),
+			],
+			11,
+			&mut store,
+		)
+		.await;
+
+		let previous = status_by_swap_id(&mut store, SWAP_ID).await.unwrap().unwrap();
+		assert_eq!(previous.opened_at, Some(1));
+		assert_eq!(previous.swaps.len(), 1);
+		assert_eq!(previous.swaps[0].state, SwapState::Broadcast);
+
+		let current = status_by_deposit_address(
+			&mut store,
+			ForeignChain::Ethereum,
+			&format!("0x{}", hex::encode(DEPOSIT_ADDRESS)),
+		)
+		.await
+		.unwrap()
+		.unwrap();
+		assert_eq!(current.opened_at, Some(10));
+		assert_eq!(current.swaps.len(), 1);
+		assert_eq!(current.swaps[0].swap_id, SWAP_ID + 1);
+		assert_eq!(status_by_swap_id(&mut store, SWAP_ID + 1).await.unwrap(), Some(current));
+	}
+
+	#[tokio::test]
+	async fn test_boosted_deposits_are_tracked() {
+		let mut store = MockStore::default();
+
+		handle_events(
+			[
+				swap_scheduled(SWAP_ID, CHANNEL_ID),
+				RuntimeEvent::EthereumIngressEgress(
+					pallet_cf_ingress_egress::Event::DepositBoosted {
+						deposit_address: H160::from(DEPOSIT_ADDRESS),
+						asset: cf_chains::assets::eth::Asset::Eth,
+						amounts: [(5, 1_010)].into_iter().collect(),
+						prewitnessed_deposit_id: 1,
+						deposit_details: (),
+						ingress_fee: 10,
+						boost_fee: 5,
+						action: DepositAction::Swap { swap_id: SWAP_ID },
+					},
+				),
+			],
+			2,
+			&mut store,
+		)
+		.await;
+
+		let status = status_by_swap_id(&mut store, SWAP_ID).await.unwrap().unwrap();
+		assert_eq!(status.swaps[0].deposit.ingress_fee, Some(10u128.into()));
+		assert_eq!(status.swaps[0].deposit.boost_fee, Some(5u128.into()));
+	}
+}
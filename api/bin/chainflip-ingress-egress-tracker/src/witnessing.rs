@@ -5,8 +5,7 @@ mod eth;
 pub mod state_chain;
 
 use self::state_chain::handle_call;
-use crate::{settings::DepositTrackerSettings, store::RedisStore};
-use anyhow::anyhow;
+use crate::{settings::DepositTrackerSettings, store::Store, swap_status};
 use cf_chains::dot::PolkadotHash;
 use cf_primitives::{chains::assets::eth::Asset, NetworkEnvironment};
 use chainflip_engine::{
@@ -18,6 +17,7 @@ use chainflip_engine::{
 	},
 	witness::common::epoch_source::EpochSource,
 };
+use futures::StreamExt;
 use sp_core::H160;
 use std::{collections::HashMap, ops::Deref};
 use utilities::task_scope;
@@ -102,10 +102,10 @@ async fn get_env_parameters(state_chain_client: &StateChainClient<()>) -> Enviro
 	}
 }
 
-pub(super) async fn start(
+pub(super) async fn start<S: Store + Clone>(
 	scope: &task_scope::Scope<'_, anyhow::Error>,
 	settings: DepositTrackerSettings,
-	store: RedisStore,
+	store: S,
 ) -> anyhow::Result<EnvironmentParameters> {
 	let (state_chain_stream, unfinalized_chain_stream, state_chain_client) = {
 		state_chain_observer::client::StateChainClient::connect_without_account(
@@ -122,6 +122,33 @@ pub(super) async fn start(
 	let epoch_source =
 		EpochSource::builder(scope, state_chain_stream.clone(), state_chain_client.clone()).await;
 
+	scope.spawn({
+		let state_chain_client = state_chain_client.clone();
+		let mut state_chain_stream = state_chain_stream.clone();
+		let mut store = store.clone();
+		async move {
+			while let Some(block) = state_chain_stream.next().await {
+				match state_chain_client
+					.storage_value::<frame_system::Events<state_chain_runtime::Runtime>>(block.hash)
+					.await
+				{
+					Ok(events) =>
+						swap_status::handle_events(
+							events.into_iter().map(|event_record| event_record.event),
+							block.number,
+							&mut store,
+						)
+						.await,
+					Err(err) => tracing::error!(
+						"Failed to fetch the events of block {}, swap statuses will not be updated: {err:?}",
+						block.number
+					),
+				}
+			}
+			Ok(())
+		}
+	});
+
 	let witness_call = {
 		let state_chain_client = state_chain_client.clone();
 		move |call: state_chain_runtime::RuntimeCall, _epoch_index| {
@@ -129,10 +156,12 @@ pub(super) async fn start(
 			let state_chain_client = state_chain_client.clone();
 
 			async move {
-				handle_call(call, &mut store, chainflip_network, state_chain_client.deref())
-					.await
-					.map_err(|err| anyhow!("failed to handle call: {err:?}"))
-					.unwrap()
+				if let Err(err) =
+					handle_call(call, &mut store, chainflip_network, state_chain_client.deref())
+						.await
+				{
+					tracing::error!("Failed to handle call: {err:?}");
+				}
 			}
 		}
 	};
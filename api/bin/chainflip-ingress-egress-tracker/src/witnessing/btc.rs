@@ -38,7 +38,7 @@ where
 {
 	let btc_client = BtcRetryRpcClient::new(
 		scope,
-		NodeContainer { primary: settings.btc, backup: None },
+		NodeContainer { primary: settings.btc, backup: None, additional: vec![], quorum: 1 },
 		env_params.chainflip_network.into(),
 	)
 	.await?;
@@ -60,7 +60,7 @@ where
 		.chunk_by_vault(vaults, scope)
 		.deposit_addresses(scope, state_chain_stream.clone(), state_chain_client.clone())
 		.await
-		.btc_deposits(witness_call.clone())
+		.btc_deposits(witness_call.clone(), |_call, _epoch_index| futures::future::ready(()))
 		.egress_items(scope, state_chain_stream, state_chain_client)
 		.await
 		.then(move |epoch, header| process_egress(epoch, header, witness_call.clone()))
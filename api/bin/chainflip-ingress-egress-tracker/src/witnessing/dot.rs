@@ -40,7 +40,7 @@ where
 {
 	let dot_client = DotRetryRpcClient::new(
 		scope,
-		NodeContainer { primary: settings.dot, backup: None },
+		NodeContainer { primary: settings.dot, backup: None, additional: vec![], quorum: 1 },
 		env_params.dot_genesis_hash,
 	)?;
 
@@ -43,7 +43,12 @@ where
 	ProcessingFut: futures::Future<Output = ()> + Send + 'static,
 {
 	let eth_client = {
-		let nodes = NodeContainer { primary: settings.eth.clone(), backup: None };
+		let nodes = NodeContainer {
+			primary: settings.eth.clone(),
+			backup: None,
+			additional: vec![],
+			quorum: 1,
+		};
 
 		EthRetryRpcClient::<EthRpcClient>::new(scope, nodes, env_params.eth_chain_id.into())?
 	};
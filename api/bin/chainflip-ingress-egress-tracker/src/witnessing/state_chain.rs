@@ -11,12 +11,12 @@ use chainflip_engine::state_chain_observer::client::{
 	chain_api::ChainApi, storage_api::StorageApi,
 };
 use pallet_cf_ingress_egress::DepositWitness;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use utilities::rpc::NumberOrHex;
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 #[serde(untagged)]
-enum TransactionId {
+pub enum TransactionId {
 	Bitcoin { hash: String },
 	Ethereum { signature: SchnorrVerificationComponents },
 	Polkadot { signature: String },
@@ -109,6 +109,13 @@ impl From<DepositInfo<Polkadot>> for WitnessInformation {
 	}
 }
 
+/// A failing write only loses this deposit, the remaining deposits of the call are still saved.
+async fn save_deposit<S: Store>(store: &mut S, deposit: WitnessInformation) {
+	if let Err(err) = store.save_to_array(&deposit).await {
+		tracing::error!("Failed to save deposit {}: {err:?}", deposit.get_key());
+	}
+}
+
 pub async fn handle_call<S, StateChainClient>(
 	call: state_chain_runtime::RuntimeCall,
 	store: &mut S,
@@ -129,39 +136,33 @@ where
 			block_height,
 		}) =>
 			for witness in deposit_witnesses as Vec<DepositWitness<Ethereum>> {
-				store
-					.save_to_array(&WitnessInformation::from((
-						witness,
-						block_height,
-						chainflip_network,
-					)))
-					.await?;
+				save_deposit(
+					store,
+					WitnessInformation::from((witness, block_height, chainflip_network)),
+				)
+				.await;
 			},
 		BitcoinIngressEgress(IngressEgressCall::process_deposits {
 			deposit_witnesses,
 			block_height,
 		}) =>
 			for witness in deposit_witnesses as Vec<DepositWitness<Bitcoin>> {
-				store
-					.save_to_array(&WitnessInformation::from((
-						witness,
-						block_height,
-						chainflip_network,
-					)))
-					.await?;
+				save_deposit(
+					store,
+					WitnessInformation::from((witness, block_height, chainflip_network)),
+				)
+				.await;
 			},
 		PolkadotIngressEgress(IngressEgressCall::process_deposits {
 			deposit_witnesses,
 			block_height,
 		}) =>
 			for witness in deposit_witnesses as Vec<DepositWitness<Polkadot>> {
-				store
-					.save_to_array(&WitnessInformation::from((
-						witness,
-						block_height,
-						chainflip_network,
-					)))
-					.await?;
+				save_deposit(
+					store,
+					WitnessInformation::from((witness, block_height, chainflip_network)),
+				)
+				.await;
 			},
 		EthereumBroadcaster(BroadcastCall::transaction_succeeded { tx_out_id, .. }) => {
 			let broadcast_id =
@@ -249,7 +250,7 @@ where
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use anyhow::anyhow;
+	use crate::store::test_utils::MockStore;
 	use async_trait::async_trait;
 	use cf_chains::{
 		dot::PolkadotAccountId,
@@ -270,44 +271,6 @@ mod tests {
 	use pallet_cf_ingress_egress::DepositWitness;
 	use sp_core::{storage::StorageKey, H160};
 	use state_chain_runtime::PalletInstanceAlias;
-	use std::collections::HashMap;
-
-	#[derive(Default)]
-	struct MockStore {
-		storage: HashMap<String, serde_json::Value>,
-	}
-
-	#[async_trait]
-	impl Store for MockStore {
-		type Output = ();
-
-		async fn save_to_array<S: Storable>(
-			&mut self,
-			storable: &S,
-		) -> anyhow::Result<Self::Output> {
-			let key = storable.get_key();
-			let value = serde_json::to_value(storable)?;
-
-			let array = self.storage.entry(key).or_insert(serde_json::Value::Array(vec![]));
-
-			array.as_array_mut().ok_or(anyhow!("expect array"))?.push(value);
-
-			Ok(())
-		}
-
-		async fn save_singleton<S: Storable>(
-			&mut self,
-			storable: &S,
-		) -> anyhow::Result<Self::Output> {
-			let key = storable.get_key();
-
-			let value = serde_json::to_value(storable)?;
-
-			self.storage.insert(key, value);
-
-			Ok(())
-		}
-	}
 
 	mock! {
 		pub StateChainClient {}
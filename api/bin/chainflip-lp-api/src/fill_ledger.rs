@@ -0,0 +1,580 @@
+use crate::{order_fills, OrderFilled};
+use anyhow::{anyhow, Context};
+use cf_primitives::{AccountId, BlockNumber};
+use chainflip_api::{
+	lp::Side,
+	primitives::{chains::assets::any::OldAsset, state_chain_runtime::Runtime, Asset},
+	BaseRpcApi, BlockInfo, ChainApi, StateChainClient, StorageApi,
+};
+use futures::StreamExt;
+use pallet_cf_pools::{IncreaseOrDecrease, OrderId};
+use rocksdb::{Direction, IteratorMode, WriteBatch, DB};
+use serde::{Deserialize, Serialize};
+use sp_core::U256;
+use std::{collections::BTreeMap, path::Path, sync::Arc};
+use tracing::log;
+
+/// Fills keyed by LP, then by block, then by their index within the block.
+const FILL_PREFIX: u8 = b'f';
+/// The same fills keyed by LP and pool first, so that queries for a single pool don't scan the
+/// others.
+const POOL_FILL_PREFIX: u8 = b'p';
+/// Block numbers keyed by their timestamp, to turn time ranges into block ranges.
+const BLOCK_TIMESTAMP_PREFIX: u8 = b't';
+/// Followed by the LP, since each LP's fills are recorded independently.
+const LAST_RECORDED_BLOCK_KEY: &[u8] = b"last_recorded_block";
+
+/// A fill of one of the LP's orders, as reported by `order_fills`.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FillRecord {
+	pub block_number: BlockNumber,
+	/// Unix timestamp of the block, in milliseconds.
+	pub timestamp: u64,
+	pub fill: OrderFilled,
+}
+
+impl FillRecord {
+	fn assets(&self) -> (OldAsset, OldAsset) {
+		match self.fill {
+			OrderFilled::LimitOrder { base_asset, quote_asset, .. } |
+			OrderFilled::RangeOrder { base_asset, quote_asset, .. } => (base_asset, quote_asset),
+		}
+	}
+
+	fn id(&self) -> U256 {
+		match self.fill {
+			OrderFilled::LimitOrder { id, .. } | OrderFilled::RangeOrder { id, .. } => id,
+		}
+	}
+}
+
+/// Filters for querying the ledger. All filters are optional and inclusive.
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct FillQuery {
+	pub from_block: Option<BlockNumber>,
+	pub to_block: Option<BlockNumber>,
+	/// Unix timestamp, in milliseconds.
+	pub from_timestamp: Option<u64>,
+	/// Unix timestamp, in milliseconds.
+	pub to_timestamp: Option<u64>,
+	pub base_asset: Option<Asset>,
+	pub quote_asset: Option<Asset>,
+	pub order_id: Option<OrderId>,
+}
+
+impl FillQuery {
+	fn matches(&self, record: &FillRecord) -> bool {
+		let (base_asset, quote_asset) = record.assets();
+		self.from_timestamp.map_or(true, |from| record.timestamp >= from) &&
+			self.to_timestamp.map_or(true, |to| record.timestamp <= to) &&
+			self.base_asset.map_or(true, |asset| asset == base_asset.into()) &&
+			self.quote_asset.map_or(true, |asset| asset == quote_asset.into()) &&
+			self.order_id.map_or(true, |id| U256::from(id) == record.id())
+	}
+}
+
+/// The totals of a set of fills for a single asset.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AssetSummary {
+	pub asset: OldAsset,
+	/// Fees earned by limit and range orders.
+	pub fees: U256,
+	/// The amount sold by limit and range orders.
+	pub sold: U256,
+	/// The amount bought by limit and range orders, excluding fees.
+	pub bought: U256,
+	/// The net change in inventory, i.e. the amount bought plus fees minus the amount sold.
+	pub inventory_change: IncreaseOrDecrease<U256>,
+}
+
+pub fn summarise<'a>(records: impl IntoIterator<Item = &'a FillRecord>) -> Vec<AssetSummary> {
+	#[derive(Default)]
+	struct Totals {
+		fees: U256,
+		sold: U256,
+		bought: U256,
+	}
+
+	let mut totals = BTreeMap::<Asset, Totals>::new();
+	for record in records {
+		match record.fill {
+			OrderFilled::LimitOrder {
+				base_asset, quote_asset, side, sold, bought, fees, ..
+			} => {
+				let (sold_asset, bought_asset) = match side {
+					Side::Sell => (base_asset, quote_asset),
+					Side::Buy => (quote_asset, base_asset),
+				};
+				totals.entry(sold_asset.into()).or_default().sold += sold;
+				let bought_totals = totals.entry(bought_asset.into()).or_default();
+				bought_totals.bought += bought;
+				bought_totals.fees += fees;
+			},
+			OrderFilled::RangeOrder { base_asset, quote_asset, fees, sold, bought, .. } => {
+				let base_totals = totals.entry(base_asset.into()).or_default();
+				base_totals.fees += fees.base;
+				base_totals.sold += sold.base;
+				base_totals.bought += bought.base;
+				let quote_totals = totals.entry(quote_asset.into()).or_default();
+				quote_totals.fees += fees.quote;
+				quote_totals.sold += sold.quote;
+				quote_totals.bought += bought.quote;
+			},
+		}
+	}
+
+	totals
+		.into_iter()
+		.map(|(asset, Totals { fees, sold, bought })| {
+			let gained = bought + fees;
+			AssetSummary {
+				asset: asset.into(),
+				fees,
+				sold,
+				bought,
+				inventory_change: if gained >= sold {
+					IncreaseOrDecrease::Increase(gained - sold)
+				} else {
+					IncreaseOrDecrease::Decrease(sold - gained)
+				},
+			}
+		})
+		.collect()
+}
+
+fn fill_prefix(lp: &AccountId) -> Vec<u8> {
+	[&[FILL_PREFIX][..], lp.as_ref()].concat()
+}
+
+fn fill_key(lp: &AccountId, block_number: BlockNumber, index: u32) -> Vec<u8> {
+	[fill_prefix(lp), block_number.to_be_bytes().to_vec(), index.to_be_bytes().to_vec()].concat()
+}
+
+fn pool_prefix(lp: &AccountId, base_asset: Asset, quote_asset: Asset) -> Vec<u8> {
+	[
+		&[POOL_FILL_PREFIX][..],
+		lp.as_ref(),
+		&(base_asset as u32).to_be_bytes(),
+		&(quote_asset as u32).to_be_bytes(),
+	]
+	.concat()
+}
+
+fn pool_fill_key(
+	lp: &AccountId,
+	base_asset: Asset,
+	quote_asset: Asset,
+	block_number: BlockNumber,
+	index: u32,
+) -> Vec<u8> {
+	[
+		pool_prefix(lp, base_asset, quote_asset),
+		block_number.to_be_bytes().to_vec(),
+		index.to_be_bytes().to_vec(),
+	]
+	.concat()
+}
+
+fn last_recorded_block_key(lp: &AccountId) -> Vec<u8> {
+	[LAST_RECORDED_BLOCK_KEY, lp.as_ref()].concat()
+}
+
+fn block_timestamp_key(timestamp: u64, block_number: BlockNumber) -> Vec<u8> {
+	[&[BLOCK_TIMESTAMP_PREFIX][..], &timestamp.to_be_bytes(), &block_number.to_be_bytes()].concat()
+}
+
+/// Decodes the big-endian block number at the end of a key.
+fn block_number_suffix(key: &[u8]) -> anyhow::Result<BlockNumber> {
+	Ok(BlockNumber::from_be_bytes(
+		key.get(key.len().saturating_sub(4)..)
+			.and_then(|bytes| bytes.try_into().ok())
+			.ok_or_else(|| anyhow!("Invalid key"))?,
+	))
+}
+
+/// A persistent record of the fills of the LP's orders, indexed by block. The database can be
+/// shared by several LPs: all fills are keyed by LP, and only the LP's own fills are recorded and
+/// returned.
+#[derive(Clone)]
+pub struct FillLedger {
+	db: Arc<DB>,
+	lp: AccountId,
+}
+
+impl FillLedger {
+	pub fn open(db_path: &Path, lp: AccountId) -> anyhow::Result<Self> {
+		Ok(Self {
+			db: Arc::new(DB::open_default(db_path).with_context(|| {
+				format!("Failed to open fill ledger at: {}", db_path.display())
+			})?),
+			lp,
+		})
+	}
+
+	pub fn last_recorded_block(&self) -> anyhow::Result<Option<BlockNumber>> {
+		self.db
+			.get(last_recorded_block_key(&self.lp))?
+			.map(|data| {
+				Ok(BlockNumber::from_be_bytes(
+					data.try_into().map_err(|_| anyhow!("Invalid last recorded block"))?,
+				))
+			})
+			.transpose()
+	}
+
+	/// Records the LP's fills among the fills of a block. Blocks must be recorded in order.
+	pub fn record_block(
+		&self,
+		block_number: BlockNumber,
+		timestamp: u64,
+		fills: Vec<OrderFilled>,
+	) -> anyhow::Result<()> {
+		let mut batch = WriteBatch::default();
+		for (index, fill) in fills
+			.into_iter()
+			.filter(|fill| match fill {
+				OrderFilled::LimitOrder { lp, .. } | OrderFilled::RangeOrder { lp, .. } =>
+					*lp == self.lp,
+			})
+			.enumerate()
+		{
+			let record = FillRecord { block_number, timestamp, fill };
+			let (base_asset, quote_asset) = record.assets();
+			let data = serde_json::to_vec(&record)?;
+			batch.put(
+				pool_fill_key(
+					&self.lp,
+					base_asset.into(),
+					quote_asset.into(),
+					block_number,
+					index as u32,
+				),
+				&data,
+			);
+			batch.put(fill_key(&self.lp, block_number, index as u32), data);
+		}
+		batch.put(block_timestamp_key(timestamp, block_number), b"");
+		batch.put(last_recorded_block_key(&self.lp), block_number.to_be_bytes());
+		self.db.write(batch).context("Failed to write fills to ledger.")
+	}
+
+	/// The first block at or after the given timestamp.
+	fn first_block_from(&self, timestamp: u64) -> anyhow::Result<Option<BlockNumber>> {
+		match self
+			.db
+			.iterator(IteratorMode::From(&block_timestamp_key(timestamp, 0), Direction::Forward))
+			.next()
+			.transpose()?
+		{
+			Some((key, _)) if key.first() == Some(&BLOCK_TIMESTAMP_PREFIX) =>
+				Ok(Some(block_number_suffix(&key)?)),
+			_ => Ok(None),
+		}
+	}
+
+	/// The last block at or before the given timestamp.
+	fn last_block_until(&self, timestamp: u64) -> anyhow::Result<Option<BlockNumber>> {
+		match self
+			.db
+			.iterator(IteratorMode::From(
+				&block_timestamp_key(timestamp, BlockNumber::MAX),
+				Direction::Reverse,
+			))
+			.next()
+			.transpose()?
+		{
+			Some((key, _)) if key.first() == Some(&BLOCK_TIMESTAMP_PREFIX) =>
+				Ok(Some(block_number_suffix(&key)?)),
+			_ => Ok(None),
+		}
+	}
+
+	/// Only the LP's records within the queried block range, and the queried pool if both assets
+	/// are given, are read. Time ranges are converted to block ranges first.
+	pub fn fills(&self, query: &FillQuery) -> anyhow::Result<Vec<FillRecord>> {
+		let mut from_block = query.from_block.unwrap_or_default();
+		let mut to_block = query.to_block.unwrap_or(BlockNumber::MAX);
+		if let Some(from_timestamp) = query.from_timestamp {
+			match self.first_block_from(from_timestamp)? {
+				Some(block_number) => from_block = from_block.max(block_number),
+				None => return Ok(Vec::new()),
+			}
+		}
+		if let Some(to_timestamp) = query.to_timestamp {
+			match self.last_block_until(to_timestamp)? {
+				Some(block_number) => to_block = to_block.min(block_number),
+				None => return Ok(Vec::new()),
+			}
+		}
+
+		let (prefix, start_key) = match (query.base_asset, query.quote_asset) {
+			(Some(base_asset), Some(quote_asset)) => (
+				pool_prefix(&self.lp, base_asset, quote_asset),
+				pool_fill_key(&self.lp, base_asset, quote_asset, from_block, 0),
+			),
+			_ => (fill_prefix(&self.lp), fill_key(&self.lp, from_block, 0)),
+		};
+
+		let mut records = Vec::new();
+		for item in self.db.iterator(IteratorMode::From(&start_key, Direction::Forward)) {
+			let (key, data) = item?;
+			if !key.starts_with(&prefix) {
+				break
+			}
+			let record: FillRecord =
+				serde_json::from_slice(&data).context("Deserialization failed")?;
+			if record.block_number > to_block {
+				break
+			}
+			if query.matches(&record) {
+				records.push(record);
+			}
+		}
+		Ok(records)
+	}
+}
+
+async fn record_block(
+	state_chain_client: &Arc<StateChainClient>,
+	ledger: &FillLedger,
+	block: BlockInfo,
+) -> anyhow::Result<()> {
+	let (order_fills, timestamp) = futures::try_join!(
+		order_fills(state_chain_client.clone(), block),
+		state_chain_client.storage_value::<pallet_timestamp::Now<Runtime>>(block.hash),
+	)?;
+
+	ledger.record_block(block.number, timestamp, order_fills.data.fills)
+}
+
+/// Records the fills of the LP's orders in every finalized block. If the ledger has fallen behind,
+/// for example because the LP API was stopped, the missed blocks are recorded first. This requires
+/// the state of those blocks to be available, i.e. they must not have been pruned by the node.
+pub async fn run(
+	state_chain_client: Arc<StateChainClient>,
+	ledger: FillLedger,
+) -> anyhow::Result<()> {
+	let mut finalized_block_stream = state_chain_client.finalized_block_stream().await;
+	let mut next_block = match ledger.last_recorded_block()? {
+		Some(last_recorded_block) => last_recorded_block + 1,
+		None => finalized_block_stream.cache().number,
+	};
+	log::info!("Recording order fills from block {next_block}.");
+
+	while let Some(block) = finalized_block_stream.next().await {
+		for block_number in next_block..block.number {
+			let block_hash = state_chain_client
+				.base_rpc_client
+				.block_hash(block_number)
+				.await?
+				.ok_or_else(|| anyhow!("Block {block_number} not found"))?;
+			record_block(&state_chain_client, &ledger, state_chain_client.block(block_hash).await?)
+				.await?;
+		}
+		if block.number >= next_block {
+			record_block(&state_chain_client, &ledger, block).await?;
+			next_block = block.number + 1;
+		}
+	}
+
+	Err(anyhow!("Finalized block stream ended"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chainflip_api::lp::PoolPairsMap;
+
+	fn record(fill: OrderFilled) -> FillRecord {
+		FillRecord { block_number: 1, timestamp: 0, fill }
+	}
+
+	#[test]
+	fn test_summarise() {
+		let lp = AccountId::new([1; 32]);
+		let records = [
+			record(OrderFilled::LimitOrder {
+				lp: lp.clone(),
+				base_asset: Asset::Eth.into(),
+				quote_asset: Asset::Usdc.into(),
+				side: Side::Sell,
+				id: 0.into(),
+				tick: 0,
+				sold: 100.into(),
+				bought: 200.into(),
+				fees: 2.into(),
+				remaining: 0.into(),
+			}),
+			record(OrderFilled::LimitOrder {
+				lp: lp.clone(),
+				base_asset: Asset::Eth.into(),
+				quote_asset: Asset::Usdc.into(),
+				side: Side::Buy,
+				id: 1.into(),
+				tick: 0,
+				sold: 300.into(),
+				bought: 150.into(),
+				fees: 1.into(),
+				remaining: 0.into(),
+			}),
+			record(OrderFilled::RangeOrder {
+				lp,
+				base_asset: Asset::Eth.into(),
+				quote_asset: Asset::Usdc.into(),
+				id: 0.into(),
+				range: -10..10,
+				fees: PoolPairsMap { base: 3.into(), quote: 4.into() },
+				sold: PoolPairsMap { base: 0.into(), quote: 50.into() },
+				bought: PoolPairsMap { base: 20.into(), quote: 0.into() },
+				liquidity: 1_000.into(),
+			}),
+		];
+
+		let summary = summarise(&records);
+		assert_eq!(summary.len(), 2);
+
+		let eth = &summary[0];
+		assert_eq!(Asset::from(eth.asset), Asset::Eth);
+		assert_eq!(eth.fees, 4.into());
+		assert_eq!(eth.sold, 100.into());
+		assert_eq!(eth.bought, 170.into());
+		assert_eq!(eth.inventory_change, IncreaseOrDecrease::Increase(74.into()));
+
+		let usdc = &summary[1];
+		assert_eq!(Asset::from(usdc.asset), Asset::Usdc);
+		assert_eq!(usdc.fees, 6.into());
+		assert_eq!(usdc.sold, 350.into());
+		assert_eq!(usdc.bought, 200.into());
+		assert_eq!(usdc.inventory_change, IncreaseOrDecrease::Decrease(144.into()));
+	}
+
+	#[test]
+	fn test_query_filters() {
+		let fill = |id: u64| {
+			record(OrderFilled::RangeOrder {
+				lp: AccountId::new([1; 32]),
+				base_asset: Asset::Eth.into(),
+				quote_asset: Asset::Usdc.into(),
+				id: id.into(),
+				range: -10..10,
+				fees: PoolPairsMap { base: 1.into(), quote: 1.into() },
+				sold: Default::default(),
+				bought: Default::default(),
+				liquidity: 1_000.into(),
+			})
+		};
+
+		assert!(FillQuery::default().matches(&fill(0)));
+		assert!(FillQuery { order_id: Some(1), ..Default::default() }.matches(&fill(1)));
+		assert!(!FillQuery { order_id: Some(1), ..Default::default() }.matches(&fill(0)));
+		assert!(FillQuery { base_asset: Some(Asset::Eth), ..Default::default() }.matches(&fill(0)));
+		assert!(
+			!FillQuery { base_asset: Some(Asset::Flip), ..Default::default() }.matches(&fill(0))
+		);
+		assert!(!FillQuery { from_timestamp: Some(1), ..Default::default() }.matches(&fill(0)));
+	}
+
+	#[test]
+	fn test_fills_are_queried_by_pool_and_time() {
+		let (_tempdir, db_path) = cf_utilities::testing::new_temp_directory_with_nonexistent_file();
+		let ledger = FillLedger::open(&db_path, AccountId::new([1; 32])).unwrap();
+
+		let fill = |base_asset: Asset| OrderFilled::RangeOrder {
+			lp: AccountId::new([1; 32]),
+			base_asset: base_asset.into(),
+			quote_asset: Asset::Usdc.into(),
+			id: 0.into(),
+			range: -10..10,
+			fees: PoolPairsMap { base: 1.into(), quote: 1.into() },
+			sold: Default::default(),
+			bought: Default::default(),
+			liquidity: 1_000.into(),
+		};
+		for block_number in 1..=3 {
+			ledger
+				.record_block(
+					block_number,
+					block_number as u64 * 6_000,
+					vec![fill(Asset::Eth), fill(Asset::Flip)],
+				)
+				.unwrap();
+		}
+		assert_eq!(ledger.last_recorded_block().unwrap(), Some(3));
+
+		let blocks = |query: FillQuery| {
+			ledger
+				.fills(&query)
+				.unwrap()
+				.into_iter()
+				.map(|record| (record.block_number, Asset::from(record.assets().0)))
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(blocks(FillQuery::default()).len(), 6);
+		assert_eq!(
+			blocks(FillQuery {
+				base_asset: Some(Asset::Flip),
+				quote_asset: Some(Asset::Usdc),
+				from_block: Some(2),
+				..Default::default()
+			}),
+			vec![(2, Asset::Flip), (3, Asset::Flip)]
+		);
+		assert_eq!(
+			blocks(FillQuery {
+				from_timestamp: Some(7_000),
+				to_timestamp: Some(18_000),
+				base_asset: Some(Asset::Eth),
+				..Default::default()
+			}),
+			vec![(2, Asset::Eth), (3, Asset::Eth)]
+		);
+		assert!(blocks(FillQuery { from_timestamp: Some(20_000), ..Default::default() }).is_empty());
+		assert!(blocks(FillQuery { to_timestamp: Some(5_000), ..Default::default() }).is_empty());
+	}
+
+	#[test]
+	fn test_fills_are_recorded_and_queried_per_lp() {
+		let (_tempdir, db_path) = cf_utilities::testing::new_temp_directory_with_nonexistent_file();
+		let alice = AccountId::new([1; 32]);
+		let bob = AccountId::new([2; 32]);
+		let alice_ledger = FillLedger::open(&db_path, alice.clone()).unwrap();
+		let bob_ledger = FillLedger { db: alice_ledger.db.clone(), lp: bob.clone() };
+
+		let fill = |lp: &AccountId, id: u64| OrderFilled::RangeOrder {
+			lp: lp.clone(),
+			base_asset: Asset::Eth.into(),
+			quote_asset: Asset::Usdc.into(),
+			id: id.into(),
+			range: -10..10,
+			fees: PoolPairsMap { base: 1.into(), quote: 1.into() },
+			sold: Default::default(),
+			bought: Default::default(),
+			liquidity: 1_000.into(),
+		};
+		alice_ledger
+			.record_block(1, 6_000, vec![fill(&alice, 0), fill(&bob, 1), fill(&alice, 2)])
+			.unwrap();
+		assert_eq!(alice_ledger.last_recorded_block().unwrap(), Some(1));
+		assert_eq!(bob_ledger.last_recorded_block().unwrap(), None);
+		bob_ledger.record_block(1, 6_000, vec![fill(&alice, 0), fill(&bob, 1)]).unwrap();
+
+		let ids = |ledger: &FillLedger, query: FillQuery| {
+			ledger
+				.fills(&query)
+				.unwrap()
+				.into_iter()
+				.map(|record| record.id())
+				.collect::<Vec<_>>()
+		};
+		let pool_query = FillQuery {
+			base_asset: Some(Asset::Eth),
+			quote_asset: Some(Asset::Usdc),
+			..Default::default()
+		};
+		assert_eq!(ids(&alice_ledger, FillQuery::default()), vec![U256::from(0), U256::from(2)]);
+		assert_eq!(ids(&alice_ledger, pool_query.clone()), vec![U256::from(0), U256::from(2)]);
+		assert_eq!(ids(&bob_ledger, FillQuery::default()), vec![U256::from(1)]);
+		assert_eq!(ids(&bob_ledger, pool_query), vec![U256::from(1)]);
+	}
+}
@@ -7,8 +7,8 @@ use cf_utilities::{
 use chainflip_api::{
 	self,
 	lp::{
-		types::{LimitOrder, RangeOrder},
-		ApiWaitForResult, LpApi, PoolPairsMap, Side, Tick,
+		types::{ClosedOrders, LimitOrder, OrderUpdateResult, RangeOrder},
+		ApiWaitForResult, LpApi, OrderUpdate, PoolPairsMap, Side, Tick,
 	},
 	primitives::{
 		chains::{assets::any::OldAsset, Bitcoin, Ethereum, Polkadot},
@@ -20,6 +20,7 @@ use chainflip_api::{
 };
 use clap::Parser;
 use custom_rpc::CustomApiClient;
+use fill_ledger::{AssetSummary, FillLedger, FillQuery, FillRecord};
 use futures::{try_join, FutureExt, StreamExt};
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
@@ -28,8 +29,8 @@ use jsonrpsee::{
 	types::SubscriptionResult,
 	SubscriptionSink,
 };
-use pallet_cf_pools::{AssetPair, IncreaseOrDecrease, OrderId, RangeOrderSize};
-use rpc_types::{AssetBalance, OpenSwapChannels, OrderIdJson, RangeOrderSizeJson};
+use pallet_cf_pools::{AssetPair, CloseOrderAt, IncreaseOrDecrease, OrderId, RangeOrderSize};
+use rpc_types::{AssetBalance, OpenSwapChannels, OrderIdJson, OrderUpdateJson, RangeOrderSizeJson};
 use sp_core::U256;
 use std::{
 	collections::{BTreeMap, HashMap, HashSet},
@@ -39,6 +40,8 @@ use std::{
 };
 use tracing::log;
 
+mod fill_ledger;
+
 /// Contains RPC interface types that differ from internal types.
 pub mod rpc_types {
 	use super::*;
@@ -85,6 +88,111 @@ pub mod rpc_types {
 		}
 	}
 
+	#[derive(Clone, Debug, Serialize, Deserialize)]
+	#[serde(rename_all = "snake_case")]
+	pub enum OrderUpdateJson {
+		UpdateRangeOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			id: OrderIdJson,
+			tick_range: Option<Range<Tick>>,
+			size_change: IncreaseOrDecrease<RangeOrderSizeJson>,
+		},
+		SetRangeOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			id: OrderIdJson,
+			tick_range: Option<Range<Tick>>,
+			size: RangeOrderSizeJson,
+		},
+		UpdateLimitOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			side: Side,
+			id: OrderIdJson,
+			tick: Option<Tick>,
+			amount_change: IncreaseOrDecrease<NumberOrHex>,
+			close_order_at: Option<CloseOrderAt<BlockNumber>>,
+		},
+		SetLimitOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			side: Side,
+			id: OrderIdJson,
+			tick: Option<Tick>,
+			sell_amount: NumberOrHex,
+			close_order_at: Option<CloseOrderAt<BlockNumber>>,
+		},
+	}
+	impl TryFrom<OrderUpdateJson> for OrderUpdate {
+		type Error = anyhow::Error;
+
+		fn try_from(value: OrderUpdateJson) -> Result<Self, Self::Error> {
+			Ok(match value {
+				OrderUpdateJson::UpdateRangeOrder {
+					base_asset,
+					quote_asset,
+					id,
+					tick_range,
+					size_change,
+				} => OrderUpdate::UpdateRangeOrder {
+					base_asset,
+					quote_asset,
+					id: id.try_into()?,
+					option_tick_range: tick_range,
+					size_change: size_change.try_map(|size| size.try_into())?,
+				},
+				OrderUpdateJson::SetRangeOrder {
+					base_asset,
+					quote_asset,
+					id,
+					tick_range,
+					size,
+				} => OrderUpdate::SetRangeOrder {
+					base_asset,
+					quote_asset,
+					id: id.try_into()?,
+					option_tick_range: tick_range,
+					size: size.try_into()?,
+				},
+				OrderUpdateJson::UpdateLimitOrder {
+					base_asset,
+					quote_asset,
+					side,
+					id,
+					tick,
+					amount_change,
+					close_order_at,
+				} => OrderUpdate::UpdateLimitOrder {
+					base_asset,
+					quote_asset,
+					side,
+					id: id.try_into()?,
+					option_tick: tick,
+					amount_change: amount_change.try_map(try_parse_number_or_hex)?,
+					close_order_at: close_order_at.unwrap_or_default(),
+				},
+				OrderUpdateJson::SetLimitOrder {
+					base_asset,
+					quote_asset,
+					side,
+					id,
+					tick,
+					sell_amount,
+					close_order_at,
+				} => OrderUpdate::SetLimitOrder {
+					base_asset,
+					quote_asset,
+					side,
+					id: id.try_into()?,
+					option_tick: tick,
+					sell_amount: try_parse_number_or_hex(sell_amount)?,
+					close_order_at: close_order_at.unwrap_or_default(),
+				},
+			})
+		}
+	}
+
 	#[derive(Serialize, Deserialize, Clone)]
 	pub struct OpenSwapChannels {
 		pub ethereum: Vec<SwapChannelInfo<Ethereum>>,
@@ -160,6 +268,7 @@ pub trait Rpc {
 		tick: Option<Tick>,
 		amount_change: IncreaseOrDecrease<NumberOrHex>,
 		dispatch_at: Option<BlockNumber>,
+		close_order_at: Option<CloseOrderAt<BlockNumber>>,
 		wait_for: Option<WaitFor>,
 	) -> RpcResult<ApiWaitForResult<Vec<LimitOrder>>>;
 
@@ -173,9 +282,24 @@ pub trait Rpc {
 		tick: Option<Tick>,
 		sell_amount: NumberOrHex,
 		dispatch_at: Option<BlockNumber>,
+		close_order_at: Option<CloseOrderAt<BlockNumber>>,
 		wait_for: Option<WaitFor>,
 	) -> RpcResult<ApiWaitForResult<Vec<LimitOrder>>>;
 
+	#[method(name = "batch_orders")]
+	async fn batch_orders(
+		&self,
+		orders: Vec<OrderUpdateJson>,
+		wait_for: Option<WaitFor>,
+	) -> RpcResult<ApiWaitForResult<Vec<OrderUpdateResult>>>;
+
+	#[method(name = "close_all_orders")]
+	async fn close_all_orders(
+		&self,
+		asset_pair: Option<PoolPairsMap<Asset>>,
+		wait_for: Option<WaitFor>,
+	) -> RpcResult<ApiWaitForResult<ClosedOrders>>;
+
 	#[method(name = "asset_balances")]
 	async fn asset_balances(&self) -> RpcResult<BTreeMap<ForeignChain, Vec<AssetBalance>>>;
 
@@ -195,20 +319,42 @@ pub trait Rpc {
 
 	#[method(name = "order_fills")]
 	async fn order_fills(&self, at: Option<Hash>) -> RpcResult<BlockUpdate<OrderFills>>;
+
+	#[method(name = "fills")]
+	async fn fills(&self, query: FillQuery) -> RpcResult<Vec<FillRecord>>;
+
+	#[method(name = "fill_summary")]
+	async fn fill_summary(&self, query: FillQuery) -> RpcResult<Vec<AssetSummary>>;
 }
 
 pub struct RpcServerImpl {
 	api: StateChainApi,
+	fill_ledger: Option<FillLedger>,
 }
 
 impl RpcServerImpl {
 	pub async fn new(
 		scope: &Scope<'_, anyhow::Error>,
-		LPOptions { ws_endpoint, signing_key_file, .. }: LPOptions,
+		LPOptions { ws_endpoint, signing_key_file, fill_ledger_db_path, .. }: LPOptions,
 	) -> Result<Self, anyhow::Error> {
-		Ok(Self {
-			api: StateChainApi::connect(scope, StateChain { ws_endpoint, signing_key_file })
-				.await?,
+		let api =
+			StateChainApi::connect(scope, StateChain { ws_endpoint, signing_key_file }).await?;
+
+		let fill_ledger = if let Some(db_path) = fill_ledger_db_path {
+			let state_chain_client = api.state_chain_client.clone();
+			let fill_ledger = FillLedger::open(&db_path, state_chain_client.account_id())?;
+			scope.spawn(fill_ledger::run(state_chain_client, fill_ledger.clone()));
+			Some(fill_ledger)
+		} else {
+			None
+		};
+
+		Ok(Self { api, fill_ledger })
+	}
+
+	fn fill_ledger(&self) -> Result<&FillLedger, anyhow::Error> {
+		self.fill_ledger.as_ref().ok_or_else(|| {
+			anyhow::anyhow!("The fill ledger is disabled. Set --fill_ledger.db_path to enable it.")
 		})
 	}
 }
@@ -240,6 +386,10 @@ pub enum OrderFilled {
 		id: U256,
 		range: Range<Tick>,
 		fees: PoolPairsMap<U256>,
+		/// The amounts of each asset sold and bought by the order as the price moved, excluding
+		/// fees. Liquidity added or removed in the same block is not included.
+		sold: PoolPairsMap<U256>,
+		bought: PoolPairsMap<U256>,
 		liquidity: U256,
 	},
 }
@@ -371,6 +521,7 @@ impl RpcServer for RpcServerImpl {
 		tick: Option<Tick>,
 		amount_change: IncreaseOrDecrease<NumberOrHex>,
 		dispatch_at: Option<BlockNumber>,
+		close_order_at: Option<CloseOrderAt<BlockNumber>>,
 		wait_for: Option<WaitFor>,
 	) -> RpcResult<ApiWaitForResult<Vec<LimitOrder>>> {
 		Ok(self
@@ -384,6 +535,7 @@ impl RpcServer for RpcServerImpl {
 				tick,
 				amount_change.try_map(try_parse_number_or_hex)?,
 				dispatch_at,
+				close_order_at.unwrap_or_default(),
 				wait_for.unwrap_or_default(),
 			)
 			.await?)
@@ -398,6 +550,7 @@ impl RpcServer for RpcServerImpl {
 		tick: Option<Tick>,
 		sell_amount: NumberOrHex,
 		dispatch_at: Option<BlockNumber>,
+		close_order_at: Option<CloseOrderAt<BlockNumber>>,
 		wait_for: Option<WaitFor>,
 	) -> RpcResult<ApiWaitForResult<Vec<LimitOrder>>> {
 		Ok(self
@@ -411,11 +564,39 @@ impl RpcServer for RpcServerImpl {
 				tick,
 				try_parse_number_or_hex(sell_amount)?,
 				dispatch_at,
+				close_order_at.unwrap_or_default(),
 				wait_for.unwrap_or_default(),
 			)
 			.await?)
 	}
 
+	async fn batch_orders(
+		&self,
+		orders: Vec<OrderUpdateJson>,
+		wait_for: Option<WaitFor>,
+	) -> RpcResult<ApiWaitForResult<Vec<OrderUpdateResult>>> {
+		Ok(self
+			.api
+			.lp_api()
+			.batch_orders(
+				orders.into_iter().map(TryInto::try_into).collect::<Result<_, _>>()?,
+				wait_for.unwrap_or_default(),
+			)
+			.await?)
+	}
+
+	async fn close_all_orders(
+		&self,
+		asset_pair: Option<PoolPairsMap<Asset>>,
+		wait_for: Option<WaitFor>,
+	) -> RpcResult<ApiWaitForResult<ClosedOrders>> {
+		Ok(self
+			.api
+			.lp_api()
+			.close_all_orders(asset_pair, wait_for.unwrap_or_default())
+			.await?)
+	}
+
 	/// Returns the tx hash that the account role was set
 	async fn register_account(&self) -> RpcResult<Hash> {
 		Ok(self
@@ -491,6 +672,14 @@ impl RpcServer for RpcServerImpl {
 
 		Ok(order_fills(state_chain_client.clone(), block).await?)
 	}
+
+	async fn fills(&self, query: FillQuery) -> RpcResult<Vec<FillRecord>> {
+		Ok(self.fill_ledger()?.fills(&query)?)
+	}
+
+	async fn fill_summary(&self, query: FillQuery) -> RpcResult<Vec<AssetSummary>> {
+		Ok(fill_ledger::summarise(&self.fill_ledger()?.fills(&query)?))
+	}
 }
 
 async fn order_fills<StateChainClient>(
@@ -640,7 +829,43 @@ where
 									}
 								};
 
-								if fees == Default::default() {
+								// Values the liquidity the order had before the block at the
+								// previous and current price, so that only swaps are counted.
+								let (sold, bought) = previous_pools
+									.get(asset_pair)
+									.and_then(|previous_pool| {
+										let (_, previous_position_info) = previous_pool
+											.pool_state
+											.range_order(&(lp.clone(), id), range.clone())
+											.ok()?;
+										let before = previous_pool
+											.pool_state
+											.range_order_liquidity_value(
+												range.clone(),
+												previous_position_info.liquidity,
+											)
+											.ok()?;
+										let after = pool
+											.pool_state
+											.range_order_liquidity_value(
+												range.clone(),
+												previous_position_info.liquidity,
+											)
+											.ok()?;
+										Some((
+											before.zip(after).map(|(before, after)| {
+												before.saturating_sub(after)
+											}),
+											after.zip(before).map(|(after, before)| {
+												after.saturating_sub(before)
+											}),
+										))
+									})
+									.unwrap_or_default();
+
+								if fees == Default::default() &&
+									sold == Default::default() && bought == Default::default()
+								{
 									None
 								} else {
 									Some(OrderFilled::RangeOrder {
@@ -650,6 +875,8 @@ where
 										id: id.into(),
 										range: range.clone(),
 										fees: fees.map(|fees| fees),
+										sold,
+										bought,
 										liquidity: position_info.liquidity.into(),
 									})
 								}
@@ -684,6 +911,11 @@ pub struct LPOptions {
 		help = "A path to a file that contains the LP's secret key for signing extrinsics."
 	)]
 	pub signing_key_file: PathBuf,
+	#[clap(
+		long = "fill_ledger.db_path",
+		help = "A path to a database in which the fills of the LP's orders are recorded. Enables the lp_fills and lp_fill_summary methods."
+	)]
+	pub fill_ledger_db_path: Option<PathBuf>,
 }
 
 #[tokio::main]
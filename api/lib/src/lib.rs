@@ -25,7 +25,7 @@ pub mod primitives {
 	pub type RedemptionAmount = pallet_cf_funding::RedemptionAmount<FlipBalance>;
 	pub use cf_chains::{
 		address::{EncodedAddress, ForeignChainAddress},
-		CcmChannelMetadata, CcmDepositMetadata,
+		CcmChannelMetadata, CcmDepositMetadata, DcaParameters, SwapRefundParameters,
 	};
 }
 pub use cf_chains::eth::Address as EthereumAddress;
@@ -34,7 +34,7 @@ pub use chainflip_engine::state_chain_observer::client::{
 	chain_api::ChainApi,
 	extrinsic_api::signed::{SignedExtrinsicApi, UntilFinalized, WaitFor, WaitForResult},
 	storage_api::StorageApi,
-	BlockInfo,
+	BlockInfo, StateChainClient,
 };
 
 pub mod lp;
@@ -45,7 +45,6 @@ pub use chainflip_node::chain_spec::use_chainflip_account_id_encoding;
 
 use chainflip_engine::state_chain_observer::client::{
 	base_rpc_api::BaseRpcClient, extrinsic_api::signed::UntilInBlock, DefaultRpcClient,
-	StateChainClient,
 };
 use utilities::{clean_hex_address, task_scope::Scope};
 
@@ -315,6 +314,8 @@ pub trait BrokerApi: SignedExtrinsicApi {
 		broker_commission_bps: BasisPoints,
 		channel_metadata: Option<CcmChannelMetadata>,
 		boost_fee: Option<BasisPoints>,
+		refund_parameters: Option<SwapRefundParameters<EncodedAddress>>,
+		dca_parameters: Option<DcaParameters>,
 	) -> Result<SwapDepositAddress> {
 		let (_tx_hash, events, header, ..) = self
 			.submit_signed_extrinsic_with_dry_run(
@@ -325,6 +326,8 @@ pub trait BrokerApi: SignedExtrinsicApi {
 					broker_commission_bps,
 					channel_metadata,
 					boost_fee: boost_fee.unwrap_or_default(),
+					refund_parameters,
+					dca_parameters,
 				},
 			)
 			.await?
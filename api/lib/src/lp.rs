@@ -10,7 +10,9 @@ use chainflip_engine::state_chain_observer::client::{
 	extrinsic_api::signed::{SignedExtrinsicApi, UntilInBlock, WaitFor, WaitForResult},
 	StateChainClient,
 };
-use pallet_cf_pools::{IncreaseOrDecrease, OrderId, RangeOrderSize};
+use pallet_cf_pools::{
+	CloseOrderAt, IncreaseOrDecrease, OrderId, RangeOrderSize, MAX_ORDERS_PER_BATCH,
+};
 use serde::{Deserialize, Serialize};
 use sp_core::{H256, U256};
 use state_chain_runtime::RuntimeCall;
@@ -74,6 +76,149 @@ pub mod types {
 		pub bought_amount: U256,
 		pub sell_amount_change: Option<IncreaseOrDecrease<U256>>,
 	}
+
+	/// The result of a single order update within a batch, in the same shape as the result of the
+	/// corresponding individual call.
+	#[derive(Serialize, Deserialize, Clone)]
+	#[serde(untagged)]
+	pub enum OrderUpdateResult {
+		RangeOrder(Vec<RangeOrder>),
+		LimitOrder(Vec<LimitOrder>),
+	}
+
+	#[derive(Serialize, Deserialize, Clone)]
+	pub struct ClosedOrders {
+		pub range_orders: Vec<RangeOrder>,
+		pub limit_orders: Vec<LimitOrder>,
+		/// The number of orders left open because too many were to be closed at once. These can
+		/// be closed by calling `close_all_orders` again.
+		pub orders_remaining: u32,
+	}
+}
+
+/// An order update that can be submitted as part of a batch, see [LpApi::batch_orders].
+#[derive(Debug, Clone)]
+pub enum OrderUpdate {
+	UpdateRangeOrder {
+		base_asset: Asset,
+		quote_asset: Asset,
+		id: OrderId,
+		option_tick_range: Option<Range<Tick>>,
+		size_change: IncreaseOrDecrease<RangeOrderSize>,
+	},
+	SetRangeOrder {
+		base_asset: Asset,
+		quote_asset: Asset,
+		id: OrderId,
+		option_tick_range: Option<Range<Tick>>,
+		size: RangeOrderSize,
+	},
+	UpdateLimitOrder {
+		base_asset: Asset,
+		quote_asset: Asset,
+		side: Side,
+		id: OrderId,
+		option_tick: Option<Tick>,
+		amount_change: IncreaseOrDecrease<AssetAmount>,
+		close_order_at: CloseOrderAt<BlockNumber>,
+	},
+	SetLimitOrder {
+		base_asset: Asset,
+		quote_asset: Asset,
+		side: Side,
+		id: OrderId,
+		option_tick: Option<Tick>,
+		sell_amount: AssetAmount,
+		close_order_at: CloseOrderAt<BlockNumber>,
+	},
+}
+
+/// Identifies an order: range orders have no side.
+type OrderKey = (Asset, Asset, Option<Side>, OrderId);
+
+impl OrderUpdate {
+	fn key(&self) -> OrderKey {
+		match *self {
+			OrderUpdate::UpdateRangeOrder { base_asset, quote_asset, id, .. } |
+			OrderUpdate::SetRangeOrder { base_asset, quote_asset, id, .. } =>
+				(base_asset, quote_asset, None, id),
+			OrderUpdate::UpdateLimitOrder { base_asset, quote_asset, side, id, .. } |
+			OrderUpdate::SetLimitOrder { base_asset, quote_asset, side, id, .. } =>
+				(base_asset, quote_asset, Some(side), id),
+		}
+	}
+
+	fn into_call(self) -> pallet_cf_pools::Call<state_chain_runtime::Runtime> {
+		match self {
+			OrderUpdate::UpdateRangeOrder {
+				base_asset,
+				quote_asset,
+				id,
+				option_tick_range,
+				size_change,
+			} => pallet_cf_pools::Call::update_range_order {
+				base_asset,
+				quote_asset,
+				id,
+				option_tick_range,
+				size_change,
+			},
+			OrderUpdate::SetRangeOrder { base_asset, quote_asset, id, option_tick_range, size } =>
+				pallet_cf_pools::Call::set_range_order {
+					base_asset,
+					quote_asset,
+					id,
+					option_tick_range,
+					size,
+				},
+			OrderUpdate::UpdateLimitOrder {
+				base_asset,
+				quote_asset,
+				side,
+				id,
+				option_tick,
+				amount_change,
+				close_order_at,
+			} => pallet_cf_pools::Call::update_limit_order {
+				base_asset,
+				quote_asset,
+				side,
+				id,
+				option_tick,
+				amount_change,
+				close_order_at,
+			},
+			OrderUpdate::SetLimitOrder {
+				base_asset,
+				quote_asset,
+				side,
+				id,
+				option_tick,
+				sell_amount,
+				close_order_at,
+			} => pallet_cf_pools::Call::set_limit_order {
+				base_asset,
+				quote_asset,
+				side,
+				id,
+				option_tick,
+				sell_amount,
+				close_order_at,
+			},
+		}
+	}
+}
+
+fn event_order_key(event: &state_chain_runtime::RuntimeEvent) -> Option<OrderKey> {
+	match *event {
+		state_chain_runtime::RuntimeEvent::LiquidityPools(
+			pallet_cf_pools::Event::RangeOrderUpdated { base_asset, quote_asset, id, .. },
+		) => Some((base_asset, quote_asset, None, id)),
+		state_chain_runtime::RuntimeEvent::LiquidityPools(
+			pallet_cf_pools::Event::LimitOrderUpdated { base_asset, quote_asset, side, id, .. },
+		) => Some((base_asset, quote_asset, Some(side), id)),
+		_ => None,
+	}
 }
 
 fn collect_range_order_returns(
@@ -318,6 +463,7 @@ pub trait LpApi: SignedExtrinsicApi {
 		option_tick: Option<Tick>,
 		amount_change: IncreaseOrDecrease<AssetAmount>,
 		dispatch_at: Option<BlockNumber>,
+		close_order_at: CloseOrderAt<BlockNumber>,
 		wait_for: WaitFor,
 	) -> Result<ApiWaitForResult<Vec<types::LimitOrder>>> {
 		self.scheduled_or_immediate(
@@ -328,6 +474,7 @@ pub trait LpApi: SignedExtrinsicApi {
 				id,
 				option_tick,
 				amount_change,
+				close_order_at,
 			},
 			dispatch_at,
 			wait_for,
@@ -345,6 +492,7 @@ pub trait LpApi: SignedExtrinsicApi {
 		option_tick: Option<Tick>,
 		sell_amount: AssetAmount,
 		dispatch_at: Option<BlockNumber>,
+		close_order_at: CloseOrderAt<BlockNumber>,
 		wait_for: WaitFor,
 	) -> Result<ApiWaitForResult<Vec<types::LimitOrder>>> {
 		self.scheduled_or_immediate(
@@ -355,6 +503,7 @@ pub trait LpApi: SignedExtrinsicApi {
 				id,
 				option_tick,
 				sell_amount,
+				close_order_at,
 			},
 			dispatch_at,
 			wait_for,
@@ -384,4 +533,85 @@ pub trait LpApi: SignedExtrinsicApi {
 			collect_limit_order_returns,
 		))
 	}
+
+	/// Submits a list of order updates, possibly across different pools, as a single extrinsic.
+	/// Either all of the updates are applied or none of them are. The results are returned in the
+	/// same order as the updates.
+	async fn batch_orders(
+		&self,
+		orders: Vec<OrderUpdate>,
+		wait_for: WaitFor,
+	) -> Result<ApiWaitForResult<Vec<types::OrderUpdateResult>>> {
+		if orders.is_empty() {
+			bail!("At least one order update is required");
+		}
+		// Results are attributed to orders by their events, which is ambiguous if an order is
+		// updated more than once.
+		let keys = orders.iter().map(OrderUpdate::key).collect::<Vec<_>>();
+		if keys.iter().enumerate().any(|(i, key)| keys[..i].contains(key)) {
+			bail!("Each order may only be updated once per batch");
+		}
+
+		let calls = orders.into_iter().map(OrderUpdate::into_call).collect::<Vec<_>>();
+		let orders = calls.try_into().map_err(|_| {
+			anyhow::anyhow!("A batch may contain at most {MAX_ORDERS_PER_BATCH} order updates")
+		})?;
+
+		Ok(into_api_wait_for_result(
+			self.submit_signed_extrinsic_wait_for(
+				pallet_cf_pools::Call::batch_orders { orders },
+				wait_for,
+			)
+			.await?,
+			|events| {
+				keys.into_iter()
+					.map(|key| {
+						let order_events = events
+							.iter()
+							.filter(|event| event_order_key(event) == Some(key))
+							.cloned();
+						if key.2.is_some() {
+							types::OrderUpdateResult::LimitOrder(collect_limit_order_returns(
+								order_events,
+							))
+						} else {
+							types::OrderUpdateResult::RangeOrder(collect_range_order_returns(
+								order_events,
+							))
+						}
+					})
+					.collect()
+			},
+		))
+	}
+
+	/// Closes all of the account's orders, or only those in the given pool if an asset pair is
+	/// specified. Returns the final state of each closed order, and the number of orders that
+	/// were left open because there were too many to close at once.
+	async fn close_all_orders(
+		&self,
+		asset_pair: Option<PoolPairsMap<Asset>>,
+		wait_for: WaitFor,
+	) -> Result<ApiWaitForResult<types::ClosedOrders>> {
+		Ok(into_api_wait_for_result(
+			self.submit_signed_extrinsic_wait_for(
+				pallet_cf_pools::Call::close_all_orders { asset_pair },
+				wait_for,
+			)
+			.await?,
+			|events| types::ClosedOrders {
+				range_orders: collect_range_order_returns(events.clone()),
+				limit_orders: collect_limit_order_returns(events.clone()),
+				orders_remaining: events
+					.into_iter()
+					.find_map(|event| match event {
+						state_chain_runtime::RuntimeEvent::LiquidityPools(
+							pallet_cf_pools::Event::OrdersClosed { orders_remaining, .. },
+						) => Some(orders_remaining),
+						_ => None,
+					})
+					.unwrap_or_default(),
+			},
+		))
+	}
 }
@@ -105,6 +105,9 @@ fn generate_and_save_keys<Crypto: ChainSigning>(
 			// The genesis hash is unknown at this time, it will be written when the node runs for
 			// the first time.
 			None,
+			// The key shares are encrypted when the node first runs with key share encryption
+			// enabled.
+			None,
 		)
 		.expect("Should create database at latest version")
 		.update_key::<Crypto>(&KeyId::new(GENESIS_EPOCH, public_key.clone()), &key_share);
@@ -130,9 +133,12 @@ fn should_generate_and_save_all_keys() {
 	generate_and_save_keys::<BtcSigning>(&node_id_to_name_map);
 
 	// Open the db and check the keys
-	let db =
-		PersistentKeyDB::open_and_migrate_to_latest(&db_path.with_extension(DB_EXTENSION), None)
-			.unwrap();
+	let db = PersistentKeyDB::open_and_migrate_to_latest(
+		&db_path.with_extension(DB_EXTENSION),
+		None,
+		None,
+	)
+	.unwrap();
 
 	assert_eq!(db.load_keys::<EthSigning>().len(), 1);
 	assert_eq!(db.load_keys::<PolkadotSigning>().len(), 1);
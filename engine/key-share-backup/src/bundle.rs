@@ -0,0 +1,284 @@
+//! The backup bundle format. Everything except the format version and the salt is encrypted and
+//! authenticated, so a bundle can't be read or tampered with without the passphrase.
+
+use std::{fmt, path::Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+use cf_primitives::EpochIndex;
+use chainflip_engine::db::{KeyShareCipher, KeyShareEncryptionKey, PersistentKeyDB};
+use clap::ArgEnum;
+use multisig::{
+	bitcoin::BtcSigning, client::KeygenResultInfo, eth::EthSigning, polkadot::PolkadotSigning,
+	CanonicalEncoding, ChainSigning, KeyId,
+};
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+const BUNDLE_FORMAT_VERSION: u32 = 1;
+const SALT_SIZE: usize = 16;
+
+/// Calls the generic function `$f` with the `ChainSigning` type of the given [Chain].
+macro_rules! with_chain_signing {
+	($chain:expr, $f:ident($($arg:expr),*)) => {
+		match $chain {
+			Chain::Ethereum => $f::<EthSigning>($($arg),*),
+			Chain::Polkadot => $f::<PolkadotSigning>($($arg),*),
+			Chain::Bitcoin => $f::<BtcSigning>($($arg),*),
+		}
+	};
+}
+
+#[derive(ArgEnum, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Chain {
+	Ethereum,
+	Polkadot,
+	Bitcoin,
+}
+
+impl Chain {
+	pub const ALL: [Chain; 3] = [Chain::Ethereum, Chain::Polkadot, Chain::Bitcoin];
+}
+
+impl fmt::Display for Chain {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fn name<C: ChainSigning>() -> &'static str {
+			C::NAME
+		}
+		write!(f, "{}", with_chain_signing!(self, name()))
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BundledKeyShare {
+	pub chain: Chain,
+	pub key_id: KeyId,
+	/// The serialized `KeygenResultInfo`, as the engine stores it.
+	key_share: Vec<u8>,
+}
+
+impl BundledKeyShare {
+	fn key_share<C: ChainSigning>(&self) -> Result<KeygenResultInfo<C::CryptoScheme>> {
+		bincode::deserialize(&self.key_share)
+			.with_context(|| format!("Invalid {} key share for {}", self.chain, self.key_id))
+	}
+
+	/// Checks that the key share belongs to the aggregate key it is stored under.
+	pub fn verify(&self) -> Result<()> {
+		fn verify<C: ChainSigning>(key_share: &BundledKeyShare) -> Result<()> {
+			if key_share.key_share::<C>()?.key.get_agg_public_key().encode_key() !=
+				key_share.key_id.public_key_bytes()
+			{
+				bail!(
+					"The {} key share does not match the aggregate key {}",
+					key_share.chain,
+					key_share.key_id
+				);
+			}
+			Ok(())
+		}
+		with_chain_signing!(self.chain, verify(self))
+	}
+
+	fn import(&self, db: &PersistentKeyDB) -> Result<()> {
+		fn import<C: ChainSigning>(
+			key_share: &BundledKeyShare,
+			db: &PersistentKeyDB,
+		) -> Result<()> {
+			db.update_key::<C>(&key_share.key_id, &key_share.key_share::<C>()?);
+			Ok(())
+		}
+		with_chain_signing!(self.chain, import(self, db))
+	}
+}
+
+impl Drop for BundledKeyShare {
+	fn drop(&mut self) {
+		zeroize::Zeroize::zeroize(&mut self.key_share);
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BundleContents {
+	pub key_shares: Vec<BundledKeyShare>,
+}
+
+impl BundleContents {
+	/// Reads the key shares for the given chains from the db, optionally only those of one epoch.
+	pub fn export(db: &PersistentKeyDB, chains: &[Chain], epoch: Option<EpochIndex>) -> Self {
+		fn export<C: ChainSigning>(
+			db: &PersistentKeyDB,
+			chain: Chain,
+			epoch: Option<EpochIndex>,
+		) -> Vec<BundledKeyShare> {
+			db.load_keys::<C>()
+				.into_iter()
+				.filter(|(key_id, _)| epoch.map_or(true, |epoch| key_id.epoch_index() == epoch))
+				.map(|(key_id, key_share)| BundledKeyShare {
+					chain,
+					key_id,
+					key_share: bincode::serialize(&key_share)
+						.expect("Serialization is not expected to fail"),
+				})
+				.collect()
+		}
+
+		let mut key_shares: Vec<_> = chains
+			.iter()
+			.flat_map(|chain| with_chain_signing!(chain, export(db, *chain, epoch)))
+			.collect();
+		key_shares.sort_by_key(|key_share| (key_share.chain as u8, key_share.key_id.epoch_index()));
+		BundleContents { key_shares }
+	}
+
+	pub fn verify(&self) -> Result<()> {
+		self.key_shares.iter().try_for_each(BundledKeyShare::verify)
+	}
+
+	/// Writes the key shares into a new database.
+	pub fn import(
+		&self,
+		db_path: &Path,
+		key_share_encryption_key: Option<&KeyShareEncryptionKey>,
+	) -> Result<()> {
+		if db_path.exists() {
+			bail!(
+				"{} already exists, key shares can only be imported into a new database",
+				db_path.display()
+			);
+		}
+		let db = PersistentKeyDB::open_and_migrate_to_latest(
+			db_path,
+			// The genesis hash is written when the engine first runs with this database.
+			None,
+			key_share_encryption_key,
+		)?;
+		self.key_shares.iter().try_for_each(|key_share| key_share.import(&db))
+	}
+}
+
+/// An encrypted [BundleContents], as it is written to disk.
+#[derive(Serialize, Deserialize)]
+struct Bundle {
+	version: u32,
+	/// Hex encoded
+	salt: String,
+	/// Hex encoded
+	ciphertext: String,
+}
+
+pub fn write_bundle(path: &Path, contents: &BundleContents, passphrase: &str) -> Result<()> {
+	let salt = rand::random::<[u8; SALT_SIZE]>();
+	let plaintext = Zeroizing::new(
+		bincode::serialize(contents).expect("Serialization is not expected to fail"),
+	);
+	let bundle = Bundle {
+		version: BUNDLE_FORMAT_VERSION,
+		salt: hex::encode(salt),
+		ciphertext: hex::encode(bundle_cipher(passphrase, &salt)?.encrypt(&plaintext)),
+	};
+	std::fs::write(
+		path,
+		serde_json::to_vec_pretty(&bundle).expect("Serialization is not expected to fail"),
+	)
+	.with_context(|| format!("Failed to write bundle to {}", path.display()))
+}
+
+pub fn read_bundle(path: &Path, passphrase: &str) -> Result<BundleContents> {
+	let bundle: Bundle = serde_json::from_slice(
+		&std::fs::read(path)
+			.with_context(|| format!("Failed to read bundle {}", path.display()))?,
+	)
+	.context("Invalid bundle")?;
+	if bundle.version != BUNDLE_FORMAT_VERSION {
+		bail!("Unsupported bundle format version {}", bundle.version);
+	}
+	let plaintext = bundle_cipher(passphrase, &hex::decode(bundle.salt).context("Invalid salt")?)?
+		.decrypt(&hex::decode(bundle.ciphertext).context("Invalid ciphertext")?)
+		.map_err(|_| {
+			anyhow!("Failed to decrypt bundle, the passphrase is incorrect or the bundle has been modified")
+		})?;
+	bincode::deserialize(&plaintext).context("Invalid bundle contents")
+}
+
+fn bundle_cipher(passphrase: &str, salt: &[u8]) -> Result<KeyShareCipher> {
+	KeyShareCipher::new(
+		&KeyShareEncryptionKey::Passphrase(Zeroizing::new(passphrase.to_owned())),
+		salt,
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use multisig::{client::keygen::generate_key_data, Rng};
+	use rand::SeedableRng;
+	use state_chain_runtime::AccountId;
+	use std::collections::BTreeSet;
+
+	fn add_key<C: ChainSigning>(db: &PersistentKeyDB, epoch: EpochIndex) -> KeyId {
+		let (public_key, key_shares) = generate_key_data::<C::CryptoScheme>(
+			BTreeSet::from([AccountId::new([0; 32])]),
+			&mut Rng::from_seed([epoch as u8; 32]),
+		);
+		let key_id = KeyId::new(epoch, public_key);
+		db.update_key::<C>(&key_id, key_shares.values().next().unwrap());
+		key_id
+	}
+
+	#[test]
+	fn can_export_and_import_key_shares() {
+		let directory = tempfile::TempDir::new().unwrap();
+		let bundle_path = directory.path().join("bundle.json");
+		let imported_db_path = directory.path().join("imported.db");
+
+		let (eth_key_id, btc_key_id) = {
+			let db = PersistentKeyDB::open_and_migrate_to_latest(
+				&directory.path().join("exported.db"),
+				None,
+				None,
+			)
+			.unwrap();
+			add_key::<EthSigning>(&db, 1);
+			add_key::<PolkadotSigning>(&db, 2);
+			let eth_key_id = add_key::<EthSigning>(&db, 2);
+			let btc_key_id = add_key::<BtcSigning>(&db, 2);
+
+			let contents = BundleContents::export(&db, &[Chain::Ethereum, Chain::Bitcoin], Some(2));
+			assert_eq!(contents.key_shares.len(), 2);
+			write_bundle(&bundle_path, &contents, "passphrase").unwrap();
+			(eth_key_id, btc_key_id)
+		};
+
+		assert!(read_bundle(&bundle_path, "wrong passphrase").is_err());
+
+		let contents = read_bundle(&bundle_path, "passphrase").unwrap();
+		contents.verify().unwrap();
+		contents.import(&imported_db_path, None).unwrap();
+		// Only new databases can be imported into
+		assert!(contents.import(&imported_db_path, None).is_err());
+
+		let db =
+			PersistentKeyDB::open_and_migrate_to_latest(&imported_db_path, None, None).unwrap();
+		assert_eq!(db.load_keys::<EthSigning>().into_keys().collect::<Vec<_>>(), vec![eth_key_id]);
+		assert_eq!(db.load_keys::<BtcSigning>().into_keys().collect::<Vec<_>>(), vec![btc_key_id]);
+		assert!(db.load_keys::<PolkadotSigning>().is_empty());
+	}
+
+	#[test]
+	fn verify_rejects_key_share_for_another_key() {
+		let directory = tempfile::TempDir::new().unwrap();
+		let db = PersistentKeyDB::open_and_migrate_to_latest(
+			&directory.path().join("test.db"),
+			None,
+			None,
+		)
+		.unwrap();
+		add_key::<EthSigning>(&db, 1);
+
+		let mut contents = BundleContents::export(&db, &Chain::ALL, None);
+		contents.verify().unwrap();
+
+		contents.key_shares[0].key_id = KeyId::new(1, [0u8; 33]);
+		assert!(contents.verify().is_err());
+	}
+}
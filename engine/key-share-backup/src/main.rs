@@ -0,0 +1,226 @@
+//! Exports the threshold key shares from an engine database into an encrypted bundle, and imports
+//! them into a new database, for example to move a validator to another host.
+
+mod bundle;
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use bundle::{read_bundle, write_bundle, BundleContents, Chain};
+use cf_primitives::EpochIndex;
+use chainflip_engine::{
+	db::{KeyShareEncryptionKey, PersistentKeyDB},
+	settings::KeyShareEncryption,
+	state_chain_observer::client::{storage_api::StorageApi, StateChainClient},
+};
+use clap::{Args, Parser};
+use futures::FutureExt;
+use multisig::{CanonicalEncoding, KeyId};
+use state_chain_runtime::{BitcoinInstance, EthereumInstance, PolkadotInstance, Runtime};
+use utilities::{cached_stream::CachedStream, task_scope::task_scope};
+use zeroize::Zeroizing;
+
+#[derive(Parser)]
+#[clap(version, about)]
+enum Command {
+	/// Write the key shares in the database to a bundle encrypted with the passphrase.
+	Export {
+		#[clap(long = "signing.db_file", parse(from_os_str))]
+		db_file: PathBuf,
+		#[clap(flatten)]
+		db_encryption: DbEncryptionOptions,
+		#[clap(long, parse(from_os_str))]
+		bundle: PathBuf,
+		#[clap(flatten)]
+		passphrase: BundlePassphrase,
+		/// Only export the key shares of this chain. Defaults to all chains.
+		#[clap(long, arg_enum)]
+		chain: Option<Chain>,
+		/// Only export the key shares of this epoch. Defaults to all epochs.
+		#[clap(long)]
+		epoch: Option<EpochIndex>,
+	},
+	/// Check that the key shares in the bundle match their aggregate keys, and, if a State Chain
+	/// endpoint is given, the aggregate keys on chain.
+	Verify {
+		#[clap(long, parse(from_os_str))]
+		bundle: PathBuf,
+		#[clap(flatten)]
+		passphrase: BundlePassphrase,
+		#[clap(long = "state_chain.ws_endpoint")]
+		state_chain_ws_endpoint: Option<String>,
+	},
+	/// Verify the bundle and write its key shares into a new database.
+	Import {
+		#[clap(long, parse(from_os_str))]
+		bundle: PathBuf,
+		#[clap(flatten)]
+		passphrase: BundlePassphrase,
+		#[clap(long = "state_chain.ws_endpoint")]
+		state_chain_ws_endpoint: Option<String>,
+		#[clap(long = "signing.db_file", parse(from_os_str))]
+		db_file: PathBuf,
+		#[clap(flatten)]
+		db_encryption: DbEncryptionOptions,
+	},
+}
+
+/// The passphrase is read from [BUNDLE_PASSPHRASE_ENV] unless a file is given. It can't be passed
+/// as an argument, so that it doesn't end up in the shell history or the process list.
+#[derive(Args)]
+struct BundlePassphrase {
+	#[clap(long = "bundle_passphrase_file", parse(from_os_str))]
+	passphrase_file: Option<PathBuf>,
+}
+
+const BUNDLE_PASSPHRASE_ENV: &str = "CF_KEY_SHARE_BUNDLE_PASSPHRASE";
+
+impl BundlePassphrase {
+	fn load(self) -> Result<Zeroizing<String>> {
+		let passphrase = match self.passphrase_file {
+			Some(passphrase_file) => Zeroizing::new(
+				std::fs::read_to_string(&passphrase_file)
+					.with_context(|| {
+						format!("Failed to read passphrase file {}", passphrase_file.display())
+					})?
+					.trim_end_matches(['\r', '\n'])
+					.to_owned(),
+			),
+			None => Zeroizing::new(std::env::var(BUNDLE_PASSPHRASE_ENV).with_context(|| {
+				format!("Set {BUNDLE_PASSPHRASE_ENV} or pass --bundle_passphrase_file")
+			})?),
+		};
+		if passphrase.is_empty() {
+			bail!("The bundle passphrase is empty");
+		}
+		Ok(passphrase)
+	}
+}
+
+/// How the key shares in the database are encrypted, see `signing.key_share_encryption` in the
+/// engine settings. Like the bundle passphrase, a database passphrase can only be given through
+/// [DB_PASSPHRASE_ENV], not as an argument.
+#[derive(Args)]
+struct DbEncryptionOptions {
+	#[clap(long = "signing.key_share_encryption.key_file", parse(from_os_str))]
+	key_file: Option<PathBuf>,
+	#[clap(long = "signing.key_share_encryption.key_command")]
+	key_command: Option<String>,
+}
+
+const DB_PASSPHRASE_ENV: &str = "CF_KEY_SHARE_ENCRYPTION_PASSPHRASE";
+
+impl DbEncryptionOptions {
+	fn load_key(self) -> Result<Option<KeyShareEncryptionKey>> {
+		let settings = KeyShareEncryption {
+			passphrase: std::env::var(DB_PASSPHRASE_ENV).ok(),
+			key_file: self.key_file,
+			key_command: self.key_command,
+		};
+		if settings == KeyShareEncryption::default() {
+			Ok(None)
+		} else {
+			KeyShareEncryptionKey::load(&settings).map(Some)
+		}
+	}
+}
+
+fn main() -> Result<()> {
+	match Command::parse() {
+		Command::Export { db_file, db_encryption, bundle, passphrase, chain, epoch } => {
+			let passphrase = passphrase.load()?;
+			let db = PersistentKeyDB::open_read_only(&db_file, db_encryption.load_key()?.as_ref())?;
+			let contents = BundleContents::export(
+				&db,
+				chain.as_ref().map_or(&Chain::ALL[..], std::slice::from_ref),
+				epoch,
+			);
+			if contents.key_shares.is_empty() {
+				bail!("No matching key shares found in {}", db_file.display());
+			}
+			write_bundle(&bundle, &contents, &passphrase)?;
+			print_key_shares(&contents);
+			println!("Exported to {}", bundle.display());
+		},
+		Command::Verify { bundle, passphrase, state_chain_ws_endpoint } => {
+			let contents = read_bundle(&bundle, &passphrase.load()?)?;
+			verify(&contents, state_chain_ws_endpoint)?;
+			print_key_shares(&contents);
+			println!("Verified {}", bundle.display());
+		},
+		Command::Import { bundle, passphrase, state_chain_ws_endpoint, db_file, db_encryption } => {
+			let contents = read_bundle(&bundle, &passphrase.load()?)?;
+			verify(&contents, state_chain_ws_endpoint)?;
+			contents.import(&db_file, db_encryption.load_key()?.as_ref())?;
+			print_key_shares(&contents);
+			println!("Imported into {}", db_file.display());
+		},
+	}
+	Ok(())
+}
+
+fn print_key_shares(contents: &BundleContents) {
+	for key_share in &contents.key_shares {
+		println!("{}: {}", key_share.chain, key_share.key_id);
+	}
+}
+
+fn verify(contents: &BundleContents, state_chain_ws_endpoint: Option<String>) -> Result<()> {
+	contents.verify()?;
+	if let Some(ws_endpoint) = state_chain_ws_endpoint {
+		tokio::runtime::Runtime::new()?.block_on(verify_against_state_chain(
+			contents
+				.key_shares
+				.iter()
+				.map(|key_share| (key_share.chain, key_share.key_id.clone()))
+				.collect(),
+			ws_endpoint,
+		))?;
+	}
+	Ok(())
+}
+
+/// Checks that each key is the aggregate key the State Chain has for its chain and epoch.
+async fn verify_against_state_chain(keys: Vec<(Chain, KeyId)>, ws_endpoint: String) -> Result<()> {
+	task_scope(|scope| {
+		async move {
+			let (state_chain_stream, _, state_chain_client) =
+				StateChainClient::connect_without_account(scope, &ws_endpoint, false).await?;
+			let block_hash = state_chain_stream.cache().hash;
+
+			for (chain, key_id) in keys {
+				let epoch = key_id.epoch_index();
+				let on_chain_key = match chain {
+					Chain::Ethereum => state_chain_client
+						.storage_map_entry::<pallet_cf_threshold_signature::Keys<Runtime, EthereumInstance>>(
+							block_hash, &epoch,
+						)
+						.await?
+						.map(|key| key.encode_key()),
+					Chain::Polkadot => state_chain_client
+						.storage_map_entry::<pallet_cf_threshold_signature::Keys<Runtime, PolkadotInstance>>(
+							block_hash, &epoch,
+						)
+						.await?
+						.map(|key| key.encode_key()),
+					Chain::Bitcoin => state_chain_client
+						.storage_map_entry::<pallet_cf_threshold_signature::Keys<Runtime, BitcoinInstance>>(
+							block_hash, &epoch,
+						)
+						.await?
+						.map(|key| key.current.encode_key()),
+				}
+				.with_context(|| format!("The State Chain has no {chain} key for epoch {epoch}"))?;
+
+				if on_chain_key != key_id.public_key_bytes() {
+					bail!(
+						"{key_id} does not match the State Chain's {chain} key for epoch {epoch}"
+					);
+				}
+			}
+			Ok(())
+		}
+		.boxed()
+	})
+	.await
+}
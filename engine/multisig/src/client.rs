@@ -1,5 +1,6 @@
 #[macro_use]
 mod utils;
+pub mod ceremony_record;
 mod ceremony_runner;
 mod common;
 pub mod key_store_api;
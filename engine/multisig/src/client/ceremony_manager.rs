@@ -58,8 +58,8 @@ pub type CeremonyOutcome<C> = Result<
 pub type CeremonyResultSender<Ceremony> = oneshot::Sender<CeremonyOutcome<Ceremony>>;
 pub type CeremonyResultReceiver<Ceremony> = oneshot::Receiver<CeremonyOutcome<Ceremony>>;
 
-const KEYGEN_LABEL: &str = "keygen";
-const SIGNING_LABEL: &str = "signing";
+pub const KEYGEN_LABEL: &str = "keygen";
+pub const SIGNING_LABEL: &str = "signing";
 
 /// Ceremony trait combines type parameters that are often used together
 pub trait CeremonyTrait: 'static {
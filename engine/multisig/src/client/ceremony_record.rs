@@ -0,0 +1,268 @@
+use std::{
+	collections::{BTreeMap, BTreeSet, VecDeque},
+	fmt::{Debug, Display},
+	sync::Mutex,
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use cf_primitives::CeremonyId;
+use serde::Serialize;
+use state_chain_runtime::AccountId;
+
+/// The number of ceremonies for which we keep a record.
+const MAX_CEREMONY_RECORDS: usize = 200;
+
+lazy_static::lazy_static! {
+	/// Records of the most recent ceremonies run by this node, used for post-mortems of failed
+	/// ceremonies.
+	pub static ref CEREMONY_RECORDS: CeremonyRecords = CeremonyRecords::default();
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct MessageRecord {
+	/// Time between the start of the stage and the message being processed.
+	pub received_after_ms: u128,
+	/// The size of the message's stage data in bytes.
+	pub size_bytes: u64,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct StageRecord {
+	pub stage: String,
+	/// Time between the start of the ceremony and the start of the stage.
+	pub started_after_ms: u128,
+	/// `None` if the ceremony ended before the stage could be finalized.
+	pub duration_ms: Option<u128>,
+	/// Messages received from other parties, keyed by sender.
+	pub messages: BTreeMap<String, MessageRecord>,
+	/// Parties whose message arrived in the second half of the stage's allotted time.
+	pub late_parties: BTreeSet<String>,
+	/// Parties we were still waiting for when the stage timed out.
+	pub missing_parties: BTreeSet<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CeremonyRecordOutcome {
+	Success,
+	Failure { reason: String, reported_parties: BTreeSet<String> },
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CeremonyRecord {
+	pub ceremony_id: CeremonyId,
+	pub chain: &'static str,
+	pub ceremony_type: &'static str,
+	pub started_at_unix_ms: u128,
+	pub duration_ms: u128,
+	pub participants: BTreeSet<String>,
+	pub stages: Vec<StageRecord>,
+	pub outcome: CeremonyRecordOutcome,
+}
+
+/// Builds up the record of an authorised ceremony as it progresses through its stages.
+pub struct CeremonyRecorder {
+	ceremony_id: CeremonyId,
+	chain: &'static str,
+	ceremony_type: &'static str,
+	participants: BTreeSet<String>,
+	started_at: SystemTime,
+	ceremony_started: Instant,
+	stage_started: Instant,
+	/// Messages arriving later than this after the start of a stage are considered late.
+	late_message_threshold: Duration,
+	stages: Vec<StageRecord>,
+}
+
+impl CeremonyRecorder {
+	pub fn new<'a>(
+		ceremony_id: CeremonyId,
+		chain: &'static str,
+		ceremony_type: &'static str,
+		participants: impl IntoIterator<Item = &'a AccountId>,
+		max_stage_duration: Duration,
+	) -> Self {
+		let now = Instant::now();
+		CeremonyRecorder {
+			ceremony_id,
+			chain,
+			ceremony_type,
+			participants: participants.into_iter().map(ToString::to_string).collect(),
+			started_at: SystemTime::now(),
+			ceremony_started: now,
+			stage_started: now,
+			late_message_threshold: max_stage_duration / 2,
+			stages: Vec::new(),
+		}
+	}
+
+	pub fn start_stage(&mut self, stage: impl Display) {
+		self.stage_started = Instant::now();
+		self.stages.push(StageRecord {
+			stage: stage.to_string(),
+			started_after_ms: self.stage_started.duration_since(self.ceremony_started).as_millis(),
+			duration_ms: None,
+			messages: Default::default(),
+			late_parties: Default::default(),
+			missing_parties: Default::default(),
+		});
+	}
+
+	/// Records the first message from `sender` for the current stage.
+	pub fn message_received(&mut self, sender: &AccountId, size_bytes: u64) {
+		let received_after = self.stage_started.elapsed();
+		let late_message_threshold = self.late_message_threshold;
+		if let Some(stage) = self.stages.last_mut() {
+			let sender = sender.to_string();
+			if !stage.messages.contains_key(&sender) {
+				if received_after > late_message_threshold {
+					stage.late_parties.insert(sender.clone());
+				}
+				stage.messages.insert(
+					sender,
+					MessageRecord { received_after_ms: received_after.as_millis(), size_bytes },
+				);
+			}
+		}
+	}
+
+	/// Records the end of the current stage, along with the parties that never sent their message.
+	pub fn end_stage<'a>(&mut self, missing_parties: impl IntoIterator<Item = &'a AccountId>) {
+		let duration = self.stage_started.elapsed();
+		if let Some(stage) = self.stages.last_mut() {
+			stage.duration_ms = Some(duration.as_millis());
+			stage.missing_parties = missing_parties.into_iter().map(ToString::to_string).collect();
+		}
+	}
+
+	pub fn finish<Output, FailureReason: Debug>(
+		self,
+		outcome: &Result<Output, (BTreeSet<AccountId>, FailureReason)>,
+	) -> CeremonyRecord {
+		CeremonyRecord {
+			ceremony_id: self.ceremony_id,
+			chain: self.chain,
+			ceremony_type: self.ceremony_type,
+			started_at_unix_ms: self
+				.started_at
+				.duration_since(UNIX_EPOCH)
+				.unwrap_or_default()
+				.as_millis(),
+			duration_ms: self.ceremony_started.elapsed().as_millis(),
+			participants: self.participants,
+			stages: self.stages,
+			outcome: match outcome {
+				Ok(_) => CeremonyRecordOutcome::Success,
+				Err((reported_parties, reason)) => CeremonyRecordOutcome::Failure {
+					reason: format!("{reason:?}"),
+					reported_parties: reported_parties.iter().map(ToString::to_string).collect(),
+				},
+			},
+		}
+	}
+}
+
+/// A bounded store of the most recent ceremony records, oldest first.
+#[derive(Default)]
+pub struct CeremonyRecords {
+	records: Mutex<VecDeque<CeremonyRecord>>,
+}
+
+impl CeremonyRecords {
+	fn with_records<R>(&self, f: impl FnOnce(&mut VecDeque<CeremonyRecord>) -> R) -> R {
+		f(&mut self.records.lock().expect("Lock should not be poisoned"))
+	}
+
+	pub fn insert(&self, record: CeremonyRecord) {
+		self.with_records(|records| {
+			if records.len() >= MAX_CEREMONY_RECORDS {
+				records.pop_front();
+			}
+			records.push_back(record);
+		});
+	}
+
+	pub fn all(&self) -> Vec<CeremonyRecord> {
+		self.with_records(|records| records.iter().cloned().collect())
+	}
+
+	/// Records for the given ceremony id. Ceremony ids are per chain, so there may be more than
+	/// one.
+	pub fn get(&self, ceremony_id: CeremonyId) -> Vec<CeremonyRecord> {
+		self.with_records(|records| {
+			records
+				.iter()
+				.filter(|record| record.ceremony_id == ceremony_id)
+				.cloned()
+				.collect()
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn record_with_id(ceremony_id: CeremonyId) -> CeremonyRecord {
+		CeremonyRecorder::new(ceremony_id, "Ethereum", "signing", [], Duration::from_secs(10))
+			.finish::<(), ()>(&Ok(()))
+	}
+
+	#[test]
+	fn only_keeps_the_most_recent_records() {
+		let records = CeremonyRecords::default();
+
+		for ceremony_id in 0..(MAX_CEREMONY_RECORDS as CeremonyId + 1) {
+			records.insert(record_with_id(ceremony_id));
+		}
+
+		assert_eq!(records.all().len(), MAX_CEREMONY_RECORDS);
+		assert!(records.get(0).is_empty());
+		assert_eq!(
+			records.get(1).into_iter().map(|record| record.ceremony_id).collect::<Vec<_>>(),
+			vec![1]
+		);
+	}
+
+	#[test]
+	fn records_messages_and_failures() {
+		let account = |byte| AccountId::new([byte; 32]);
+		let mut recorder = CeremonyRecorder::new(
+			1,
+			"Ethereum",
+			"keygen",
+			&[account(1), account(2), account(3)],
+			// Any delay counts as late.
+			Duration::ZERO,
+		);
+
+		// Messages received before the first stage starts are ignored.
+		recorder.message_received(&account(2), 100);
+		recorder.start_stage("Stage1");
+		std::thread::sleep(Duration::from_millis(1));
+		recorder.message_received(&account(2), 100);
+		// Only the first message from each party is recorded.
+		recorder.message_received(&account(2), 200);
+		recorder.end_stage(&[account(3)]);
+		recorder.start_stage("Stage2");
+
+		let record = recorder.finish::<(), _>(&Err((BTreeSet::from([account(3)]), "Timeout")));
+
+		assert_eq!(record.stages.len(), 2);
+		let stage_1 = &record.stages[0];
+		assert_eq!(stage_1.stage, "Stage1");
+		assert!(stage_1.duration_ms.is_some());
+		assert_eq!(stage_1.messages.len(), 1);
+		assert_eq!(stage_1.messages[&account(2).to_string()].size_bytes, 100);
+		assert_eq!(stage_1.late_parties, BTreeSet::from([account(2).to_string()]));
+		assert_eq!(stage_1.missing_parties, BTreeSet::from([account(3).to_string()]));
+		assert_eq!(record.stages[1].duration_ms, None);
+		assert_eq!(
+			record.outcome,
+			CeremonyRecordOutcome::Failure {
+				reason: "\"Timeout\"".to_string(),
+				reported_parties: BTreeSet::from([account(3).to_string()]),
+			}
+		);
+	}
+}
@@ -14,7 +14,7 @@ use tokio::sync::{
 	mpsc::{UnboundedReceiver, UnboundedSender},
 	oneshot,
 };
-use tracing::{debug, warn, Instrument};
+use tracing::{debug, info, warn, Instrument};
 use utilities::{format_iterator, metrics::CeremonyMetrics};
 
 use crate::{
@@ -28,6 +28,7 @@ use state_chain_runtime::{constants::common::MAX_STAGE_DURATION_SECONDS, Account
 
 use super::{
 	ceremony_manager::{CeremonyOutcome, CeremonyTrait, DynStage, PreparedRequest},
+	ceremony_record::{CeremonyRecorder, CEREMONY_RECORDS},
 	common::PreProcessStageDataCheck,
 };
 
@@ -56,6 +57,8 @@ where
 	outcome_sender: UnboundedSender<(CeremonyId, CeremonyOutcome<Ceremony>)>,
 	_phantom: std::marker::PhantomData<Chain>,
 	metrics: CeremonyMetrics,
+	/// `None` until the ceremony is authorised
+	recorder: Option<CeremonyRecorder>,
 }
 
 impl<Ceremony, Chain> CeremonyRunner<Ceremony, Chain>
@@ -117,6 +120,13 @@ where
 				tracing::info!("Ceremony took {}ms to complete", duration.as_millis())
 			});
 		}
+		if let Some(recorder) = runner.recorder.take() {
+			let record = recorder.finish(&outcome);
+			if outcome.is_err() {
+				span.in_scope(|| info!("Ceremony failed, post-mortem record: {record:?}"));
+			}
+			CEREMONY_RECORDS.insert(record);
+		}
 		let _result = runner.outcome_sender.send((ceremony_id, outcome));
 		Ok(())
 	}
@@ -135,6 +145,7 @@ where
 			outcome_sender,
 			_phantom: Default::default(),
 			metrics: CeremonyMetrics::new(Chain::NAME, Ceremony::CEREMONY_TYPE),
+			recorder: None,
 		}
 	}
 
@@ -144,6 +155,17 @@ where
 		&mut self,
 		mut initial_stage: DynStage<Ceremony>,
 	) -> OptionalCeremonyReturn<Ceremony> {
+		let common = initial_stage.ceremony_common();
+		let mut recorder = CeremonyRecorder::new(
+			common.ceremony_id,
+			Chain::NAME,
+			Ceremony::CEREMONY_TYPE,
+			common.validator_mapping.get_ids(common.all_idxs.clone()).iter(),
+			MAX_STAGE_DURATION,
+		);
+		recorder.start_stage(initial_stage.get_stage_name());
+		self.recorder = Some(recorder);
+
 		let single_party_result = initial_stage.init(&mut self.metrics);
 
 		// This function is only ever called from a oneshot channel,
@@ -176,10 +198,17 @@ where
 			let stage_name = stage.get_stage_name().to_string();
 			let validator_mapping = stage.ceremony_common().validator_mapping.clone();
 
+			if let Some(recorder) = &mut self.recorder {
+				recorder.end_stage(validator_mapping.get_ids(stage.awaited_parties()).iter());
+			}
+
 			match stage.finalize(&mut self.metrics).await {
 				StageResult::NextStage(mut next_stage) => {
 					debug!("Ceremony transitions to {}", next_stage.get_stage_name());
 					self.metrics.stage_completing.inc(&[&stage_name]);
+					if let Some(recorder) = &mut self.recorder {
+						recorder.start_stage(next_stage.get_stage_name());
+					}
 
 					let single_party_result = next_stage.init(&mut self.metrics);
 
@@ -278,6 +307,13 @@ where
 					return None
 				}
 
+				if let Some(recorder) = &mut self.recorder {
+					recorder.message_received(
+						&sender_id,
+						bincode::serialized_size(&data).unwrap_or_default(),
+					);
+				}
+
 				if let ProcessMessageResult::Ready =
 					stage.process_message(sender_idx, data, &mut self.metrics)
 				{
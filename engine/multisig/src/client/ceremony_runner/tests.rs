@@ -385,3 +385,40 @@ async fn should_timeout_authorised_ceremony() {
 	tokio::time::sleep(CEREMONY_TIMEOUT_DURATION).await;
 	assert!(task_handle.is_finished());
 }
+
+#[tokio::test]
+async fn should_record_missing_parties_when_stage_times_out() {
+	let our_account_id = ACCOUNT_IDS[0].clone();
+	let sender_account_id = ACCOUNT_IDS[1].clone();
+	let participants = BTreeSet::from_iter(ACCOUNT_IDS.iter().cloned());
+
+	let (mut stage_1_state, _) =
+		gen_stage_1_signing_state(our_account_id.clone(), participants.clone()).await;
+
+	assert_eq!(
+		stage_1_state
+			.process_or_delay_message(sender_account_id.clone(), gen_signing_data_stage1(1))
+			.await,
+		None
+	);
+	let _result = stage_1_state.force_timeout().await;
+
+	let record = stage_1_state.recorder.take().unwrap().finish::<(), ()>(&Ok(()));
+	assert_eq!(record.participants.len(), participants.len());
+
+	let stage_1_record = &record.stages[0];
+	assert_eq!(stage_1_record.stage, SigningStageName::AwaitCommitments1.to_string());
+	assert!(stage_1_record.duration_ms.is_some());
+	assert_eq!(
+		stage_1_record.messages.keys().cloned().collect::<Vec<_>>(),
+		vec![sender_account_id.to_string()]
+	);
+	assert_eq!(
+		stage_1_record.missing_parties,
+		participants
+			.iter()
+			.filter(|id| ![&our_account_id, &sender_account_id].contains(id))
+			.map(ToString::to_string)
+			.collect()
+	);
+}
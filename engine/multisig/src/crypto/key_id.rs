@@ -16,6 +16,14 @@ impl KeyId {
 	pub fn new<Key: CanonicalEncoding>(epoch_index: EpochIndex, key: Key) -> Self {
 		KeyId { epoch_index, public_key_bytes: key.encode_key() }
 	}
+
+	pub fn epoch_index(&self) -> EpochIndex {
+		self.epoch_index
+	}
+
+	pub fn public_key_bytes(&self) -> &[u8] {
+		&self.public_key_bytes
+	}
 }
 
 impl CanonicalEncoding for cf_chains::dot::PolkadotPublicKey {
@@ -29,19 +29,19 @@ impl BtcRetryRpcClient {
 		nodes: NodeContainer<HttpBasicAuthEndpoint>,
 		expected_btc_network: BitcoinNetwork,
 	) -> Result<Self> {
-		let rpc_client = BtcRpcClient::new(nodes.primary, Some(expected_btc_network))?;
-
-		let backup_rpc_client = nodes
-			.backup
-			.map(|backup_endpoint| BtcRpcClient::new(backup_endpoint, Some(expected_btc_network)))
-			.transpose()?;
+		let quorum = nodes.quorum;
+		let rpc_clients = nodes
+			.into_nodes()
+			.into_iter()
+			.map(|endpoint| BtcRpcClient::new(endpoint, Some(expected_btc_network)))
+			.collect::<Result<_>>()?;
 
 		Ok(Self {
 			retry_client: RetrierClient::new(
 				scope,
 				"btc_rpc",
-				rpc_client,
-				backup_rpc_client,
+				rpc_clients,
+				quorum,
 				BITCOIN_RPC_TIMEOUT,
 				MAX_CONCURRENT_SUBMISSIONS,
 			),
@@ -68,7 +68,7 @@ pub trait BtcRetryRpcApi: Clone {
 impl BtcRetryRpcApi for BtcRetryRpcClient {
 	async fn block(&self, block_hash: BlockHash) -> VerboseBlock {
 		self.retry_client
-			.request(
+			.request_with_quorum(
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move { client.block(block_hash).await })
@@ -80,7 +80,7 @@ impl BtcRetryRpcApi for BtcRetryRpcClient {
 
 	async fn block_hash(&self, block_number: cf_chains::btc::BlockNumber) -> BlockHash {
 		self.retry_client
-			.request(
+			.request_with_quorum(
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move { client.block_hash(block_number).await })
@@ -164,7 +164,7 @@ impl ChainClient for BtcRetryRpcClient {
 		index: Self::Index,
 	) -> Header<Self::Index, Self::Hash, Self::Data> {
 		self.retry_client
-			.request(
+			.request_with_quorum(
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move {
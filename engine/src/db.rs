@@ -1,7 +1,7 @@
 pub mod persistent;
 use std::{collections::HashMap, sync::Arc};
 
-pub use persistent::PersistentKeyDB;
+pub use persistent::{KeyShareCipher, KeyShareEncryptionKey, PersistentKeyDB};
 
 use multisig::{
 	client::{key_store_api::KeyStoreAPI, KeygenResultInfo},
@@ -69,7 +69,7 @@ mod tests {
 		// Create a new db and use the keystore to save the key
 		{
 			let mut key_store = KeyStore::<EthSigning>::new(Arc::new(
-				PersistentKeyDB::open_and_migrate_to_latest(&db_file, None)
+				PersistentKeyDB::open_and_migrate_to_latest(&db_file, None, None)
 					.expect("Failed to open database"),
 			));
 			assert!(key_store.keys.is_empty(), "The db should be empty");
@@ -78,7 +78,7 @@ mod tests {
 
 		// Create the keystore again
 		let key_store = KeyStore::<EthSigning>::new(Arc::new(
-			PersistentKeyDB::open_and_migrate_to_latest(&db_file, None)
+			PersistentKeyDB::open_and_migrate_to_latest(&db_file, None, None)
 				.expect("Failed to open database"),
 		));
 
@@ -1,3 +1,4 @@
+mod encryption;
 mod rocksdb_kv;
 #[cfg(test)]
 mod tests;
@@ -6,14 +7,18 @@ use std::{cmp::Ordering, collections::HashMap, path::Path};
 
 use cf_primitives::EpochIndex;
 use serde::{de::DeserializeOwned, Serialize};
-use tracing::{debug, info, info_span};
+use tracing::{debug, info, info_span, warn};
 use utilities::rle_bitmap::RleBitmap;
 
 use multisig::{client::KeygenResultInfo, ChainSigning, KeyId, CHAIN_TAG_SIZE};
 
 use anyhow::{anyhow, bail, Context, Result};
+use zeroize::Zeroizing;
 
-use rocksdb_kv::{RocksDBKeyValueStore, PREFIX_SIZE};
+use encryption::{seal_key_share, unseal_key_share, SALT_SIZE};
+use rocksdb_kv::{KVWriteBatch, RocksDBKeyValueStore, PREFIX_SIZE};
+
+pub use encryption::{KeyShareCipher, KeyShareEncryptionKey};
 
 /// Name of the directory that the backups will go into (only created before migrations)
 const BACKUPS_DIRECTORY: &str = "backups";
@@ -21,7 +26,7 @@ const BACKUPS_DIRECTORY: &str = "backups";
 /// This is the version of the data on this current branch
 /// This version *must* be bumped, and appropriate migrations
 /// written on any changes to the persistent application data format
-const LATEST_SCHEMA_VERSION: u32 = 0;
+const LATEST_SCHEMA_VERSION: u32 = 1;
 
 const PARTIAL_PREFIX_SIZE: usize = PREFIX_SIZE - CHAIN_TAG_SIZE;
 
@@ -34,6 +39,12 @@ const PROCESSED_BLOCKS_PARTIAL_PREFIX: &[u8; PARTIAL_PREFIX_SIZE] = b"seen____";
 /// Key used to store the `LATEST_SCHEMA_VERSION` value in the `METADATA_COLUMN`
 const DB_SCHEMA_VERSION_KEY: &[u8; 17] = b"db_schema_version";
 const GENESIS_HASH_KEY: &[u8; 12] = b"genesis_hash";
+/// Salt used to derive the key share encryption key from a passphrase
+const KEY_SHARE_ENCRYPTION_SALT_KEY: &[u8; 25] = b"key_share_encryption_salt";
+/// Holds [KEY_SHARE_ENCRYPTION_CHECK_VALUE] encrypted with the key share encryption key, if the
+/// key shares are encrypted. Used to detect an incorrect key before touching any key shares.
+const KEY_SHARE_ENCRYPTION_CHECK_KEY: &[u8; 26] = b"key_share_encryption_check";
+const KEY_SHARE_ENCRYPTION_CHECK_VALUE: &[u8] = b"chainflip key share encryption";
 
 /// Used to specify whether a backup should be created, and if so,
 /// the provided path is used to derive the name of the backup
@@ -46,19 +57,28 @@ enum BackupOption<'a> {
 pub struct PersistentKeyDB {
 	/// Underlying key-value database instance
 	kv_db: RocksDBKeyValueStore,
+	/// If set, key shares are encrypted at rest
+	key_share_cipher: Option<KeyShareCipher>,
 }
 
 impl PersistentKeyDB {
 	/// Open a key database or create one if it doesn't exist. If the schema version of the
 	/// existing database is below the latest, it will attempt to migrate to the latest version.
+	/// If a key share encryption key is provided, any unencrypted key shares are encrypted with it.
 	pub fn open_and_migrate_to_latest(
 		db_path: &Path,
 		genesis_hash: Option<state_chain_runtime::Hash>,
+		key_share_encryption_key: Option<&KeyShareEncryptionKey>,
 	) -> Result<Self> {
 		let span = info_span!("PersistentKeyDB");
 		let _entered = span.enter();
 
-		Self::open_and_migrate_to_version(db_path, genesis_hash, LATEST_SCHEMA_VERSION)
+		Self::open_and_migrate_to_version(
+			db_path,
+			genesis_hash,
+			key_share_encryption_key,
+			LATEST_SCHEMA_VERSION,
+		)
 	}
 
 	/// As [Self::open_and_migrate_to_latest], but allows specifying a specific version
@@ -66,11 +86,18 @@ impl PersistentKeyDB {
 	fn open_and_migrate_to_version(
 		db_path: &Path,
 		genesis_hash: Option<state_chain_runtime::Hash>,
+		key_share_encryption_key: Option<&KeyShareEncryptionKey>,
 		version: u32,
 	) -> Result<Self> {
 		let is_existing_db = db_path.exists();
 
-		let db = PersistentKeyDB { kv_db: RocksDBKeyValueStore::open(db_path)? };
+		let kv_db = RocksDBKeyValueStore::open(db_path)?;
+		let key_share_cipher = key_share_encryption_key
+			.map(|key| KeyShareCipher::new(key, &get_or_create_key_share_salt(&kv_db)?))
+			.transpose()?;
+		check_key_share_cipher(&kv_db, key_share_cipher.as_ref())?;
+
+		let db = PersistentKeyDB { kv_db, key_share_cipher };
 
 		// Only create a backup if there is an existing db that we don't
 		// want to accidentally corrupt
@@ -95,17 +122,65 @@ impl PersistentKeyDB {
 				db_path.display()
 			)
 		})?;
+
+		// The key shares of a database that was already at the latest version when key share
+		// encryption was enabled still need to be encrypted.
+		if db.key_share_cipher.is_some() &&
+			db.kv_db.get_metadata(KEY_SHARE_ENCRYPTION_CHECK_KEY).is_none()
+		{
+			db.reseal_key_shares(|value| unseal_key_share(None, value), db.kv_db.create_batch())
+				.context("Failed to encrypt existing key shares")?;
+		}
+
 		Ok(db)
 	}
 
+	/// Open an existing key database without modifying it, for example to export its key shares.
+	/// Fails if the database is not at the latest schema version, since it can't be migrated.
+	pub fn open_read_only(
+		db_path: &Path,
+		key_share_encryption_key: Option<&KeyShareEncryptionKey>,
+	) -> Result<Self> {
+		if !db_path.exists() {
+			bail!("No database found at {}", db_path.display());
+		}
+		let kv_db = RocksDBKeyValueStore::open_read_only(db_path)?;
+
+		let schema_version = kv_db
+			.get_metadata(DB_SCHEMA_VERSION_KEY)
+			.map(|version| u32::from_be_bytes(version.try_into().expect("Version should be a u32")))
+			.ok_or_else(|| anyhow!("Could not find db schema version"))?;
+		if schema_version != LATEST_SCHEMA_VERSION {
+			bail!(
+				"Database schema version {schema_version} does not match the current schema version {LATEST_SCHEMA_VERSION}. Open it with a matching version of the Chainflip Engine first."
+			);
+		}
+
+		// Without a salt, none of the key shares have been encrypted.
+		let key_share_cipher = key_share_encryption_key
+			.zip(kv_db.get_metadata(KEY_SHARE_ENCRYPTION_SALT_KEY))
+			.map(|(key, salt)| KeyShareCipher::new(key, &salt))
+			.transpose()?;
+		check_key_share_cipher(&kv_db, key_share_cipher.as_ref())?;
+
+		Ok(PersistentKeyDB { kv_db, key_share_cipher })
+	}
+
 	/// Write the keyshare to the db, indexed by the key id
 	pub fn update_key<C: ChainSigning>(
 		&self,
 		key_id: &KeyId,
 		keygen_result_info: &KeygenResultInfo<C::CryptoScheme>,
 	) {
+		let key_share = Zeroizing::new(
+			bincode::serialize(keygen_result_info).expect("Serialization is not expected to fail"),
+		);
 		self.kv_db
-			.put_data(&keygen_data_prefix::<C>(), &key_id, &keygen_result_info)
+			.put_raw_data(
+				&keygen_data_prefix::<C>(),
+				&key_id,
+				&seal_key_share(self.key_share_cipher.as_ref(), &key_share),
+			)
 			.unwrap_or_else(|e| panic!("Failed to update key {}. Error: {}", &key_id, e));
 	}
 
@@ -113,8 +188,19 @@ impl PersistentKeyDB {
 		let span = info_span!("PersistentKeyDB");
 		let _entered = span.enter();
 
-		let keys: HashMap<_, _> =
-			self.kv_db.get_data_for_prefix(&keygen_data_prefix::<C>()).collect();
+		let keys: HashMap<_, _> = self
+			.kv_db
+			.get_raw_data_for_prefix(&keygen_data_prefix::<C>())
+			.map(|(key_id, value)| {
+				let key_share = unseal_key_share(self.key_share_cipher.as_ref(), &value)
+					.unwrap_or_else(|e| panic!("Failed to read key {key_id}. Error: {e}"));
+				(
+					key_id,
+					bincode::deserialize(&key_share)
+						.expect("Deserialization is not expected to fail"),
+				)
+			})
+			.collect();
 
 		for key in &keys {
 			tracing::trace!("Loaded {} key from the database: {}", C::NAME, key.0);
@@ -177,6 +263,46 @@ impl PersistentKeyDB {
 		self.kv_db.put_metadata(DB_SCHEMA_VERSION_KEY, version.to_be_bytes())
 	}
 
+	/// Rewrites all key shares in the current format, encrypting them if key share encryption is
+	/// enabled. `read_key_share` returns the serialized key share from a stored value. The changes
+	/// are written together with anything already in `batch`.
+	fn reseal_key_shares(
+		&self,
+		read_key_share: impl Fn(&[u8]) -> Result<Zeroizing<Vec<u8>>>,
+		mut batch: KVWriteBatch<'_>,
+	) -> Result<()> {
+		let mut count = 0;
+		for (key, value) in
+			self.kv_db.get_raw_entries_with_partial_prefix(KEYGEN_DATA_PARTIAL_PREFIX)
+		{
+			batch.put_value(
+				&key,
+				&seal_key_share(self.key_share_cipher.as_ref(), &read_key_share(&value)?),
+			);
+			count += 1;
+		}
+		if let Some(cipher) = &self.key_share_cipher {
+			batch.put_metadata(
+				KEY_SHARE_ENCRYPTION_CHECK_KEY,
+				cipher.encrypt(KEY_SHARE_ENCRYPTION_CHECK_VALUE),
+			);
+		}
+		batch.write()?;
+
+		if self.key_share_cipher.is_some() {
+			// Make sure the overwritten plaintext key shares don't linger in the files on disk.
+			self.kv_db.compact_data();
+			info!("Encrypted {count} key shares");
+			if count > 0 {
+				warn!(
+					"Backups in the {BACKUPS_DIRECTORY} directory and older copies of the database still contain unencrypted key shares, and should be deleted"
+				);
+			}
+		}
+
+		Ok(())
+	}
+
 	pub fn get_schema_version(&self) -> Result<u32> {
 		self.kv_db
 			.get_metadata(DB_SCHEMA_VERSION_KEY)
@@ -192,6 +318,35 @@ fn keygen_data_prefix<C: ChainSigning>() -> Vec<u8> {
 	[&KEYGEN_DATA_PARTIAL_PREFIX[..], &(C::CHAIN_TAG.to_bytes())[..]].concat()
 }
 
+fn get_or_create_key_share_salt(kv_db: &RocksDBKeyValueStore) -> Result<Vec<u8>> {
+	match kv_db.get_metadata(KEY_SHARE_ENCRYPTION_SALT_KEY) {
+		Some(salt) => Ok(salt),
+		None => {
+			let salt = rand::random::<[u8; SALT_SIZE]>();
+			kv_db.put_metadata(KEY_SHARE_ENCRYPTION_SALT_KEY, salt)?;
+			Ok(salt.to_vec())
+		},
+	}
+}
+
+/// Ensures the key shares in the database can be read with the given cipher.
+fn check_key_share_cipher(
+	kv_db: &RocksDBKeyValueStore,
+	key_share_cipher: Option<&KeyShareCipher>,
+) -> Result<()> {
+	match (kv_db.get_metadata(KEY_SHARE_ENCRYPTION_CHECK_KEY), key_share_cipher) {
+		(Some(check), Some(cipher)) =>
+			if !matches!(cipher.decrypt(&check), Ok(value) if value.as_slice() == KEY_SHARE_ENCRYPTION_CHECK_VALUE)
+			{
+				bail!("Incorrect key share encryption key")
+			},
+		(Some(_), None) =>
+			bail!("Key shares in the database are encrypted, but no key share encryption key is configured"),
+		(None, _) => {},
+	}
+	Ok(())
+}
+
 fn processed_blocks_prefix(witnessner_name: &str) -> Vec<u8> {
 	[PROCESSED_BLOCKS_PARTIAL_PREFIX, witnessner_name.as_bytes()].concat()
 }
@@ -246,11 +401,17 @@ fn migrate_db_to_version(
 				);
 			}
 
-			#[allow(clippy::never_loop)]
 			for version in current_version..target_version {
 				info!("Database is migrating from version {version} to {}", version + 1);
-				// Once we have migrations, we will match on the version here
-				panic!("Unexpected migration from version {version}");
+				// Each migration writes the new schema version in the same batch as its changes, so
+				// an interrupted migration is never applied twice.
+				let mut batch = db.kv_db.create_batch();
+				batch.put_metadata(DB_SCHEMA_VERSION_KEY, (version + 1).to_be_bytes());
+				match version {
+					0 => migrate_0_to_1(db, batch),
+					_ => panic!("Unexpected migration from version {version}"),
+				}
+				.with_context(|| format!("Failed to migrate from version {version}"))?;
 			}
 
 			Ok(())
@@ -258,6 +419,12 @@ fn migrate_db_to_version(
 	}
 }
 
+/// Key shares gain a tag saying whether they are encrypted, and are encrypted if key share
+/// encryption is enabled.
+fn migrate_0_to_1(db: &PersistentKeyDB, batch: KVWriteBatch<'_>) -> Result<()> {
+	db.reseal_key_shares(|value| Ok(Zeroizing::new(value.to_vec())), batch)
+}
+
 // Creates a backup of the database folder to BACKUPS_DIRECTORY/backup_vx_xx_xx
 fn create_backup(path: &Path, schema_version: u32) -> Result<String, anyhow::Error> {
 	// Build the name for the new backup using the schema version and a timestamp
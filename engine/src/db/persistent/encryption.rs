@@ -0,0 +1,145 @@
+//! Encryption of key shares at rest, so that a copy of the database on its own does not leak
+//! threshold key shares.
+//!
+//! Key shares are stored with a leading tag byte. Encrypted key shares are followed by the random
+//! nonce and the XChaCha20Poly1305 ciphertext.
+
+use std::{path::Path, process::Command};
+
+use anyhow::{anyhow, bail, Context, Result};
+use chacha20poly1305::{aead::Aead, Key, KeyInit, XChaCha20Poly1305, XNonce};
+use zeroize::Zeroizing;
+
+use crate::settings::KeyShareEncryption;
+
+const KEY_SIZE: usize = 32;
+pub const SALT_SIZE: usize = 16;
+const NONCE_SIZE: usize = 24;
+
+const PLAINTEXT_TAG: u8 = 0;
+const ENCRYPTED_TAG: u8 = 1;
+
+/// The secret from which the key share encryption key is derived.
+pub enum KeyShareEncryptionKey {
+	/// Stretched into a key with scrypt, using a salt stored in the database.
+	Passphrase(Zeroizing<String>),
+	/// Used as the key directly.
+	Key(Zeroizing<[u8; KEY_SIZE]>),
+}
+
+impl KeyShareEncryptionKey {
+	pub fn load(settings: &KeyShareEncryption) -> Result<Self> {
+		match settings {
+			KeyShareEncryption { passphrase: Some(passphrase), key_file: None, key_command: None } =>
+				Ok(Self::Passphrase(Zeroizing::new(passphrase.clone()))),
+			KeyShareEncryption { passphrase: None, key_file: Some(key_file), key_command: None } =>
+				read_key_file(key_file),
+			KeyShareEncryption { passphrase: None, key_file: None, key_command: Some(command) } =>
+				run_key_command(command),
+			_ => bail!("Exactly one of passphrase, key_file or key_command must be set for key share encryption"),
+		}
+	}
+
+	fn from_hex(hex_key: &str) -> Result<Self> {
+		let bytes = Zeroizing::new(hex::decode(hex_key.trim()).context("Key is not valid hex")?);
+		let mut key = Zeroizing::new([0u8; KEY_SIZE]);
+		if bytes.len() != KEY_SIZE {
+			bail!("Key must be {KEY_SIZE} bytes, got {}", bytes.len());
+		}
+		key.copy_from_slice(&bytes);
+		Ok(Self::Key(key))
+	}
+}
+
+fn read_key_file(key_file: &Path) -> Result<KeyShareEncryptionKey> {
+	let contents = Zeroizing::new(std::fs::read_to_string(key_file).with_context(|| {
+		format!("Failed to read key share encryption key file {}", key_file.display())
+	})?);
+	KeyShareEncryptionKey::from_hex(&contents)
+		.with_context(|| format!("Invalid key share encryption key in {}", key_file.display()))
+}
+
+/// Runs the command, for example a lookup in the OS keyring, and uses the hex encoded key it
+/// prints.
+fn run_key_command(command: &str) -> Result<KeyShareEncryptionKey> {
+	let output = Command::new("sh")
+		.arg("-c")
+		.arg(command)
+		.output()
+		.context("Failed to run key share encryption key command")?;
+	let stdout = Zeroizing::new(output.stdout);
+	if !output.status.success() {
+		bail!("Key share encryption key command exited with {}", output.status);
+	}
+	KeyShareEncryptionKey::from_hex(
+		std::str::from_utf8(&stdout).context("Key command output is not valid utf8")?,
+	)
+	.context("Invalid key share encryption key printed by the key command")
+}
+
+pub struct KeyShareCipher(XChaCha20Poly1305);
+
+impl KeyShareCipher {
+	/// The salt is only used when deriving the key from a passphrase.
+	pub fn new(key: &KeyShareEncryptionKey, salt: &[u8]) -> Result<Self> {
+		let cipher = match key {
+			KeyShareEncryptionKey::Passphrase(passphrase) => {
+				let mut key = Zeroizing::new([0u8; KEY_SIZE]);
+				scrypt::scrypt(
+					passphrase.as_bytes(),
+					salt,
+					&scrypt::Params::recommended(),
+					&mut key[..],
+				)
+				.map_err(|e| anyhow!("Failed to derive key share encryption key: {e}"))?;
+				XChaCha20Poly1305::new(Key::from_slice(&key[..]))
+			},
+			KeyShareEncryptionKey::Key(key) => XChaCha20Poly1305::new(Key::from_slice(&key[..])),
+		};
+		Ok(Self(cipher))
+	}
+
+	pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+		let nonce: [u8; NONCE_SIZE] = rand::random();
+		let ciphertext = self
+			.0
+			.encrypt(XNonce::from_slice(&nonce), plaintext)
+			.expect("Encryption is not expected to fail");
+		[&nonce[..], &ciphertext[..]].concat()
+	}
+
+	pub fn decrypt(&self, data: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+		if data.len() < NONCE_SIZE {
+			bail!("Encrypted data is too short");
+		}
+		let (nonce, ciphertext) = data.split_at(NONCE_SIZE);
+		self.0
+			.decrypt(XNonce::from_slice(nonce), ciphertext)
+			.map(Zeroizing::new)
+			.map_err(|_| {
+				anyhow!("Failed to decrypt, the key share encryption key is incorrect or the data is corrupt")
+			})
+	}
+}
+
+/// Tags the serialized key share, encrypting it if there is a cipher.
+pub fn seal_key_share(cipher: Option<&KeyShareCipher>, key_share: &[u8]) -> Vec<u8> {
+	match cipher {
+		Some(cipher) => [&[ENCRYPTED_TAG][..], &cipher.encrypt(key_share)].concat(),
+		None => [&[PLAINTEXT_TAG][..], key_share].concat(),
+	}
+}
+
+/// Returns the serialized key share from a value written by [seal_key_share].
+pub fn unseal_key_share(
+	cipher: Option<&KeyShareCipher>,
+	sealed: &[u8],
+) -> Result<Zeroizing<Vec<u8>>> {
+	match (sealed.split_first(), cipher) {
+		(Some((&PLAINTEXT_TAG, key_share)), _) => Ok(Zeroizing::new(key_share.to_vec())),
+		(Some((&ENCRYPTED_TAG, encrypted)), Some(cipher)) => cipher.decrypt(encrypted),
+		(Some((&ENCRYPTED_TAG, _)), None) =>
+			bail!("Key share is encrypted, but no key share encryption key is configured"),
+		_ => bail!("Unknown key share format"),
+	}
+}
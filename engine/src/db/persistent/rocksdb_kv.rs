@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use rocksdb::{ColumnFamily, ColumnFamilyDescriptor, Options, WriteBatch, DB};
+use rocksdb::{ColumnFamily, ColumnFamilyDescriptor, IteratorMode, Options, WriteBatch, DB};
 use serde::{de::DeserializeOwned, Serialize};
 
 use anyhow::{Context, Result};
@@ -48,21 +48,46 @@ impl RocksDBKeyValueStore {
 		Ok(RocksDBKeyValueStore { db })
 	}
 
+	/// Opens an existing db without writing to it. Any attempt to write will fail.
+	pub fn open_read_only(db_path: &Path) -> Result<Self> {
+		let mut cfopts_for_prefix = Options::default();
+		cfopts_for_prefix
+			.set_prefix_extractor(rocksdb::SliceTransform::create_fixed_prefix(PREFIX_SIZE));
+
+		let db = DB::open_cf_descriptors_read_only(
+			&Options::default(),
+			db_path,
+			[
+				ColumnFamilyDescriptor::new(METADATA_COLUMN, Options::default()),
+				ColumnFamilyDescriptor::new(DATA_COLUMN, cfopts_for_prefix),
+			],
+			false,
+		)
+		.with_context(|| format!("Failed to open database at: {}", db_path.display()))?;
+
+		Ok(RocksDBKeyValueStore { db })
+	}
+
 	pub fn put_data<T: Serialize, K: Serialize>(
 		&self,
 		prefix: &[u8],
 		key: &K,
 		value: &T,
 	) -> Result<()> {
+		self.put_raw_data(
+			prefix,
+			key,
+			&bincode::serialize(value).expect("Serialization is not expected to fail"),
+		)
+	}
+
+	/// As [Self::put_data], but the value is written as is.
+	pub fn put_raw_data<K: Serialize>(&self, prefix: &[u8], key: &K, value: &[u8]) -> Result<()> {
 		let key_with_prefix =
 			[prefix, &bincode::serialize(key).expect("Serialization is not expected to fail.")]
 				.concat();
 		self.db
-			.put_cf(
-				get_data_column_handle(&self.db),
-				key_with_prefix,
-				bincode::serialize(value).expect("Serialization is not expected to fail"),
-			)
+			.put_cf(get_data_column_handle(&self.db), key_with_prefix, value)
 			.context("Failed to write data to database.")
 	}
 
@@ -85,18 +110,46 @@ impl RocksDBKeyValueStore {
 		&'a self,
 		prefix: &[u8],
 	) -> impl Iterator<Item = (K, V)> + 'a {
+		self.get_raw_data_for_prefix(prefix).map(|(key, value)| {
+			(key, bincode::deserialize(&value).expect("Deserialization is not expected to fail"))
+		})
+	}
+
+	/// As [Self::get_data_for_prefix], but the values are returned as they are stored.
+	pub fn get_raw_data_for_prefix<'a, K: DeserializeOwned>(
+		&'a self,
+		prefix: &[u8],
+	) -> impl Iterator<Item = (K, Box<[u8]>)> + 'a {
 		self.db
 			.prefix_iterator_cf(get_data_column_handle(&self.db), prefix)
 			.map(|result| result.expect("prefix iterator should not fail"))
-			.map(|(key, value)| (Vec::from(&key[PREFIX_SIZE..]), value))
 			.map(|(key, value)| {
 				(
-					bincode::deserialize(&key).expect("Deserialization is not expected to fail"),
-					bincode::deserialize(&value).expect("Deserialization is not expected to fail"),
+					bincode::deserialize(&key[PREFIX_SIZE..])
+						.expect("Deserialization is not expected to fail"),
+					value,
 				)
 			})
 	}
 
+	/// All entries in the data column whose key starts with `partial_prefix`, with their full
+	/// keys. Unlike the prefix iterator, `partial_prefix` can be shorter than [PREFIX_SIZE].
+	pub fn get_raw_entries_with_partial_prefix<'a>(
+		&'a self,
+		partial_prefix: &'a [u8],
+	) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a {
+		self.db
+			.iterator_cf(get_data_column_handle(&self.db), IteratorMode::Start)
+			.map(|result| result.expect("iterator should not fail"))
+			.filter(move |(key, _)| key.starts_with(partial_prefix))
+	}
+
+	/// Compacts the data column, so that overwritten values are removed from the files on disk.
+	pub fn compact_data(&self) {
+		self.db
+			.compact_range_cf(get_data_column_handle(&self.db), None::<&[u8]>, None::<&[u8]>);
+	}
+
 	pub fn put_metadata<V>(&self, key: &[u8], value: V) -> Result<()>
 	where
 		V: AsRef<[u8]>,
@@ -123,7 +176,6 @@ pub struct KVWriteBatch<'a> {
 }
 
 impl<'a> KVWriteBatch<'a> {
-	#[allow(dead_code)]
 	pub fn put_value(&mut self, key: &[u8], value: &[u8]) {
 		self.batch.put_cf(get_data_column_handle(self.db), key, value);
 	}
@@ -35,7 +35,7 @@ fn can_use_multiple_crypto_schemes() {
 
 	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
 	// Create a normal db and save multiple keys to it of different crypto schemes
-	let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+	let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
 
 	let key_1 = add_key_for_scheme::<Scheme1>(&db);
 	let key_2 = add_key_for_scheme::<Scheme2>(&db);
@@ -44,7 +44,7 @@ fn can_use_multiple_crypto_schemes() {
 	drop(db);
 
 	// Open the db and load the keys of all types
-	let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+	let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
 
 	ensure_loaded_one_key::<Scheme1>(&db, &key_1);
 	ensure_loaded_one_key::<Scheme2>(&db, &key_2);
@@ -64,7 +64,7 @@ fn can_load_keys_with_current_keygen_info() {
 	let key_id = KeyId::new(GENESIS_EPOCH, TEST_KEY);
 	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
 	{
-		let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+		let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
 
 		p_db.update_key::<Scheme>(
 			&key_id,
@@ -73,7 +73,7 @@ fn can_load_keys_with_current_keygen_info() {
 	}
 
 	{
-		let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+		let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
 		let keys = p_db.load_keys::<Scheme>();
 		let key = keys.get(&key_id).expect("Should have an entry for key");
 		// single party keygen has a threshold of 0
@@ -88,7 +88,7 @@ fn can_update_key() {
 	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
 	let key_id = KeyId::new(GENESIS_EPOCH, [0; 33]);
 
-	let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+	let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
 
 	let keys_before = p_db.load_keys::<Scheme>();
 	// there should be no key [0; 33] yet
@@ -131,7 +131,7 @@ fn can_load_key_from_backup() {
 
 	// Create a normal db and save a key in it
 	{
-		let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+		let p_db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
 
 		p_db.update_key::<Scheme>(
 			&key_id,
@@ -149,7 +149,8 @@ fn can_load_key_from_backup() {
 
 		// Should be able to open the backup and load the key
 		let p_db =
-			PersistentKeyDB::open_and_migrate_to_latest(backups.first().unwrap(), None).unwrap();
+			PersistentKeyDB::open_and_migrate_to_latest(backups.first().unwrap(), None, None)
+				.unwrap();
 
 		assert!(p_db.load_keys::<Scheme>().get(&key_id).is_some());
 	}
@@ -163,7 +164,7 @@ fn backup_should_fail_if_cant_copy_files() {
 	let (directory, db_path) = new_temp_directory_with_nonexistent_file();
 
 	// Create a normal db
-	assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None));
+	assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None));
 	// Do a backup of the db,
 	assert_ok!(create_backup(&db_path, LATEST_SCHEMA_VERSION));
 
@@ -186,7 +187,7 @@ fn backup_should_fail_if_cant_copy_files() {
 fn backup_should_fail_if_already_exists() {
 	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
 	// Create a normal db
-	assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None));
+	assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None));
 
 	// Backup up the db to a specified directory.
 	// We cannot use the normal backup directory because it has a timestamp in it.
@@ -202,18 +203,56 @@ fn should_not_migrate_backwards() {
 	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
 	// Create a db with schema version + 1
 	{
-		let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+		let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
 		db.put_schema_version(LATEST_SCHEMA_VERSION + 1).unwrap();
 	}
 
 	// Open the db and make sure the migration errors
-	assert!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).is_err());
+	assert!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).is_err());
+}
+
+#[test]
+fn can_open_read_only() {
+	type Scheme = EthSigning;
+
+	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
+	let key_id = KeyId::new(GENESIS_EPOCH, [0; 33]);
+	let key_share = get_single_key_data::<<Scheme as ChainSigning>::CryptoScheme>();
+	let encryption_key = test_encryption_key(1);
+
+	assert!(PersistentKeyDB::open_read_only(&db_path, None).is_err());
+	assert!(!db_path.exists());
+
+	{
+		let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, Some(&encryption_key))
+			.unwrap();
+		db.update_key::<Scheme>(&key_id, &key_share);
+	}
+
+	let db = PersistentKeyDB::open_read_only(&db_path, Some(&encryption_key)).unwrap();
+	assert_eq!(db.load_keys::<Scheme>().get(&key_id), Some(&key_share));
+	assert!(db.put_schema_version(LATEST_SCHEMA_VERSION).is_err());
+	drop(db);
+
+	assert!(PersistentKeyDB::open_read_only(&db_path, None).is_err());
+	assert!(PersistentKeyDB::open_read_only(&db_path, Some(&test_encryption_key(2))).is_err());
+}
+
+#[test]
+fn should_not_open_read_only_if_schema_version_does_not_match() {
+	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
+	{
+		let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
+		db.put_schema_version(LATEST_SCHEMA_VERSION - 1).unwrap();
+	}
+
+	assert!(PersistentKeyDB::open_read_only(&db_path, None).is_err());
 }
 
 #[test]
 fn can_create_new_database() {
 	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
-	assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None));
+	assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None));
 	assert!(db_path.exists());
 }
 
@@ -221,10 +260,10 @@ fn can_create_new_database() {
 fn new_db_returns_db_when_db_data_version_is_latest() {
 	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
 	{
-		let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+		let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
 		db.put_schema_version(LATEST_SCHEMA_VERSION).unwrap();
 	}
-	assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None));
+	assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None));
 }
 
 #[test]
@@ -233,13 +272,18 @@ fn new_db_is_created_with_correct_metadata() {
 	let starting_genesis_hash: state_chain_runtime::Hash = sp_core::H256::random();
 
 	// Create a fresh db. This will write the schema version and genesis hash
-	assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, Some(starting_genesis_hash),));
+	assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(
+		&db_path,
+		Some(starting_genesis_hash),
+		None
+	));
 
 	assert!(db_path.exists());
 
 	// Open the db again, and check metadata
 	let db =
-		PersistentKeyDB::open_and_migrate_to_latest(&db_path, Some(starting_genesis_hash)).unwrap();
+		PersistentKeyDB::open_and_migrate_to_latest(&db_path, Some(starting_genesis_hash), None)
+			.unwrap();
 
 	// Check the schema version is at the latest
 
@@ -261,12 +305,13 @@ fn should_add_genesis_hash_if_missing() {
 
 	// Create a fresh db with no genesis hash
 	{
-		PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+		PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
 	}
 
 	// Open the db normally, so the genesis hash will be added
-	let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, Some(genesis_hash_added_later))
-		.unwrap();
+	let db =
+		PersistentKeyDB::open_and_migrate_to_latest(&db_path, Some(genesis_hash_added_later), None)
+			.unwrap();
 
 	// Check that the genesis hash was added and is correct
 	assert_eq!(
@@ -286,14 +331,17 @@ fn should_error_if_genesis_hash_is_different() {
 
 	// Open the db, so hash 1 is written
 	{
-		assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, Some(genesis_hash_1),));
+		assert_ok!(PersistentKeyDB::open_and_migrate_to_latest(
+			&db_path,
+			Some(genesis_hash_1),
+			None
+		));
 	}
 
 	// Open the db again, but with hash 2, so it should compare them and return an error
 	{
-		assert!(
-			PersistentKeyDB::open_and_migrate_to_latest(&db_path, Some(genesis_hash_2),).is_err()
-		);
+		assert!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, Some(genesis_hash_2), None)
+			.is_err());
 	}
 }
 
@@ -302,12 +350,125 @@ fn test_migration_to_latest_from_0() {
 	let (_dir, db_file) = utilities::testing::new_temp_directory_with_nonexistent_file();
 
 	{
-		let db = PersistentKeyDB::open_and_migrate_to_version(&db_file, None, 0).unwrap();
+		let db = PersistentKeyDB::open_and_migrate_to_version(&db_file, None, None, 0).unwrap();
 
 		assert_eq!(db.get_schema_version().unwrap(), 0);
 	}
 
-	let db = PersistentKeyDB::open_and_migrate_to_latest(&db_file, None).unwrap();
+	let db = PersistentKeyDB::open_and_migrate_to_latest(&db_file, None, None).unwrap();
+
+	assert_eq!(db.get_schema_version().unwrap(), LATEST_SCHEMA_VERSION);
+}
+
+fn test_encryption_key(byte: u8) -> KeyShareEncryptionKey {
+	KeyShareEncryptionKey::Key(Zeroizing::new([byte; 32]))
+}
+
+/// Checks that the serialized key share does not appear anywhere in the stored key shares.
+fn assert_key_share_not_stored_in_plaintext<C: CryptoScheme>(
+	db: &PersistentKeyDB,
+	key_share: &KeygenResultInfo<C>,
+) {
+	let plaintext = bincode::serialize(key_share).unwrap();
+	let stored: Vec<_> = db
+		.kv_db
+		.get_raw_entries_with_partial_prefix(KEYGEN_DATA_PARTIAL_PREFIX)
+		.collect();
+	assert!(!stored.is_empty());
+	for (_, value) in stored {
+		assert!(!value.windows(plaintext.len()).any(|window| window == plaintext));
+	}
+}
+
+#[test]
+fn can_load_encrypted_keys() {
+	type Scheme = EthSigning;
+
+	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
+	let key_id = KeyId::new(GENESIS_EPOCH, [0; 33]);
+	let key_share = get_single_key_data::<<Scheme as ChainSigning>::CryptoScheme>();
+	let encryption_key = test_encryption_key(1);
+
+	{
+		let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, Some(&encryption_key))
+			.unwrap();
+		db.update_key::<Scheme>(&key_id, &key_share);
+		assert_key_share_not_stored_in_plaintext(&db, &key_share);
+	}
+
+	let db =
+		PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, Some(&encryption_key)).unwrap();
+	assert_eq!(db.load_keys::<Scheme>().get(&key_id), Some(&key_share));
+}
+
+#[test]
+fn should_not_open_encrypted_db_without_the_correct_key() {
+	type Scheme = EthSigning;
+
+	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
+
+	{
+		let db = PersistentKeyDB::open_and_migrate_to_latest(
+			&db_path,
+			None,
+			Some(&test_encryption_key(1)),
+		)
+		.unwrap();
+		db.update_key::<Scheme>(
+			&KeyId::new(GENESIS_EPOCH, [0; 33]),
+			&get_single_key_data::<<Scheme as ChainSigning>::CryptoScheme>(),
+		);
+	}
+
+	assert!(PersistentKeyDB::open_and_migrate_to_latest(
+		&db_path,
+		None,
+		Some(&test_encryption_key(2))
+	)
+	.is_err());
+	assert!(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).is_err());
+}
+
+#[test]
+fn should_encrypt_existing_keys_when_encryption_is_enabled() {
+	type Scheme = EthSigning;
+
+	let (_dir, db_path) = new_temp_directory_with_nonexistent_file();
+	let key_id = KeyId::new(GENESIS_EPOCH, [0; 33]);
+	let key_share = get_single_key_data::<<Scheme as ChainSigning>::CryptoScheme>();
+	let encryption_key = test_encryption_key(1);
+
+	{
+		let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, None).unwrap();
+		db.update_key::<Scheme>(&key_id, &key_share);
+	}
+
+	let db =
+		PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, Some(&encryption_key)).unwrap();
+	assert_key_share_not_stored_in_plaintext(&db, &key_share);
+	assert_eq!(db.load_keys::<Scheme>().get(&key_id), Some(&key_share));
+}
+
+#[test]
+fn test_migration_from_0_encrypts_keys() {
+	type Scheme = EthSigning;
+
+	let (directory, db_path) = new_temp_directory_with_nonexistent_file();
+	let key_id = KeyId::new(GENESIS_EPOCH, [0; 33]);
+	let key_share = get_single_key_data::<<Scheme as ChainSigning>::CryptoScheme>();
+	let encryption_key = test_encryption_key(1);
+
+	// Version 0 stored the serialized key shares as they are
+	{
+		let db = PersistentKeyDB::open_and_migrate_to_version(&db_path, None, None, 0).unwrap();
+		db.kv_db.put_data(&keygen_data_prefix::<Scheme>(), &key_id, &key_share).unwrap();
+	}
+
+	let db =
+		PersistentKeyDB::open_and_migrate_to_latest(&db_path, None, Some(&encryption_key)).unwrap();
 
 	assert_eq!(db.get_schema_version().unwrap(), LATEST_SCHEMA_VERSION);
+	assert_key_share_not_stored_in_plaintext(&db, &key_share);
+	assert_eq!(db.load_keys::<Scheme>().get(&key_id), Some(&key_share));
+	assert_eq!(find_backups(&directory, db_path).unwrap().len(), 1);
 }
@@ -1,6 +1,5 @@
 use crate::{
-	common::option_inner,
-	retrier::{Attempt, RetryLimitReturn},
+	retrier::{Attempt, NoRetryLimit, RetryLimitReturn},
 	settings::{NodeContainer, WsHttpEndpoints},
 	witness::common::chain_source::{ChainClient, Header},
 };
@@ -63,25 +62,29 @@ impl DotRetryRpcClient {
 			))
 		};
 
-		let (rpc_client, sub_client) = f_create_clients(nodes.primary)?;
-
-		let (backup_rpc_client, backup_sub_client) =
-			option_inner(nodes.backup.map(f_create_clients).transpose()?);
+		let quorum = nodes.quorum;
+		let (rpc_clients, sub_clients): (Vec<_>, Vec<_>) = nodes
+			.into_nodes()
+			.into_iter()
+			.map(f_create_clients)
+			.collect::<Result<Vec<_>>>()?
+			.into_iter()
+			.unzip();
 
 		Ok(DotRetryRpcClient {
 			rpc_retry_client: RetrierClient::new(
 				scope,
 				"dot_rpc",
-				rpc_client,
-				backup_rpc_client,
+				rpc_clients,
+				quorum,
 				POLKADOT_RPC_TIMEOUT,
 				MAX_CONCURRENT_SUBMISSIONS,
 			),
 			sub_retry_client: RetrierClient::new(
 				scope,
 				"dot_subscribe",
-				futures::future::ready(sub_client),
-				backup_sub_client.map(futures::future::ready),
+				sub_clients.into_iter().map(futures::future::ready).collect(),
+				1,
 				POLKADOT_RPC_TIMEOUT,
 				MAX_CONCURRENT_SUBMISSIONS,
 			),
@@ -114,7 +117,7 @@ pub trait DotRetryRpcApi: Clone {
 impl DotRetryRpcApi for DotRetryRpcClient {
 	async fn block_hash(&self, block_number: PolkadotBlockNumber) -> Option<PolkadotHash> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move { client.block_hash(block_number).await })
@@ -126,7 +129,7 @@ impl DotRetryRpcApi for DotRetryRpcClient {
 
 	async fn extrinsics(&self, block_hash: PolkadotHash) -> Vec<Bytes> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move {
@@ -147,13 +150,14 @@ impl DotRetryRpcApi for DotRetryRpcClient {
 		retry_limit: R,
 	) -> R::ReturnType<Option<Events<PolkadotConfig>>> {
 		self.rpc_retry_client
-			.request_with_limit(
+			.request_with_quorum_by(
 				Box::pin(move |client: DotHttpRpcClient| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move { client.events(block_hash, parent_hash).await })
 				}),
 				RequestLog::new("events".to_string(), Some(format!("{block_hash:?}"))),
 				retry_limit,
+				|a, b| a.as_ref().map(Events::bytes) == b.as_ref().map(Events::bytes),
 			)
 			.await
 	}
@@ -249,7 +253,7 @@ impl ChainClient for DotRetryRpcClient {
 		index: Self::Index,
 	) -> Header<Self::Index, Self::Hash, Self::Data> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum_by(
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move {
@@ -280,6 +284,12 @@ impl ChainClient for DotRetryRpcClient {
 					})
 				}),
 				RequestLog::new("header_at_index".to_string(), Some(format!("{index}"))),
+				NoRetryLimit,
+				|a, b| {
+					a.index == b.index &&
+						a.hash == b.hash && a.parent_hash == b.parent_hash &&
+						a.data.bytes() == b.data.bytes()
+				},
 			)
 			.await
 	}
@@ -322,8 +332,6 @@ mod tests {
 
 	use utilities::task_scope::task_scope;
 
-	use crate::retrier::NoRetryLimit;
-
 	use super::*;
 
 	#[tokio::test]
@@ -339,6 +347,8 @@ mod tests {
 							ws_endpoint: "ws://127.0.0.1:9945".into(),
 						},
 						backup: None,
+						additional: vec![],
+						quorum: 1,
 					},
 					None,
 				)
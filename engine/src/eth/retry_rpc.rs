@@ -41,31 +41,32 @@ impl<Rpc: EthRpcApi> EthRetryRpcClient<Rpc> {
 		scope: &Scope<'_, anyhow::Error>,
 		nodes: NodeContainer<WsHttpEndpoints>,
 		expected_chain_id: U256,
-		rpc_client: ClientFut,
-		backup_rpc_client: Option<ClientFut>,
+		rpc_clients: Vec<ClientFut>,
 	) -> Self {
-		let sub_client =
-			ReconnectSubscriptionClient::new(nodes.primary.ws_endpoint, expected_chain_id);
-
-		let backup_sub_client = nodes
-			.backup
-			.as_ref()
-			.map(|ep| ReconnectSubscriptionClient::new(ep.ws_endpoint.clone(), expected_chain_id));
+		let sub_clients = nodes
+			.nodes()
+			.map(|ep| {
+				futures::future::ready(ReconnectSubscriptionClient::new(
+					ep.ws_endpoint.clone(),
+					expected_chain_id,
+				))
+			})
+			.collect();
 
 		Self {
 			rpc_retry_client: RetrierClient::new(
 				scope,
 				"eth_rpc",
-				rpc_client,
-				backup_rpc_client,
+				rpc_clients,
+				nodes.quorum,
 				ETHERS_RPC_TIMEOUT,
 				MAX_CONCURRENT_SUBMISSIONS,
 			),
 			sub_retry_client: RetrierClient::new(
 				scope,
 				"eth_subscribe",
-				futures::future::ready(sub_client),
-				backup_sub_client.map(futures::future::ready),
+				sub_clients,
+				1,
 				ETHERS_RPC_TIMEOUT,
 				MAX_CONCURRENT_SUBMISSIONS,
 			),
@@ -79,16 +80,12 @@ impl EthRetryRpcClient<EthRpcClient> {
 		nodes: NodeContainer<WsHttpEndpoints>,
 		expected_chain_id: U256,
 	) -> Result<Self> {
-		let rpc_client =
-			EthRpcClient::new(nodes.primary.http_endpoint.clone(), expected_chain_id.as_u64())?;
-
-		let backup_rpc_client = nodes
-			.backup
-			.as_ref()
+		let rpc_clients = nodes
+			.nodes()
 			.map(|ep| EthRpcClient::new(ep.http_endpoint.clone(), expected_chain_id.as_u64()))
-			.transpose()?;
+			.collect::<Result<_>>()?;
 
-		Ok(Self::from_inner_clients(scope, nodes, expected_chain_id, rpc_client, backup_rpc_client))
+		Ok(Self::from_inner_clients(scope, nodes, expected_chain_id, rpc_clients))
 	}
 }
 
@@ -99,15 +96,8 @@ impl EthRetryRpcClient<EthRpcSigningClient> {
 		nodes: NodeContainer<WsHttpEndpoints>,
 		expected_chain_id: U256,
 	) -> Result<Self> {
-		let rpc_client = EthRpcSigningClient::new(
-			private_key_file.clone(),
-			nodes.primary.http_endpoint.clone(),
-			expected_chain_id.as_u64(),
-		)?;
-
-		let backup_rpc_client = nodes
-			.backup
-			.as_ref()
+		let rpc_clients = nodes
+			.nodes()
 			.map(|ep| {
 				EthRpcSigningClient::new(
 					private_key_file.clone(),
@@ -115,9 +105,9 @@ impl EthRetryRpcClient<EthRpcSigningClient> {
 					expected_chain_id.as_u64(),
 				)
 			})
-			.transpose()?;
+			.collect::<Result<_>>()?;
 
-		Ok(Self::from_inner_clients(scope, nodes, expected_chain_id, rpc_client, backup_rpc_client))
+		Ok(Self::from_inner_clients(scope, nodes, expected_chain_id, rpc_clients))
 	}
 }
 
@@ -155,7 +145,7 @@ pub trait EthersRetrySigningRpcApi: EthersRetryRpcApi {
 impl<Rpc: EthRpcApi> EthersRetryRpcApi for EthRetryRpcClient<Rpc> {
 	async fn get_logs(&self, block_hash: H256, contract_address: H160) -> Vec<Log> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move {
@@ -353,7 +343,7 @@ impl<Rpc: EthRpcApi> ChainClient for EthRetryRpcClient<Rpc> {
 		index: Self::Index,
 	) -> Header<Self::Index, Self::Hash, Self::Data> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
 					Box::pin(async move {
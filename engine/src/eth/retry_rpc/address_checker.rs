@@ -35,7 +35,7 @@ impl<Rpc: EthRpcApi + AddressCheckerRpcApi> AddressCheckerRetryRpcApi for EthRet
 		addresses: Vec<H160>,
 	) -> Vec<AddressState> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				Box::pin(move |client| {
 					let addresses = addresses.clone();
 					#[allow(clippy::redundant_async_block)]
@@ -58,7 +58,7 @@ impl<Rpc: EthRpcApi + AddressCheckerRpcApi> AddressCheckerRetryRpcApi for EthRet
 		addresses: Vec<H160>,
 	) -> Vec<U256> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				Box::pin(move |client| {
 					let addresses = addresses.clone();
 					#[allow(clippy::redundant_async_block)]
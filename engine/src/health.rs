@@ -1,41 +1,523 @@
 //! Health monitor for the CFE
 //! allowing external services to query, ensuring it's online
 //! Returns a HTTP 200 response to any request on {hostname}:{port}/health
+//! The {hostname}:{port}/live and {hostname}:{port}/ready routes return a JSON report of the
+//! status of each of the engine's components. They return a HTTP 503 response if the engine has
+//! stalled, or is not ready (still initialising or degraded) respectively.
+//! The admin routes are served on a separate listener, bound to localhost unless configured
+//! otherwise, as they aren't meant to be exposed publicly:
+//! {admin_hostname}:{admin_port}/ceremonies returns the records of the most recent multisig
+//! ceremonies, and {admin_hostname}:{admin_port}/ceremonies/{ceremony_id} those of a specific
+//! ceremony.
 //! Method returns a Sender, allowing graceful termination of the infinite loop
 
-use std::{net::IpAddr, sync::Arc};
+use std::{
+	collections::{BTreeMap, VecDeque},
+	net::IpAddr,
+	sync::{atomic::Ordering, Arc, Mutex},
+	time::{Duration, SystemTime},
+};
 
-use tracing::info;
-use utilities::task_scope;
-use warp::Filter;
+use cf_chains::{Bitcoin, Chain, Ethereum, Polkadot};
+use cf_primitives::{CeremonyId, EpochIndex};
+use multisig::client::{
+	ceremony_manager::{KEYGEN_LABEL, SIGNING_LABEL},
+	ceremony_record::CEREMONY_RECORDS,
+};
+use serde::Serialize;
+use state_chain_runtime::{BlockNumber, Runtime};
+use tracing::{info, warn};
+use utilities::{
+	metrics::{
+		AUTHORIZED_CEREMONIES, P2P_ACTIVE_CONNECTIONS, P2P_ALLOWED_PUBKEYS, P2P_RECONNECT_PEERS,
+		UNAUTHORIZED_CEREMONIES,
+	},
+	task_scope,
+};
+use warp::{http::StatusCode, Filter};
 
-use crate::settings;
+use crate::{
+	settings,
+	state_chain_observer::client::{
+		chain_api::ChainApi, extrinsic_api::signed::SignedExtrinsicApi, storage_api::StorageApi,
+	},
+};
 
 const INITIALISING: &str = "INITIALISING";
 const RUNNING: &str = "RUNNING";
 
+/// The number of blocks, beyond its safety margin, that witnessing of an external chain can fall
+/// behind the chain head before it is considered degraded.
+const MAX_WITNESSING_BACKLOG: u64 = 10;
+/// The number of blocks the State Chain observer can fall behind the finalized head before it is
+/// considered degraded.
+const MAX_STATE_CHAIN_OBSERVER_LAG: BlockNumber = 10;
+/// The number of blocks the State Chain observer can fall behind the finalized head before the
+/// engine is considered stalled, and no longer live.
+const STALLED_STATE_CHAIN_OBSERVER_LAG: BlockNumber = 100;
+/// The number of recent external chain blocks for which we remember when they were produced.
+const MAX_TRACKED_BLOCK_TIMESTAMPS: usize = 256;
+
+lazy_static::lazy_static! {
+	/// Collects the status reported by the engine's components, which is served by the health
+	/// check.
+	pub static ref HEALTH_MONITOR: HealthMonitor = HealthMonitor::default();
+}
+
+/// Which block of an external chain is being reported to the [HealthMonitor].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitoredBlock {
+	/// The latest block we have seen on the external chain.
+	Head,
+	/// The latest block that has been handed to the witnessers, which is expected to lag behind
+	/// the head by the safety margin.
+	Witnessed { safety_margin: u64 },
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+	Initialising,
+	Healthy,
+	Degraded,
+	/// The component has not reported anything yet.
+	Unknown,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct StateChainHealth {
+	pub status: Status,
+	pub best_block: BlockNumber,
+	pub finalized_block: BlockNumber,
+	/// The latest finalized block processed by the State Chain observer.
+	pub observed_block: Option<BlockNumber>,
+	pub observer_lag: Option<BlockNumber>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ExternalChainHealth {
+	pub status: Status,
+	pub head: Option<u64>,
+	pub witnessed_block: Option<u64>,
+	pub lag: Option<u64>,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, Default, PartialEq)]
+pub struct RpcEndpointHealth {
+	/// Whether the last request to this endpoint succeeded, or `None` if it hasn't been used yet.
+	pub reachable: Option<bool>,
+	pub average_latency_ms: Option<u128>,
+	pub error_rate: Option<f64>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct RpcClientHealth {
+	pub status: Status,
+	pub endpoints: Vec<RpcEndpointHealth>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct P2PHealth {
+	pub active_connections: i64,
+	pub reconnecting_peers: i64,
+	pub allowed_peers: i64,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PendingCeremonies {
+	pub authorised: i64,
+	pub unauthorised: i64,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct EpochHealth {
+	pub current_epoch: EpochIndex,
+	pub is_authority: bool,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct HealthReport {
+	pub status: Status,
+	pub state_chain: StateChainHealth,
+	pub external_chains: BTreeMap<&'static str, ExternalChainHealth>,
+	pub rpc_clients: BTreeMap<&'static str, RpcClientHealth>,
+	pub p2p: P2PHealth,
+	/// Keyed by chain and then by ceremony type.
+	pub pending_ceremonies: BTreeMap<&'static str, BTreeMap<&'static str, PendingCeremonies>>,
+	/// `None` if the epoch could not be queried from the State Chain.
+	pub epoch: Option<EpochHealth>,
+}
+
+impl HealthReport {
+	pub fn is_live(&self) -> bool {
+		self.state_chain
+			.observer_lag
+			.map_or(true, |lag| lag <= STALLED_STATE_CHAIN_OBSERVER_LAG)
+	}
+
+	pub fn is_ready(&self) -> bool {
+		self.status == Status::Healthy
+	}
+}
+
+#[derive(Default)]
+struct ExternalChainState {
+	head: Option<u64>,
+	witnessed_block: Option<u64>,
+	safety_margin: u64,
+	/// The timestamps of the most recent blocks, ordered by block number.
+	block_timestamps: VecDeque<(u64, SystemTime)>,
+}
+
+#[derive(Default)]
+struct MonitoredState {
+	state_chain_observer_block: Option<BlockNumber>,
+	external_chains: BTreeMap<&'static str, ExternalChainState>,
+	rpc_clients: BTreeMap<&'static str, Vec<RpcEndpointHealth>>,
+}
+
+/// Components report their progress to the health monitor as they run, and the health check
+/// builds its report from the latest values.
+#[derive(Default)]
+pub struct HealthMonitor {
+	state: Mutex<MonitoredState>,
+}
+
+impl HealthMonitor {
+	fn with_state<R>(&self, f: impl FnOnce(&mut MonitoredState) -> R) -> R {
+		f(&mut self.state.lock().expect("Lock should not be poisoned"))
+	}
+
+	pub fn record_state_chain_observer_block(&self, block_number: BlockNumber) {
+		self.with_state(|state| state.state_chain_observer_block = Some(block_number));
+	}
+
+	pub fn record_external_chain_block(
+		&self,
+		chain: &'static str,
+		block: MonitoredBlock,
+		block_number: u64,
+	) {
+		self.with_state(|state| {
+			let chain_state = state.external_chains.entry(chain).or_default();
+			match block {
+				MonitoredBlock::Head => {
+					chain_state.head = Some(block_number);
+				},
+				MonitoredBlock::Witnessed { safety_margin } => {
+					chain_state.witnessed_block = Some(block_number);
+					chain_state.safety_margin = safety_margin;
+				},
+			}
+		});
+	}
+
+	/// Records the timestamp of a block of an external chain, as reported by its source. Blocks
+	/// that don't extend the tracked ones, such as reorged blocks, are ignored.
+	pub fn record_block_timestamp(
+		&self,
+		chain: &'static str,
+		block_number: u64,
+		timestamp: SystemTime,
+	) {
+		self.with_state(|state| {
+			let block_timestamps =
+				&mut state.external_chains.entry(chain).or_default().block_timestamps;
+			if block_timestamps
+				.back()
+				.map_or(true, |(latest_block, _)| *latest_block < block_number)
+			{
+				if block_timestamps.len() >= MAX_TRACKED_BLOCK_TIMESTAMPS {
+					block_timestamps.pop_front();
+				}
+				block_timestamps.push_back((block_number, timestamp));
+			}
+		});
+	}
+
+	/// The time elapsed since the given block of an external chain was produced, according to its
+	/// timestamp, or `None` if the block was produced before the engine started or too long ago to
+	/// be tracked.
+	pub fn time_since_block_produced(
+		&self,
+		chain: &'static str,
+		block_number: u64,
+	) -> Option<Duration> {
+		self.with_state(|state| {
+			let block_timestamps = &state.external_chains.get(chain)?.block_timestamps;
+			let index =
+				block_timestamps.binary_search_by_key(&block_number, |(block, _)| *block).ok()?;
+			// Block timestamps can be slightly ahead of our clock.
+			Some(
+				SystemTime::now()
+					.duration_since(block_timestamps[index].1)
+					.unwrap_or(Duration::ZERO),
+			)
+		})
+	}
+
+	pub fn register_rpc_clients(&self, name: &'static str, count: usize) {
+		self.with_state(|state| {
+			state.rpc_clients.insert(name, vec![Default::default(); count]);
+		});
+	}
+
+	pub fn record_rpc_outcome(
+		&self,
+		name: &'static str,
+		client_index: usize,
+		succeeded: bool,
+		average_latency: Duration,
+		error_rate: f64,
+	) {
+		self.with_state(|state| {
+			let endpoints = state.rpc_clients.entry(name).or_default();
+			if endpoints.len() <= client_index {
+				endpoints.resize(client_index + 1, Default::default());
+			}
+			endpoints[client_index] = RpcEndpointHealth {
+				reachable: Some(succeeded),
+				average_latency_ms: Some(average_latency.as_millis()),
+				error_rate: Some(error_rate),
+			};
+		});
+	}
+
+	pub fn report(
+		&self,
+		has_completed_initialising: bool,
+		best_block: BlockNumber,
+		finalized_block: BlockNumber,
+		epoch: Option<EpochHealth>,
+	) -> HealthReport {
+		self.with_state(|state| {
+			let state_chain = {
+				let observer_lag = state
+					.state_chain_observer_block
+					.map(|observed_block| finalized_block.saturating_sub(observed_block));
+				StateChainHealth {
+					status: match observer_lag {
+						None => Status::Unknown,
+						Some(lag) if lag > MAX_STATE_CHAIN_OBSERVER_LAG => Status::Degraded,
+						Some(_) => Status::Healthy,
+					},
+					best_block,
+					finalized_block,
+					observed_block: state.state_chain_observer_block,
+					observer_lag,
+				}
+			};
+
+			let external_chains = state
+				.external_chains
+				.iter()
+				.map(|(chain, chain_state)| {
+					let lag = chain_state
+						.head
+						.zip(chain_state.witnessed_block)
+						.map(|(head, witnessed_block)| head.saturating_sub(witnessed_block));
+					(
+						*chain,
+						ExternalChainHealth {
+							status: match lag {
+								None => Status::Unknown,
+								Some(lag)
+									if lag > chain_state.safety_margin + MAX_WITNESSING_BACKLOG =>
+									Status::Degraded,
+								Some(_) => Status::Healthy,
+							},
+							head: chain_state.head,
+							witnessed_block: chain_state.witnessed_block,
+							lag,
+						},
+					)
+				})
+				.collect::<BTreeMap<_, _>>();
+
+			let rpc_clients = state
+				.rpc_clients
+				.iter()
+				.map(|(name, endpoints)| {
+					(
+						*name,
+						RpcClientHealth {
+							status: if endpoints.iter().any(|e| e.reachable == Some(true)) {
+								Status::Healthy
+							} else if endpoints.iter().any(|e| e.reachable == Some(false)) {
+								Status::Degraded
+							} else {
+								Status::Unknown
+							},
+							endpoints: endpoints.clone(),
+						},
+					)
+				})
+				.collect::<BTreeMap<_, _>>();
+
+			let status = if !has_completed_initialising {
+				Status::Initialising
+			} else if state_chain.status == Status::Degraded ||
+				external_chains.values().any(|chain| chain.status == Status::Degraded) ||
+				rpc_clients.values().any(|client| client.status == Status::Degraded)
+			{
+				Status::Degraded
+			} else {
+				Status::Healthy
+			};
+
+			HealthReport {
+				status,
+				state_chain,
+				external_chains,
+				rpc_clients,
+				p2p: P2PHealth {
+					active_connections: P2P_ACTIVE_CONNECTIONS.get(),
+					reconnecting_peers: P2P_RECONNECT_PEERS.get(),
+					allowed_peers: P2P_ALLOWED_PUBKEYS.get(),
+				},
+				pending_ceremonies: [Ethereum::NAME, Polkadot::NAME, Bitcoin::NAME]
+					.into_iter()
+					.map(|chain| {
+						(
+							chain,
+							[KEYGEN_LABEL, SIGNING_LABEL]
+								.into_iter()
+								.map(|ceremony_type| {
+									(
+										ceremony_type,
+										PendingCeremonies {
+											authorised: AUTHORIZED_CEREMONIES
+												.get(&[chain, ceremony_type]),
+											unauthorised: UNAUTHORIZED_CEREMONIES
+												.get(&[chain, ceremony_type]),
+										},
+									)
+								})
+								.collect(),
+						)
+					})
+					.collect(),
+				epoch,
+			}
+		})
+	}
+}
+
+async fn health_report<StateChainClient>(
+	monitor: &HealthMonitor,
+	state_chain_client: &StateChainClient,
+	has_completed_initialising: bool,
+) -> HealthReport
+where
+	StateChainClient: StorageApi + ChainApi + SignedExtrinsicApi,
+{
+	let finalized_block = state_chain_client.latest_finalized_block();
+
+	let epoch = match futures::try_join!(
+		state_chain_client
+			.storage_value::<pallet_cf_validator::CurrentEpoch<Runtime>>(finalized_block.hash),
+		state_chain_client.storage_value::<pallet_cf_validator::CurrentAuthorities<Runtime>>(
+			finalized_block.hash
+		),
+	) {
+		Ok((current_epoch, authorities)) => Some(EpochHealth {
+			current_epoch,
+			is_authority: authorities.contains(&state_chain_client.account_id()),
+		}),
+		Err(error) => {
+			warn!("Failed to query the current epoch: {error}");
+			None
+		},
+	};
+
+	monitor.report(
+		has_completed_initialising,
+		state_chain_client.latest_unfinalized_block().number,
+		finalized_block.number,
+		epoch,
+	)
+}
+
 #[tracing::instrument(name = "health-check", skip_all)]
-pub async fn start<'a, 'env>(
+pub async fn start<'a, 'env, StateChainClient>(
 	scope: &'a task_scope::Scope<'env, anyhow::Error>,
 	health_check_settings: &'a settings::HealthCheck,
 	has_completed_initialising: Arc<std::sync::atomic::AtomicBool>,
-) -> Result<(), anyhow::Error> {
+	state_chain_client: Arc<StateChainClient>,
+	monitor: &'static HealthMonitor,
+) -> Result<(), anyhow::Error>
+where
+	StateChainClient: StorageApi + ChainApi + SignedExtrinsicApi + Send + Sync + 'static,
+{
 	info!("Starting");
 
 	const PATH: &str = "health";
 
-	let future =
-		warp::serve(warp::any().and(warp::path(PATH)).and(warp::path::end()).map(move || {
+	let health = warp::path(PATH).and(warp::path::end()).map({
+		let has_completed_initialising = has_completed_initialising.clone();
+		move || {
 			warp::reply::with_status(
-				if has_completed_initialising.load(std::sync::atomic::Ordering::Relaxed) {
+				if has_completed_initialising.load(Ordering::Relaxed) {
 					RUNNING
 				} else {
 					INITIALISING
 				},
-				warp::http::StatusCode::OK,
+				StatusCode::OK,
 			)
-		}))
-		.bind((health_check_settings.hostname.parse::<IpAddr>()?, health_check_settings.port));
+		}
+	});
+
+	let probe = |path: &'static str, is_ok: fn(&HealthReport) -> bool| {
+		let state_chain_client = state_chain_client.clone();
+		let has_completed_initialising = has_completed_initialising.clone();
+		warp::path(path).and(warp::path::end()).then(move || {
+			let state_chain_client = state_chain_client.clone();
+			let has_completed_initialising = has_completed_initialising.clone();
+			async move {
+				let report = health_report(
+					monitor,
+					&*state_chain_client,
+					has_completed_initialising.load(Ordering::Relaxed),
+				)
+				.await;
+				warp::reply::with_status(
+					warp::reply::json(&report),
+					if is_ok(&report) { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE },
+				)
+			}
+		})
+	};
+
+	let future = warp::serve(
+		health
+			.or(probe("live", HealthReport::is_live))
+			.or(probe("ready", HealthReport::is_ready)),
+	)
+	.bind((health_check_settings.hostname.parse::<IpAddr>()?, health_check_settings.port));
+
+	scope.spawn_weak(async move {
+		future.await;
+		Ok(())
+	});
+
+	Ok(())
+}
+
+#[tracing::instrument(name = "admin", skip_all)]
+pub async fn start_admin<'a, 'env>(
+	scope: &'a task_scope::Scope<'env, anyhow::Error>,
+	admin_settings: &'a settings::Admin,
+) -> Result<(), anyhow::Error> {
+	info!("Starting");
+
+	let ceremonies = warp::path("ceremonies")
+		.and(warp::path::end())
+		.map(|| warp::reply::json(&CEREMONY_RECORDS.all()))
+		.or(warp::path!("ceremonies" / CeremonyId)
+			.map(|ceremony_id| warp::reply::json(&CEREMONY_RECORDS.get(ceremony_id))));
+
+	let future = warp::serve(ceremonies)
+		.bind((admin_settings.hostname.parse::<IpAddr>()?, admin_settings.port));
 
 	scope.spawn_weak(async move {
 		future.await;
@@ -49,8 +531,13 @@ pub async fn start<'a, 'env>(
 mod tests {
 
 	use futures_util::FutureExt;
+	use sp_core::H256;
+	use sp_runtime::AccountId32;
 
-	use crate::settings::Settings;
+	use crate::{
+		settings::Settings,
+		state_chain_observer::client::{mocks::MockStateChainClient, BlockInfo},
+	};
 
 	use super::*;
 
@@ -58,15 +545,45 @@ mod tests {
 	async fn health_check_test() {
 		let health_check = Settings::new_test().unwrap().health_check.unwrap();
 
+		lazy_static::lazy_static! {
+			static ref MONITOR: HealthMonitor = HealthMonitor::default();
+		}
+
+		let mut state_chain_client = MockStateChainClient::new();
+		state_chain_client.expect_latest_finalized_block().returning(|| BlockInfo {
+			parent_hash: H256::default(),
+			hash: H256::default(),
+			number: 20,
+		});
+		state_chain_client.expect_latest_unfinalized_block().returning(|| BlockInfo {
+			parent_hash: H256::default(),
+			hash: H256::default(),
+			number: 22,
+		});
+		state_chain_client.expect_account_id().returning(|| AccountId32::new([0; 32]));
+		state_chain_client
+			.expect_storage_value::<pallet_cf_validator::CurrentEpoch<Runtime>>()
+			.returning(|_| Ok(3));
+		state_chain_client
+			.expect_storage_value::<pallet_cf_validator::CurrentAuthorities<Runtime>>()
+			.returning(|_| Ok([AccountId32::new([0; 32])].into_iter().collect()));
+		let state_chain_client = Arc::new(state_chain_client);
+
 		task_scope::task_scope(|scope| {
 			async {
 				let has_completed_initialising =
 					Arc::new(std::sync::atomic::AtomicBool::new(false));
-				start(scope, &health_check, has_completed_initialising.clone()).await.unwrap();
+				start(
+					scope,
+					&health_check,
+					has_completed_initialising.clone(),
+					state_chain_client,
+					&MONITOR,
+				)
+				.await
+				.unwrap();
 
-				let request_test = |path: &'static str,
-				                    expected_status: reqwest::StatusCode,
-				                    expected_text: &'static str| {
+				let request = |path: &'static str| {
 					let health_check = health_check.clone();
 
 					async move {
@@ -77,18 +594,51 @@ mod tests {
 						.await
 						.unwrap();
 
-						assert_eq!(expected_status, resp.status());
-						assert_eq!(resp.text().await.unwrap(), expected_text);
+						(resp.status(), resp.text().await.unwrap())
 					}
 				};
 
+				let request_report = |path: &'static str| async move {
+					let (status, text) = request(path).await;
+					(status, serde_json::from_str::<serde_json::Value>(&text).unwrap())
+				};
+
 				// starts with `has_completed_initialising` set to false
-				request_test("health", reqwest::StatusCode::OK, INITIALISING).await;
-				request_test("invalid", reqwest::StatusCode::NOT_FOUND, "").await;
+				assert_eq!(
+					request("health").await,
+					(reqwest::StatusCode::OK, INITIALISING.to_string())
+				);
+				assert_eq!(
+					request("invalid").await,
+					(reqwest::StatusCode::NOT_FOUND, String::new())
+				);
+				assert_eq!(request_report("live").await.0, reqwest::StatusCode::OK);
+				let (status, report) = request_report("ready").await;
+				assert_eq!(status, reqwest::StatusCode::SERVICE_UNAVAILABLE);
+				assert_eq!(report["status"], "initialising");
 
-				has_completed_initialising.store(true, std::sync::atomic::Ordering::Relaxed);
+				has_completed_initialising.store(true, Ordering::Relaxed);
+				MONITOR.record_state_chain_observer_block(18);
 
-				request_test("health", reqwest::StatusCode::OK, RUNNING).await;
+				assert_eq!(request("health").await, (reqwest::StatusCode::OK, RUNNING.to_string()));
+				let (status, report) = request_report("ready").await;
+				assert_eq!(status, reqwest::StatusCode::OK);
+				assert_eq!(report["status"], "healthy");
+				assert_eq!(report["state_chain"]["best_block"], 22);
+				assert_eq!(report["state_chain"]["observer_lag"], 2);
+				assert_eq!(report["epoch"]["current_epoch"], 3);
+				assert_eq!(report["epoch"]["is_authority"], true);
+
+				// A lagging State Chain observer is not ready, but is still live.
+				MONITOR.record_state_chain_observer_block(5);
+				let (status, report) = request_report("ready").await;
+				assert_eq!(status, reqwest::StatusCode::SERVICE_UNAVAILABLE);
+				assert_eq!(report["status"], "degraded");
+				assert_eq!(report["state_chain"]["status"], "degraded");
+				assert_eq!(request_report("live").await.0, reqwest::StatusCode::OK);
+
+				// Admin routes are not served by the health check.
+				assert_eq!(request("ceremonies").await.0, reqwest::StatusCode::NOT_FOUND);
 
 				Ok(())
 			}
@@ -97,4 +647,148 @@ mod tests {
 		.await
 		.unwrap();
 	}
+
+	#[tokio::test]
+	async fn admin_test() {
+		let admin = Settings::new_test().unwrap().admin.unwrap();
+
+		task_scope::task_scope(|scope| {
+			async {
+				start_admin(scope, &admin).await.unwrap();
+
+				let request = |path: &'static str| {
+					let admin = admin.clone();
+
+					async move {
+						let resp = reqwest::get(&format!(
+							"http://{}:{}/{}",
+							&admin.hostname, &admin.port, path
+						))
+						.await
+						.unwrap();
+
+						(
+							resp.status(),
+							serde_json::from_str::<serde_json::Value>(&resp.text().await.unwrap())
+								.unwrap(),
+						)
+					}
+				};
+
+				let (status, records) = request("ceremonies").await;
+				assert_eq!(status, reqwest::StatusCode::OK);
+				assert!(records.is_array());
+				let (status, records) = request("ceremonies/1").await;
+				assert_eq!(status, reqwest::StatusCode::OK);
+				assert!(records.is_array());
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap();
+	}
+
+	#[test]
+	fn report_reflects_degraded_components() {
+		let monitor = HealthMonitor::default();
+
+		let report = monitor.report(true, 110, 100, None);
+		assert_eq!(report.status, Status::Healthy);
+		assert_eq!(report.state_chain.status, Status::Unknown);
+		assert!(report.is_live());
+
+		monitor.record_state_chain_observer_block(100 - MAX_STATE_CHAIN_OBSERVER_LAG - 1);
+		let report = monitor.report(true, 110, 100, None);
+		assert_eq!(report.state_chain.status, Status::Degraded);
+		assert_eq!(report.status, Status::Degraded);
+		assert!(report.is_live());
+		assert!(!monitor.report(true, 310, 300, None).is_live());
+
+		monitor.record_state_chain_observer_block(100);
+		assert!(monitor.report(true, 110, 100, None).is_ready());
+
+		monitor.record_external_chain_block("Ethereum", MonitoredBlock::Head, 1000);
+		assert_eq!(
+			monitor.report(true, 110, 100, None).external_chains["Ethereum"].status,
+			Status::Unknown
+		);
+		monitor.record_external_chain_block(
+			"Ethereum",
+			MonitoredBlock::Witnessed { safety_margin: 5 },
+			1000 - 5 - MAX_WITNESSING_BACKLOG,
+		);
+		let report = monitor.report(true, 110, 100, None);
+		assert_eq!(report.external_chains["Ethereum"].status, Status::Healthy);
+		assert_eq!(report.external_chains["Ethereum"].lag, Some(5 + MAX_WITNESSING_BACKLOG));
+		monitor.record_external_chain_block("Ethereum", MonitoredBlock::Head, 1001);
+		let report = monitor.report(true, 110, 100, None);
+		assert_eq!(report.external_chains["Ethereum"].status, Status::Degraded);
+		assert!(!report.is_ready());
+		monitor.record_external_chain_block(
+			"Ethereum",
+			MonitoredBlock::Witnessed { safety_margin: 5 },
+			996,
+		);
+		assert!(monitor.report(true, 110, 100, None).is_ready());
+
+		monitor.register_rpc_clients("eth_rpc", 2);
+		assert_eq!(
+			monitor.report(true, 110, 100, None).rpc_clients["eth_rpc"].status,
+			Status::Unknown
+		);
+		monitor.record_rpc_outcome("eth_rpc", 0, false, Duration::from_millis(200), 0.2);
+		let report = monitor.report(true, 110, 100, None);
+		assert_eq!(report.rpc_clients["eth_rpc"].status, Status::Degraded);
+		assert_eq!(
+			report.rpc_clients["eth_rpc"].endpoints,
+			vec![
+				RpcEndpointHealth {
+					reachable: Some(false),
+					average_latency_ms: Some(200),
+					error_rate: Some(0.2)
+				},
+				RpcEndpointHealth::default(),
+			]
+		);
+		assert!(!report.is_ready());
+		monitor.record_rpc_outcome("eth_rpc", 1, true, Duration::from_millis(100), 0.0);
+		assert!(monitor.report(true, 110, 100, None).is_ready());
+
+		assert!(!monitor.report(false, 110, 100, None).is_ready());
+	}
+	#[test]
+	fn remembers_when_recent_blocks_were_produced() {
+		let monitor = HealthMonitor::default();
+		let produced_at = SystemTime::now() - Duration::from_secs(60);
+
+		assert_eq!(monitor.time_since_block_produced("Bitcoin", 100), None);
+		monitor.record_block_timestamp("Bitcoin", 100, produced_at);
+		assert!(
+			monitor.time_since_block_produced("Bitcoin", 100).unwrap() >= Duration::from_secs(60)
+		);
+		assert_eq!(monitor.time_since_block_produced("Bitcoin", 99), None);
+		assert_eq!(monitor.time_since_block_produced("Ethereum", 100), None);
+
+		// Timestamps ahead of our clock don't underflow.
+		monitor.record_block_timestamp("Bitcoin", 101, SystemTime::now() + Duration::from_secs(60));
+		assert_eq!(monitor.time_since_block_produced("Bitcoin", 101), Some(Duration::ZERO));
+
+		// Reorged blocks don't replace the timestamps we already have.
+		monitor.record_block_timestamp("Bitcoin", 100, SystemTime::now());
+		assert_eq!(
+			monitor.with_state(|state| state.external_chains["Bitcoin"].block_timestamps.len()),
+			2
+		);
+		assert!(
+			monitor.time_since_block_produced("Bitcoin", 100).unwrap() >= Duration::from_secs(60)
+		);
+
+		for block_number in 102..(100 + MAX_TRACKED_BLOCK_TIMESTAMPS as u64 + 1) {
+			monitor.record_block_timestamp("Bitcoin", block_number, produced_at);
+		}
+		assert_eq!(monitor.time_since_block_produced("Bitcoin", 100), None);
+		assert!(monitor.time_since_block_produced("Bitcoin", 101).is_some());
+	}
 }
@@ -3,7 +3,7 @@ use cf_chains::dot::PolkadotHash;
 use cf_primitives::AccountRole;
 use chainflip_engine::{
 	btc::retry_rpc::BtcRetryRpcClient,
-	db::{KeyStore, PersistentKeyDB},
+	db::{KeyShareEncryptionKey, KeyStore, PersistentKeyDB},
 	dot::retry_rpc::DotRetryRpcClient,
 	eth::{retry_rpc::EthRetryRpcClient, rpc::EthRpcSigningClient},
 	health, p2p,
@@ -72,18 +72,36 @@ async fn run_main(settings: Settings) -> anyhow::Result<()> {
 			start_logger_server_fn.take().expect("only called once")(scope);
 
 			if let Some(health_check_settings) = &settings.health_check {
-				health::start(scope, health_check_settings, has_completed_initialising.clone())
-					.await?;
+				health::start(
+					scope,
+					health_check_settings,
+					has_completed_initialising.clone(),
+					state_chain_client.clone(),
+					&health::HEALTH_MONITOR,
+				)
+				.await?;
+			}
+
+			if let Some(admin_settings) = &settings.admin {
+				health::start_admin(scope, admin_settings).await?;
 			}
 
 			if let Some(prometheus_settings) = &settings.prometheus {
 				metrics::start(scope, prometheus_settings).await?;
 			}
 
+			let key_share_encryption_key = settings
+				.signing
+				.key_share_encryption
+				.as_ref()
+				.map(KeyShareEncryptionKey::load)
+				.transpose()
+				.context("Failed to load key share encryption key")?;
 			let db = Arc::new(
 				PersistentKeyDB::open_and_migrate_to_latest(
 					&settings.signing.db_file,
 					Some(state_chain_client.genesis_hash()),
+					key_share_encryption_key.as_ref(),
 				)
 				.context("Failed to open database")?,
 			);
@@ -193,6 +211,7 @@ async fn run_main(settings: Settings) -> anyhow::Result<()> {
 				scope,
 				eth_client.clone(),
 				btc_client.clone(),
+				settings.btc.zmq_endpoint,
 				dot_client.clone(),
 				state_chain_client.clone(),
 				state_chain_stream.clone(),
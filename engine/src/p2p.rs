@@ -1,6 +1,9 @@
 mod core;
+mod libp2p_transport;
 mod muxer;
 mod peer_info_submitter;
+#[cfg(test)]
+mod tests;
 
 use std::{
 	marker::PhantomData,
@@ -10,7 +13,7 @@ use std::{
 
 use crate::{
 	p2p::core::ed25519_secret_key_to_x25519_secret_key,
-	settings::P2P as P2PSettings,
+	settings::{P2PTransport, P2P as P2PSettings},
 	state_chain_observer::client::{
 		chain_api::ChainApi,
 		extrinsic_api::signed::SignedExtrinsicApi,
@@ -167,18 +170,32 @@ where
 
 					p2p_ready_sender.send(()).unwrap();
 
-					core::start(
-						node_key,
-						settings.port,
-						current_peers,
-						our_account_id,
-						incoming_message_sender,
-						outgoing_message_receiver,
-						peer_update_receiver,
-					)
-					.await;
-
-					Ok(())
+					match settings.transport {
+						P2PTransport::Zmq => {
+							core::start(
+								node_key,
+								settings.port,
+								current_peers,
+								our_account_id,
+								incoming_message_sender,
+								outgoing_message_receiver,
+								peer_update_receiver,
+							)
+							.await;
+							Ok(())
+						},
+						P2PTransport::Libp2p =>
+							libp2p_transport::start(
+								node_key,
+								settings.port,
+								current_peers,
+								our_account_id,
+								incoming_message_sender,
+								outgoing_message_receiver,
+								peer_update_receiver,
+							)
+							.await,
+					}
 				}
 			});
 
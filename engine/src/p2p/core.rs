@@ -37,7 +37,7 @@ use super::{EdPublicKey, P2PKey, XPublicKey};
 /// for the client to authenticate themselves. We want to keep
 /// this somewhat short to mitigate some attacks where clients
 /// can use system resources without authenticating.
-const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(3);
+pub const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(3);
 /// How long to wait until some activity on a socket (defined by a need to
 /// send a message) before deeming the connection "stale" (the state in which
 /// we drop the socket and are not actively trying to reconnect)
@@ -60,6 +60,7 @@ pub enum PeerUpdate {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PeerInfo {
 	pub account_id: AccountId,
+	pub ed_pubkey: EdPublicKey,
 	pub pubkey: XPublicKey,
 	pub ip: Ipv6Addr,
 	pub port: Port,
@@ -72,10 +73,11 @@ impl PeerInfo {
 		ip: Ipv6Addr,
 		port: Port,
 	) -> Self {
-		let ed_public_key = ed25519_dalek::VerifyingKey::from_bytes(&ed_public_key.0).unwrap();
-		let x_public_key = ed25519_public_key_to_x25519_public_key(&ed_public_key);
+		let x_public_key = ed25519_public_key_to_x25519_public_key(
+			&ed25519_dalek::VerifyingKey::from_bytes(&ed_public_key.0).unwrap(),
+		);
 
-		PeerInfo { account_id, pubkey: x_public_key, ip, port }
+		PeerInfo { account_id, ed_pubkey: ed_public_key, pubkey: x_public_key, ip, port }
 	}
 
 	pub fn zmq_endpoint(&self) -> String {
@@ -0,0 +1,368 @@
+//! An alternative to the ZMQ transport in [super::core], built on libp2p. Connections are
+//! authenticated with Noise using the same ed25519 node keys that are registered on the State
+//! Chain, and multiplexed with Yamux, so a single connection to each peer is reused for messages
+//! in both directions regardless of which side dialed it. In particular, a node behind a NAT is
+//! reached over the connection it opened itself rather than having to accept a new one.
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+	collections::HashMap,
+	io,
+	net::{IpAddr, Ipv4Addr},
+	time::Duration,
+};
+
+use anyhow::{anyhow, Context};
+use async_trait::async_trait;
+use futures::{AsyncRead, AsyncWrite, StreamExt};
+use libp2p::{
+	core::upgrade::{self, read_length_prefixed, write_length_prefixed},
+	identity,
+	multiaddr::Protocol,
+	noise,
+	request_response::{self, ProtocolName, ProtocolSupport},
+	swarm::{SwarmBuilder, SwarmEvent},
+	tcp, yamux, Multiaddr, PeerId, Swarm, Transport,
+};
+use state_chain_runtime::AccountId;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tracing::{debug, info, trace, warn};
+use utilities::{
+	metrics::{
+		P2P_ACTIVE_CONNECTIONS, P2P_BAD_MSG, P2P_DECLINED_CONNECTIONS, P2P_MSG_RECEIVED,
+		P2P_MSG_SENT,
+	},
+	Port,
+};
+
+use crate::p2p::OutgoingMultisigStageMessages;
+
+use super::{
+	core::{HANDSHAKE_TIMEOUT, MAX_INACTIVITY_THRESHOLD},
+	EdPublicKey, P2PKey, PeerInfo, PeerUpdate,
+};
+
+const PROTOCOL_NAME: &[u8] = b"/chainflip/multisig/1";
+/// Same limit as the ZMQ transport, see `core::socket`.
+const MAX_MESSAGE_SIZE: usize = 2 * 1024 * 1024;
+/// How long to wait for a peer to acknowledge a message, including the time it takes to dial
+/// the peer if we are not connected yet.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+type Behaviour = request_response::Behaviour<CeremonyMessageCodec>;
+
+#[derive(Clone)]
+struct CeremonyMessageProtocol;
+
+impl ProtocolName for CeremonyMessageProtocol {
+	fn protocol_name(&self) -> &[u8] {
+		PROTOCOL_NAME
+	}
+}
+
+/// Each message is sent as a length prefixed request on its own substream. The (empty) response
+/// only acknowledges that the message was received.
+#[derive(Clone)]
+struct CeremonyMessageCodec;
+
+#[async_trait]
+impl request_response::Codec for CeremonyMessageCodec {
+	type Protocol = CeremonyMessageProtocol;
+	type Request = Vec<u8>;
+	type Response = ();
+
+	async fn read_request<T>(
+		&mut self,
+		_protocol: &CeremonyMessageProtocol,
+		io: &mut T,
+	) -> io::Result<Vec<u8>>
+	where
+		T: AsyncRead + Unpin + Send,
+	{
+		read_length_prefixed(io, MAX_MESSAGE_SIZE).await
+	}
+
+	async fn read_response<T>(
+		&mut self,
+		_protocol: &CeremonyMessageProtocol,
+		io: &mut T,
+	) -> io::Result<()>
+	where
+		T: AsyncRead + Unpin + Send,
+	{
+		read_length_prefixed(io, 0).await.map(|_| ())
+	}
+
+	async fn write_request<T>(
+		&mut self,
+		_protocol: &CeremonyMessageProtocol,
+		io: &mut T,
+		payload: Vec<u8>,
+	) -> io::Result<()>
+	where
+		T: AsyncWrite + Unpin + Send,
+	{
+		write_length_prefixed(io, payload).await
+	}
+
+	async fn write_response<T>(
+		&mut self,
+		_protocol: &CeremonyMessageProtocol,
+		io: &mut T,
+		_response: (),
+	) -> io::Result<()>
+	where
+		T: AsyncWrite + Unpin + Send,
+	{
+		write_length_prefixed(io, b"").await
+	}
+}
+
+fn peer_id_from_ed25519_pubkey(pubkey: &EdPublicKey) -> anyhow::Result<PeerId> {
+	let public_key = identity::ed25519::PublicKey::try_from_bytes(&pubkey.0)
+		.context("invalid ed25519 public key")?;
+	Ok(PeerId::from_public_key(&public_key.into()))
+}
+
+fn peer_address(peer: &PeerInfo) -> Multiaddr {
+	let ip = match peer.ip.to_ipv4_mapped() {
+		Some(ipv4) => IpAddr::V4(ipv4),
+		None => IpAddr::V6(peer.ip),
+	};
+	Multiaddr::from(ip).with(Protocol::Tcp(peer.port))
+}
+
+struct RegisteredPeer {
+	peer_id: PeerId,
+	address: Multiaddr,
+}
+
+struct Libp2pContext {
+	swarm: Swarm<Behaviour>,
+	our_account_id: AccountId,
+	/// Peers we are allowed to exchange messages with
+	peers: HashMap<AccountId, RegisteredPeer>,
+	/// Used to map the peer id authenticated by Noise to the sender's account id
+	peer_id_to_account_id: HashMap<PeerId, AccountId>,
+	incoming_message_sender: UnboundedSender<(AccountId, Vec<u8>)>,
+}
+
+pub(super) async fn start(
+	p2p_key: P2PKey,
+	port: Port,
+	current_peers: Vec<PeerInfo>,
+	our_account_id: AccountId,
+	incoming_message_sender: UnboundedSender<(AccountId, Vec<u8>)>,
+	outgoing_message_receiver: UnboundedReceiver<OutgoingMultisigStageMessages>,
+	peer_update_receiver: UnboundedReceiver<PeerUpdate>,
+) -> anyhow::Result<()> {
+	let keypair = identity::Keypair::ed25519_from_bytes(p2p_key.signing_key.to_bytes())
+		.context("Invalid node key")?;
+	let local_peer_id = keypair.public().to_peer_id();
+	debug!("Our libp2p peer id: {local_peer_id}");
+
+	let transport = tcp::tokio::Transport::new(tcp::Config::new().nodelay(true))
+		.upgrade(upgrade::Version::V1)
+		.authenticate(noise::Config::new(&keypair).context("Failed to configure noise")?)
+		.multiplex(yamux::Config::default())
+		.timeout(HANDSHAKE_TIMEOUT)
+		.boxed();
+
+	let behaviour = {
+		let mut config = request_response::Config::default();
+		config
+			.set_request_timeout(REQUEST_TIMEOUT)
+			.set_connection_keep_alive(MAX_INACTIVITY_THRESHOLD);
+		Behaviour::new(
+			CeremonyMessageCodec,
+			[(CeremonyMessageProtocol, ProtocolSupport::Full)],
+			config,
+		)
+	};
+
+	let mut swarm = SwarmBuilder::with_tokio_executor(transport, behaviour, local_peer_id).build();
+
+	swarm
+		.listen_on(Multiaddr::from(Ipv4Addr::UNSPECIFIED).with(Protocol::Tcp(port)))
+		.with_context(|| format!("Failed to listen on p2p port {port}"))?;
+
+	let mut context = Libp2pContext {
+		swarm,
+		our_account_id,
+		peers: Default::default(),
+		peer_id_to_account_id: Default::default(),
+		incoming_message_sender,
+	};
+
+	debug!("Registering peer info for {} peers", current_peers.len());
+	for peer_info in current_peers {
+		context.add_or_update_peer(peer_info);
+	}
+
+	context.control_loop(outgoing_message_receiver, peer_update_receiver).await
+}
+
+impl Libp2pContext {
+	async fn control_loop(
+		mut self,
+		mut outgoing_message_receiver: UnboundedReceiver<OutgoingMultisigStageMessages>,
+		mut peer_update_receiver: UnboundedReceiver<PeerUpdate>,
+	) -> anyhow::Result<()> {
+		loop {
+			tokio::select! {
+				Some(messages) = outgoing_message_receiver.recv() => {
+					self.send_messages(messages);
+				}
+				Some(peer_update) = peer_update_receiver.recv() => {
+					self.on_peer_update(peer_update);
+				}
+				event = self.swarm.select_next_some() => {
+					self.on_swarm_event(event)?;
+				}
+			}
+		}
+	}
+
+	fn send_messages(&mut self, messages: OutgoingMultisigStageMessages) {
+		match messages {
+			OutgoingMultisigStageMessages::Broadcast(account_ids, payload) => {
+				trace!("Broadcasting a message to all {} peers", account_ids.len());
+				for acc_id in account_ids {
+					self.send_message(acc_id, payload.clone());
+				}
+			},
+			OutgoingMultisigStageMessages::Private(messages) => {
+				trace!("Sending private messages to all {} peers", messages.len());
+				for (acc_id, payload) in messages {
+					self.send_message(acc_id, payload);
+				}
+			},
+		}
+	}
+
+	fn send_message(&mut self, account_id: AccountId, payload: Vec<u8>) {
+		if let Some(peer) = self.peers.get(&account_id) {
+			// Dials the peer first if there is no connection to reuse
+			self.swarm.behaviour_mut().send_request(&peer.peer_id, payload);
+			P2P_MSG_SENT.inc();
+		} else {
+			warn!("Failed to send message. Peer not registered: {account_id}")
+		}
+	}
+
+	fn on_peer_update(&mut self, update: PeerUpdate) {
+		match update {
+			PeerUpdate::Registered(peer_info) => self.add_or_update_peer(peer_info),
+			PeerUpdate::Deregistered(account_id, _pubkey) =>
+				if account_id == self.our_account_id {
+					warn!("Received peer info deregistration of our own node!");
+				} else if self.remove_peer(&account_id).is_none() {
+					warn!("Failed remove unknown peer: {account_id}");
+				},
+		}
+	}
+
+	fn add_or_update_peer(&mut self, peer_info: PeerInfo) {
+		if peer_info.account_id == self.our_account_id {
+			// nothing to do
+			return
+		}
+
+		debug!(
+			peer_info = peer_info.to_string(),
+			"Received info for peer with account id {}, adding to allowed peers",
+			&peer_info.account_id
+		);
+
+		// Drop any connection made with the previous key or address
+		self.remove_peer(&peer_info.account_id);
+
+		let peer_id = match peer_id_from_ed25519_pubkey(&peer_info.ed_pubkey) {
+			Ok(peer_id) => peer_id,
+			Err(e) => {
+				warn!("Ignoring peer {}: {e:#}", peer_info.account_id);
+				return
+			},
+		};
+
+		let peer = RegisteredPeer { peer_id, address: peer_address(&peer_info) };
+		self.swarm.behaviour_mut().add_address(&peer.peer_id, peer.address.clone());
+		self.peer_id_to_account_id.insert(peer.peer_id, peer_info.account_id.clone());
+		self.peers.insert(peer_info.account_id, peer);
+	}
+
+	fn remove_peer(&mut self, account_id: &AccountId) -> Option<RegisteredPeer> {
+		let peer = self.peers.remove(account_id)?;
+		self.swarm.behaviour_mut().remove_address(&peer.peer_id, &peer.address);
+		self.peer_id_to_account_id.remove(&peer.peer_id);
+		// Fails if we weren't connected, which is fine
+		let _result = self.swarm.disconnect_peer_id(peer.peer_id);
+		Some(peer)
+	}
+
+	fn on_swarm_event<E: std::fmt::Debug>(
+		&mut self,
+		event: SwarmEvent<request_response::Event<Vec<u8>, ()>, E>,
+	) -> anyhow::Result<()> {
+		match event {
+			SwarmEvent::Behaviour(request_response::Event::Message {
+				peer,
+				message: request_response::Message::Request { request, channel, .. },
+			}) => {
+				P2P_MSG_RECEIVED.inc();
+				if let Some(acc_id) = self.peer_id_to_account_id.get(&peer) {
+					trace!("Received a message from {acc_id}");
+					self.incoming_message_sender
+						.send((acc_id.clone(), request))
+						.map_err(|_| anyhow!("Incoming message receiver was dropped"))?;
+					// Fails if the connection has been closed in the meantime, in which case the
+					// sender will see the request time out.
+					let _result = self.swarm.behaviour_mut().send_response(channel, ());
+				} else {
+					P2P_BAD_MSG.inc(&["unknown_peer_id"]);
+					warn!("Received a message from an unknown peer id: {peer}");
+				}
+			},
+			SwarmEvent::Behaviour(request_response::Event::OutboundFailure {
+				peer, error, ..
+			}) => {
+				warn!(
+					"Failed to send message to {}: {error}",
+					self.peer_id_to_account_id
+						.get(&peer)
+						.map_or_else(|| peer.to_string(), ToString::to_string)
+				);
+			},
+			SwarmEvent::Behaviour(event) => {
+				trace!("Request-response event: {event:?}");
+			},
+			SwarmEvent::ConnectionEstablished { peer_id, num_established, .. } => {
+				if num_established.get() == 1 {
+					P2P_ACTIVE_CONNECTIONS.inc();
+				}
+				if let Some(acc_id) = self.peer_id_to_account_id.get(&peer_id) {
+					debug!("Connected to peer {acc_id}");
+				} else {
+					P2P_DECLINED_CONNECTIONS.inc();
+					debug!("Disconnecting from unknown peer id: {peer_id}");
+					let _result = self.swarm.disconnect_peer_id(peer_id);
+				}
+			},
+			SwarmEvent::ConnectionClosed { peer_id, num_established, cause, .. } => {
+				if num_established == 0 {
+					P2P_ACTIVE_CONNECTIONS.dec();
+				}
+				debug!("Connection to {peer_id} closed: {cause:?}");
+			},
+			SwarmEvent::NewListenAddr { address, .. } => {
+				info!("Listening for p2p connections on {address}");
+			},
+			event => {
+				trace!("Swarm event: {event:?}");
+			},
+		}
+		Ok(())
+	}
+}
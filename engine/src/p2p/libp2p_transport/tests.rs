@@ -0,0 +1,164 @@
+use super::{PeerInfo, PeerUpdate};
+use crate::p2p::{OutgoingMultisigStageMessages, P2PKey};
+use sp_core::ed25519::Public;
+use state_chain_runtime::AccountId;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tracing::{info_span, Instrument};
+use utilities::{testing::recv_with_custom_timeout, Port};
+
+use std::time::Duration;
+
+/// Unlike ZMQ, libp2p dials peers when we first send them a message, so this only
+/// needs to account for connecting over loopback
+const MAX_CONNECTION_DELAY: Duration = Duration::from_millis(500);
+
+fn create_node_info(id: AccountId, node_key: &ed25519_dalek::SigningKey, port: Port) -> PeerInfo {
+	use std::net::Ipv4Addr;
+	let ip = Ipv4Addr::LOCALHOST.to_ipv6_mapped();
+	let pubkey = Public(node_key.verifying_key().to_bytes());
+	PeerInfo::new(id, pubkey, ip, port)
+}
+
+fn create_keypair() -> ed25519_dalek::SigningKey {
+	ed25519_dalek::SigningKey::generate(&mut rand::thread_rng())
+}
+
+struct Node {
+	account_id: AccountId,
+	msg_sender: UnboundedSender<OutgoingMultisigStageMessages>,
+	peer_update_sender: UnboundedSender<PeerUpdate>,
+	msg_receiver: UnboundedReceiver<(AccountId, Vec<u8>)>,
+}
+
+fn spawn_node(
+	key: &ed25519_dalek::SigningKey,
+	idx: usize,
+	our_peer_info: PeerInfo,
+	peer_infos: &[PeerInfo],
+) -> Node {
+	let account_id = our_peer_info.account_id.clone();
+
+	let (incoming_message_sender, incoming_message_receiver) =
+		tokio::sync::mpsc::unbounded_channel();
+
+	let (outgoing_message_sender, outgoing_message_receiver) =
+		tokio::sync::mpsc::unbounded_channel();
+
+	let (peer_update_sender, peer_update_receiver) = tokio::sync::mpsc::unbounded_channel();
+
+	tokio::spawn({
+		super::start(
+			P2PKey::new(key.as_bytes()),
+			our_peer_info.port,
+			peer_infos.to_vec(),
+			account_id.clone(),
+			incoming_message_sender,
+			outgoing_message_receiver,
+			peer_update_receiver,
+		)
+		.instrument(info_span!("node", idx = idx))
+	});
+
+	Node {
+		account_id,
+		msg_sender: outgoing_message_sender,
+		peer_update_sender,
+		msg_receiver: incoming_message_receiver,
+	}
+}
+
+async fn send_and_receive_message(from: &Node, to: &mut Node) -> Option<(AccountId, Vec<u8>)> {
+	from.msg_sender
+		.send(OutgoingMultisigStageMessages::Private(vec![(
+			to.account_id.clone(),
+			b"test".to_vec(),
+		)]))
+		.unwrap();
+
+	recv_with_custom_timeout(&mut to.msg_receiver, MAX_CONNECTION_DELAY).await
+}
+
+#[tokio::test]
+async fn only_accepts_messages_from_registered_peers() {
+	let node_key1 = create_keypair();
+	let node_key2 = create_keypair();
+
+	let pi1 = create_node_info(AccountId::new([1; 32]), &node_key1, 8120);
+	let pi2 = create_node_info(AccountId::new([2; 32]), &node_key2, 8121);
+
+	// Node 1 doesn't know about node 2 yet
+	let mut node1 = spawn_node(&node_key1, 0, pi1.clone(), &[pi1.clone()]);
+	let node2 = spawn_node(&node_key2, 1, pi2.clone(), &[pi1.clone(), pi2.clone()]);
+
+	assert_eq!(send_and_receive_message(&node2, &mut node1).await, None);
+
+	node1.peer_update_sender.send(PeerUpdate::Registered(pi2.clone())).unwrap();
+
+	assert_eq!(
+		send_and_receive_message(&node2, &mut node1).await,
+		Some((pi2.account_id.clone(), b"test".to_vec()))
+	);
+
+	// Node 1 stops accepting messages once node 2 is deregistered
+	node1
+		.peer_update_sender
+		.send(PeerUpdate::Deregistered(pi2.account_id.clone(), pi2.ed_pubkey))
+		.unwrap();
+
+	assert_eq!(send_and_receive_message(&node2, &mut node1).await, None);
+}
+
+#[tokio::test]
+async fn can_connect_after_pubkey_change() {
+	let node_key1 = create_keypair();
+	let node_key2 = create_keypair();
+
+	let pi1 = create_node_info(AccountId::new([1; 32]), &node_key1, 8122);
+	let pi2 = create_node_info(AccountId::new([2; 32]), &node_key2, 8123);
+
+	let mut node1 = spawn_node(&node_key1, 0, pi1.clone(), &[pi1.clone(), pi2.clone()]);
+	let mut node2 = spawn_node(&node_key2, 1, pi2.clone(), &[pi1.clone(), pi2.clone()]);
+
+	send_and_receive_message(&node2, &mut node1).await.unwrap();
+	send_and_receive_message(&node1, &mut node2).await.unwrap();
+
+	// Node 2 disconnects:
+	drop(node2);
+
+	// Node 2 connects with a different key:
+	let node_key2b = create_keypair();
+	let pi2 = create_node_info(AccountId::new([2; 32]), &node_key2b, 8124);
+	let mut node2b = spawn_node(&node_key2b, 1, pi2.clone(), &[pi1.clone(), pi2.clone()]);
+
+	// Node 1 learns about Node 2's new key:
+	node1.peer_update_sender.send(PeerUpdate::Registered(pi2.clone())).unwrap();
+
+	send_and_receive_message(&node2b, &mut node1).await.unwrap();
+	send_and_receive_message(&node1, &mut node2b).await.unwrap();
+}
+
+#[tokio::test]
+async fn fails_to_start_if_port_is_taken() {
+	let node_key = create_keypair();
+	let pi = create_node_info(AccountId::new([1; 32]), &node_key, 8125);
+
+	let _listener = std::net::TcpListener::bind(("0.0.0.0", pi.port)).unwrap();
+
+	let (incoming_message_sender, _incoming_message_receiver) =
+		tokio::sync::mpsc::unbounded_channel();
+	let (_outgoing_message_sender, outgoing_message_receiver) =
+		tokio::sync::mpsc::unbounded_channel();
+	let (_peer_update_sender, peer_update_receiver) = tokio::sync::mpsc::unbounded_channel();
+
+	assert!(super::start(
+		P2PKey::new(node_key.as_bytes()),
+		pi.port,
+		vec![pi.clone()],
+		pi.account_id.clone(),
+		incoming_message_sender,
+		outgoing_message_receiver,
+		peer_update_receiver,
+	)
+	.await
+	.is_err());
+}
@@ -0,0 +1,186 @@
+//! Runs multisig ceremonies between nodes connected over loopback, once for each transport.
+
+use std::{collections::BTreeSet, net::Ipv4Addr, sync::Arc, time::Duration};
+
+use futures::future::join_all;
+use multisig::{
+	client::MultisigClientApi,
+	eth::{EthSigning, SigningPayload},
+	ChainSigning, CryptoScheme, KeyId, MultisigClient,
+};
+use sp_core::ed25519::Public;
+use state_chain_runtime::AccountId;
+use tracing::{info_span, Instrument};
+use utilities::Port;
+
+use crate::{
+	db::{KeyStore, PersistentKeyDB},
+	settings::P2PTransport,
+};
+
+use super::{core, libp2p_transport, muxer::P2PMuxer, P2PKey, PeerInfo};
+
+const NODE_COUNT: u8 = 3;
+/// How long to give the nodes to connect to each other before starting a ceremony
+const CONNECTION_DELAY: Duration = Duration::from_secs(1);
+const CEREMONY_TIMEOUT: Duration = Duration::from_secs(60);
+
+type EthMultisigClient = MultisigClient<EthSigning, KeyStore<EthSigning>>;
+
+struct Node {
+	multisig_client: EthMultisigClient,
+	// Deletes the node's database once the test is done
+	_db_dir: tempfile::TempDir,
+}
+
+fn spawn_node(
+	transport: P2PTransport,
+	key: &ed25519_dalek::SigningKey,
+	our_peer_info: &PeerInfo,
+	peer_infos: &[PeerInfo],
+) -> Node {
+	let (incoming_message_sender, incoming_message_receiver) =
+		tokio::sync::mpsc::unbounded_channel();
+
+	let (outgoing_message_sender, outgoing_message_receiver) =
+		tokio::sync::mpsc::unbounded_channel();
+
+	// All the peers are known from the start
+	let (_peer_update_sender, peer_update_receiver) = tokio::sync::mpsc::unbounded_channel();
+
+	let p2p_key = P2PKey::new(key.as_bytes());
+	let port = our_peer_info.port;
+	let account_id = our_peer_info.account_id.clone();
+	let span = info_span!("node", account_id = account_id.to_string());
+	match transport {
+		P2PTransport::Zmq => tokio::spawn(
+			core::start(
+				p2p_key,
+				port,
+				peer_infos.to_vec(),
+				account_id.clone(),
+				incoming_message_sender,
+				outgoing_message_receiver,
+				peer_update_receiver,
+			)
+			.instrument(span),
+		),
+		P2PTransport::Libp2p => tokio::spawn(
+			libp2p_transport::start(
+				p2p_key,
+				port,
+				peer_infos.to_vec(),
+				account_id.clone(),
+				incoming_message_sender,
+				outgoing_message_receiver,
+				peer_update_receiver,
+			)
+			.instrument(span),
+		),
+	};
+
+	let (eth_outgoing_sender, eth_incoming_receiver, _, _, _, _, muxer_future) =
+		P2PMuxer::start(incoming_message_receiver, outgoing_message_sender);
+	tokio::spawn(muxer_future);
+
+	let db_dir = tempfile::TempDir::new().unwrap();
+	let db =
+		PersistentKeyDB::open_and_migrate_to_latest(&db_dir.path().join("test.db"), None, None)
+			.unwrap();
+
+	let (multisig_client, multisig_client_backend_future) =
+		crate::multisig::start_client::<EthSigning>(
+			account_id,
+			KeyStore::new(Arc::new(db)),
+			eth_incoming_receiver,
+			eth_outgoing_sender,
+			0,
+		);
+	tokio::spawn(multisig_client_backend_future);
+
+	Node { multisig_client, _db_dir: db_dir }
+}
+
+fn spawn_nodes(transport: P2PTransport, first_port: Port) -> (Vec<Node>, BTreeSet<AccountId>) {
+	let keys: Vec<_> = (0..NODE_COUNT)
+		.map(|_| ed25519_dalek::SigningKey::generate(&mut rand::thread_rng()))
+		.collect();
+
+	let peer_infos: Vec<_> = keys
+		.iter()
+		.zip(0..)
+		.map(|(key, i)| {
+			PeerInfo::new(
+				AccountId::new([i + 1; 32]),
+				Public(key.verifying_key().to_bytes()),
+				Ipv4Addr::LOCALHOST.to_ipv6_mapped(),
+				first_port + i as Port,
+			)
+		})
+		.collect();
+
+	let nodes = keys
+		.iter()
+		.zip(&peer_infos)
+		.map(|(key, peer_info)| spawn_node(transport, key, peer_info, &peer_infos))
+		.collect();
+
+	(nodes, peer_infos.into_iter().map(|peer_info| peer_info.account_id).collect())
+}
+
+async fn run_keygen_and_signing_ceremonies(transport: P2PTransport, first_port: Port) {
+	let (nodes, participants) = spawn_nodes(transport, first_port);
+
+	tokio::time::sleep(CONNECTION_DELAY).await;
+
+	let public_keys = tokio::time::timeout(
+		CEREMONY_TIMEOUT,
+		join_all(
+			nodes
+				.iter()
+				.map(|node| node.multisig_client.initiate_keygen(1, 1, participants.clone())),
+		),
+	)
+	.await
+	.expect("keygen should complete");
+
+	let public_keys: Vec<_> = public_keys
+		.into_iter()
+		.map(|result| result.expect("keygen should succeed"))
+		.collect();
+	let public_key = public_keys[0].clone();
+	assert!(public_keys.iter().all(|key| *key == public_key));
+
+	let payload = SigningPayload([7; 32]);
+	let signatures = tokio::time::timeout(
+		CEREMONY_TIMEOUT,
+		join_all(nodes.iter().map(|node| {
+			node.multisig_client.initiate_signing(
+				2,
+				participants.clone(),
+				vec![(KeyId::new(1, public_key.clone()), payload.clone())],
+			)
+		})),
+	)
+	.await
+	.expect("signing should complete");
+
+	for signature in signatures {
+		<<EthSigning as ChainSigning>::CryptoScheme as CryptoScheme>::verify_signature(
+			&signature.expect("signing should succeed")[0],
+			&public_key,
+			&payload,
+		)
+		.unwrap();
+	}
+}
+
+#[tokio::test]
+async fn can_run_ceremonies_over_zmq() {
+	run_keygen_and_signing_ceremonies(P2PTransport::Zmq, 8100).await;
+}
+
+#[tokio::test]
+async fn can_run_ceremonies_over_libp2p() {
+	run_keygen_and_signing_ceremonies(P2PTransport::Libp2p, 8110).await;
+}
@@ -5,20 +5,28 @@
 //! On each request it applies a timeout, such that requests cannot hang.
 //! It applies exponential backoff and jitter to the requests if they fail, and will retry them
 //! until they succeed.
+//!
+//! Requests are balanced across any number of clients, favouring those that have recently been
+//! responding quickly and without errors. Requests can also require a quorum of clients to return
+//! the same result, so that a single faulty or lagging node cannot feed us incorrect data.
 
 use std::{
 	any::Any,
 	collections::{BTreeMap, VecDeque},
 	pin::Pin,
-	time::Duration,
+	sync::Arc,
+	time::{Duration, Instant},
 };
 
-use crate::common::Signal;
+use crate::{common::Signal, health::HEALTH_MONITOR};
 use anyhow::Result;
 use core::cmp::min;
-use futures::Future;
+use futures::{Future, FutureExt};
 use futures_util::stream::FuturesUnordered;
-use rand::Rng;
+use rand::{
+	distributions::{Distribution, WeightedIndex},
+	Rng,
+};
 use std::fmt;
 use tokio::sync::{mpsc, oneshot};
 use utilities::{
@@ -46,11 +54,19 @@ type TypedFutureGenerator<T, Client> = Pin<
 	>,
 >;
 
-type FutureAnyGenerator<Client> = TypedFutureGenerator<BoxAny, Client>;
+// Shared, so that the submission futures can call it once their clients are ready.
+type FutureAnyGenerator<Client> = Arc<
+	dyn Fn(Client) -> Pin<Box<dyn Future<Output = Result<BoxAny, anyhow::Error>> + Send>>
+		+ Send
+		+ Sync,
+>;
 
 // The id per *request* from the external caller. This is not tracking *submissions*.
 type RequestId = u64;
 
+// The position of a client in the list of clients passed to the retrier.
+type ClientIndex = usize;
+
 pub type Attempt = u32;
 
 #[derive(Debug, Clone)]
@@ -75,28 +91,39 @@ impl fmt::Display for RequestLog {
 	}
 }
 
-#[derive(PartialEq, Eq, Debug, Clone)]
-pub enum PrimaryOrSecondary {
-	Primary,
-	Secondary,
+// The number of clients that must return matching results for a request to succeed, and how to
+// tell whether two (type erased) results match.
+#[derive(Clone)]
+struct Quorum {
+	size: usize,
+	agree: Arc<dyn Fn(&BoxAny, &BoxAny) -> bool + Send + Sync>,
 }
 
-impl std::ops::Not for PrimaryOrSecondary {
-	type Output = Self;
-
-	fn not(self) -> Self::Output {
-		match self {
-			PrimaryOrSecondary::Primary => PrimaryOrSecondary::Secondary,
-			PrimaryOrSecondary::Secondary => PrimaryOrSecondary::Primary,
-		}
+impl Quorum {
+	// Returns a result that at least `size` of the results (including itself) agree with.
+	fn find_agreement(&self, mut results: Vec<BoxAny>) -> Option<BoxAny> {
+		(0..results.len())
+			.find(|i| {
+				results.iter().filter(|result| (self.agree)(&results[*i], result)).count() >=
+					self.size
+			})
+			.map(|i| results.swap_remove(i))
 	}
 }
 
+// The outcome of a single submission to a single client.
+#[derive(Debug, Clone)]
+struct ClientOutcome {
+	client_index: ClientIndex,
+	latency: Duration,
+	succeeded: bool,
+}
+
 type SubmissionFutureOutput = (
 	RequestId,
 	RequestLog,
 	RetryLimit,
-	PrimaryOrSecondary,
+	Vec<ClientOutcome>,
 	Result<BoxAny, (anyhow::Error, Attempt)>,
 );
 type SubmissionFuture = Pin<Box<dyn Future<Output = SubmissionFutureOutput> + Send + 'static>>;
@@ -105,7 +132,7 @@ type SubmissionFutures = FuturesUnordered<SubmissionFuture>;
 type RetryDelays = FuturesUnordered<
 	Pin<
 		Box<
-			dyn Future<Output = (RequestId, RequestLog, Attempt, RetryLimit, PrimaryOrSecondary)>
+			dyn Future<Output = (RequestId, RequestLog, Attempt, RetryLimit, Vec<ClientIndex>)>
 				+ Send
 				+ 'static,
 		>,
@@ -114,16 +141,18 @@ type RetryDelays = FuturesUnordered<
 
 type BoxAny = Box<dyn Any + Send>;
 
-type RequestPackage<Client> = (oneshot::Sender<BoxAny>, FutureAnyGenerator<Client>);
+type RequestPackage<Client> = (oneshot::Sender<BoxAny>, FutureAnyGenerator<Client>, Option<Quorum>);
 
 type RequestSent<Client> =
-	(oneshot::Sender<BoxAny>, RequestLog, FutureAnyGenerator<Client>, RetryLimit);
+	(oneshot::Sender<BoxAny>, RequestLog, FutureAnyGenerator<Client>, RetryLimit, Option<Quorum>);
 
 /// Tracks all the retries
 #[derive(Clone)]
 pub struct RetrierClient<Client> {
 	// The channel to send requests to the client.
 	request_sender: mpsc::Sender<RequestSent<Client>>,
+	// The number of clients that must agree on the result of requests made with a quorum.
+	quorum: usize,
 }
 
 #[derive(Default)]
@@ -197,47 +226,123 @@ fn max_sleep_duration(initial_request_timeout: Duration, attempt: u32) -> Durati
 	min(MAX_DELAY_TIME_MILLIS, initial_request_timeout.saturating_mul(2u32.saturating_pow(attempt)))
 }
 
-// Creates a future of a particular submission.
+// Creates a future of a particular submission. Once the clients are ready, the request is made to
+// all of them concurrently. Without a quorum there is only ever one client. Waiting for the clients
+// here, rather than before creating the submission, ensures requests that are waiting for enough
+// clients to be ready don't hold up any others.
 fn submission_future<Client: Clone + Send + Sync + 'static>(
-	client: Client,
+	clients: impl Future<Output = Vec<(ClientIndex, Client)>> + Send + 'static,
 	request_log: RequestLog,
 	retry_limit: RetryLimit,
-	submission_fn: &FutureAnyGenerator<Client>,
+	submission_fn: FutureAnyGenerator<Client>,
+	quorum: Option<Quorum>,
 	request_id: RequestId,
 	initial_request_timeout: Duration,
 	attempt: Attempt,
-	primary_or_secondary: PrimaryOrSecondary,
 ) -> SubmissionFuture {
-	let submission_fut = submission_fn(client);
 	// Apply exponential backoff to the request.
+	let timeout = max_sleep_duration(initial_request_timeout, attempt);
+
 	Box::pin(async move {
-		(
-			request_id,
-			request_log.clone(),
-			retry_limit,
-			primary_or_secondary,
-			match tokio::time::timeout(
-				max_sleep_duration(initial_request_timeout, attempt),
-				submission_fut,
-			)
-			.await
-			{
-				Ok(Ok(t)) => Ok(t),
-				Ok(Err(e)) => Err(e),
-				Err(_) => Err(anyhow::anyhow!("Request timed out")),
-			}
-			.map_err(|e| (e, attempt)),
-		)
+		let clients = clients.await;
+		tracing::trace!(
+			"Submitting request `{request_log}` with id `{request_id}` to clients `{:?}`, attempt `{attempt}`",
+			clients.iter().map(|(index, _)| index).collect::<Vec<_>>()
+		);
+		let submissions =
+			futures::future::join_all(clients.into_iter().map(|(client_index, client)| {
+				let submission_fut = submission_fn(client);
+				async move {
+					let start = Instant::now();
+					let result = match tokio::time::timeout(timeout, submission_fut).await {
+						Ok(result) => result,
+						Err(_) => Err(anyhow::anyhow!("Request timed out")),
+					};
+					(client_index, start.elapsed(), result)
+				}
+			}))
+			.await;
+		let outcomes = submissions
+			.iter()
+			.map(|(client_index, latency, result)| ClientOutcome {
+				client_index: *client_index,
+				latency: *latency,
+				succeeded: result.is_ok(),
+			})
+			.collect();
+		let mut results = submissions.into_iter().map(|(.., result)| result);
+
+		let result = match quorum {
+			None => results.next().expect("Each submission is made to a client"),
+			Some(quorum) => {
+				let mut successes = Vec::new();
+				let mut errors = Vec::new();
+				for result in results {
+					match result {
+						Ok(value) => successes.push(value),
+						Err(e) => errors.push(e.to_string()),
+					}
+				}
+				let responses = successes.len();
+				if responses < quorum.size {
+					Err(anyhow::anyhow!(
+						"Only {responses} clients responded, but a quorum of {} is required. Errors: [{}]",
+						quorum.size,
+						errors.join(", ")
+					))
+				} else {
+					quorum.find_agreement(successes).ok_or_else(|| {
+						anyhow::anyhow!(
+							"No result was returned by a quorum of {} of the {responses} clients that responded",
+							quorum.size
+						)
+					})
+				}
+			},
+		};
+
+		(request_id, request_log, retry_limit, outcomes, result.map_err(|e| (e, attempt)))
 	})
 }
 
-// Pass in two clients, a primary and an optional secondary.
-// We can then select the client requested if it's ready, otherwise we return the client that's
-// ready first.
-#[derive(Clone)]
+// The weight of the latest observation in the moving averages of a client's stats.
+const STATS_SMOOTHING_FACTOR: f64 = 0.2;
+// Ensures that clients that have been failing are still selected occasionally, so that we notice
+// when they recover.
+const MIN_SUCCESS_RATE: f64 = 0.05;
+const MIN_LATENCY_SECS: f64 = 0.001;
+
+// How a client has been performing recently, used to balance requests across the clients.
+#[derive(Debug, Clone, Copy)]
+struct ClientStats {
+	// Moving average of the time taken to respond, in seconds.
+	latency: f64,
+	// Moving average of the proportion of requests that failed, including timeouts.
+	error_rate: f64,
+}
+
+impl ClientStats {
+	fn new(initial_latency: Duration) -> Self {
+		Self { latency: initial_latency.as_secs_f64(), error_rate: 0.0 }
+	}
+
+	fn record(&mut self, outcome: &ClientOutcome) {
+		self.latency += STATS_SMOOTHING_FACTOR * (outcome.latency.as_secs_f64() - self.latency);
+		self.error_rate +=
+			STATS_SMOOTHING_FACTOR * (if outcome.succeeded { 0.0 } else { 1.0 } - self.error_rate);
+	}
+
+	// The relative likelihood of the client being selected for a request.
+	fn weight(&self) -> f64 {
+		(1.0 - self.error_rate).max(MIN_SUCCESS_RATE) / self.latency.max(MIN_LATENCY_SECS)
+	}
+}
+
+// Holds any number of clients, which may not be ready yet. Clients are selected at random,
+// weighted by how quickly and reliably they have been responding, so that the load is balanced
+// across the clients but biased away from slow or failing ones.
 struct ClientSelector<Client: Clone + Send + Sync + 'static> {
-	primary_signal: Signal<(Client, PrimaryOrSecondary)>,
-	secondary_signal: Option<Signal<(Client, PrimaryOrSecondary)>>,
+	clients: Vec<(Signal<Client>, ClientStats)>,
 }
 
 impl<Client: Send + Sync + Clone + 'static> ClientSelector<Client> {
@@ -245,57 +350,102 @@ impl<Client: Send + Sync + Clone + 'static> ClientSelector<Client> {
 	/// client.
 	pub fn new<ClientFut: Future<Output = Client> + Send + 'static>(
 		scope: &Scope<'_, anyhow::Error>,
-		primary_fut: ClientFut,
-		secondary_fut: Option<ClientFut>,
+		client_futs: Vec<ClientFut>,
+		initial_latency: Duration,
 	) -> Self {
-		let (primary_signaller, primary_signal) = Signal::new();
-
-		scope.spawn_weak(async move {
-			let client = primary_fut.await;
-			primary_signaller.signal((client, PrimaryOrSecondary::Primary));
-			Ok(())
-		});
-
-		let secondary_signal = if let Some(secondary_fut) = secondary_fut {
-			let (secondary_signaller, secondary_signal) = Signal::new();
+		Self {
+			clients: client_futs
+				.into_iter()
+				.map(|client_fut| {
+					let (signaller, signal) = Signal::new();
+
+					scope.spawn_weak(async move {
+						signaller.signal(client_fut.await);
+						Ok(())
+					});
+
+					(signal, ClientStats::new(initial_latency))
+				})
+				.collect(),
+		}
+	}
 
-			scope.spawn_weak(async move {
-				let client = secondary_fut.await;
-				secondary_signaller.signal((client, PrimaryOrSecondary::Secondary));
-				Ok(())
-			});
-			Some(secondary_signal)
-		} else {
-			None
-		};
+	pub fn record(&mut self, outcome: &ClientOutcome) -> ClientStats {
+		let stats = &mut self.clients[outcome.client_index].1;
+		stats.record(outcome);
+		*stats
+	}
 
-		Self { primary_signal, secondary_signal }
+	// Returns a future that resolves to all the clients that are ready, once at least `count` of
+	// them are. The future doesn't borrow the selector, so it can be awaited without blocking the
+	// selection of clients for other requests.
+	pub fn wait_for_ready_clients(
+		&mut self,
+		count: usize,
+	) -> impl Future<Output = Vec<(ClientIndex, Client)>> + Send + 'static {
+		assert!(count <= self.clients.len());
+		// Checking the signals here means the clones of those that are ready don't need to be
+		// checked again.
+		let mut signals = self
+			.clients
+			.iter_mut()
+			.map(|(signal, _)| {
+				signal.get();
+				signal.clone()
+			})
+			.collect::<Vec<_>>();
+		async move {
+			loop {
+				let ready_clients = signals
+					.iter_mut()
+					.enumerate()
+					.filter_map(|(client_index, signal)| {
+						signal.get().map(|client| (client_index, client.clone()))
+					})
+					.collect::<Vec<_>>();
+				if ready_clients.len() >= count {
+					break ready_clients
+				}
+				futures::future::select_all(signals.iter().filter_map(|signal| {
+					matches!(signal, Signal::Pending(_)).then(|| Box::pin(signal.clone().wait()))
+				}))
+				.await;
+			}
+		}
 	}
 
-	// Returns a client, and the type of client selected.
-	pub async fn select_client(
-		&self,
-		primary_or_secondary: PrimaryOrSecondary,
-	) -> (Client, PrimaryOrSecondary) {
-		futures::future::select_all(
-			utilities::conditional::conditional(
-				&self.secondary_signal,
-				|secondary_signal| {
-					// If we have two clients, then we should bias the requested one, but if it's
-					// not ready, request from the other one.
-					match primary_or_secondary {
-						PrimaryOrSecondary::Secondary => [secondary_signal, &self.primary_signal],
-						PrimaryOrSecondary::Primary => [&self.primary_signal, secondary_signal],
-					}
-					.into_iter()
-				},
-				// If we only have a primary, we have to wait for it to be ready.
-				|()| [&self.primary_signal].into_iter(),
-			)
-			.map(|signal| Box::pin(signal.clone().wait())),
-		)
-		.await
-		.0
+	// Returns a future that resolves to `count` distinct clients once enough are ready, avoiding
+	// the `excluded` clients if there are enough others that are ready. Clients are weighted by
+	// their stats at the time of the call.
+	pub fn select_clients(
+		&mut self,
+		count: usize,
+		excluded: Vec<ClientIndex>,
+	) -> impl Future<Output = Vec<(ClientIndex, Client)>> + Send + 'static {
+		let weights = self.clients.iter().map(|(_, stats)| stats.weight()).collect::<Vec<_>>();
+		let ready_clients = self.wait_for_ready_clients(count);
+		async move {
+			let mut candidates = ready_clients.await;
+			let preferred =
+				candidates.iter().filter(|(index, _)| !excluded.contains(index)).count();
+
+			let mut rng = rand::thread_rng();
+			(0..count)
+				.map(|selected| {
+					let candidate_weights = candidates.iter().map(|(index, _)| {
+						if selected < preferred && excluded.contains(index) {
+							0.0
+						} else {
+							weights[*index]
+						}
+					});
+					let chosen = WeightedIndex::new(candidate_weights)
+						.expect("There is always a candidate with a positive weight")
+						.sample(&mut rng);
+					candidates.swap_remove(chosen)
+				})
+				.collect()
+		}
 	}
 }
 
@@ -358,11 +508,19 @@ where
 		scope: &Scope<'_, anyhow::Error>,
 		// The name of the retrier that appears in the logs.
 		name: &'static str,
-		primary_client_fut: ClientFut,
-		secondary_client_fut: Option<ClientFut>,
+		client_futs: Vec<ClientFut>,
+		// The number of clients that must agree on the result of requests made with a quorum.
+		quorum: usize,
 		initial_request_timeout: Duration,
 		maximum_concurrent_submissions: u32,
 	) -> Self {
+		assert!(!client_futs.is_empty(), "Retrier {name} requires at least one client");
+		assert!(
+			(1..=client_futs.len()).contains(&quorum),
+			"Retrier {name} has a quorum of {quorum}, but only {} clients",
+			client_futs.len()
+		);
+
 		let (request_sender, mut request_receiver) = mpsc::channel::<RequestSent<Client>>(1);
 
 		let mut request_holder = RequestHolder::new();
@@ -372,25 +530,36 @@ where
 		// This holds any submissions that are waiting for a slot to open up.
 		let mut submission_holder = SubmissionHolder::new(maximum_concurrent_submissions);
 
-		let client_selector: ClientSelector<Client> =
-			ClientSelector::new(scope, primary_client_fut, secondary_client_fut);
+		HEALTH_MONITOR.register_rpc_clients(name, client_futs.len());
+		let mut client_selector: ClientSelector<Client> =
+			ClientSelector::new(scope, client_futs, initial_request_timeout);
 
 		scope.spawn(async move {
 			utilities::loop_select! {
-				if let Some((response_sender, request_log, closure, retry_limit)) = request_receiver.recv() => {
+				if let Some((response_sender, request_log, closure, retry_limit, quorum)) = request_receiver.recv() => {
 					RPC_RETRIER_REQUESTS.inc(&[name, request_log.rpc_method.as_str()]);
 					let request_id = request_holder.next_request_id();
-					let (client, primary_or_secondary) = client_selector.select_client(PrimaryOrSecondary::Primary).await;
+					let clients = client_selector.select_clients(quorum.as_ref().map_or(1, |quorum| quorum.size), Vec::new());
 
-					tracing::debug!("Retrier {name}: Received request `{request_log}` assigning request_id `{request_id}` and requesting with `{primary_or_secondary:?}`");
-					submission_holder.push(submission_future(client, request_log, retry_limit, &closure, request_id, initial_request_timeout, 0, primary_or_secondary));
-					request_holder.insert(request_id, (response_sender, closure));
+					tracing::debug!("Retrier {name}: Received request `{request_log}` assigning request_id `{request_id}`");
+					submission_holder.push(submission_future(clients, request_log, retry_limit, closure.clone(), quorum.clone(), request_id, initial_request_timeout, 0));
+					request_holder.insert(request_id, (response_sender, closure, quorum));
 				},
-				let (request_id, request_log, retry_limit, primary_or_secondary, result) = submission_holder.next_or_pending() => {
-					RPC_RETRIER_TOTAL_REQUESTS.inc(&[name, request_log.rpc_method.as_str()]);
+				let (request_id, request_log, retry_limit, outcomes, result) = submission_holder.next_or_pending() => {
+					for outcome in &outcomes {
+						RPC_RETRIER_TOTAL_REQUESTS.inc(&[name, request_log.rpc_method.as_str()]);
+						let stats = client_selector.record(outcome);
+						HEALTH_MONITOR.record_rpc_outcome(
+							name,
+							outcome.client_index,
+							outcome.succeeded,
+							Duration::from_secs_f64(stats.latency),
+							stats.error_rate,
+						);
+					}
 					match result {
 						Ok(value) => {
-							if let Some((response_sender, _)) = request_holder.remove(&request_id) {
+							if let Some((response_sender, ..)) = request_holder.remove(&request_id) {
 								let _result = response_sender.send(value);
 							}
 						},
@@ -407,21 +576,23 @@ where
 								tracing::error!(error_message);
 							}
 
+							let previous_clients = outcomes.into_iter().map(|outcome| outcome.client_index).collect::<Vec<_>>();
+
 							// Delay the request before the next retry.
 							retry_delays.push(Box::pin(
 								async move {
 									tokio::time::sleep(sleep_duration).await;
-									// pass in primary or secondary so we know which client to use.
-									(request_id, request_log, attempt, retry_limit, primary_or_secondary)
+									// pass in the clients we tried so we can use different ones.
+									(request_id, request_log, attempt, retry_limit, previous_clients)
 								}
 							));
 						},
 					}
 				},
-				let (request_id, request_log, attempt, retry_limit, primary_or_secondary) = retry_delays.next_or_pending() => {
+				let (request_id, request_log, attempt, retry_limit, previous_clients) = retry_delays.next_or_pending() => {
 					let next_attempt = attempt.saturating_add(1);
 
-					let (response_sender, closure) = request_holder.get(&request_id).expect("We only remove these on success, and if it's in `retry_delays` then it must still be in `request_holder`");
+					let (response_sender, closure, quorum) = request_holder.get(&request_id).expect("We only remove these on success, and if it's in `retry_delays` then it must still be in `request_holder`");
 
 					if response_sender.is_closed() {
 						tracing::trace!("Retrier {name}: Dropped request `{request_log}` with id `{request_id}`. Not retrying.");
@@ -433,11 +604,15 @@ where
 								request_holder.remove(&request_id);
 							}
 							_ => {
-								// We want to use a different client than the one we just tried if possible.
-								// This await should always return immediately since we must already have a client if we've already made a request.
-								let (next_client, next_primary_or_secondary) = client_selector.select_client(!primary_or_secondary).await;
-								tracing::trace!("Retrier {name}: Retrying request `{request_log}` with id `{request_id}` and client `{next_primary_or_secondary:?}`, attempt `{next_attempt}`");
-								submission_holder.push(submission_future(next_client, request_log, retry_limit, closure, request_id, initial_request_timeout, next_attempt, next_primary_or_secondary));
+								let next_clients = match quorum {
+									// Request from every ready client, so that a single client that is failing or
+									// disagreeing with the others cannot prevent a quorum from being reached.
+									Some(quorum) => client_selector.wait_for_ready_clients(quorum.size).boxed(),
+									// We want to use a different client than the one we just tried if possible.
+									None => client_selector.select_clients(1, previous_clients).boxed(),
+								};
+								tracing::trace!("Retrier {name}: Retrying request `{request_log}` with id `{request_id}`, attempt `{next_attempt}`");
+								submission_holder.push(submission_future(next_clients, request_log, retry_limit, closure.clone(), quorum.clone(), request_id, initial_request_timeout, next_attempt));
 							}
 						}
 					}
@@ -446,7 +621,7 @@ where
 			Ok(())
 		});
 
-		Self { request_sender }
+		Self { request_sender, quorum }
 	}
 
 	// Separate function so we can more easily test.
@@ -455,8 +630,9 @@ where
 		specific_closure: TypedFutureGenerator<T, Client>,
 		request_log: RequestLog,
 		retry_limit: RetryLimit,
+		quorum: Option<Quorum>,
 	) -> oneshot::Receiver<BoxAny> {
-		let future_any_fn: FutureAnyGenerator<Client> = Box::pin(move |client| {
+		let future_any_fn: FutureAnyGenerator<Client> = Arc::new(move |client| {
 			let future = specific_closure(client);
 			Box::pin(async move {
 				let result = future.await?;
@@ -465,7 +641,10 @@ where
 			})
 		});
 		let (tx, rx) = oneshot::channel::<BoxAny>();
-		let _result = self.request_sender.send((tx, request_log, future_any_fn, retry_limit)).await;
+		let _result = self
+			.request_sender
+			.send((tx, request_log, future_any_fn, retry_limit, quorum))
+			.await;
 		rx
 	}
 
@@ -489,7 +668,48 @@ where
 		retry_limit: R,
 	) -> R::ReturnType<T> {
 		let retry_limit = R::into_retry_limit(retry_limit);
-		let rx = self.send_request(specific_closure, request_log.clone(), retry_limit).await;
+		let rx = self
+			.send_request(specific_closure, request_log.clone(), retry_limit, None)
+			.await;
+		R::inner_to_return_type(
+			rx.await,
+			format!("Maximum attempt of `{retry_limit:?}` reached for request `{request_log}`."),
+		)
+	}
+
+	/// Requests something to be retried by the retry client, only accepting a result once the
+	/// retrier's quorum of clients have returned the same result. This should be used for requests
+	/// whose results we witness, so that a single faulty node cannot make us witness incorrect
+	/// data.
+	pub async fn request_with_quorum<T: PartialEq + Send + 'static>(
+		&self,
+		specific_closure: TypedFutureGenerator<T, Client>,
+		request_log: RequestLog,
+	) -> T {
+		self.request_with_quorum_by(specific_closure, request_log, NoRetryLimit, T::eq)
+			.await
+	}
+
+	/// Like [Self::request_with_quorum], but with an explicit retry limit, and for results that
+	/// can't be compared with `PartialEq`. `agree` determines whether two results are the same.
+	pub async fn request_with_quorum_by<T: Send + 'static, R: RetryLimitReturn>(
+		&self,
+		specific_closure: TypedFutureGenerator<T, Client>,
+		request_log: RequestLog,
+		retry_limit: R,
+		agree: fn(&T, &T) -> bool,
+	) -> R::ReturnType<T> {
+		let quorum = (self.quorum > 1).then(|| Quorum {
+			size: self.quorum,
+			agree: Arc::new(move |a: &BoxAny, b: &BoxAny| {
+				const EXPECT: &str = "We know we cast the T into an any, and it is a T that we are comparing. Hitting this is a programmer error.";
+				agree(a.downcast_ref::<T>().expect(EXPECT), b.downcast_ref::<T>().expect(EXPECT))
+			}),
+		});
+		let retry_limit = R::into_retry_limit(retry_limit);
+		let rx = self
+			.send_request(specific_closure, request_log.clone(), retry_limit, quorum)
+			.await;
 		R::inner_to_return_type(
 			rx.await,
 			format!("Maximum attempt of `{retry_limit:?}` reached for request `{request_log}`."),
@@ -540,7 +760,7 @@ mod tests {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
 
 				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+					RetrierClient::new(scope, "test", vec![async move {}], 1, INITIAL_TIMEOUT, 100);
 
 				const REQUEST_1: u32 = 32;
 				let rx1 = retrier_client
@@ -548,6 +768,7 @@ mod tests {
 						specific_fut_closure(REQUEST_1, INITIAL_TIMEOUT),
 						RequestLog::new("request 1".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -557,6 +778,7 @@ mod tests {
 						specific_fut_closure(REQUEST_2, INITIAL_TIMEOUT),
 						RequestLog::new("request 2".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -566,6 +788,7 @@ mod tests {
 						specific_fut_closure(REQUEST_3, INITIAL_TIMEOUT),
 						RequestLog::new("request 3".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -590,7 +813,7 @@ mod tests {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(50);
 
 				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+					RetrierClient::new(scope, "test", vec![async move {}], 1, INITIAL_TIMEOUT, 100);
 
 				const REQUEST_1: u32 = 32;
 				let rx1 = retrier_client
@@ -598,6 +821,7 @@ mod tests {
 						specific_fut_closure(REQUEST_1, TIMEOUT),
 						RequestLog::new("request 1".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -607,6 +831,7 @@ mod tests {
 						specific_fut_closure(REQUEST_2, TIMEOUT),
 						RequestLog::new("request 2".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -628,7 +853,7 @@ mod tests {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
 
 				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+					RetrierClient::new(scope, "test", vec![async move {}], 1, INITIAL_TIMEOUT, 100);
 
 				const REQUEST_1: u32 = 32;
 				assert_eq!(
@@ -667,7 +892,7 @@ mod tests {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
 
 				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+					RetrierClient::new(scope, "test", vec![async move {}], 1, INITIAL_TIMEOUT, 100);
 
 				const REQUEST_1: u32 = 32;
 				assert_eq!(
@@ -712,7 +937,7 @@ mod tests {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(1000);
 
 				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 2);
+					RetrierClient::new(scope, "test", vec![async move {}], 1, INITIAL_TIMEOUT, 2);
 
 				// Requests 1 and 2 fill the future buffer.
 				const REQUEST_1: u32 = 32;
@@ -721,6 +946,7 @@ mod tests {
 						specific_fut_closure(REQUEST_1, TIMEOUT),
 						RequestLog::new("request 1".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -730,6 +956,7 @@ mod tests {
 						specific_fut_closure(REQUEST_2, TIMEOUT),
 						RequestLog::new("request 2".to_string(), None),
 						RetryLimit::NoLimit,
+						None,
 					)
 					.await;
 
@@ -790,7 +1017,7 @@ mod tests {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
 
 				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+					RetrierClient::new(scope, "test", vec![async move {}], 1, INITIAL_TIMEOUT, 100);
 
 				retrier_client
 					.request_with_limit(
@@ -824,8 +1051,8 @@ mod tests {
 				let retrier_client = RetrierClient::new(
 					scope,
 					"test",
-					get_client(false),
-					Some(get_client(true)),
+					vec![get_client(false), get_client(true)],
+					1,
 					INITIAL_TIMEOUT,
 					100,
 				);
@@ -860,6 +1087,146 @@ mod tests {
 		.unwrap();
 	}
 
+	#[tokio::test]
+	async fn requests_are_not_held_up_by_quorum_requests_waiting_for_clients() {
+		task_scope(|scope| {
+			async move {
+				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
+
+				// Only one of the clients is ever ready, so the quorum can never be reached.
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![get_client(true), get_client(false)],
+					2,
+					INITIAL_TIMEOUT,
+					100,
+				);
+
+				let quorum_request = retrier_client.request_with_quorum(
+					specific_fut_closure(1u32, INITIAL_TIMEOUT),
+					RequestLog::new("quorum request".to_string(), None),
+				);
+				let request = retrier_client.request(
+					specific_fut_closure(2u32, INITIAL_TIMEOUT),
+					RequestLog::new("request".to_string(), None),
+				);
+
+				tokio::select! {
+					_ = quorum_request => panic!("The quorum request should not complete"),
+					result = request => assert_eq!(result, 2),
+				}
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap();
+	}
+
+	// Client 3 returns a different result to the others.
+	fn client_dependent_closure() -> TypedFutureGenerator<u32, u32> {
+		Box::pin(move |client| Box::pin(async move { Ok(if client == 3 { 20 } else { 10 }) }))
+	}
+
+	#[tokio::test]
+	async fn quorum_request_returns_result_agreed_by_quorum() {
+		task_scope(|scope| {
+			async move {
+				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
+
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					(1..=3).map(futures::future::ready).collect(),
+					2,
+					INITIAL_TIMEOUT,
+					100,
+				);
+
+				for _ in 0..5 {
+					assert_eq!(
+						10,
+						retrier_client
+							.request_with_quorum(
+								client_dependent_closure(),
+								RequestLog::new("request".to_string(), None),
+							)
+							.await
+					);
+				}
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap();
+	}
+
+	#[tokio::test]
+	async fn quorum_request_fails_without_agreement() {
+		task_scope(|scope| {
+			async move {
+				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
+
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					(1..=3).map(futures::future::ready).collect(),
+					3,
+					INITIAL_TIMEOUT,
+					100,
+				);
+
+				retrier_client
+					.request_with_quorum_by(
+						client_dependent_closure(),
+						RequestLog::new("request".to_string(), None),
+						3,
+						u32::eq,
+					)
+					.await
+					.unwrap_err();
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap();
+	}
+
+	#[test]
+	fn client_stats_favour_fast_and_reliable_clients() {
+		let record = |stats: &mut ClientStats, latency: u64, succeeded: bool| {
+			for _ in 0..10 {
+				stats.record(&ClientOutcome {
+					client_index: 0,
+					latency: Duration::from_millis(latency),
+					succeeded,
+				});
+			}
+		};
+		let new_stats = || ClientStats::new(Duration::from_millis(100));
+
+		let mut fast = new_stats();
+		record(&mut fast, 50, true);
+		let mut slow = new_stats();
+		record(&mut slow, 500, true);
+		let mut failing = new_stats();
+		record(&mut failing, 50, false);
+
+		assert!(fast.weight() > slow.weight());
+		assert!(fast.weight() > failing.weight());
+		assert!(failing.weight() > 0.0);
+
+		// Clients recover once they start succeeding again.
+		record(&mut failing, 50, true);
+		assert!(failing.weight() > slow.weight());
+	}
+
 	#[tokio::test]
 	#[ignore = "Test runs forever. Useful for manually testing the failing requests will never return (because they are retried until success)."]
 	async fn request_always_fails() {
@@ -868,7 +1235,7 @@ mod tests {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
 
 				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+					RetrierClient::new(scope, "test", vec![async move {}], 1, INITIAL_TIMEOUT, 100);
 
 				retrier_client
 					.request(
@@ -31,6 +31,27 @@ pub struct P2P {
 	pub ip_address: IpAddr,
 	pub port: Port,
 	pub allow_local_ip: bool,
+	pub transport: P2PTransport,
+}
+
+/// The transport used to exchange multisig ceremony messages with peers. Both use the node key to
+/// authenticate connections, but they can't talk to each other.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, clap::ArgEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum P2PTransport {
+	/// One ZMQ CURVE socket per peer
+	Zmq,
+	/// A single libp2p connection per peer, authenticated with Noise and multiplexed with Yamux
+	Libp2p,
+}
+
+impl P2PTransport {
+	fn as_str(&self) -> &'static str {
+		match self {
+			P2PTransport::Zmq => "zmq",
+			P2PTransport::Libp2p => "libp2p",
+		}
+	}
 }
 
 #[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
@@ -69,19 +90,60 @@ impl ValidateSettings for WsHttpEndpoints {
 	}
 }
 
-#[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct NodeContainer<NodeConfig> {
 	#[serde(rename = "rpc")]
 	pub primary: NodeConfig,
 	#[serde(rename = "backup_rpc")]
 	pub backup: Option<NodeConfig>,
+	/// Any nodes beyond the primary and backup. Requests are balanced across all of the nodes.
+	#[serde(rename = "additional_rpcs", default)]
+	pub additional: Vec<NodeConfig>,
+	/// The number of nodes that must return the same data before we witness it.
+	#[serde(default = "default_quorum")]
+	pub quorum: usize,
+}
+
+fn default_quorum() -> usize {
+	1
+}
+
+impl<NodeConfig: Default> Default for NodeContainer<NodeConfig> {
+	fn default() -> Self {
+		Self {
+			primary: Default::default(),
+			backup: None,
+			additional: Vec::new(),
+			quorum: default_quorum(),
+		}
+	}
+}
+
+impl<NodeConfig> NodeContainer<NodeConfig> {
+	/// All of the nodes, starting with the primary.
+	pub fn nodes(&self) -> impl Iterator<Item = &NodeConfig> {
+		std::iter::once(&self.primary).chain(&self.backup).chain(&self.additional)
+	}
+
+	pub fn into_nodes(self) -> Vec<NodeConfig> {
+		std::iter::once(self.primary)
+			.chain(self.backup)
+			.chain(self.additional)
+			.collect()
+	}
 }
 
 impl<NodeConfig: ValidateSettings> NodeContainer<NodeConfig> {
 	pub fn validate(&self) -> Result<(), ConfigError> {
-		self.primary.validate()?;
-		if let Some(backup) = &self.backup {
-			backup.validate()?;
+		for node in self.nodes() {
+			node.validate()?;
+		}
+		let node_count = self.nodes().count();
+		if !(1..=node_count).contains(&self.quorum) {
+			return Err(ConfigError::Message(format!(
+				"The quorum must be between 1 and the number of nodes ({node_count}), but is {}",
+				self.quorum
+			)))
 		}
 		Ok(())
 	}
@@ -121,9 +183,8 @@ impl Dot {
 					))
 				})
 		};
-		validate_dot_endpoints(&self.nodes.primary)?;
-		if let Some(backup) = &self.nodes.backup {
-			validate_dot_endpoints(backup)?;
+		for node in self.nodes.nodes() {
+			validate_dot_endpoints(node)?;
 		}
 		Ok(())
 	}
@@ -160,11 +221,19 @@ impl ValidateSettings for HttpBasicAuthEndpoint {
 pub struct Btc {
 	#[serde(flatten)]
 	pub nodes: NodeContainer<HttpBasicAuthEndpoint>,
+	/// The bitcoind `zmqpubhashblock` endpoint. If set, new blocks are picked up as soon as
+	/// bitcoind announces them, instead of by polling. `zmqpubrawtx` is not needed, since only
+	/// mined transactions are witnessed.
+	pub zmq_endpoint: Option<String>,
 }
 
 impl Btc {
 	pub fn validate_settings(&self) -> Result<(), ConfigError> {
-		self.nodes.validate()
+		self.nodes.validate()?;
+		if let Some(zmq_endpoint) = &self.zmq_endpoint {
+			validate_zmq_endpoint(zmq_endpoint).map_err(|e| ConfigError::Message(e.to_string()))?;
+		}
+		Ok(())
 	}
 }
 
@@ -174,10 +243,61 @@ pub struct HealthCheck {
 	pub port: Port,
 }
 
+/// Serves routes meant for the operator only, such as the multisig ceremony records.
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+pub struct Admin {
+	#[serde(default = "default_admin_hostname")]
+	pub hostname: String,
+	pub port: Port,
+}
+
+fn default_admin_hostname() -> String {
+	"127.0.0.1".to_owned()
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct Signing {
 	#[serde(deserialize_with = "deser_path")]
 	pub db_file: PathBuf,
+	/// If set, key shares are encrypted in the database.
+	pub key_share_encryption: Option<KeyShareEncryption>,
+}
+
+/// Where the key share encryption key comes from. Exactly one of the fields must be set.
+#[derive(Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct KeyShareEncryption {
+	/// A passphrase the key is derived from.
+	pub passphrase: Option<String>,
+	/// A file containing the hex encoded 32 byte key.
+	pub key_file: Option<PathBuf>,
+	/// A shell command that prints the hex encoded 32 byte key, for example a lookup in the OS
+	/// keyring.
+	pub key_command: Option<String>,
+}
+
+impl fmt::Debug for KeyShareEncryption {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("KeyShareEncryption")
+			.field("passphrase", &self.passphrase.as_ref().map(|_| "<redacted>"))
+			.field("key_file", &self.key_file)
+			.field("key_command", &self.key_command)
+			.finish()
+	}
+}
+
+impl KeyShareEncryption {
+	pub fn validate_settings(&self) -> Result<(), ConfigError> {
+		if [self.passphrase.is_some(), self.key_file.is_some(), self.key_command.is_some()]
+			.into_iter()
+			.filter(|is_set| *is_set)
+			.count() != 1
+		{
+			return Err(ConfigError::Message(
+				"Exactly one of passphrase, key_file or key_command must be set for signing.key_share_encryption".to_string(),
+			))
+		}
+		Ok(())
+	}
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
@@ -190,6 +310,7 @@ pub struct Settings {
 	pub btc: Btc,
 
 	pub health_check: Option<HealthCheck>,
+	pub admin: Option<Admin>,
 	pub prometheus: Option<Prometheus>,
 	pub signing: Signing,
 	pub logging: LoggingSettings,
@@ -247,6 +368,9 @@ pub struct BtcOptions {
 	pub btc_backup_basic_auth_user: Option<String>,
 	#[clap(long = "btc.backup_rpc.basic_auth_password")]
 	pub btc_backup_basic_auth_password: Option<String>,
+
+	#[clap(long = "btc.zmq_endpoint")]
+	pub btc_zmq_endpoint: Option<String>,
 }
 
 #[derive(Parser, Debug, Clone, Default)]
@@ -259,6 +383,8 @@ pub struct P2POptions {
 	p2p_port: Option<Port>,
 	#[clap(long = "p2p.allow_local_ip")]
 	allow_local_ip: Option<bool>,
+	#[clap(long = "p2p.transport", arg_enum)]
+	transport: Option<P2PTransport>,
 }
 
 #[derive(Parser, Debug, Clone)]
@@ -289,6 +415,12 @@ pub struct CommandLineOptions {
 	#[clap(long = "health_check.port")]
 	pub health_check_port: Option<Port>,
 
+	// Admin Settings
+	#[clap(long = "admin.hostname")]
+	pub admin_hostname: Option<String>,
+	#[clap(long = "admin.port")]
+	pub admin_port: Option<Port>,
+
 	// Prometheus Settings
 	#[clap(long = "prometheus.hostname")]
 	pub prometheus_hostname: Option<String>,
@@ -298,6 +430,8 @@ pub struct CommandLineOptions {
 	// Signing Settings
 	#[clap(long = "signing.db_file", parse(from_os_str))]
 	pub signing_db_file: Option<PathBuf>,
+	#[clap(long = "signing.key_share_encryption.key_file", parse(from_os_str))]
+	pub signing_key_share_encryption_key_file: Option<PathBuf>,
 
 	// Logging settings
 	#[clap(long = "logging.span_lifecycle")]
@@ -321,9 +455,12 @@ impl Default for CommandLineOptions {
 			btc_opts: BtcOptions::default(),
 			health_check_hostname: None,
 			health_check_port: None,
+			admin_hostname: None,
+			admin_port: None,
 			prometheus_hostname: None,
 			prometheus_port: None,
 			signing_db_file: None,
+			signing_key_share_encryption_key_file: None,
 			logging_span_lifecycle: false,
 			logging_command_server_port: None,
 		}
@@ -333,6 +470,7 @@ impl Default for CommandLineOptions {
 const NODE_P2P_KEY_FILE: &str = "node_p2p.node_key_file";
 const NODE_P2P_PORT: &str = "node_p2p.port";
 const NODE_P2P_ALLOW_LOCAL_IP: &str = "node_p2p.allow_local_ip";
+const NODE_P2P_TRANSPORT: &str = "node_p2p.transport";
 
 const STATE_CHAIN_WS_ENDPOINT: &str = "state_chain.ws_endpoint";
 const STATE_CHAIN_SIGNING_KEY_FILE: &str = "state_chain.signing_key_file";
@@ -340,6 +478,7 @@ const STATE_CHAIN_SIGNING_KEY_FILE: &str = "state_chain.signing_key_file";
 const ETH_PRIVATE_KEY_FILE: &str = "eth.private_key_file";
 
 const SIGNING_DB_FILE: &str = "signing.db_file";
+const SIGNING_KEY_SHARE_ENCRYPTION_KEY_FILE: &str = "signing.key_share_encryption.key_file";
 
 const LOGGING_SPAN_LIFECYCLE: &str = "logging.span_lifecycle";
 const LOGGING_COMMAND_SERVER_PORT: &str = "logging.command_server_port";
@@ -515,6 +654,16 @@ impl CfSettings for Settings {
 			Some(PathResolutionExpectation::ExistingFile),
 		)?;
 		self.signing.db_file = resolve_settings_path(config_root, &self.signing.db_file, None)?;
+		if let Some(key_share_encryption) = &mut self.signing.key_share_encryption {
+			key_share_encryption.validate_settings()?;
+			if let Some(key_file) = &key_share_encryption.key_file {
+				key_share_encryption.key_file = Some(resolve_settings_path(
+					config_root,
+					key_file,
+					Some(PathResolutionExpectation::ExistingFile),
+				)?);
+			}
+		}
 		self.node_p2p.node_key_file = resolve_settings_path(
 			config_root,
 			&self.node_p2p.node_key_file,
@@ -540,6 +689,7 @@ impl CfSettings for Settings {
 					.expect("Invalid node_key_file path"),
 			)?
 			.set_default(NODE_P2P_PORT, 8078)?
+			.set_default(NODE_P2P_TRANSPORT, P2PTransport::Zmq.as_str())?
 			.set_default(STATE_CHAIN_WS_ENDPOINT, "ws://localhost:9944")?
 			.set_default(
 				STATE_CHAIN_SIGNING_KEY_FILE,
@@ -586,10 +736,18 @@ impl Source for CommandLineOptions {
 		insert_command_line_option(&mut map, "health_check.hostname", &self.health_check_hostname);
 		insert_command_line_option(&mut map, "health_check.port", &self.health_check_port);
 
+		insert_command_line_option(&mut map, "admin.hostname", &self.admin_hostname);
+		insert_command_line_option(&mut map, "admin.port", &self.admin_port);
+
 		insert_command_line_option(&mut map, "prometheus.hostname", &self.prometheus_hostname);
 		insert_command_line_option(&mut map, "prometheus.port", &self.prometheus_port);
 
 		insert_command_line_option_path(&mut map, SIGNING_DB_FILE, &self.signing_db_file);
+		insert_command_line_option_path(
+			&mut map,
+			SIGNING_KEY_SHARE_ENCRYPTION_KEY_FILE,
+			&self.signing_key_share_encryption_key_file,
+		);
 		insert_command_line_option(
 			&mut map,
 			LOGGING_SPAN_LIFECYCLE,
@@ -671,6 +829,11 @@ impl P2POptions {
 		);
 		insert_command_line_option(map, NODE_P2P_PORT, &self.p2p_port);
 		insert_command_line_option(map, NODE_P2P_ALLOW_LOCAL_IP, &self.allow_local_ip);
+		insert_command_line_option(
+			map,
+			NODE_P2P_TRANSPORT,
+			&self.transport.map(|transport| transport.as_str()),
+		);
 	}
 }
 
@@ -699,6 +862,8 @@ impl BtcOptions {
 			"btc.backup_rpc.basic_auth_password",
 			&self.btc_backup_basic_auth_password,
 		);
+
+		insert_command_line_option(map, "btc.zmq_endpoint", &self.btc_zmq_endpoint);
 	}
 }
 
@@ -750,6 +915,15 @@ pub fn validate_http_endpoint(url: SecretUrl) -> Result<()> {
 	validate_endpoint(vec!["http", "https"], url)
 }
 
+/// Validate a ZMQ endpoint, which must be a tcp or ipc endpoint.
+pub fn validate_zmq_endpoint(endpoint: &str) -> Result<()> {
+	match endpoint.split_once("://") {
+		Some(("tcp", address)) | Some(("ipc", address)) if !address.is_empty() => Ok(()),
+		_ =>
+			bail!("Invalid ZMQ endpoint: {endpoint}. Expected tcp://<host>:<port> or ipc://<path>"),
+	}
+}
+
 /// Parse the URL to check that it is the correct scheme and a valid endpoint URL
 fn validate_endpoint(valid_schemes: Vec<&str>, url: SecretUrl) -> Result<()> {
 	let parsed_url = Url::parse(url.as_ref()).context(format!("Error parsing url: {url}"))?;
@@ -878,6 +1052,7 @@ pub mod tests {
 				.canonicalize()
 				.unwrap()
 		);
+		assert_eq!(test_settings.node_p2p.transport, P2PTransport::Zmq);
 	}
 
 	fn test_base_config_path_command_line_option() {
@@ -922,6 +1097,7 @@ pub mod tests {
 				ip_address: Some("1.1.1.1".parse().unwrap()),
 				p2p_port: Some(8087),
 				allow_local_ip: Some(false),
+				transport: Some(P2PTransport::Libp2p),
 			},
 			state_chain_opts: StateChainOptions {
 				state_chain_ws_endpoint: Some("ws://endpoint:1234".to_owned()),
@@ -951,12 +1127,18 @@ pub mod tests {
 				btc_backup_http_endpoint: Some("http://second.btc-endpoint:4321".to_owned()),
 				btc_backup_basic_auth_user: Some("second.my_username".to_owned()),
 				btc_backup_basic_auth_password: Some("second.my_password".to_owned()),
+				btc_zmq_endpoint: Some("tcp://btc-endpoint:28332".to_owned()),
 			},
 			health_check_hostname: Some("health_check_hostname".to_owned()),
 			health_check_port: Some(1337),
+			admin_hostname: Some("admin_hostname".to_owned()),
+			admin_port: Some(7331),
 			prometheus_hostname: Some(("prometheus_hostname").to_owned()),
 			prometheus_port: Some(9999),
 			signing_db_file: Some(PathBuf::from_str("also/not/real.db").unwrap()),
+			signing_key_share_encryption_key_file: Some(
+				PathBuf::from_str("keys/key_share_encryption_key").unwrap(),
+			),
 			logging_span_lifecycle: true,
 			logging_command_server_port: Some(6969),
 		};
@@ -971,6 +1153,7 @@ pub mod tests {
 		assert_eq!(opts.p2p_opts.p2p_port.unwrap(), settings.node_p2p.port);
 		assert_eq!(opts.p2p_opts.ip_address.unwrap(), settings.node_p2p.ip_address);
 		assert_eq!(opts.p2p_opts.allow_local_ip.unwrap(), settings.node_p2p.allow_local_ip);
+		assert_eq!(opts.p2p_opts.transport.unwrap(), settings.node_p2p.transport);
 
 		assert_eq!(
 			opts.state_chain_opts.state_chain_ws_endpoint.unwrap(),
@@ -1040,6 +1223,7 @@ pub mod tests {
 			opts.btc_opts.btc_backup_basic_auth_password.unwrap(),
 			btc_backup_node.basic_auth_password
 		);
+		assert_eq!(opts.btc_opts.btc_zmq_endpoint, settings.btc.zmq_endpoint);
 
 		assert_eq!(
 			opts.health_check_hostname.unwrap(),
@@ -1047,6 +1231,9 @@ pub mod tests {
 		);
 		assert_eq!(opts.health_check_port.unwrap(), settings.health_check.as_ref().unwrap().port);
 
+		assert_eq!(opts.admin_hostname.unwrap(), settings.admin.as_ref().unwrap().hostname);
+		assert_eq!(opts.admin_port.unwrap(), settings.admin.as_ref().unwrap().port);
+
 		assert_eq!(
 			opts.prometheus_hostname.unwrap(),
 			settings.prometheus.as_ref().unwrap().hostname
@@ -1054,6 +1241,15 @@ pub mod tests {
 		assert_eq!(opts.prometheus_port.unwrap(), settings.prometheus.as_ref().unwrap().port);
 
 		assert!(settings.signing.db_file.ends_with("not/real.db"));
+		assert!(settings
+			.signing
+			.key_share_encryption
+			.as_ref()
+			.unwrap()
+			.key_file
+			.as_ref()
+			.unwrap()
+			.ends_with("keys/key_share_encryption_key"));
 	}
 
 	#[test]
@@ -1110,6 +1306,28 @@ pub mod tests {
 		assert!(is_valid_db_path(Path::new("thishasnoextension")).is_err());
 	}
 
+	#[test]
+	fn test_key_share_encryption_validation() {
+		assert_ok!(KeyShareEncryption {
+			passphrase: Some("passphrase".to_owned()),
+			..Default::default()
+		}
+		.validate_settings());
+		assert_ok!(KeyShareEncryption {
+			key_command: Some("cat key".to_owned()),
+			..Default::default()
+		}
+		.validate_settings());
+		assert!(KeyShareEncryption::default().validate_settings().is_err());
+		assert!(KeyShareEncryption {
+			passphrase: Some("passphrase".to_owned()),
+			key_file: Some(PathBuf::from("key")),
+			..Default::default()
+		}
+		.validate_settings()
+		.is_err());
+	}
+
 	#[test]
 	fn test_dot_port_validation() {
 		let valid_settings = Dot {
@@ -1122,6 +1340,11 @@ pub mod tests {
 					ws_endpoint: "ws://valid.endpoint_with_port:1234".into(),
 					http_endpoint: "http://valid.endpoint_with_port:6969".into(),
 				}),
+				additional: vec![WsHttpEndpoints {
+					ws_endpoint: "ws://valid.endpoint_with_port:4321".into(),
+					http_endpoint: "http://valid.endpoint_with_port:9696".into(),
+				}],
+				quorum: 2,
 			},
 		};
 		assert_ok!(valid_settings.validate_settings());
@@ -1137,6 +1360,81 @@ pub mod tests {
 			http_endpoint: "http://invalid.no_port_in_url/secret_key".into(),
 		});
 		assert!(invalid_backup_settings.validate_settings().is_err());
+
+		let mut invalid_additional_settings = valid_settings.clone();
+		invalid_additional_settings.nodes.additional[0].http_endpoint =
+			"http://invalid.no_port_in_url/secret_key".into();
+		assert!(invalid_additional_settings.validate_settings().is_err());
+	}
+
+	#[test]
+	fn test_additional_nodes_and_quorum() {
+		let load_btc_settings = |toml: &str| {
+			Config::builder()
+				.add_source(File::from_str(toml, config::FileFormat::Toml))
+				.build()
+				.unwrap()
+				.try_deserialize::<Btc>()
+				.unwrap()
+		};
+
+		let btc_settings = load_btc_settings(
+			r#"
+			[rpc]
+			http_endpoint = "http://localhost:18443"
+			basic_auth_user = "user"
+			basic_auth_password = "password"
+			"#,
+		);
+		assert!(btc_settings.nodes.additional.is_empty());
+		assert_eq!(btc_settings.nodes.quorum, 1);
+		assert_eq!(btc_settings.zmq_endpoint, None);
+		assert_ok!(btc_settings.validate_settings());
+
+		let mut btc_settings = load_btc_settings(
+			r#"
+			quorum = 2
+
+			[rpc]
+			http_endpoint = "http://localhost:18443"
+			basic_auth_user = "user"
+			basic_auth_password = "password"
+
+			[[additional_rpcs]]
+			http_endpoint = "http://localhost:18444"
+			basic_auth_user = "user"
+			basic_auth_password = "password"
+
+			[[additional_rpcs]]
+			http_endpoint = "http://localhost:18445"
+			basic_auth_user = "user"
+			basic_auth_password = "password"
+			"#,
+		);
+		assert_eq!(
+			btc_settings
+				.nodes
+				.nodes()
+				.map(|node| node.http_endpoint.as_ref())
+				.collect::<Vec<_>>(),
+			vec!["http://localhost:18443", "http://localhost:18444", "http://localhost:18445"]
+		);
+		assert_eq!(btc_settings.nodes.quorum, 2);
+		assert_ok!(btc_settings.validate_settings());
+
+		btc_settings.nodes.quorum = 4;
+		assert!(btc_settings.validate_settings().is_err());
+		btc_settings.nodes.quorum = 0;
+		assert!(btc_settings.validate_settings().is_err());
+	}
+
+	#[test]
+	fn test_zmq_endpoint_validation() {
+		assert_ok!(validate_zmq_endpoint("tcp://127.0.0.1:28332"));
+		assert_ok!(validate_zmq_endpoint("ipc:///tmp/bitcoind.sock"));
+		assert!(validate_zmq_endpoint("http://127.0.0.1:28332").is_err());
+		assert!(validate_zmq_endpoint("tcp://").is_err());
+		assert!(validate_zmq_endpoint("127.0.0.1:28332").is_err());
 	}
 
 	#[test]
@@ -29,6 +29,7 @@ use crate::{
 	btc::retry_rpc::BtcRetryRpcApi,
 	dot::retry_rpc::DotRetryRpcApi,
 	eth::retry_rpc::EthersRetrySigningRpcApi,
+	health::HEALTH_MONITOR,
 	state_chain_observer::client::{
 		extrinsic_api::{
 			signed::{SignedExtrinsicApi, UntilFinalized},
@@ -295,6 +296,7 @@ where
             match sc_block_stream.next().await {
                 Some(current_block) => {
                     debug!("Processing SC block {} with block hash: {:#x}", current_block.number, current_block.hash);
+                    HEALTH_MONITOR.record_state_chain_observer_block(current_block.number);
 
                     match state_chain_client
                         .storage_value::<pallet_cf_cfe_interface::CfeEvents<Runtime>>(
@@ -1,3 +1,4 @@
+mod block_notifier;
 mod btc_chain_tracking;
 mod btc_deposits;
 pub mod btc_source;
@@ -17,6 +18,7 @@ use crate::{
 		rpc::VerboseTransaction,
 	},
 	db::PersistentKeyDB,
+	health::MonitoredBlock,
 	state_chain_observer::client::{
 		extrinsic_api::signed::SignedExtrinsicApi,
 		storage_api::StorageApi,
@@ -75,6 +77,7 @@ pub async fn start<
 >(
 	scope: &Scope<'_, anyhow::Error>,
 	btc_client: BtcRetryRpcClient,
+	zmq_endpoint: Option<String>,
 	process_call: ProcessCall,
 	prewitness_call: PrewitnessCall,
 	state_chain_client: Arc<StateChainClient>,
@@ -99,7 +102,11 @@ where
 		+ 'static,
 	PrewitnessFut: Future<Output = ()> + Send + 'static,
 {
-	let btc_source = BtcSource::new(btc_client.clone()).strictly_monotonic().shared(scope);
+	let btc_source = BtcSource::new(btc_client.clone())
+		.with_zmq_endpoint(zmq_endpoint)
+		.strictly_monotonic()
+		.report_health(MonitoredBlock::Head)
+		.shared(scope);
 
 	btc_source
 		.clone()
@@ -129,7 +136,9 @@ where
 		.chunk_by_vault(vaults.clone(), scope)
 		.deposit_addresses(scope, unfinalised_state_chain_stream, state_chain_client.clone())
 		.await
-		.btc_deposits(prewitness_call)
+		// Deposits into channels that accept boosting can be credited as soon as they are
+		// prewitnessed, so the deposits are also witnessed as such.
+		.btc_deposits(prewitness_call, process_call.clone())
 		.logging("pre-witnessing")
 		.spawn(scope);
 
@@ -162,10 +171,11 @@ where
 	block_source
 		.lag_safety(btc_safety_margin as usize)
 		.logging("safe block produced")
+		.report_health(MonitoredBlock::Witnessed { safety_margin: btc_safety_margin })
 		.chunk_by_vault(vaults, scope)
 		.deposit_addresses(scope, state_chain_stream.clone(), state_chain_client.clone())
 		.await
-		.btc_deposits(process_call.clone())
+		.btc_deposits(process_call.clone(), |_call, _epoch_index| futures::future::ready(()))
 		.egress_items(scope, state_chain_stream, state_chain_client.clone())
 		.await
 		.then({
@@ -0,0 +1,252 @@
+//! Tells the Bitcoin chain source when to check for a new block. If a bitcoind ZMQ endpoint is
+//! configured, we subscribe to its `hashblock` notifications so new blocks are picked up as soon as
+//! they are announced. While the socket is disconnected (or if no endpoint is configured) we fall
+//! back to polling. While connected we still poll occasionally, since ZMQ drops messages silently
+//! when its queues are full.
+//!
+//! We don't subscribe to `rawtx` notifications: deposits are only witnessed once they are in a
+//! block, so the engine has no use for mempool transactions.
+
+use std::time::Duration;
+
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tracing::{error, info, warn};
+use utilities::make_periodic_tick;
+
+const HASHBLOCK_TOPIC: &[u8] = b"hashblock";
+
+/// How long the subscriber thread waits for a message before checking whether the notifier has
+/// been dropped.
+const RECEIVE_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Notification {
+	Connected,
+	Disconnected,
+	NewBlock,
+}
+
+pub struct BlockNotifier {
+	notifications: Option<UnboundedReceiver<Notification>>,
+	connected: bool,
+	poll_tick: tokio::time::Interval,
+	safety_poll_tick: Option<tokio::time::Interval>,
+}
+
+impl BlockNotifier {
+	pub fn polling(poll_interval: Duration) -> Self {
+		Self {
+			notifications: None,
+			connected: false,
+			poll_tick: make_periodic_tick(poll_interval, true),
+			safety_poll_tick: None,
+		}
+	}
+
+	/// Subscribes to the bitcoind `zmqpubhashblock` endpoint, polling at `poll_interval` while the
+	/// socket is not connected, and at `safety_poll_interval` while it is.
+	pub fn subscribe(
+		endpoint: String,
+		poll_interval: Duration,
+		safety_poll_interval: Duration,
+	) -> Self {
+		let (notification_sender, notification_receiver) = mpsc::unbounded_channel();
+
+		// ZMQ sockets are blocking, so the subscriber runs on its own OS thread. It stops once the
+		// notifier is dropped.
+		std::thread::spawn(move || {
+			if let Err(e) = run_subscriber(&endpoint, &notification_sender) {
+				error!(
+					"Bitcoin ZMQ subscriber for {endpoint} failed: {e}. Falling back to polling."
+				);
+			}
+		});
+
+		Self {
+			notifications: Some(notification_receiver),
+			safety_poll_tick: Some(make_periodic_tick(safety_poll_interval, false)),
+			..Self::polling(poll_interval)
+		}
+	}
+
+	/// Returns when a new block may be available.
+	pub async fn wait(&mut self) {
+		loop {
+			tokio::select! {
+				notification = next_notification(&mut self.notifications) => match notification {
+					Some(Notification::NewBlock) => return,
+					Some(Notification::Connected) => {
+						info!("Connected to the Bitcoin ZMQ endpoint, polling for blocks less often.");
+						self.connected = true;
+						// Catch up on any blocks we missed while disconnected.
+						return
+					},
+					Some(Notification::Disconnected) => {
+						warn!("Disconnected from the Bitcoin ZMQ endpoint, polling for blocks.");
+						self.connected = false;
+					},
+					None => {
+						self.notifications = None;
+						self.connected = false;
+					},
+				},
+				_ = self.poll_tick.tick(), if !self.connected => return,
+				_ = next_tick(&mut self.safety_poll_tick), if self.connected => return,
+			}
+		}
+	}
+}
+
+async fn next_notification(
+	notifications: &mut Option<UnboundedReceiver<Notification>>,
+) -> Option<Notification> {
+	match notifications {
+		Some(notifications) => notifications.recv().await,
+		None => futures::future::pending().await,
+	}
+}
+
+async fn next_tick(tick: &mut Option<tokio::time::Interval>) {
+	match tick {
+		Some(tick) => {
+			tick.tick().await;
+		},
+		None => futures::future::pending().await,
+	}
+}
+
+fn run_subscriber(
+	endpoint: &str,
+	notification_sender: &UnboundedSender<Notification>,
+) -> zmq::Result<()> {
+	let context = zmq::Context::new();
+
+	let socket = context.socket(zmq::SUB)?;
+	socket.set_subscribe(HASHBLOCK_TOPIC)?;
+
+	// ZMQ reconnects transparently, so socket events are the only way to tell that the
+	// connection to bitcoind has dropped.
+	const MONITOR_ENDPOINT: &str = "inproc://btc-zmq-monitor";
+	socket.monitor(
+		MONITOR_ENDPOINT,
+		(zmq::SocketEvent::CONNECTED.to_raw() | zmq::SocketEvent::DISCONNECTED.to_raw()) as i32,
+	)?;
+	let monitor = context.socket(zmq::PAIR)?;
+	monitor.connect(MONITOR_ENDPOINT)?;
+
+	socket.connect(endpoint)?;
+
+	while !notification_sender.is_closed() {
+		let mut poll_items = [monitor.as_poll_item(zmq::POLLIN), socket.as_poll_item(zmq::POLLIN)];
+		zmq::poll(&mut poll_items, RECEIVE_TIMEOUT.as_millis() as i64)?;
+
+		if poll_items[0].is_readable() {
+			// The event id is the first two bytes of the first part of the message.
+			let event = monitor.recv_multipart(0)?;
+			match event
+				.first()
+				.and_then(|part| part.get(0..2))
+				.map(|id| zmq::SocketEvent::from_raw(u16::from_le_bytes([id[0], id[1]])))
+			{
+				Some(zmq::SocketEvent::CONNECTED) => {
+					let _result = notification_sender.send(Notification::Connected);
+				},
+				Some(zmq::SocketEvent::DISCONNECTED) => {
+					let _result = notification_sender.send(Notification::Disconnected);
+				},
+				_ => {},
+			}
+		}
+
+		if poll_items[1].is_readable() {
+			// We don't need the block hash, since the chain source fetches the best block header.
+			socket.recv_multipart(0)?;
+			let _result = notification_sender.send(Notification::NewBlock);
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const TIMEOUT: Duration = Duration::from_secs(10);
+
+	async fn wait_until(notifier: &mut BlockNotifier, connected: bool) {
+		tokio::time::timeout(TIMEOUT, async {
+			while notifier.connected != connected {
+				notifier.wait().await;
+			}
+		})
+		.await
+		.unwrap();
+	}
+
+	#[tokio::test]
+	async fn notifies_new_blocks_and_polls_while_disconnected() {
+		const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+		let context = zmq::Context::new();
+		let publisher = context.socket(zmq::PUB).unwrap();
+		publisher.set_linger(0).unwrap();
+		publisher.bind("tcp://127.0.0.1:*").unwrap();
+		let endpoint = publisher.get_last_endpoint().unwrap().unwrap();
+
+		let mut notifier = BlockNotifier::subscribe(endpoint, POLL_INTERVAL, TIMEOUT * 10);
+		wait_until(&mut notifier, true).await;
+
+		// Subscriptions take a moment to reach the publisher, so keep publishing until the block is
+		// received. The safety poll is too slow to wake us, so only a notification can.
+		tokio::time::timeout(TIMEOUT, async {
+			loop {
+				publisher
+					.send_multipart([HASHBLOCK_TOPIC, &[0xab; 32][..], &0u32.to_le_bytes()[..]], 0)
+					.unwrap();
+				if tokio::time::timeout(POLL_INTERVAL, notifier.wait()).await.is_ok() {
+					break
+				}
+			}
+		})
+		.await
+		.unwrap();
+		assert!(notifier.connected);
+
+		drop(publisher);
+		wait_until(&mut notifier, false).await;
+
+		// Falls back to polling.
+		tokio::time::timeout(POLL_INTERVAL * 5, notifier.wait()).await.unwrap();
+	}
+
+	#[tokio::test]
+	async fn polls_occasionally_while_connected() {
+		const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+		let context = zmq::Context::new();
+		let publisher = context.socket(zmq::PUB).unwrap();
+		publisher.set_linger(0).unwrap();
+		publisher.bind("tcp://127.0.0.1:*").unwrap();
+		let endpoint = publisher.get_last_endpoint().unwrap().unwrap();
+
+		let mut notifier = BlockNotifier::subscribe(endpoint, TIMEOUT * 10, POLL_INTERVAL);
+		wait_until(&mut notifier, true).await;
+
+		// Nothing is published, so only the safety poll can wake us.
+		for _ in 0..3 {
+			tokio::time::timeout(POLL_INTERVAL * 5, notifier.wait()).await.unwrap();
+			assert!(notifier.connected);
+		}
+	}
+
+	#[tokio::test]
+	async fn polls_without_zmq_endpoint() {
+		const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+		let mut notifier = BlockNotifier::polling(POLL_INTERVAL);
+		for _ in 0..3 {
+			tokio::time::timeout(POLL_INTERVAL * 5, notifier.wait()).await.unwrap();
+		}
+	}
+}
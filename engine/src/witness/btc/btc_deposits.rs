@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use cf_primitives::EpochIndex;
 use futures_core::Future;
@@ -25,9 +25,13 @@ use cf_chains::{
 };
 
 impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
-	pub fn btc_deposits<ProcessCall, ProcessingFut>(
+	/// Submits the deposits into monitored channels in each block with `process_call`. Deposits
+	/// into channels that accept boosting are additionally passed to `process_boostable_call`, so
+	/// that they can be boosted as soon as they are prewitnessed.
+	pub fn btc_deposits<ProcessCall, ProcessingFut, ProcessBoostableCall, ProcessingBoostableFut>(
 		self,
 		process_call: ProcessCall,
+		process_boostable_call: ProcessBoostableCall,
 	) -> ChunkedByVaultBuilder<
 		impl ChunkedByVault<
 			Index = u64,
@@ -49,20 +53,47 @@ impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
 			+ Clone
 			+ 'static,
 		ProcessingFut: Future<Output = ()> + Send + 'static,
+		ProcessBoostableCall: Fn(state_chain_runtime::RuntimeCall, EpochIndex) -> ProcessingBoostableFut
+			+ Send
+			+ Sync
+			+ Clone
+			+ 'static,
+		ProcessingBoostableFut: Future<Output = ()> + Send + 'static,
 		state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
 		state_chain_runtime::RuntimeCall:
 			RuntimeCallHasChain<state_chain_runtime::Runtime, Inner::Chain>,
 	{
 		self.then(move |epoch, header| {
 			let process_call = process_call.clone();
+			let process_boostable_call = process_boostable_call.clone();
 			async move {
 				// TODO: Make addresses a Map of some kind?
 				let (((), txs), addresses) = header.data;
 
+				let boostable_addresses = boostable_addresses(&addresses);
 				let script_addresses = script_addresses(addresses);
 
 				let deposit_witnesses = deposit_witnesses(&txs, &script_addresses);
 
+				let boostable_deposit_witnesses = deposit_witnesses
+					.iter()
+					.filter(|deposit_witness| {
+						boostable_addresses.contains(&deposit_witness.deposit_address.bytes())
+					})
+					.cloned()
+					.collect::<Vec<_>>();
+				if !boostable_deposit_witnesses.is_empty() {
+					process_boostable_call(
+						pallet_cf_ingress_egress::Call::<_, BitcoinInstance>::add_prewitnessed_deposits {
+							deposit_witnesses: boostable_deposit_witnesses,
+							block_height: header.index,
+						}
+						.into(),
+						epoch.index,
+					)
+					.await;
+				}
+
 				// Submit all deposit witnesses for the block.
 				if !deposit_witnesses.is_empty() {
 					process_call(
@@ -118,6 +149,17 @@ fn deposit_witnesses(
 		.collect()
 }
 
+/// The script pubkeys of the channels that accept boosting, i.e. that have a non-zero boost fee.
+fn boostable_addresses(
+	addresses: &[DepositChannelDetails<state_chain_runtime::Runtime, BitcoinInstance>],
+) -> HashSet<Vec<u8>> {
+	addresses
+		.iter()
+		.filter(|channel| channel.boost_fee > 0)
+		.map(|channel| channel.deposit_channel.address.bytes())
+		.collect()
+}
+
 fn script_addresses(
 	addresses: Vec<DepositChannelDetails<state_chain_runtime::Runtime, BitcoinInstance>>,
 ) -> HashMap<Vec<u8>, ScriptPubkey> {
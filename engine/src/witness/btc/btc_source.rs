@@ -1,11 +1,13 @@
-use std::time::Duration;
+use std::time::{Duration, UNIX_EPOCH};
 
 use bitcoin::BlockHash;
+use cf_chains::Chain;
 use futures_util::stream;
-use utilities::make_periodic_tick;
 
+use super::block_notifier::BlockNotifier;
 use crate::{
 	btc::retry_rpc::BtcRetryRpcApi,
+	health::HEALTH_MONITOR,
 	witness::common::{
 		chain_source::{BoxChainStream, ChainClient, ChainSource, Header},
 		ExternalChainSource,
@@ -15,15 +17,24 @@ use crate::{
 #[derive(Clone)]
 pub struct BtcSource<C> {
 	client: C,
+	zmq_endpoint: Option<String>,
 }
 
 impl<C> BtcSource<C> {
 	pub fn new(client: C) -> Self {
-		Self { client }
+		Self { client, zmq_endpoint: None }
+	}
+
+	/// Picks up new blocks from bitcoind's `zmqpubhashblock` notifications rather than by polling,
+	/// if an endpoint is given. We still poll while the socket is disconnected, and occasionally
+	/// while it is connected in case a notification is dropped.
+	pub fn with_zmq_endpoint(self, zmq_endpoint: Option<String>) -> Self {
+		Self { zmq_endpoint, ..self }
 	}
 }
 
 const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const ZMQ_SAFETY_POLL_INTERVAL: Duration = Duration::from_secs(60);
 
 #[async_trait::async_trait]
 impl<C> ChainSource for BtcSource<C>
@@ -40,13 +51,29 @@ where
 	) -> (BoxChainStream<'_, Self::Index, Self::Hash, Self::Data>, Self::Client) {
 		(
 			Box::pin(stream::unfold(
-				(self.client.clone(), None, make_periodic_tick(POLL_INTERVAL, true)),
-				|(client, last_block_hash_yielded, mut tick)| async move {
+				(
+					self.client.clone(),
+					None,
+					match &self.zmq_endpoint {
+						Some(zmq_endpoint) => BlockNotifier::subscribe(
+							zmq_endpoint.clone(),
+							POLL_INTERVAL,
+							ZMQ_SAFETY_POLL_INTERVAL,
+						),
+						None => BlockNotifier::polling(POLL_INTERVAL),
+					},
+				),
+				|(client, last_block_hash_yielded, mut block_notifier)| async move {
 					loop {
-						tick.tick().await;
+						block_notifier.wait().await;
 
 						let best_block_header = client.best_block_header().await;
 						if last_block_hash_yielded != Some(best_block_header.hash) {
+							HEALTH_MONITOR.record_block_timestamp(
+								cf_chains::Bitcoin::NAME,
+								best_block_header.height,
+								UNIX_EPOCH + Duration::from_secs(best_block_header.time as u64),
+							);
 							return Some((
 								Header {
 									index: best_block_header.height,
@@ -54,7 +81,7 @@ where
 									parent_hash: best_block_header.previous_block_hash,
 									data: (),
 								},
-								(client, Some(best_block_header.hash), tick),
+								(client, Some(best_block_header.hash), block_notifier),
 							))
 						}
 					}
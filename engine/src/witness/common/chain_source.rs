@@ -2,6 +2,7 @@ pub mod and_then;
 pub mod extension;
 pub mod lag_safety;
 pub mod logging;
+pub mod report_health;
 pub mod shared;
 pub mod strictly_monotonic;
 pub mod then;
@@ -1,18 +1,22 @@
 use futures_core::Future;
 use utilities::task_scope::Scope;
 
-use crate::witness::common::{
-	chunked_chain_source::{
-		chunked_by_time::{builder::ChunkedByTimeBuilder, ChunkByTime},
-		chunked_by_vault::{builder::ChunkedByVaultBuilder, ChunkByVault},
+use crate::{
+	health::MonitoredBlock,
+	witness::common::{
+		chunked_chain_source::{
+			chunked_by_time::{builder::ChunkedByTimeBuilder, ChunkByTime},
+			chunked_by_vault::{builder::ChunkedByVaultBuilder, ChunkByVault},
+		},
+		epoch_source::{EpochSource, VaultSource},
+		ExternalChainSource, RuntimeHasChain,
 	},
-	epoch_source::{EpochSource, VaultSource},
-	ExternalChainSource, RuntimeHasChain,
 };
 
 use super::{
-	aliases, and_then::AndThen, lag_safety::LagSafety, logging::Logging, shared::SharedSource,
-	strictly_monotonic::StrictlyMonotonic, then::Then, ChainSource, Header,
+	aliases, and_then::AndThen, lag_safety::LagSafety, logging::Logging,
+	report_health::ReportHealth, shared::SharedSource, strictly_monotonic::StrictlyMonotonic,
+	then::Then, ChainSource, Header,
 };
 
 #[async_trait::async_trait]
@@ -71,6 +75,15 @@ pub trait ChainSourceExt: ChainSource {
 		Logging::new(self, log_prefix)
 	}
 
+	/// Reports the index of each header produced by the underlying stream to the health monitor,
+	/// as either the chain head or the latest block handed to the witnessers.
+	fn report_health(self, block: MonitoredBlock) -> ReportHealth<Self>
+	where
+		Self: Sized,
+	{
+		ReportHealth::new(self, block)
+	}
+
 	/// Ensures the stream is always increasing with respect to the header index (normally the block
 	/// number). We don't assume the root chain source is strictly increasing, since we could
 	/// encounter reorgs.
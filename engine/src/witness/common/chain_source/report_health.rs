@@ -0,0 +1,56 @@
+use futures_util::StreamExt;
+use utilities::metrics::EXTERNAL_CHAIN_HEAD;
+
+use crate::{
+	health::{MonitoredBlock, HEALTH_MONITOR},
+	witness::common::ExternalChainSource,
+};
+
+use super::{BoxChainStream, ChainSource};
+
+#[derive(Clone)]
+pub struct ReportHealth<InnerSource: ChainSource> {
+	inner_source: InnerSource,
+	block: MonitoredBlock,
+}
+impl<InnerSource: ChainSource> ReportHealth<InnerSource> {
+	pub fn new(inner_source: InnerSource, block: MonitoredBlock) -> Self {
+		Self { inner_source, block }
+	}
+}
+
+#[async_trait::async_trait]
+impl<InnerSource: ChainSource + ExternalChainSource> ChainSource for ReportHealth<InnerSource>
+where
+	InnerSource::Client: Clone,
+{
+	type Index = InnerSource::Index;
+	type Hash = InnerSource::Hash;
+	type Data = InnerSource::Data;
+
+	type Client = InnerSource::Client;
+
+	async fn stream_and_client(
+		&self,
+	) -> (BoxChainStream<'_, Self::Index, Self::Hash, Self::Data>, Self::Client) {
+		let (chain_stream, chain_client) = self.inner_source.stream_and_client().await;
+		(
+			Box::pin(chain_stream.then(move |header| async move {
+				let chain = <<InnerSource as ExternalChainSource>::Chain as cf_chains::Chain>::NAME;
+				HEALTH_MONITOR.record_external_chain_block(chain, self.block, header.index.into());
+				if self.block == MonitoredBlock::Head {
+					EXTERNAL_CHAIN_HEAD.set(&[chain], Into::<u64>::into(header.index));
+				}
+				header
+			})),
+			chain_client,
+		)
+	}
+}
+
+impl<InnerSource: ExternalChainSource> ExternalChainSource for ReportHealth<InnerSource>
+where
+	InnerSource::Client: Clone,
+{
+	type Chain = InnerSource::Chain;
+}
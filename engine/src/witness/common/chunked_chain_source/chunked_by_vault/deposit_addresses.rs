@@ -1,7 +1,9 @@
+use cf_chains::Chain;
+use cf_primitives::EpochIndex;
 use pallet_cf_ingress_egress::DepositChannelDetails;
 use state_chain_runtime::PalletInstanceAlias;
 use std::sync::Arc;
-use utilities::task_scope::Scope;
+use utilities::{metrics::MONITORED_DEPOSIT_ADDRESSES, task_scope::Scope};
 
 use crate::{
 	state_chain_observer::client::{
@@ -34,7 +36,11 @@ impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
 		MonitoredSCItems<
 			Inner,
 			Addresses<Inner>,
-			impl Fn(Inner::Index, &Addresses<Inner>) -> Addresses<Inner> + Send + Sync + Clone + 'static,
+			impl Fn(EpochIndex, Inner::Index, &Addresses<Inner>) -> Addresses<Inner>
+				+ Send
+				+ Sync
+				+ Clone
+				+ 'static,
 		>,
 	>
 	where
@@ -61,12 +67,17 @@ impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
 							.expect(STATE_CHAIN_CONNECTION)
 					}
 				},
-				|index, addresses: &Addresses<Inner>| {
-					addresses
+				|epoch_index, index, addresses: &Addresses<Inner>| {
+					let monitored_addresses: Addresses<Inner> = addresses
 						.iter()
 						.filter(|details| details.opened_at <= index && index <= details.expires_at)
 						.cloned()
-						.collect()
+						.collect();
+					MONITORED_DEPOSIT_ADDRESSES.set(
+						&[<Inner::Chain as Chain>::NAME, epoch_index.to_string().as_str()],
+						monitored_addresses.len(),
+					);
+					monitored_addresses
 				},
 			)
 			.await,
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use cf_chains::{Chain, ChainCrypto};
+use cf_primitives::EpochIndex;
 use state_chain_runtime::PalletInstanceAlias;
 use utilities::task_scope::Scope;
 
@@ -33,7 +34,11 @@ impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
 		MonitoredSCItems<
 			Inner,
 			TxOutIdsInitiatedAt<Inner>,
-			impl Fn(Inner::Index, &TxOutIdsInitiatedAt<Inner>) -> TxOutIdsInitiatedAt<Inner>
+			impl Fn(
+					EpochIndex,
+					Inner::Index,
+					&TxOutIdsInitiatedAt<Inner>,
+				) -> TxOutIdsInitiatedAt<Inner>
 				+ Send
 				+ Sync
 				+ Clone
@@ -69,7 +74,7 @@ impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
 							.collect()
 					}
 				},
-				|index, tx_out_ids: &TxOutIdsInitiatedAt<Inner>| {
+				|_epoch_index, index, tx_out_ids: &TxOutIdsInitiatedAt<Inner>| {
 					tx_out_ids
 						.iter()
 						.filter(|(_, initiated_at)| initiated_at <= &index)
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use cf_chains::ChainState;
+use cf_primitives::EpochIndex;
 use frame_support::CloneNoBound;
 use futures::Future;
 use futures_util::{stream, StreamExt};
@@ -52,7 +53,11 @@ pub struct MonitoredSCItems<Inner: ChunkedByVault, MonitoredItems, ItemFilter>
 where
 	state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
 	MonitoredItems: Send + Sync + 'static,
-	ItemFilter: Fn(Inner::Index, &MonitoredItems) -> MonitoredItems + Send + Sync + Clone + 'static,
+	ItemFilter: Fn(EpochIndex, Inner::Index, &MonitoredItems) -> MonitoredItems
+		+ Send
+		+ Sync
+		+ Clone
+		+ 'static,
 {
 	inner: Inner,
 	receiver: tokio::sync::watch::Receiver<(ChainState<Inner::Chain>, MonitoredItems)>,
@@ -64,7 +69,11 @@ impl<Inner: ChunkedByVault, MonitoredItems, ItemFilter>
 where
 	state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
 	MonitoredItems: Send + Sync + 'static,
-	ItemFilter: Fn(Inner::Index, &MonitoredItems) -> MonitoredItems + Send + Sync + Clone + 'static,
+	ItemFilter: Fn(EpochIndex, Inner::Index, &MonitoredItems) -> MonitoredItems
+		+ Send
+		+ Sync
+		+ Clone
+		+ 'static,
 {
 	async fn get_chain_state_and_items<
 		StateChainClient: StorageApi + Send + Sync + 'static,
@@ -141,7 +150,11 @@ impl<Inner: ChunkedByVault, MonitoredItems, ItemFilter> ChunkedByVault
 where
 	state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
 	MonitoredItems: Send + Sync + Unpin + 'static,
-	ItemFilter: Fn(Inner::Index, &MonitoredItems) -> MonitoredItems + Send + Sync + Clone + 'static,
+	ItemFilter: Fn(EpochIndex, Inner::Index, &MonitoredItems) -> MonitoredItems
+		+ Send
+		+ Sync
+		+ Clone
+		+ 'static,
 {
 	type ExtraInfo = Inner::ExtraInfo;
 	type ExtraHistoricInfo = Inner::ExtraHistoricInfo;
@@ -169,7 +182,7 @@ where
 				where
 					state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
 					MonitoredItems: Send + Sync + 'static,
-					ItemFilter: Fn(Inner::Index, &MonitoredItems) -> MonitoredItems
+					ItemFilter: Fn(EpochIndex, Inner::Index, &MonitoredItems) -> MonitoredItems
 						+ Send
 						+ Sync
 						+ Clone
@@ -180,13 +193,14 @@ where
 					pending_headers: Vec<Header<Inner::Index, Inner::Hash, Inner::Data>>,
 					ready_headers:
 						Vec<Header<Inner::Index, Inner::Hash, (Inner::Data, MonitoredItems)>>,
+					epoch_index: EpochIndex,
 					filter_fn: ItemFilter,
 				}
 				impl<Inner: ChunkedByVault, MonitoredItems, ItemFilter> State<Inner, MonitoredItems, ItemFilter>
 				where
 					state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
 					MonitoredItems: Send + Sync + 'static,
-					ItemFilter: Fn(Inner::Index, &MonitoredItems) -> MonitoredItems
+					ItemFilter: Fn(EpochIndex, Inner::Index, &MonitoredItems) -> MonitoredItems
 						+ Send
 						+ Sync
 						+ Clone
@@ -207,7 +221,7 @@ where
 								// 1. An item in the header has expired. Its expiry block is after the current block.
 								// 2. An item in the header has an initiation/starting block after the current block.
 								self.ready_headers.push(header.map_data(|header| {
-									(header.data, (self.filter_fn)(header.index, addresses))
+									(header.data, (self.filter_fn)(self.epoch_index, header.index, addresses))
 								}));
 							} else {
 								self.pending_headers.push(header);
@@ -225,6 +239,7 @@ where
 								receiver: self.receiver.clone(),
 								pending_headers: vec![],
 								ready_headers: vec![],
+								epoch_index: epoch.index,
 								filter_fn: self.filter_fn.clone(),
 							},
 						),
@@ -246,6 +261,7 @@ where
 					MonitoredSCItemsClient::new(
 						chain_client,
 						self.receiver.clone(),
+						epoch.index,
 						(self.filter_fn).clone(),
 					),
 				)
@@ -259,10 +275,15 @@ where
 pub struct MonitoredSCItemsClient<Inner: ChunkedByVault, MonitoredItems, ItemFilter>
 where
 	state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
-	ItemFilter: Fn(Inner::Index, &MonitoredItems) -> MonitoredItems + Send + Sync + Clone + 'static,
+	ItemFilter: Fn(EpochIndex, Inner::Index, &MonitoredItems) -> MonitoredItems
+		+ Send
+		+ Sync
+		+ Clone
+		+ 'static,
 {
 	inner_client: Inner::Client,
 	receiver: tokio::sync::watch::Receiver<(ChainState<Inner::Chain>, MonitoredItems)>,
+	epoch_index: EpochIndex,
 	filter_fn: ItemFilter,
 }
 
@@ -270,14 +291,19 @@ impl<Inner: ChunkedByVault, MonitoredItems, ItemFilter>
 	MonitoredSCItemsClient<Inner, MonitoredItems, ItemFilter>
 where
 	state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
-	ItemFilter: Fn(Inner::Index, &MonitoredItems) -> MonitoredItems + Send + Sync + Clone + 'static,
+	ItemFilter: Fn(EpochIndex, Inner::Index, &MonitoredItems) -> MonitoredItems
+		+ Send
+		+ Sync
+		+ Clone
+		+ 'static,
 {
 	pub fn new(
 		inner_client: Inner::Client,
 		receiver: tokio::sync::watch::Receiver<(ChainState<Inner::Chain>, MonitoredItems)>,
+		epoch_index: EpochIndex,
 		filter_fn: ItemFilter,
 	) -> Self {
-		Self { inner_client, receiver, filter_fn }
+		Self { inner_client, receiver, epoch_index, filter_fn }
 	}
 }
 #[async_trait::async_trait]
@@ -286,7 +312,11 @@ impl<Inner: ChunkedByVault, MonitoredItems, ItemFilter> ChainClient
 where
 	state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
 	MonitoredItems: Send + Sync + Unpin + 'static,
-	ItemFilter: Fn(Inner::Index, &MonitoredItems) -> MonitoredItems + Send + Sync + Clone + 'static,
+	ItemFilter: Fn(EpochIndex, Inner::Index, &MonitoredItems) -> MonitoredItems
+		+ Send
+		+ Sync
+		+ Clone
+		+ 'static,
 {
 	type Index = Inner::Index;
 	type Hash = Inner::Hash;
@@ -305,7 +335,7 @@ where
 				.await;
 			let (_option_chain_state, addresses) = &*chain_state_and_addresses;
 
-			(self.filter_fn)(index, addresses)
+			(self.filter_fn)(self.epoch_index, index, addresses)
 		};
 
 		self.inner_client
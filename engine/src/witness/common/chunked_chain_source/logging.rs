@@ -1,3 +1,8 @@
+use cf_chains::Chain;
+use utilities::metrics::{WITNESSED_BLOCK, WITNESSING_DELAY};
+
+use crate::health::HEALTH_MONITOR;
+
 use super::{
 	chunked_by_time::{builder::ChunkedByTimeBuilder, ChunkedByTime},
 	chunked_by_vault::{builder::ChunkedByVaultBuilder, ChunkedByVault},
@@ -17,6 +22,7 @@ impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
 				header.index,
 				header.hash
 			);
+			record_witnessed_block::<Inner::Chain>(log_prefix, header.index);
 			Ok::<_, anyhow::Error>(header.data)
 		})
 	}
@@ -36,7 +42,20 @@ impl<Inner: ChunkedByTime> ChunkedByTimeBuilder<Inner> {
 				header.index,
 				header.hash
 			);
+			record_witnessed_block::<Inner::Chain>(log_prefix, header.index);
 			Ok::<_, anyhow::Error>(header.data)
 		})
 	}
 }
+
+/// Updates the metrics tracking how far, and how quickly, the given source has witnessed the chain.
+fn record_witnessed_block<C: Chain>(source: &'static str, block_number: C::ChainBlockNumber) {
+	let block_number: u64 = block_number.into();
+	// Sources may process historical epochs concurrently, so we only report the highest block.
+	if WITNESSED_BLOCK.get(&[C::NAME, source]) < block_number as i64 {
+		WITNESSED_BLOCK.set(&[C::NAME, source], block_number);
+	}
+	if let Some(delay) = HEALTH_MONITOR.time_since_block_produced(C::NAME, block_number) {
+		WITNESSING_DELAY.observe(&[C::NAME, source], delay);
+	}
+}
@@ -25,6 +25,7 @@ use utilities::task_scope::Scope;
 use crate::{
 	db::PersistentKeyDB,
 	dot::retry_rpc::{DotRetryRpcApi, DotRetryRpcClient},
+	health::MonitoredBlock,
 	state_chain_observer::client::{
 		extrinsic_api::signed::SignedExtrinsicApi,
 		storage_api::StorageApi,
@@ -195,6 +196,7 @@ where
 {
 	let unfinalised_source = DotUnfinalisedSource::new(dot_client.clone())
 		.strictly_monotonic()
+		.report_health(MonitoredBlock::Head)
 		.then(|header| async move { header.data.iter().filter_map(filter_map_events).collect() })
 		.shared(scope);
 
@@ -225,6 +227,7 @@ where
 	DotFinalisedSource::new(dot_client.clone())
 		.strictly_monotonic()
 		.logging("finalised block produced")
+		.report_health(MonitoredBlock::Witnessed { safety_margin: 0 })
 		.then(|header| async move { header.data.iter().filter_map(filter_map_events).collect() })
 		.chunk_by_vault(vaults, scope)
 		.deposit_addresses(scope, state_chain_stream.clone(), state_chain_client.clone())
@@ -1,16 +1,20 @@
-use std::{pin::Pin, time::Duration};
+use std::{
+	pin::Pin,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use crate::retrier::NoRetryLimit;
-use cf_chains::dot::PolkadotHash;
+use cf_chains::{dot::PolkadotHash, Chain};
 use cf_primitives::PolkadotBlockNumber;
 use futures_util::stream;
-use subxt::{events::Events, PolkadotConfig};
+use subxt::{config::substrate::DigestItem, events::Events, PolkadotConfig};
 
 use crate::{
 	dot::{
 		retry_rpc::{DotRetryRpcApi, DotRetrySubscribeApi},
 		rpc::PolkadotHeader,
 	},
+	health::HEALTH_MONITOR,
 	witness::common::{
 		chain_source::{BoxChainStream, ChainClient, ChainSource, Header},
 		ExternalChainSource,
@@ -21,6 +25,21 @@ use futures::{stream::StreamExt, Stream};
 use anyhow::Result;
 use subxt::{self, config::Header as SubxtHeader};
 
+const BABE_ENGINE_ID: [u8; 4] = *b"BABE";
+const SLOT_DURATION: Duration = Duration::from_secs(6);
+
+/// Polkadot headers don't include a timestamp, but the BABE slot a block was produced in, which
+/// the pre-runtime digest encodes after the digest variant and the authority index, does.
+fn block_timestamp(header: &PolkadotHeader) -> Option<SystemTime> {
+	header.digest.logs.iter().find_map(|log| match log {
+		DigestItem::PreRuntime(BABE_ENGINE_ID, pre_digest) => {
+			let slot = u64::from_le_bytes(pre_digest.get(5..13)?.try_into().ok()?);
+			UNIX_EPOCH.checked_add(SLOT_DURATION.checked_mul(slot.try_into().ok()?)?)
+		},
+		_ => None,
+	})
+}
+
 macro_rules! polkadot_source {
 	($self:expr, $func:ident, $retry_limit:expr, $unwrap_events:expr) => {{
 		struct State<C> {
@@ -48,6 +67,14 @@ macro_rules! polkadot_source {
 								continue
 							};
 
+							if let Some(timestamp) = block_timestamp(&header) {
+								HEALTH_MONITOR.record_block_timestamp(
+									cf_chains::Polkadot::NAME,
+									header.number.into(),
+									timestamp,
+								);
+							}
+
 							return Some((
 								Header {
 									index: header.number,
@@ -18,6 +18,7 @@ use utilities::task_scope::Scope;
 use crate::{
 	db::PersistentKeyDB,
 	eth::{retry_rpc::EthRetryRpcClient, rpc::EthRpcSigningClient},
+	health::MonitoredBlock,
 	state_chain_observer::client::{
 		chain_api::ChainApi,
 		extrinsic_api::signed::SignedExtrinsicApi,
@@ -101,7 +102,10 @@ where
 		.map(|(asset, address)| (address, asset.into()))
 		.collect();
 
-	let eth_source = EthSource::new(eth_client.clone()).strictly_monotonic().shared(scope);
+	let eth_source = EthSource::new(eth_client.clone())
+		.strictly_monotonic()
+		.report_health(MonitoredBlock::Head)
+		.shared(scope);
 
 	eth_source
 		.clone()
@@ -128,6 +132,7 @@ where
 	let eth_safe_vault_source = eth_source
 		.lag_safety(eth_safety_margin as usize)
 		.logging("safe block produced")
+		.report_health(MonitoredBlock::Witnessed { safety_margin: eth_safety_margin })
 		.chunk_by_vault(vaults, scope);
 
 	let eth_safe_vault_source_deposit_addresses = eth_safe_vault_source
@@ -1,3 +1,4 @@
+use cf_chains::Chain;
 use ethers::types::Bloom;
 use sp_core::H256;
 
@@ -5,6 +6,7 @@ use crate::{
 	eth::{
 		core_h256, retry_rpc::EthersRetrySubscribeApi, ConscientiousEthWebsocketBlockHeaderStream,
 	},
+	health::HEALTH_MONITOR,
 	witness::common::{
 		chain_source::{BoxChainStream, ChainClient, ChainSource, Header},
 		ExternalChainSource,
@@ -12,7 +14,7 @@ use crate::{
 };
 use futures::stream::StreamExt;
 use futures_util::stream;
-use std::time::Duration;
+use std::time::{Duration, UNIX_EPOCH};
 
 #[derive(Clone)]
 pub struct EthSource<C> {
@@ -61,6 +63,12 @@ where
 								continue
 							};
 
+							HEALTH_MONITOR.record_block_timestamp(
+								cf_chains::Ethereum::NAME,
+								index.as_u64(),
+								UNIX_EPOCH + Duration::from_secs(header.timestamp.as_u64()),
+							);
+
 							return Some((
 								Header {
 									index: index.as_u64(),
@@ -198,6 +198,8 @@ mod tests {
 							http_endpoint: "http://localhost:8545".into(),
 						},
 						backup: None,
+						additional: vec![],
+						quorum: 1,
 					},
 					U256::from(1337u64),
 				)
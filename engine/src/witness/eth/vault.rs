@@ -118,6 +118,7 @@ pub fn call_from_event(
 							anyhow!("Failed to deposit CCM: `cf_parameters` too long.")
 						})?,
 					},
+					refund_parameters: None,
 				},
 				tx_hash: event.tx_hash.into(),
 			})),
@@ -155,6 +156,7 @@ pub fn call_from_event(
 							anyhow!("Failed to deposit CCM. cf_parameter too long.")
 						})?,
 					},
+					refund_parameters: None,
 				},
 				tx_hash: event.tx_hash.into(),
 			})),
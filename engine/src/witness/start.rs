@@ -1,6 +1,12 @@
 use std::sync::Arc;
 
-use utilities::task_scope::Scope;
+use cf_chains::{Bitcoin, Chain, Ethereum, Polkadot};
+use futures::stream::FuturesUnordered;
+use utilities::{
+	metrics::{WITNESS_EXTRINSICS_FAILED, WITNESS_EXTRINSICS_SUBMITTED},
+	task_scope::Scope,
+	UnendingStream,
+};
 
 use crate::{
 	btc::retry_rpc::BtcRetryRpcClient,
@@ -8,7 +14,7 @@ use crate::{
 	dot::retry_rpc::DotRetryRpcClient,
 	eth::{retry_rpc::EthRetryRpcClient, rpc::EthRpcSigningClient},
 	state_chain_observer::client::{
-		extrinsic_api::signed::SignedExtrinsicApi,
+		extrinsic_api::signed::{SignedExtrinsicApi, UntilFinalized},
 		storage_api::StorageApi,
 		stream_api::{StreamApi, FINALIZED, UNFINALIZED},
 	},
@@ -29,6 +35,7 @@ pub async fn start<StateChainClient>(
 	scope: &Scope<'_, anyhow::Error>,
 	eth_client: EthRetryRpcClient<EthRpcSigningClient>,
 	btc_client: BtcRetryRpcClient,
+	btc_zmq_endpoint: Option<String>,
 	dot_client: DotRetryRpcClient,
 	state_chain_client: Arc<StateChainClient>,
 	state_chain_stream: impl StreamApi<FINALIZED> + Clone,
@@ -37,6 +44,7 @@ pub async fn start<StateChainClient>(
 ) -> Result<()>
 where
 	StateChainClient: StorageApi + ChainApi + SignedExtrinsicApi + 'static + Send + Sync,
+	StateChainClient::UntilFinalizedFuture: 'static,
 {
 	let epoch_source =
 		EpochSource::builder(scope, state_chain_stream.clone(), state_chain_client.clone())
@@ -44,27 +52,55 @@ where
 			.participating(state_chain_client.account_id())
 			.await;
 
-	let witness_call = {
+	// Witness extrinsics are submitted without waiting for them to be finalized, so that
+	// witnessing isn't held up. Their outcomes are tracked separately for the metrics.
+	let (witness_outcome_sender, mut witness_outcome_receiver) =
+		tokio::sync::mpsc::unbounded_channel();
+
+	scope.spawn(async move {
+		let mut pending_outcomes = FuturesUnordered::new();
+		utilities::loop_select! {
+			if let Some((chain, until_finalized)) = witness_outcome_receiver.recv() => {
+				pending_outcomes.push(async move {
+					(chain, UntilFinalized::until_finalized(until_finalized).await)
+				});
+			} else break Ok(()),
+			let (chain, result) = pending_outcomes.next_or_pending() => {
+				if let Err(error) = result {
+					tracing::warn!("{chain} witness extrinsic failed: {error:?}");
+					WITNESS_EXTRINSICS_FAILED.inc(&[chain]);
+				}
+			},
+		}
+	});
+
+	let witness_call = |chain: &'static str| {
 		let state_chain_client = state_chain_client.clone();
+		let witness_outcome_sender = witness_outcome_sender.clone();
 		move |call, epoch_index| {
 			let state_chain_client = state_chain_client.clone();
+			let witness_outcome_sender = witness_outcome_sender.clone();
 			async move {
-				let _ = state_chain_client
+				let (_until_in_block, until_finalized) = state_chain_client
 					.finalize_signed_extrinsic(pallet_cf_witnesser::Call::witness_at_epoch {
 						call: Box::new(call),
 						epoch_index,
 					})
 					.await;
+				WITNESS_EXTRINSICS_SUBMITTED.inc(&[chain]);
+				let _result = witness_outcome_sender.send((chain, until_finalized));
 			}
 		}
 	};
 
 	let prewitness_call = {
 		let state_chain_client = state_chain_client.clone();
+		let witness_outcome_sender = witness_outcome_sender.clone();
 		move |call, epoch_index| {
 			let state_chain_client = state_chain_client.clone();
+			let witness_outcome_sender = witness_outcome_sender.clone();
 			async move {
-				let _ = state_chain_client
+				let (_until_in_block, until_finalized) = state_chain_client
 					.finalize_signed_extrinsic(pallet_cf_witnesser::Call::witness_at_epoch {
 						call: Box::new(
 							pallet_cf_witnesser::Call::prewitness { call: Box::new(call) }.into(),
@@ -72,6 +108,8 @@ where
 						epoch_index,
 					})
 					.await;
+				WITNESS_EXTRINSICS_SUBMITTED.inc(&[Bitcoin::NAME]);
+				let _result = witness_outcome_sender.send((Bitcoin::NAME, until_finalized));
 			}
 		}
 	};
@@ -79,7 +117,7 @@ where
 	let start_eth = super::eth::start(
 		scope,
 		eth_client,
-		witness_call.clone(),
+		witness_call(Ethereum::NAME),
 		state_chain_client.clone(),
 		state_chain_stream.clone(),
 		epoch_source.clone(),
@@ -89,8 +127,9 @@ where
 	let start_btc = super::btc::start(
 		scope,
 		btc_client,
-		witness_call.clone(),
-		prewitness_call.clone(),
+		btc_zmq_endpoint,
+		witness_call(Bitcoin::NAME),
+		prewitness_call,
 		state_chain_client.clone(),
 		state_chain_stream.clone(),
 		unfinalised_state_chain_stream.clone(),
@@ -101,7 +140,7 @@ where
 	let start_dot = super::dot::start(
 		scope,
 		dot_client,
-		witness_call,
+		witness_call(Polkadot::NAME),
 		state_chain_client,
 		state_chain_stream,
 		epoch_source,
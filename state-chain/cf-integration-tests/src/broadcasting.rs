@@ -1,11 +1,16 @@
 use super::*;
 use cf_chains::{
-	btc::{deposit_address::DepositAddress, ScriptPubkey},
+	btc::{
+		api::{batch_transfer::BatchTransfer, BitcoinApi},
+		deposit_address::DepositAddress,
+		BitcoinOutput, ScriptPubkey, Utxo, CHANGE_ADDRESS_SALT,
+	},
 	AllBatch, Bitcoin, TransferAssetParams,
 };
 use cf_primitives::{chains::assets::btc, AuthorityCount, BroadcastId};
 use cf_traits::{Broadcaster, EpochInfo};
 use pallet_cf_broadcast::{AwaitingBroadcast, DelayedBroadcastRetryQueue, PendingBroadcasts};
+use pallet_cf_environment::BitcoinAvailableUtxos;
 use state_chain_runtime::{
 	BitcoinBroadcaster, BitcoinInstance, BitcoinThresholdSigner, Environment, Runtime, Validator,
 };
@@ -109,3 +114,70 @@ fn bitcoin_broadcast_delay_works() {
 			}
 		});
 }
+
+#[test]
+fn changeless_bitcoin_broadcast_does_not_register_change_utxo() {
+	const EPOCH_BLOCKS: u32 = 200;
+	const MAX_AUTHORITIES: AuthorityCount = 10;
+	super::genesis::with_test_defaults()
+		.blocks_per_epoch(EPOCH_BLOCKS)
+		.max_authorities(MAX_AUTHORITIES)
+		.build()
+		.execute_with(|| {
+			let (mut testnet, _, _) = network::fund_authorities_and_join_auction(MAX_AUTHORITIES);
+			testnet.move_to_the_next_epoch();
+			let epoch = Validator::epoch_index();
+			let agg_key = BitcoinThresholdSigner::keys(epoch).unwrap();
+			let utxos_before = BitcoinAvailableUtxos::<Runtime>::get();
+
+			let broadcast_batch = |testnet: &mut network::Network, outputs: Vec<BitcoinOutput>| {
+				let broadcast_id =
+					<BitcoinBroadcaster as Broadcaster<Bitcoin>>::threshold_sign_and_broadcast(
+						BitcoinApi::BatchTransfer(BatchTransfer::new_unsigned(
+							&agg_key,
+							agg_key.current,
+							vec![Utxo {
+								id: Default::default(),
+								amount: 1_000_000,
+								deposit_address: DepositAddress::new(agg_key.current, 1u32),
+							}],
+							outputs,
+						)),
+					);
+				// Finish threshold signing.
+				testnet.move_forward_blocks(11);
+				assert!(AwaitingBroadcast::<Runtime, BitcoinInstance>::contains_key(broadcast_id));
+			};
+
+			// The only output pays the recipient, so nothing is returned to the vault.
+			broadcast_batch(
+				&mut testnet,
+				vec![BitcoinOutput {
+					amount: 990_000,
+					script_pubkey: ScriptPubkey::P2PKH([0u8; 20]),
+				}],
+			);
+			assert_eq!(BitcoinAvailableUtxos::<Runtime>::get(), utxos_before);
+
+			// With a change output, the change is made available to later transactions.
+			broadcast_batch(
+				&mut testnet,
+				vec![
+					BitcoinOutput {
+						amount: 500_000,
+						script_pubkey: ScriptPubkey::P2PKH([0u8; 20]),
+					},
+					BitcoinOutput {
+						amount: 490_000,
+						script_pubkey: DepositAddress::new(agg_key.current, CHANGE_ADDRESS_SALT)
+							.script_pubkey(),
+					},
+				],
+			);
+			let available_utxos = BitcoinAvailableUtxos::<Runtime>::get();
+			assert_eq!(available_utxos.len(), utxos_before.len() + 1);
+			let change_utxo = available_utxos.last().unwrap();
+			assert_eq!(change_utxo.amount, 490_000);
+			assert_eq!(change_utxo.id.vout, 1);
+		});
+}
@@ -159,6 +159,7 @@ fn set_limit_order(
 		id,
 		tick,
 		sell_amount,
+		pallet_cf_pools::CloseOrderAt::Unchanged,
 	));
 	let new_sell_balance =
 		pallet_cf_lp::FreeBalances::<Runtime>::get(account_id, sell_asset).unwrap_or_default();
@@ -226,6 +227,8 @@ fn basic_pool_setup_provision_and_swap() {
 			0u16,
 			None,
 			0u16,
+			None,
+			None,
 		));
 
 		let deposit_address = <AddressDerivation as AddressDerivationApi<Ethereum>>::generate_address(
@@ -327,7 +330,9 @@ fn can_process_ccm_via_swap_deposit_address() {
 			EncodedAddress::Eth([0x02; 20]),
 			0u16,
 			Some(message),
-			0u16
+			0u16,
+			None,
+			None,
 		));
 
 		// Deposit funds for the ccm.
@@ -429,6 +434,7 @@ fn can_process_ccm_via_direct_deposit() {
 				gas_budget,
 				cf_parameters: Default::default(),
 			},
+			refund_parameters: None,
 		};
 
 		witness_call(RuntimeCall::Swapping(pallet_cf_swapping::Call::ccm_deposit{
@@ -779,6 +785,7 @@ fn can_resign_failed_ccm() {
 					gas_budget: 1_000,
 					cf_parameters: Default::default(),
 				},
+				refund_parameters: None,
 			};
 
 			witness_call(RuntimeCall::Swapping(pallet_cf_swapping::Call::ccm_deposit {
@@ -0,0 +1,64 @@
+//! Replays a sequence of Bitcoin deposits, egresses and fee rate changes against each utxo
+//! selection strategy, and prints the fees paid and the resulting utxo set fragmentation.
+//!
+//! Usage: `cargo run -p cf-chains --example utxo_selection_simulator -- <events file>`
+//!
+//! See [cf_chains::btc::utxo_selection::simulation] for the format of the events file.
+
+use anyhow::Context;
+use cf_chains::btc::utxo_selection::{
+	simulation::{parse_events, simulate},
+	UtxoSelectionStrategy,
+};
+
+fn main() -> anyhow::Result<()> {
+	let path = std::env::args()
+		.nth(1)
+		.context("Usage: utxo_selection_simulator <events file>")?;
+	let events = parse_events(
+		&std::fs::read_to_string(&path).with_context(|| format!("Failed to read {path}"))?,
+	)?;
+
+	let strategies = [
+		("smallest first", UtxoSelectionStrategy::SmallestFirst),
+		("branch and bound", UtxoSelectionStrategy::BranchAndBound),
+		(
+			"low fee consolidation",
+			UtxoSelectionStrategy::LowFeeConsolidation {
+				max_sats_per_kilobyte: 5_000,
+				max_extra_inputs: 10,
+			},
+		),
+		("randomised", UtxoSelectionStrategy::Randomised),
+	];
+
+	println!(
+		"{:<24}{:>8}{:>8}{:>14}{:>10}{:>12}{:>8}{:>8}{:>16}",
+		"strategy",
+		"txs",
+		"failed",
+		"total fees",
+		"inputs",
+		"changeless",
+		"utxos",
+		"max",
+		"uneconomical"
+	);
+	for (name, strategy) in strategies {
+		let report = simulate(strategy, &events);
+		println!(
+			"{:<24}{:>8}{:>8}{:>14}{:>10}{:>12}{:>8}{:>8}{:>16}",
+			name,
+			report.transactions,
+			report.failed_egresses,
+			report.total_fees,
+			report.total_inputs,
+			report.changeless_transactions,
+			report.final_utxo_count,
+			report.max_utxo_count,
+			report.uneconomical_utxos
+		);
+	}
+
+	Ok(())
+}
@@ -755,6 +755,22 @@ impl BitcoinTransaction {
 			!self.signatures.iter().any(|signature| signature == &[0u8; 64])
 	}
 
+	/// The fee paid by this transaction, ie. the amount by which its inputs exceed its outputs.
+	pub fn fee(&self) -> BtcAmount {
+		self.inputs
+			.iter()
+			.map(|input| input.amount)
+			.sum::<BtcAmount>()
+			.saturating_sub(self.outputs.iter().map(|output| output.amount).sum())
+	}
+
+	/// The fee this transaction should pay to be included at the given fee rate.
+	pub fn required_fee(&self, fee_info: &BitcoinFeeInfo) -> BtcAmount {
+		self.inputs.iter().map(|input| fee_info.fee_for_utxo(input)).sum::<BtcAmount>() +
+			self.outputs.len() as BtcAmount * fee_info.fee_per_output_utxo() +
+			fee_info.min_fee_required_per_tx()
+	}
+
 	pub fn txid(&self) -> [u8; 32] {
 		let mut id_bytes = Vec::default();
 		id_bytes.extend(VERSION);
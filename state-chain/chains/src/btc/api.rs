@@ -1,8 +1,8 @@
 pub mod batch_transfer;
 
 use super::{
-	deposit_address::DepositAddress, AggKey, Bitcoin, BitcoinCrypto, BitcoinOutput, BtcAmount,
-	Utxo, BITCOIN_DUST_LIMIT, CHANGE_ADDRESS_SALT,
+	deposit_address::DepositAddress, AggKey, Bitcoin, BitcoinCrypto, BitcoinFeeInfo, BitcoinOutput,
+	BtcAmount, Utxo, UtxoId, BITCOIN_DUST_LIMIT, CHANGE_ADDRESS_SALT,
 };
 use crate::*;
 use frame_support::{CloneNoBound, DebugNoBound, EqNoBound, Never, PartialEqNoBound};
@@ -133,6 +133,72 @@ where
 	}
 }
 
+impl<E> BitcoinApi<E>
+where
+	E: ChainEnvironment<UtxoId, Utxo>
+		+ ChainEnvironment<(), BitcoinFeeInfo>
+		+ ChainEnvironment<(), AggKey>,
+{
+	/// Builds a child-pays-for-parent (CPFP) transaction that spends the change output of a
+	/// stuck `parent` transaction back into the vault. The child's fee covers both its own size
+	/// and the shortfall of the parent at the current fee rate, so that miners are incentivised
+	/// to include both.
+	///
+	/// The change utxo is taken from the environment, so this returns `None` if it has already
+	/// been spent by a subsequent transaction, or if the parent already pays the current rate.
+	pub fn child_pays_for_parent(parent: &Self) -> Option<Self> {
+		let BitcoinApi::BatchTransfer(parent) = parent else { return None };
+		let parent_tx = &parent.bitcoin_transaction;
+
+		let fee_info = <E as ChainEnvironment<(), BitcoinFeeInfo>>::lookup(())?;
+		let agg_key @ AggKey { current, previous } =
+			<E as ChainEnvironment<(), AggKey>>::lookup(())?;
+
+		// We can only sign for change outputs to the current or previous vault.
+		if parent.change_utxo_key != current && previous != Some(parent.change_utxo_key) {
+			return None
+		}
+
+		let (vout, change_output) = parent.change_output()?;
+		let change_utxo = Utxo {
+			id: UtxoId { tx_id: parent_tx.txid(), vout },
+			amount: change_output.amount,
+			deposit_address: DepositAddress::new(parent.change_utxo_key, CHANGE_ADDRESS_SALT),
+		};
+
+		let parent_shortfall = parent_tx
+			.required_fee(&fee_info)
+			.checked_sub(parent_tx.fee())
+			.filter(|shortfall| *shortfall > 0)?;
+		let child_fee = parent_shortfall +
+			fee_info.fee_for_utxo(&change_utxo) +
+			fee_info.fee_per_output_utxo() +
+			fee_info.min_fee_required_per_tx();
+		let child_amount = change_utxo
+			.amount
+			.checked_sub(child_fee)
+			.filter(|amount| *amount >= BITCOIN_DUST_LIMIT)?;
+
+		let change_utxo = <E as ChainEnvironment<UtxoId, Utxo>>::lookup(change_utxo.id)?;
+
+		log::info!(
+			"Bumping fee of btc transaction {:?} by {} sats",
+			parent_tx.txid(),
+			parent_shortfall
+		);
+
+		Some(Self::BatchTransfer(batch_transfer::BatchTransfer::new_unsigned(
+			&agg_key,
+			current,
+			vec![change_utxo],
+			vec![BitcoinOutput {
+				amount: child_amount,
+				script_pubkey: DepositAddress::new(current, CHANGE_ADDRESS_SALT).script_pubkey(),
+			}],
+		)))
+	}
+}
+
 impl<E> From<batch_transfer::BatchTransfer> for BitcoinApi<E> {
 	fn from(tx: batch_transfer::BatchTransfer) -> Self {
 		Self::BatchTransfer(tx)
@@ -202,3 +268,153 @@ impl<E> ApiCall<BitcoinCrypto> for BitcoinApi<E> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::btc::BitcoinTransaction;
+	use core::cell::RefCell;
+
+	const VAULT_KEY: [u8; 32] =
+		hex_literal::hex!("78C79A2B436DA5575A03CDE40197775C656FFF9F0F59FC1466E09C20A81A9CDB");
+	const PARENT_INPUT_AMOUNT: BtcAmount = 100_000;
+	const TRANSFER_AMOUNT: BtcAmount = 50_000;
+
+	thread_local! {
+		static AVAILABLE_UTXOS: RefCell<Vec<Utxo>> = RefCell::new(vec![]);
+	}
+
+	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	struct MockEnvironment;
+
+	impl ChainEnvironment<UtxoId, Utxo> for MockEnvironment {
+		fn lookup(utxo_id: UtxoId) -> Option<Utxo> {
+			AVAILABLE_UTXOS.with(|utxos| {
+				let mut utxos = utxos.borrow_mut();
+				utxos
+					.iter()
+					.position(|utxo| utxo.id == utxo_id)
+					.map(|index| utxos.remove(index))
+			})
+		}
+	}
+
+	impl ChainEnvironment<(), BitcoinFeeInfo> for MockEnvironment {
+		fn lookup(_: ()) -> Option<BitcoinFeeInfo> {
+			Some(BitcoinFeeInfo::new(10_000))
+		}
+	}
+
+	impl ChainEnvironment<(), AggKey> for MockEnvironment {
+		fn lookup(_: ()) -> Option<AggKey> {
+			Some(AggKey { previous: None, current: VAULT_KEY })
+		}
+	}
+
+	fn parent_call(parent_fee: BtcAmount) -> BitcoinApi<MockEnvironment> {
+		let vault_address = DepositAddress::new(VAULT_KEY, CHANGE_ADDRESS_SALT);
+		BitcoinApi::BatchTransfer(batch_transfer::BatchTransfer::new_unsigned(
+			&AggKey { previous: None, current: VAULT_KEY },
+			VAULT_KEY,
+			vec![Utxo {
+				id: UtxoId { tx_id: [0xab; 32], vout: 0 },
+				amount: PARENT_INPUT_AMOUNT,
+				deposit_address: DepositAddress::new(VAULT_KEY, 1),
+			}],
+			vec![
+				BitcoinOutput {
+					amount: TRANSFER_AMOUNT,
+					script_pubkey: DepositAddress::new([0x01; 32], 0).script_pubkey(),
+				},
+				BitcoinOutput {
+					amount: PARENT_INPUT_AMOUNT - TRANSFER_AMOUNT - parent_fee,
+					script_pubkey: vault_address.script_pubkey(),
+				},
+			],
+		))
+	}
+
+	fn parent_tx(call: &BitcoinApi<MockEnvironment>) -> &BitcoinTransaction {
+		match call {
+			BitcoinApi::BatchTransfer(batch_transfer) => &batch_transfer.bitcoin_transaction,
+			BitcoinApi::_Phantom(..) => unreachable!(),
+		}
+	}
+
+	fn make_change_utxo_available(parent: &BitcoinApi<MockEnvironment>) -> Utxo {
+		let parent_tx = parent_tx(parent);
+		let change_utxo = Utxo {
+			id: UtxoId { tx_id: parent_tx.txid(), vout: 1 },
+			amount: parent_tx.outputs[1].amount,
+			deposit_address: DepositAddress::new(VAULT_KEY, CHANGE_ADDRESS_SALT),
+		};
+		AVAILABLE_UTXOS.with(|utxos| utxos.borrow_mut().push(change_utxo.clone()));
+		change_utxo
+	}
+
+	#[test]
+	fn child_pays_for_underpaying_parent() {
+		let parent = parent_call(100);
+		let change_utxo = make_change_utxo_available(&parent);
+		let fee_info = BitcoinFeeInfo::new(10_000);
+		let parent_shortfall = parent_tx(&parent).required_fee(&fee_info) - 100;
+
+		let BitcoinApi::BatchTransfer(child) =
+			BitcoinApi::<MockEnvironment>::child_pays_for_parent(&parent).unwrap()
+		else {
+			unreachable!()
+		};
+		let child_tx = &child.bitcoin_transaction;
+
+		assert_eq!(child.change_utxo_key, VAULT_KEY);
+		assert_eq!(child_tx.outputs.len(), 1);
+		assert_eq!(
+			child_tx.outputs[0].script_pubkey,
+			DepositAddress::new(VAULT_KEY, CHANGE_ADDRESS_SALT).script_pubkey()
+		);
+		// The child pays for itself as well as the parent's shortfall.
+		assert_eq!(child_tx.fee(), child_tx.required_fee(&fee_info) + parent_shortfall);
+		assert_eq!(child_tx.fee() + child_tx.outputs[0].amount, change_utxo.amount);
+		// The change utxo is spent by the child.
+		assert!(AVAILABLE_UTXOS.with(|utxos| utxos.borrow().is_empty()));
+	}
+
+	#[test]
+	fn no_child_if_parent_pays_enough() {
+		let fee_info = BitcoinFeeInfo::new(10_000);
+		let required_fee = parent_tx(&parent_call(0)).required_fee(&fee_info);
+		let parent = parent_call(required_fee);
+		make_change_utxo_available(&parent);
+
+		assert!(BitcoinApi::<MockEnvironment>::child_pays_for_parent(&parent).is_none());
+		assert_eq!(AVAILABLE_UTXOS.with(|utxos| utxos.borrow().len()), 1);
+	}
+
+	#[test]
+	fn no_child_if_parent_is_changeless() {
+		let parent = BitcoinApi::<MockEnvironment>::BatchTransfer(
+			batch_transfer::BatchTransfer::new_unsigned(
+				&AggKey { previous: None, current: VAULT_KEY },
+				VAULT_KEY,
+				vec![Utxo {
+					id: UtxoId { tx_id: [0xab; 32], vout: 0 },
+					amount: PARENT_INPUT_AMOUNT,
+					deposit_address: DepositAddress::new(VAULT_KEY, 1),
+				}],
+				vec![BitcoinOutput {
+					amount: PARENT_INPUT_AMOUNT - 100,
+					script_pubkey: DepositAddress::new([0x01; 32], 0).script_pubkey(),
+				}],
+			),
+		);
+		let BitcoinApi::BatchTransfer(batch_transfer) = &parent else { unreachable!() };
+
+		assert!(batch_transfer.change_output().is_none());
+		assert!(BitcoinApi::<MockEnvironment>::child_pays_for_parent(&parent).is_none());
+	}
+
+	#[test]
+	fn no_child_if_change_already_spent() {
+		assert!(BitcoinApi::<MockEnvironment>::child_pays_for_parent(&parent_call(100)).is_none());
+	}
+}
@@ -2,7 +2,10 @@ use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_std::vec::Vec;
 
-use crate::btc::{AggKey, BitcoinCrypto, BitcoinOutput, BitcoinTransaction, Utxo};
+use crate::btc::{
+	deposit_address::DepositAddress, AggKey, BitcoinCrypto, BitcoinOutput, BitcoinTransaction,
+	Utxo, CHANGE_ADDRESS_SALT,
+};
 
 use crate::{ApiCall, ChainCrypto};
 
@@ -33,6 +36,18 @@ impl BatchTransfer {
 			change_utxo_key,
 		}
 	}
+
+	/// Returns the index and the output that pays change back to the vault, if any.
+	///
+	/// The change output is always the last output, but it is omitted if the change is below the
+	/// dust limit or if the selected utxos match the egress amount exactly.
+	pub fn change_output(&self) -> Option<(u32, &BitcoinOutput)> {
+		let vout = self.bitcoin_transaction.outputs.len().checked_sub(1)?;
+		let output = &self.bitcoin_transaction.outputs[vout];
+		(output.script_pubkey ==
+			DepositAddress::new(self.change_utxo_key, CHANGE_ADDRESS_SALT).script_pubkey())
+		.then_some((vout as u32, output))
+	}
 }
 
 impl ApiCall<BitcoinCrypto> for BatchTransfer {
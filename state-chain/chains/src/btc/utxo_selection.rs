@@ -1,6 +1,101 @@
-use sp_std::{vec, vec::Vec};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::pallet_prelude::RuntimeDebug;
+use nanorand::{Rng, WyRand};
+use scale_info::TypeInfo;
+use sp_std::{cmp::Reverse, collections::btree_set::BTreeSet, vec, vec::Vec};
 
-use super::{BitcoinFeeInfo, ConsolidationParameters, Utxo};
+use super::{BitcoinFeeInfo, BtcAmount, ConsolidationParameters, Utxo, BITCOIN_DUST_LIMIT};
+
+#[cfg(feature = "std")]
+pub mod simulation;
+
+/// Selects utxos to fund a transaction from the pool of available utxos.
+///
+/// Implementations remove the selected utxos from `available_utxos` and return them, along with
+/// their cumulative amount net of the fee required to spend each of them. `None` is returned if the
+/// available utxos can't cover `amount_to_be_spent`, in which case `available_utxos` may still
+/// have been modified and should be discarded by the caller.
+///
+/// `seed` is a source of randomness for strategies that need it. It must be deterministic, since
+/// the selection is made on-chain.
+pub trait UtxoSelector {
+	fn select_utxos(
+		&self,
+		available_utxos: &mut Vec<Utxo>,
+		fee_info: &BitcoinFeeInfo,
+		amount_to_be_spent: BtcAmount,
+		seed: u64,
+	) -> Option<(Vec<Utxo>, BtcAmount)>;
+}
+
+/// The strategy used to select utxos for egress transactions. This can be updated via governance.
+#[derive(
+	Encode, Decode, MaxEncodedLen, TypeInfo, Default, Copy, Clone, PartialEq, Eq, RuntimeDebug,
+)]
+pub enum UtxoSelectionStrategy {
+	/// See [select_utxos_from_pool].
+	#[default]
+	SmallestFirst,
+	/// See [BranchAndBound].
+	BranchAndBound,
+	/// See [LowFeeConsolidation].
+	LowFeeConsolidation { max_sats_per_kilobyte: BtcAmount, max_extra_inputs: u32 },
+	/// See [Randomised].
+	Randomised,
+}
+
+impl UtxoSelectionStrategy {
+	pub fn is_valid(&self) -> bool {
+		match self {
+			UtxoSelectionStrategy::LowFeeConsolidation { max_extra_inputs, .. } =>
+				*max_extra_inputs > 0,
+			_ => true,
+		}
+	}
+}
+
+impl UtxoSelector for UtxoSelectionStrategy {
+	fn select_utxos(
+		&self,
+		available_utxos: &mut Vec<Utxo>,
+		fee_info: &BitcoinFeeInfo,
+		amount_to_be_spent: BtcAmount,
+		seed: u64,
+	) -> Option<(Vec<Utxo>, BtcAmount)> {
+		match *self {
+			UtxoSelectionStrategy::SmallestFirst =>
+				SmallestFirst.select_utxos(available_utxos, fee_info, amount_to_be_spent, seed),
+			UtxoSelectionStrategy::BranchAndBound =>
+				BranchAndBound.select_utxos(available_utxos, fee_info, amount_to_be_spent, seed),
+			UtxoSelectionStrategy::LowFeeConsolidation {
+				max_sats_per_kilobyte,
+				max_extra_inputs,
+			} => LowFeeConsolidation { max_sats_per_kilobyte, max_extra_inputs }.select_utxos(
+				available_utxos,
+				fee_info,
+				amount_to_be_spent,
+				seed,
+			),
+			UtxoSelectionStrategy::Randomised =>
+				Randomised.select_utxos(available_utxos, fee_info, amount_to_be_spent, seed),
+		}
+	}
+}
+
+/// Greedy smallest-first selection. See [select_utxos_from_pool].
+pub struct SmallestFirst;
+
+impl UtxoSelector for SmallestFirst {
+	fn select_utxos(
+		&self,
+		available_utxos: &mut Vec<Utxo>,
+		fee_info: &BitcoinFeeInfo,
+		amount_to_be_spent: BtcAmount,
+		_seed: u64,
+	) -> Option<(Vec<Utxo>, BtcAmount)> {
+		select_utxos_from_pool(available_utxos, fee_info, amount_to_be_spent)
+	}
+}
 
 /// The algorithm for the utxo selection works as follows: In a greedy approach it starts selecting
 /// utxos from the lowest value utxos in a sorted array. It keeps selecting the utxos until the
@@ -83,6 +178,196 @@ pub fn select_utxos_for_consolidation(
 	}
 }
 
+/// The amount a utxo contributes to a transaction once the fee to spend it has been paid.
+fn effective_value(utxo: &Utxo, fee_info: &BitcoinFeeInfo) -> Option<BtcAmount> {
+	utxo.amount.checked_sub(fee_info.fee_for_utxo(utxo)).filter(|value| *value > 0)
+}
+
+/// Removes the utxos at the given indices from `utxos` and returns them.
+fn take_indices(utxos: &mut Vec<Utxo>, indices: &BTreeSet<usize>) -> Vec<Utxo> {
+	let (selected, remaining) =
+		utxos.drain(..).enumerate().partition::<Vec<_>, _>(|(i, _)| indices.contains(i));
+	utxos.extend(remaining.into_iter().map(|(_, utxo)| utxo));
+	selected.into_iter().map(|(_, utxo)| utxo).collect()
+}
+
+/// The maximum number of branches explored by [BranchAndBound] before falling back.
+const BRANCH_AND_BOUND_MAX_TRIES: u32 = 100_000;
+
+/// Searches for a combination of utxos that covers the amount to be spent with an excess below the
+/// dust limit, so that no change output is created and the change utxo doesn't fragment the pool.
+/// This is a depth-first search over the utxos ordered by descending value, as used by Bitcoin
+/// Core. Of the combinations found, the one with the least excess is chosen.
+///
+/// If no such combination is found within [BRANCH_AND_BOUND_MAX_TRIES], this falls back to
+/// [select_utxos_from_pool].
+pub struct BranchAndBound;
+
+impl UtxoSelector for BranchAndBound {
+	fn select_utxos(
+		&self,
+		available_utxos: &mut Vec<Utxo>,
+		fee_info: &BitcoinFeeInfo,
+		amount_to_be_spent: BtcAmount,
+		_seed: u64,
+	) -> Option<(Vec<Utxo>, BtcAmount)> {
+		let mut candidates = available_utxos
+			.iter()
+			.enumerate()
+			.filter_map(|(i, utxo)| effective_value(utxo, fee_info).map(|value| (i, value)))
+			.collect::<Vec<_>>();
+		candidates.sort_by_key(|(_, value)| Reverse(*value));
+
+		let values = candidates.iter().map(|(_, value)| *value).collect::<Vec<_>>();
+		match branch_and_bound(
+			&values,
+			amount_to_be_spent,
+			amount_to_be_spent.saturating_add(BITCOIN_DUST_LIMIT - 1),
+			BRANCH_AND_BOUND_MAX_TRIES,
+		) {
+			Some(selection) => {
+				let cumulative_amount = selection.iter().map(|i| values[*i]).sum();
+				let indices = selection.into_iter().map(|i| candidates[i].0).collect();
+				Some((take_indices(available_utxos, &indices), cumulative_amount))
+			},
+			None => select_utxos_from_pool(available_utxos, fee_info, amount_to_be_spent),
+		}
+	}
+}
+
+/// Returns the indices of the `values` (which must be sorted in descending order) whose sum lies
+/// within `target..=upper_bound` with the least excess, if any is found within `max_tries`.
+fn branch_and_bound(
+	values: &[BtcAmount],
+	target: BtcAmount,
+	upper_bound: BtcAmount,
+	max_tries: u32,
+) -> Option<Vec<usize>> {
+	let mut remaining_value: BtcAmount = values.iter().sum();
+	if target == 0 || remaining_value < target {
+		return None
+	}
+
+	let mut selection: Vec<usize> = vec![];
+	let mut selected_value: BtcAmount = 0;
+	let mut best: Option<(BtcAmount, Vec<usize>)> = None;
+	let mut index = 0;
+
+	for _ in 0..max_tries {
+		let backtrack = if selected_value + remaining_value < target || selected_value > upper_bound
+		{
+			true
+		} else if selected_value >= target {
+			let excess = selected_value - target;
+			if best.as_ref().map_or(true, |(best_excess, _)| excess < *best_excess) {
+				best = Some((excess, selection.clone()));
+			}
+			if excess == 0 {
+				break
+			}
+			true
+		} else {
+			false
+		};
+
+		if backtrack {
+			let Some(&last_selected) = selection.last() else { break };
+			// Make the values we skipped over available again, then explore the branch that
+			// excludes the last selected value.
+			index -= 1;
+			while index > last_selected {
+				remaining_value += values[index];
+				index -= 1;
+			}
+			selected_value -= values[index];
+			selection.pop();
+		} else {
+			remaining_value -= values[index];
+			selected_value += values[index];
+			selection.push(index);
+		}
+		index += 1;
+	}
+
+	best.map(|(_, selection)| selection)
+}
+
+/// Selects utxos as in [select_utxos_from_pool], and while the fee rate is at or below
+/// `max_sats_per_kilobyte`, also spends up to `max_extra_inputs` of the smallest remaining utxos.
+/// This consolidates the pool while it is cheap to do so, reducing the cost of future
+/// transactions.
+pub struct LowFeeConsolidation {
+	pub max_sats_per_kilobyte: BtcAmount,
+	pub max_extra_inputs: u32,
+}
+
+impl UtxoSelector for LowFeeConsolidation {
+	fn select_utxos(
+		&self,
+		available_utxos: &mut Vec<Utxo>,
+		fee_info: &BitcoinFeeInfo,
+		amount_to_be_spent: BtcAmount,
+		_seed: u64,
+	) -> Option<(Vec<Utxo>, BtcAmount)> {
+		let (mut selected_utxos, mut cumulative_amount) =
+			select_utxos_from_pool(available_utxos, fee_info, amount_to_be_spent)?;
+
+		if fee_info.sats_per_kilobyte() <= self.max_sats_per_kilobyte {
+			available_utxos.sort_by_key(|utxo| utxo.amount);
+			let extra_indices = available_utxos
+				.iter()
+				.enumerate()
+				.filter_map(|(i, utxo)| effective_value(utxo, fee_info).map(|value| (i, value)))
+				.take(self.max_extra_inputs as usize)
+				.map(|(i, value)| {
+					cumulative_amount += value;
+					i
+				})
+				.collect();
+			selected_utxos.extend(take_indices(available_utxos, &extra_indices));
+		}
+
+		Some((selected_utxos, cumulative_amount))
+	}
+}
+
+/// Selects utxos in a pseudo-random order until the amount to be spent is covered. This makes it
+/// harder for observers to link our transactions to each other based on the selection pattern.
+pub struct Randomised;
+
+impl UtxoSelector for Randomised {
+	fn select_utxos(
+		&self,
+		available_utxos: &mut Vec<Utxo>,
+		fee_info: &BitcoinFeeInfo,
+		amount_to_be_spent: BtcAmount,
+		seed: u64,
+	) -> Option<(Vec<Utxo>, BtcAmount)> {
+		let mut candidates = available_utxos
+			.iter()
+			.enumerate()
+			.filter_map(|(i, utxo)| effective_value(utxo, fee_info).map(|value| (i, value)))
+			.collect::<Vec<_>>();
+		WyRand::new_seed(seed).shuffle(&mut candidates);
+
+		let mut cumulative_amount = 0;
+		let mut indices = BTreeSet::new();
+		for (i, value) in candidates {
+			if cumulative_amount >= amount_to_be_spent && !indices.is_empty() {
+				break
+			}
+			cumulative_amount += value;
+			indices.insert(i);
+		}
+
+		if indices.is_empty() || cumulative_amount < amount_to_be_spent {
+			return None
+		}
+
+		Some((take_indices(available_utxos, &indices), cumulative_amount))
+	}
+}
+
 #[test]
 fn test_utxo_selection() {
 	use crate::btc::{deposit_address::DepositAddress, UtxoId};
@@ -196,3 +481,127 @@ fn test_utxo_selection() {
 		)),
 	);
 }
+
+#[cfg(test)]
+fn build_test_utxos(amounts: &[u64]) -> Vec<Utxo> {
+	use crate::btc::{deposit_address::DepositAddress, UtxoId};
+
+	amounts
+		.iter()
+		.zip(1u32..)
+		.map(|(amount, salt)| Utxo {
+			id: UtxoId { tx_id: [salt as u8; 32], vout: 0 },
+			amount: *amount,
+			deposit_address: DepositAddress::new(
+				hex_literal::hex!(
+					"0000111122223333444455556666777788889999AAAABBBBCCCCDDDDEEEEFFFF"
+				),
+				salt,
+			),
+		})
+		.collect()
+}
+
+#[test]
+fn test_branch_and_bound() {
+	// Effective values with no fees are just the amounts.
+	assert_eq!(branch_and_bound(&[10, 7, 5, 3], 8, 8, 1000), Some(vec![2, 3]));
+	assert_eq!(branch_and_bound(&[10, 7, 5, 3], 15, 15, 1000), Some(vec![0, 2]));
+	// The least excess within the bound is chosen.
+	assert_eq!(branch_and_bound(&[10, 7, 5, 3], 11, 13, 1000), Some(vec![1, 2]));
+	// No combination within the bound.
+	assert_eq!(branch_and_bound(&[10, 7], 12, 16, 1000), None);
+	// Not enough funds.
+	assert_eq!(branch_and_bound(&[10, 7], 18, 20, 1000), None);
+	// Running out of tries.
+	assert_eq!(branch_and_bound(&[10, 7, 5, 3], 8, 8, 2), None);
+
+	let fee_info = BitcoinFeeInfo { sats_per_kilobyte: 0 };
+	let utxos = build_test_utxos(&[50_000, 1_000, 30_000, 20_000, 100_000]);
+
+	// 50_000 covers the amount, leaving change below the dust limit.
+	let mut available_utxos = utxos.clone();
+	let (selected, cumulative_amount) =
+		BranchAndBound.select_utxos(&mut available_utxos, &fee_info, 49_500, 0).unwrap();
+	assert_eq!(cumulative_amount, 50_000);
+	assert_eq!(selected.len(), 1);
+	assert_eq!(available_utxos.len(), 4);
+
+	let mut available_utxos = utxos.clone();
+	let (selected, cumulative_amount) =
+		BranchAndBound.select_utxos(&mut available_utxos, &fee_info, 71_000, 0).unwrap();
+	assert_eq!(cumulative_amount, 71_000);
+	assert_eq!(
+		selected.iter().map(|utxo| utxo.amount).collect::<Vec<_>>(),
+		[50_000, 1_000, 20_000]
+	);
+
+	// Falls back to smallest-first if there is no changeless selection.
+	let mut available_utxos = utxos.clone();
+	assert_eq!(
+		BranchAndBound.select_utxos(&mut available_utxos, &fee_info, 25_000, 0),
+		select_utxos_from_pool(&mut utxos.clone(), &fee_info, 25_000),
+	);
+}
+
+#[test]
+fn test_low_fee_consolidation() {
+	let utxos = build_test_utxos(&[50_000, 1_000, 30_000, 20_000, 100_000, 2_000]);
+	let strategy = LowFeeConsolidation { max_sats_per_kilobyte: 5_000, max_extra_inputs: 2 };
+
+	// Behaves like smallest-first when fees are high.
+	let high_fee_info = BitcoinFeeInfo { sats_per_kilobyte: 10_000 };
+	let mut available_utxos = utxos.clone();
+	assert_eq!(
+		strategy.select_utxos(&mut available_utxos, &high_fee_info, 10_000, 0),
+		select_utxos_from_pool(&mut utxos.clone(), &high_fee_info, 10_000),
+	);
+
+	// Spends some extra small utxos when fees are low.
+	let low_fee_info = BitcoinFeeInfo { sats_per_kilobyte: 1_000 };
+	let mut expected_utxos = utxos.clone();
+	let (smallest_first, smallest_first_amount) =
+		select_utxos_from_pool(&mut expected_utxos, &low_fee_info, 10_000).unwrap();
+	let mut available_utxos = utxos.clone();
+	let (selected, cumulative_amount) =
+		strategy.select_utxos(&mut available_utxos, &low_fee_info, 10_000, 0).unwrap();
+	assert_eq!(selected.len(), smallest_first.len() + 2);
+	assert_eq!(available_utxos.len(), expected_utxos.len() - 2);
+	assert_eq!(
+		cumulative_amount,
+		smallest_first_amount +
+			selected[smallest_first.len()..]
+				.iter()
+				.map(|utxo| utxo.amount - low_fee_info.fee_for_utxo(utxo))
+				.sum::<u64>()
+	);
+}
+
+#[test]
+fn test_randomised_selection() {
+	let fee_info = BitcoinFeeInfo { sats_per_kilobyte: 1_000 };
+	let utxos = build_test_utxos(&[50_000, 1_000, 30_000, 20_000, 100_000, 2_000]);
+
+	let select = |seed| {
+		let mut available_utxos = utxos.clone();
+		let selection = Randomised.select_utxos(&mut available_utxos, &fee_info, 60_000, seed);
+		(selection, available_utxos)
+	};
+
+	// The selection is deterministic for a given seed.
+	assert_eq!(select(1), select(1));
+	// But varies between seeds.
+	assert!((2..10).any(|seed| select(seed).0 != select(1).0));
+
+	for seed in 0..10 {
+		let (selection, available_utxos) = select(seed);
+		let (selected, cumulative_amount) = selection.unwrap();
+		assert!(cumulative_amount >= 60_000);
+		assert_eq!(selected.len() + available_utxos.len(), utxos.len());
+		assert!(selected.iter().all(|utxo| !available_utxos.contains(utxo)));
+	}
+
+	// Not enough funds.
+	let mut available_utxos = utxos.clone();
+	assert_eq!(Randomised.select_utxos(&mut available_utxos, &fee_info, 1_000_000, 1), None);
+}
@@ -0,0 +1,250 @@
+//! Offline simulation of utxo selection strategies.
+//!
+//! Replays a sequence of deposits, egresses and fee rate changes against a [UtxoSelectionStrategy]
+//! and reports the fees paid and how fragmented the utxo set becomes. Egresses are built the same
+//! way as [AllBatch](crate::AllBatch) transactions: one output per transfer above the dust limit,
+//! plus a change output back to the vault unless the change is below the dust limit.
+//!
+//! Events are read from a simple line-based format, one event per line:
+//!
+//! ```text
+//! # Comments and empty lines are ignored.
+//! fee_rate 12000
+//! deposit 150000
+//! egress 50000 20000
+//! ```
+
+use core::str::FromStr;
+
+use anyhow::{anyhow, bail, Context};
+
+use super::{UtxoSelectionStrategy, UtxoSelector};
+use crate::btc::{
+	deposit_address::DepositAddress, BitcoinFeeInfo, BtcAmount, Utxo, UtxoId, BITCOIN_DUST_LIMIT,
+	CHANGE_ADDRESS_SALT,
+};
+
+const VAULT_KEY: [u8; 32] =
+	hex_literal::hex!("78C79A2B436DA5575A03CDE40197775C656FFF9F0F59FC1466E09C20A81A9CDB");
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SimulationEvent {
+	/// A deposit into a new deposit channel.
+	Deposit { amount: BtcAmount },
+	/// A batch of transfers out of the vault.
+	Egress { amounts: Vec<BtcAmount> },
+	/// A change of the network fee rate.
+	FeeRate { sats_per_kilobyte: BtcAmount },
+}
+
+impl FromStr for SimulationEvent {
+	type Err = anyhow::Error;
+
+	fn from_str(line: &str) -> Result<Self, Self::Err> {
+		let mut words = line.split_whitespace();
+		let kind = words.next().ok_or_else(|| anyhow!("Empty event"))?;
+		let values = words
+			.map(|word| word.parse::<BtcAmount>().with_context(|| format!("Invalid amount {word}")))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		match (kind, &values[..]) {
+			("deposit", [amount]) => Ok(SimulationEvent::Deposit { amount: *amount }),
+			("egress", [_, ..]) => Ok(SimulationEvent::Egress { amounts: values }),
+			("fee_rate", [sats_per_kilobyte]) =>
+				Ok(SimulationEvent::FeeRate { sats_per_kilobyte: *sats_per_kilobyte }),
+			_ => bail!("Invalid event: {line}"),
+		}
+	}
+}
+
+/// Parses events from the line-based format described in the [module docs](self).
+pub fn parse_events(input: &str) -> anyhow::Result<Vec<SimulationEvent>> {
+	input
+		.lines()
+		.enumerate()
+		.map(|(i, line)| (i, line.trim()))
+		.filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+		.map(|(i, line)| line.parse().with_context(|| format!("Line {}", i + 1)))
+		.collect()
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SimulationReport {
+	/// The number of egress transactions that were built.
+	pub transactions: u32,
+	/// The number of egresses that couldn't be funded from the available utxos.
+	pub failed_egresses: u32,
+	/// The total fee paid by all transactions, including change that was too small to be output.
+	pub total_fees: BtcAmount,
+	/// The total number of inputs spent by all transactions.
+	pub total_inputs: u32,
+	/// The number of transactions without a change output.
+	pub changeless_transactions: u32,
+	/// The number of utxos left at the end of the simulation.
+	pub final_utxo_count: usize,
+	/// The largest number of utxos held at any point during the simulation.
+	pub max_utxo_count: usize,
+	/// The number of utxos left at the end that cost more to spend than they are worth at the
+	/// final fee rate.
+	pub uneconomical_utxos: usize,
+}
+
+/// Replays the `events` using the given `strategy`, starting from an empty utxo set.
+pub fn simulate(strategy: UtxoSelectionStrategy, events: &[SimulationEvent]) -> SimulationReport {
+	let mut report = SimulationReport::default();
+	let mut fee_info = BitcoinFeeInfo::default();
+	let mut available_utxos: Vec<Utxo> = vec![];
+	let mut next_id: u64 = 0;
+	let mut new_utxo_id = || {
+		next_id += 1;
+		let mut tx_id = [0u8; 32];
+		tx_id[..8].copy_from_slice(&next_id.to_le_bytes());
+		(UtxoId { tx_id, vout: 0 }, next_id)
+	};
+
+	for event in events {
+		match event {
+			SimulationEvent::FeeRate { sats_per_kilobyte } => {
+				fee_info = BitcoinFeeInfo::new(*sats_per_kilobyte);
+			},
+			SimulationEvent::Deposit { amount } => {
+				let (id, channel_id) = new_utxo_id();
+				available_utxos.push(Utxo {
+					id,
+					amount: *amount,
+					deposit_address: DepositAddress::new(VAULT_KEY, channel_id as u32),
+				});
+			},
+			SimulationEvent::Egress { amounts } => {
+				let outputs = amounts
+					.iter()
+					.filter(|amount| **amount >= BITCOIN_DUST_LIMIT)
+					.collect::<Vec<_>>();
+				let total_output_amount = outputs.iter().copied().sum::<BtcAmount>();
+				if total_output_amount == 0 {
+					continue
+				}
+				// +1 for the change output.
+				let amount_to_be_spent = total_output_amount +
+					(outputs.len() as BtcAmount + 1) * fee_info.fee_per_output_utxo() +
+					fee_info.min_fee_required_per_tx();
+
+				let mut remaining_utxos = available_utxos.clone();
+				let Some((selected_utxos, cumulative_amount)) = strategy.select_utxos(
+					&mut remaining_utxos,
+					&fee_info,
+					amount_to_be_spent,
+					report.transactions as u64,
+				) else {
+					report.failed_egresses += 1;
+					continue
+				};
+				available_utxos = remaining_utxos;
+
+				let change_amount = cumulative_amount - amount_to_be_spent;
+				let change_output = if change_amount >= BITCOIN_DUST_LIMIT {
+					change_amount
+				} else {
+					report.changeless_transactions += 1;
+					0
+				};
+				report.transactions += 1;
+				report.total_inputs += selected_utxos.len() as u32;
+				report.total_fees +=
+					selected_utxos.iter().map(|utxo| utxo.amount).sum::<BtcAmount>() -
+						total_output_amount - change_output;
+
+				if change_output > 0 {
+					available_utxos.push(Utxo {
+						id: new_utxo_id().0,
+						amount: change_output,
+						deposit_address: DepositAddress::new(VAULT_KEY, CHANGE_ADDRESS_SALT),
+					});
+				}
+			},
+		}
+		report.max_utxo_count = report.max_utxo_count.max(available_utxos.len());
+	}
+
+	report.final_utxo_count = available_utxos.len();
+	report.uneconomical_utxos = available_utxos
+		.iter()
+		.filter(|utxo| utxo.amount <= fee_info.fee_for_utxo(utxo))
+		.count();
+	report
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const EVENTS: &str = "
+		# Ten small deposits, then a few egresses.
+		fee_rate 10000
+		deposit 20000
+		deposit 30000
+		deposit 40000
+		deposit 50000
+		deposit 60000
+		deposit 70000
+		deposit 80000
+		deposit 90000
+		deposit 100000
+		deposit 5000
+		egress 45000
+		fee_rate 2000
+		egress 20000 30000
+		egress 1000000
+	";
+
+	#[test]
+	fn parses_events() {
+		assert_eq!(
+			parse_events("fee_rate 1000\n\n# comment\ndeposit 5\negress 1 2").unwrap(),
+			vec![
+				SimulationEvent::FeeRate { sats_per_kilobyte: 1000 },
+				SimulationEvent::Deposit { amount: 5 },
+				SimulationEvent::Egress { amounts: vec![1, 2] },
+			]
+		);
+		assert!(parse_events("deposit").is_err());
+		assert!(parse_events("egress").is_err());
+		assert!(parse_events("withdraw 5").is_err());
+		assert!(parse_events("deposit five").is_err());
+	}
+
+	#[test]
+	fn simulation_accounts_for_all_funds() {
+		let events = parse_events(EVENTS).unwrap();
+		let deposited = 545_000;
+
+		for strategy in [
+			UtxoSelectionStrategy::SmallestFirst,
+			UtxoSelectionStrategy::BranchAndBound,
+			UtxoSelectionStrategy::LowFeeConsolidation {
+				max_sats_per_kilobyte: 5000,
+				max_extra_inputs: 3,
+			},
+			UtxoSelectionStrategy::Randomised,
+		] {
+			let report = simulate(strategy, &events);
+			assert_eq!(report.transactions, 2, "{strategy:?}");
+			assert_eq!(report.failed_egresses, 1, "{strategy:?}");
+			assert_eq!(report.max_utxo_count, 10, "{strategy:?}");
+			assert!(report.final_utxo_count < 10, "{strategy:?}");
+
+			// Replaying the events must be deterministic.
+			assert_eq!(report, simulate(strategy, &events), "{strategy:?}");
+
+			// Consolidating at the low fee rate leaves fewer utxos behind.
+			if let UtxoSelectionStrategy::LowFeeConsolidation { .. } = strategy {
+				assert!(
+					report.final_utxo_count <
+						simulate(UtxoSelectionStrategy::SmallestFirst, &events).final_utxo_count
+				);
+			}
+
+			assert!(report.total_fees > 0 && report.total_fees < deposited, "{strategy:?}");
+		}
+	}
+}
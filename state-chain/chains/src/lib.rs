@@ -252,6 +252,14 @@ where
 	fn calculate_gas_limit(_call: &Call) -> Option<U256> {
 		Default::default()
 	}
+
+	/// Build a new call that raises the effective fee paid for a *signed* call whose transaction
+	/// has been pending for too long, for example a Bitcoin child-pays-for-parent transaction.
+	///
+	/// Returns `None` if the chain doesn't support fee bumping, or if no bump is required.
+	fn build_fee_bump_call(_signed_call: &Call) -> Option<Call> {
+		None
+	}
 }
 
 pub trait TransactionMetadata<C: Chain> {
@@ -478,6 +486,55 @@ pub struct CcmDepositMetadata {
 	pub source_chain: ForeignChain,
 	pub source_address: Option<ForeignChainAddress>,
 	pub channel_metadata: CcmChannelMetadata,
+	/// Slippage protection for the principal swap of the message.
+	pub refund_parameters: Option<SwapRefundParameters>,
+}
+
+/// Slippage protection for a swap: if the swap would output less than `min_output` of the
+/// destination asset, the swap input is refunded to `refund_address` instead.
+#[derive(
+	Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, Serialize, Deserialize, MaxEncodedLen,
+)]
+pub struct SwapRefundParameters<Address = ForeignChainAddress> {
+	/// The minimum amount of the destination asset the swap must yield.
+	#[cfg_attr(feature = "std", serde(with = "cf_utilities::serde_helpers::number_or_hex"))]
+	pub min_output: AssetAmount,
+	/// Address on the source chain to refund the swap input to.
+	pub refund_address: Address,
+}
+
+impl<Address> SwapRefundParameters<Address> {
+	pub fn try_map_address<NewAddress, E>(
+		self,
+		f: impl FnOnce(Address) -> Result<NewAddress, E>,
+	) -> Result<SwapRefundParameters<NewAddress>, E> {
+		Ok(SwapRefundParameters {
+			min_output: self.min_output,
+			refund_address: f(self.refund_address)?,
+		})
+	}
+}
+
+/// Splits a swap into chunks that are executed over successive swap blocks, to reduce the price
+/// impact of large swaps.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+	MaxEncodedLen,
+)]
+pub struct DcaParameters {
+	/// The number of chunks the swap is split into.
+	pub number_of_chunks: u32,
+	/// The number of blocks between the execution of consecutive chunks.
+	pub chunk_interval: u32,
 }
 
 #[derive(
@@ -353,6 +353,7 @@ impl<C: ChainCrypto + 'static> ApiCall<C> for MockApiCall<C> {
 
 thread_local! {
 	pub static REQUIRES_REFRESH: std::cell::RefCell<bool> = const { RefCell::new(false) };
+	pub static SUPPORTS_FEE_BUMPS: std::cell::RefCell<bool> = const { RefCell::new(false) };
 }
 
 pub struct MockTransactionBuilder<C, Call>(PhantomData<(C, Call)>);
@@ -361,6 +362,10 @@ impl<C, Call> MockTransactionBuilder<C, Call> {
 	pub fn set_requires_refresh() {
 		REQUIRES_REFRESH.with(|is_valid| *is_valid.borrow_mut() = true)
 	}
+
+	pub fn set_supports_fee_bumps() {
+		SUPPORTS_FEE_BUMPS.with(|supports_fee_bumps| *supports_fee_bumps.borrow_mut() = true)
+	}
 }
 
 impl<C: Chain<Transaction = MockTransaction>, Call: ApiCall<C::ChainCrypto>>
@@ -380,4 +385,10 @@ impl<C: Chain<Transaction = MockTransaction>, Call: ApiCall<C::ChainCrypto>>
 	) -> bool {
 		REQUIRES_REFRESH.with(|is_valid| *is_valid.borrow())
 	}
+
+	fn build_fee_bump_call(signed_call: &Call) -> Option<Call> {
+		SUPPORTS_FEE_BUMPS
+			.with(|supports_fee_bumps| *supports_fee_bumps.borrow())
+			.then(|| signed_call.clone())
+	}
 }
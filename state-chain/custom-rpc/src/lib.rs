@@ -9,10 +9,11 @@ use cf_chains::{
 };
 use cf_primitives::{
 	chains::assets::any::{self, OldAsset},
-	AccountRole, Asset, AssetAmount, BroadcastId, ForeignChain, NetworkEnvironment, SemVer,
-	SwapOutput,
+	AccountRole, Asset, AssetAmount, BroadcastId, ChannelId, EpochIndex, ForeignChain,
+	NetworkEnvironment, SemVer, SwapOutput,
 };
 use cf_utilities::rpc::NumberOrHex;
+use codec::Encode;
 use core::ops::Range;
 use jsonrpsee::{
 	core::RpcResult,
@@ -22,6 +23,7 @@ use jsonrpsee::{
 };
 use pallet_cf_governance::GovCallHash;
 use pallet_cf_pools::{AskBidMap, PoolInfo, PoolLiquidity, PoolPriceV1, UnidirectionalPoolDepth};
+use pallet_cf_tokenholder_governance::{ProposalOutcome, ProposalStatus};
 use sc_client_api::{BlockchainEvents, HeaderBackend};
 use serde::{Deserialize, Serialize};
 use sp_core::U256;
@@ -34,7 +36,7 @@ use state_chain_runtime::{
 	constants::common::TX_FEE_MULTIPLIER,
 	runtime_apis::{
 		CustomRuntimeApi, DispatchErrorWithMessage, FailingWitnessValidators,
-		LiquidityProviderInfo, RuntimeApiAccountInfoV2,
+		LiquidityProviderInfo, RuntimeApiAccountInfoV2, ValidatorPerformance,
 	},
 	NetworkFee,
 };
@@ -248,6 +250,58 @@ pub struct RpcPrewitnessedSwap {
 	pub amounts: Vec<U256>,
 }
 
+/// Dust ignored for being below the minimum deposit amount that has not yet been recovered.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RpcUnrecoveredDust {
+	pub deposit_address: ForeignChainAddressHumanreadable,
+	#[serde(flatten)]
+	pub asset: Asset,
+	/// The channel the dust was sent to. This may be a previous channel of a recycled address,
+	/// in which case the dust is never refunded.
+	pub channel_id: ChannelId,
+	pub amount: NumberOrHex,
+}
+
+/// The status of a tokenholder governance proposal. Resolved proposals are reported along with the
+/// block at which they were resolved.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RpcTokenholderProposalStatus {
+	Voting { resolution_block: u32, backers: u32, backed_funds: NumberOrHex },
+	AwaitingEnactment { enactment_block: u32 },
+	Rejected { block: u32 },
+	Enacted { block: u32 },
+	EnactmentFailed { block: u32 },
+	Vetoed { block: u32 },
+}
+
+impl From<ProposalStatus<u32, u128>> for RpcTokenholderProposalStatus {
+	fn from(status: ProposalStatus<u32, u128>) -> Self {
+		match status {
+			ProposalStatus::Voting { resolution_block, backers, backed_funds } =>
+				Self::Voting { resolution_block, backers, backed_funds: backed_funds.into() },
+			ProposalStatus::AwaitingEnactment { enactment_block } =>
+				Self::AwaitingEnactment { enactment_block },
+			ProposalStatus::Resolved { block, outcome } => match outcome {
+				ProposalOutcome::Rejected => Self::Rejected { block },
+				ProposalOutcome::Enacted => Self::Enacted { block },
+				ProposalOutcome::EnactmentFailed => Self::EnactmentFailed { block },
+				ProposalOutcome::Vetoed => Self::Vetoed { block },
+			},
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RpcTokenholderProposal {
+	/// A human-readable description of the proposal.
+	pub proposal: String,
+	/// The hex-encoded proposal, as accepted by `submit_proposal` and `back_proposal`.
+	pub encoded_proposal: String,
+	#[serde(flatten)]
+	pub status: RpcTokenholderProposalStatus,
+}
+
 #[rpc(server, client, namespace = "cf")]
 /// The custom RPC endpoints for the state chain node.
 pub trait CustomApi {
@@ -476,6 +530,30 @@ pub trait CustomApi {
 		hash: state_chain_runtime::Hash,
 		at: Option<state_chain_runtime::Hash>,
 	) -> RpcResult<Option<FailingWitnessValidators>>;
+
+	#[method(name = "unrecovered_dust")]
+	fn cf_unrecovered_dust(
+		&self,
+		chain: ForeignChain,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<RpcUnrecoveredDust>>;
+	/// Returns all tokenholder governance proposals that are being voted on, are awaiting
+	/// enactment, or have been resolved.
+	#[method(name = "tokenholder_governance_proposals")]
+	fn cf_tokenholder_governance_proposals(
+		&self,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<RpcTokenholderProposal>>;
+	/// Returns the heartbeat, witnessing, ceremony and slashing statistics of a validator for the
+	/// given epoch, or the current epoch if none is given. Statistics are only kept for unexpired
+	/// epochs.
+	#[method(name = "validator_performance")]
+	fn cf_validator_performance(
+		&self,
+		account_id: state_chain_runtime::AccountId,
+		epoch_index: Option<EpochIndex>,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<ValidatorPerformance>;
 }
 
 /// An RPC extension for the state chain node.
@@ -1199,6 +1277,63 @@ where
 			.cf_witness_count(self.unwrap_or_best(at), pallet_cf_witnesser::CallHash(hash.into()))
 			.map_err(to_rpc_error)
 	}
+
+	fn cf_unrecovered_dust(
+		&self,
+		chain: ForeignChain,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<RpcUnrecoveredDust>> {
+		let runtime_api = &self.client.runtime_api();
+		let hash = self.unwrap_or_best(at);
+		let network = runtime_api.cf_network_environment(hash).map_err(to_rpc_error)?;
+
+		Ok(runtime_api
+			.cf_unrecovered_dust(hash, chain)
+			.map_err(to_rpc_error)?
+			.into_iter()
+			.map(|(deposit_address, asset, channel_id, amount)| RpcUnrecoveredDust {
+				deposit_address: deposit_address.to_humanreadable(network),
+				asset,
+				channel_id,
+				amount: amount.into(),
+			})
+			.collect())
+	}
+
+	fn cf_tokenholder_governance_proposals(
+		&self,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<RpcTokenholderProposal>> {
+		Ok(self
+			.client
+			.runtime_api()
+			.cf_tokenholder_governance_proposals(self.unwrap_or_best(at))
+			.map_err(to_rpc_error)?
+			.into_iter()
+			.map(|(proposal, status)| RpcTokenholderProposal {
+				proposal: format!("{proposal:?}"),
+				encoded_proposal: hex::encode(proposal.encode()),
+				status: status.into(),
+			})
+			.collect())
+	}
+
+	fn cf_validator_performance(
+		&self,
+		account_id: state_chain_runtime::AccountId,
+		epoch_index: Option<EpochIndex>,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<ValidatorPerformance> {
+		let runtime_api = &self.client.runtime_api();
+		let hash = self.unwrap_or_best(at);
+		let epoch_index = match epoch_index {
+			Some(epoch_index) => epoch_index,
+			None => runtime_api.cf_current_epoch(hash).map_err(to_rpc_error)?,
+		};
+		runtime_api
+			.cf_validator_performance(hash, account_id, epoch_index)
+			.map_err(to_rpc_error)
+	}
 }
 
 impl<C, B> CustomRpc<C, B>
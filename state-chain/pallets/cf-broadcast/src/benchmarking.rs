@@ -180,6 +180,38 @@ mod benchmarks {
 		assert!(TransactionMetadata::<T, I>::get(broadcast_id).is_none());
 	}
 
+	#[benchmark]
+	fn update_fee_bump_threshold() {
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+		let threshold = Some(ChainBlockNumberFor::<T, I>::from(10u32));
+		let call = Call::<T, I>::update_fee_bump_threshold { threshold };
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		assert_eq!(FeeBumpThreshold::<T, I>::get(), threshold);
+	}
+
+	#[benchmark]
+	fn bump_fees_of_stuck_broadcasts(n: Linear<0, 50>) {
+		FeeBumpThreshold::<T, I>::put(ChainBlockNumberFor::<T, I>::from(10u32));
+		let block_height = T::ChainTracking::get_block_height();
+		for broadcast_id in 1..=n {
+			insert_transaction_broadcast_attempt::<T, I>(None, broadcast_id);
+			FeeBumpQueue::<T, I>::append(block_height, broadcast_id);
+		}
+		NextFeeBumpBlock::<T, I>::put(block_height);
+
+		#[block]
+		{
+			Pallet::<T, I>::bump_fees_of_stuck_broadcasts();
+		}
+
+		assert!(FeeBumpQueue::<T, I>::get(block_height).is_empty());
+	}
+
 	#[cfg(test)]
 	use crate::mock::*;
 
@@ -200,5 +232,11 @@ mod benchmarks {
 		new_test_ext().execute_with(|| {
 			_transaction_succeeded::<Test, Instance1>(true);
 		});
+		new_test_ext().execute_with(|| {
+			_update_fee_bump_threshold::<Test, Instance1>(true);
+		});
+		new_test_ext().execute_with(|| {
+			_bump_fees_of_stuck_broadcasts::<Test, Instance1>(50, true);
+		});
 	}
 }
@@ -26,8 +26,9 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::DispatchResultWithPostInfo,
 	pallet_prelude::{ensure, DispatchResult, RuntimeDebug},
-	sp_runtime::traits::{One, Saturating},
+	sp_runtime::traits::{One, Saturating, Zero},
 	traits::{Defensive, Get, OriginTrait, StorageVersion, UnfilteredDispatchable},
+	weights::Weight,
 	Twox64Concat,
 };
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
@@ -53,6 +54,10 @@ impl<I: 'static> SafeMode for PalletSafeMode<I> {
 /// The number of broadcast attempts that were made before this one.
 pub type AttemptCount = u32;
 
+/// The maximum number of target chain blocks whose queued fee bumps are processed in a single
+/// block, when catching up after retries have been disabled.
+pub const MAX_FEE_BUMP_BLOCKS_PER_BLOCK: u32 = 10;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub enum PalletOffence {
 	FailedToBroadcastTransaction,
@@ -296,6 +301,28 @@ pub mod pallet {
 	#[pallet::getter(fn aborted_broadcasts)]
 	pub type AbortedBroadcasts<T, I = ()> = StorageValue<_, Vec<BroadcastId>, ValueQuery>;
 
+	/// The number of target chain blocks a broadcast can be pending for before we attempt to bump
+	/// its fee. Fee bumping is disabled if this is not set.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_bump_threshold)]
+	pub type FeeBumpThreshold<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, ChainBlockNumberFor<T, I>, OptionQuery>;
+
+	/// Broadcasts whose fee has been bumped, along with the id of the broadcast that bumps the fee.
+	#[pallet::storage]
+	pub type FeeBumps<T, I = ()> =
+		StorageMap<_, Twox64Concat, BroadcastId, BroadcastId, OptionQuery>;
+
+	/// Pending broadcasts, indexed by the target chain block at which their fee is to be bumped.
+	#[pallet::storage]
+	pub type FeeBumpQueue<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ChainBlockNumberFor<T, I>, Vec<BroadcastId>, ValueQuery>;
+
+	/// The earliest target chain block whose queued fee bumps have not been processed yet.
+	#[pallet::storage]
+	pub type NextFeeBumpBlock<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, ChainBlockNumberFor<T, I>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -331,6 +358,11 @@ pub mod pallet {
 		TransactionFeeDeficitRefused { beneficiary: SignerIdFor<T, I> },
 		/// A Call has been re-threshold-signed, and its signature data is inserted into storage.
 		CallResigned { broadcast_id: BroadcastId },
+		/// A broadcast has been pending for too long, and a new broadcast has been started to bump
+		/// its fee.
+		BroadcastFeeBumped { broadcast_id: BroadcastId, fee_bump_broadcast_id: BroadcastId },
+		/// The fee bump threshold has been updated.
+		FeeBumpThresholdUpdated { threshold: Option<ChainBlockNumberFor<T, I>> },
 	}
 
 	#[pallet::error]
@@ -341,12 +373,16 @@ pub mod pallet {
 		InvalidBroadcastId,
 		/// A threshold signature was expected but not available.
 		ThresholdSignatureUnavailable,
+		/// The fee bump threshold must be greater than zero.
+		InvalidFeeBumpThreshold,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
 		/// Process any broadcasts that expired are treated as failed.
 		/// Re-try any broadcasts in the Delayed Retry queue.
+		/// Bump the fee of any broadcasts that have been pending for longer than the
+		/// [FeeBumpThreshold].
 		/// If safe mode prevents retrying, the broadcasts are added to future blocks.
 		fn on_initialize(block_number: BlockNumberFor<T>) -> frame_support::weights::Weight {
 			// We treat a time out here as a Broadcast Failure. This is handled the same way - the
@@ -357,6 +393,7 @@ pub mod pallet {
 			let mut delayed_retries = DelayedBroadcastRetryQueue::<T, I>::take(block_number);
 			let expiry_count = expiries.len();
 			let retries_count = delayed_retries.len();
+			let mut fee_bump_weight = Weight::zero();
 			if T::SafeMode::get().retry_enabled {
 				for (broadcast_id, nominee) in expiries {
 					if pending_broadcasts.contains(&broadcast_id) {
@@ -387,6 +424,8 @@ pub mod pallet {
 						current.append(&mut delayed_retries)
 					});
 				}
+
+				fee_bump_weight = Self::bump_fees_of_stuck_broadcasts();
 			} else {
 				Timeouts::<T, I>::mutate(
 					block_number.saturating_add(T::SafeModeBlockMargin::get()),
@@ -398,6 +437,7 @@ pub mod pallet {
 				);
 			}
 			T::WeightInfo::on_initialize(expiry_count as u32, retries_count as u32)
+				.saturating_add(fee_bump_weight)
 		}
 	}
 
@@ -628,6 +668,36 @@ pub mod pallet {
 			Self::handle_broadcast_failure(broadcast_id, reporter.into())?;
 			Ok(().into())
 		}
+
+		/// Sets the number of target chain blocks a broadcast can be pending for before we attempt
+		/// to bump its fee. Setting this to `None` disables fee bumping.
+		///
+		/// ## Events
+		///
+		/// - [FeeBumpThresholdUpdated](Event::FeeBumpThresholdUpdated)
+		///
+		/// ## Errors
+		///
+		/// - [InvalidFeeBumpThreshold](Error::InvalidFeeBumpThreshold)
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::update_fee_bump_threshold())]
+		pub fn update_fee_bump_threshold(
+			origin: OriginFor<T>,
+			threshold: Option<ChainBlockNumberFor<T, I>>,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			ensure!(
+				!threshold.is_some_and(|t| t.is_zero()),
+				Error::<T, I>::InvalidFeeBumpThreshold
+			);
+
+			FeeBumpThreshold::<T, I>::set(threshold);
+
+			Self::deposit_event(Event::<T, I>::FeeBumpThresholdUpdated { threshold });
+
+			Ok(())
+		}
 	}
 }
 
@@ -637,6 +707,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		TransactionMetadata::<T, I>::remove(broadcast_id);
 		RequestSuccessCallbacks::<T, I>::remove(broadcast_id);
 		RequestFailureCallbacks::<T, I>::remove(broadcast_id);
+		FeeBumps::<T, I>::remove(broadcast_id);
 		if let Some((api_call, _)) = ThresholdSignatureData::<T, I>::take(broadcast_id) {
 			TransactionOutIdToBroadcastId::<T, I>::remove(api_call.transaction_out_id());
 		}
@@ -658,12 +729,40 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	/// Request a threshold signature, providing [Call::on_signature_ready] as the callback.
+	///
+	/// If fee bumping is enabled, the broadcast is queued to have its fee bumped once it has been
+	/// pending for [FeeBumpThreshold] target chain blocks.
 	pub fn threshold_sign_and_broadcast(
 		api_call: <T as Config<I>>::ApiCall,
 		maybe_success_callback: Option<<T as Config<I>>::BroadcastCallable>,
 		maybe_failed_callback_generator: impl FnOnce(
 			BroadcastId,
 		) -> Option<<T as Config<I>>::BroadcastCallable>,
+	) -> BroadcastId {
+		let broadcast_id = Self::threshold_sign_and_broadcast_without_fee_bump(
+			api_call,
+			maybe_success_callback,
+			maybe_failed_callback_generator,
+		);
+
+		if let Some(threshold) = FeeBumpThreshold::<T, I>::get() {
+			FeeBumpQueue::<T, I>::append(
+				T::ChainTracking::get_block_height().saturating_add(threshold),
+				broadcast_id,
+			);
+		}
+
+		broadcast_id
+	}
+
+	/// Like [Self::threshold_sign_and_broadcast], but the broadcast's fee is never bumped. This is
+	/// used for the fee bumps themselves.
+	fn threshold_sign_and_broadcast_without_fee_bump(
+		api_call: <T as Config<I>>::ApiCall,
+		maybe_success_callback: Option<<T as Config<I>>::BroadcastCallable>,
+		maybe_failed_callback_generator: impl FnOnce(
+			BroadcastId,
+		) -> Option<<T as Config<I>>::BroadcastCallable>,
 	) -> BroadcastId {
 		let broadcast_id = Self::next_broadcast_id();
 
@@ -711,6 +810,61 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		)
 	}
 
+	/// Attempts to bump the fee of the broadcasts whose fee bump is due at the current target
+	/// chain block, or at any earlier block that hasn't been processed yet. At most
+	/// [MAX_FEE_BUMP_BLOCKS_PER_BLOCK] target chain blocks are processed at once. Fees are only
+	/// bumped while fee bumping is enabled and the broadcast is still pending.
+	///
+	/// Returns the weight used.
+	pub(crate) fn bump_fees_of_stuck_broadcasts() -> Weight {
+		let current_block_height = T::ChainTracking::get_block_height();
+		let maybe_threshold = FeeBumpThreshold::<T, I>::get();
+		let mut block = NextFeeBumpBlock::<T, I>::get().unwrap_or(current_block_height);
+		let mut weight_used = T::DbWeight::get().reads_writes(2, 1);
+
+		for _ in 0..MAX_FEE_BUMP_BLOCKS_PER_BLOCK {
+			if block > current_block_height {
+				break
+			}
+			let broadcast_ids = FeeBumpQueue::<T, I>::take(block);
+			weight_used.saturating_accrue(T::WeightInfo::bump_fees_of_stuck_broadcasts(
+				broadcast_ids.len() as u32,
+			));
+			if maybe_threshold.is_some() {
+				for broadcast_id in broadcast_ids {
+					Self::bump_fee(broadcast_id);
+				}
+			}
+			block.saturating_inc();
+		}
+
+		NextFeeBumpBlock::<T, I>::put(block);
+		weight_used
+	}
+
+	/// Starts a broadcast that bumps the fee of the given broadcast, if it is still pending and the
+	/// [TransactionBuilder] supports fee bumps for its call.
+	fn bump_fee(broadcast_id: BroadcastId) {
+		if !PendingBroadcasts::<T, I>::get().contains(&broadcast_id) ||
+			FeeBumps::<T, I>::contains_key(broadcast_id)
+		{
+			return
+		}
+		let Some((api_call, _signature)) = ThresholdSignatureData::<T, I>::get(broadcast_id) else {
+			return
+		};
+
+		if let Some(fee_bump_call) = T::TransactionBuilder::build_fee_bump_call(&api_call) {
+			let fee_bump_broadcast_id =
+				Self::threshold_sign_and_broadcast_without_fee_bump(fee_bump_call, None, |_| None);
+			FeeBumps::<T, I>::insert(broadcast_id, fee_bump_broadcast_id);
+			Self::deposit_event(Event::<T, I>::BroadcastFeeBumped {
+				broadcast_id,
+				fee_bump_broadcast_id,
+			});
+		}
+	}
+
 	fn start_next_broadcast_attempt(broadcast_id: BroadcastId) {
 		if !PendingBroadcasts::<T, I>::get().contains(&broadcast_id) {
 			log::warn!(
@@ -1,11 +1,11 @@
 #![cfg(test)]
 
 use crate::{
-	mock::*, AbortedBroadcasts, AwaitingBroadcast, BroadcastData, BroadcastId, Config,
-	DelayedBroadcastRetryQueue, Error, Event as BroadcastEvent, FailedBroadcasters, Instance1,
-	PalletOffence, PendingBroadcasts, RequestFailureCallbacks, RequestSuccessCallbacks,
-	ThresholdSignatureData, Timeouts, TransactionFeeDeficit, TransactionMetadata,
-	TransactionOutIdToBroadcastId,
+	mock::*, AbortedBroadcasts, AwaitingBroadcast, BroadcastData, BroadcastId, BroadcastIdCounter,
+	Config, DelayedBroadcastRetryQueue, Error, Event as BroadcastEvent, FailedBroadcasters,
+	FeeBumpQueue, FeeBumpThreshold, FeeBumps, Instance1, PalletOffence, PendingBroadcasts,
+	RequestFailureCallbacks, RequestSuccessCallbacks, ThresholdSignatureData, Timeouts,
+	TransactionFeeDeficit, TransactionMetadata, TransactionOutIdToBroadcastId,
 };
 use cf_chains::{
 	evm::SchnorrVerificationComponents,
@@ -18,6 +18,7 @@ use cf_chains::{
 };
 use cf_traits::{
 	mocks::{
+		block_height_provider::BlockHeightProvider,
 		cfe_interface_mock::{MockCfeEvent, MockCfeInterface},
 		signer_nomination::MockNominator,
 		threshold_signer::MockThresholdSigner,
@@ -1266,3 +1267,116 @@ fn broadcast_is_retried_without_initial_nominee() {
 			assert_broadcast_storage_cleaned_up(broadcast_id);
 		});
 }
+
+#[test]
+fn fee_bump_threshold_must_be_non_zero() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Broadcaster::update_fee_bump_threshold(RuntimeOrigin::root(), Some(0)),
+			Error::<Test, Instance1>::InvalidFeeBumpThreshold
+		);
+
+		assert_ok!(Broadcaster::update_fee_bump_threshold(RuntimeOrigin::root(), Some(5)));
+		assert_eq!(FeeBumpThreshold::<Test, Instance1>::get(), Some(5));
+		System::assert_last_event(RuntimeEvent::Broadcaster(
+			crate::Event::<Test, Instance1>::FeeBumpThresholdUpdated { threshold: Some(5) },
+		));
+
+		assert_ok!(Broadcaster::update_fee_bump_threshold(RuntimeOrigin::root(), None));
+		assert_eq!(FeeBumpThreshold::<Test, Instance1>::get(), None);
+	});
+}
+
+const FEE_BUMP_THRESHOLD: u64 = 6;
+const INITIATED_AT: u64 = 1_000;
+
+fn set_target_chain_blocks_since_broadcast(blocks: u64) {
+	BlockHeightProvider::<MockEthereum>::set_block_height(INITIATED_AT + blocks);
+	Broadcaster::on_initialize(System::block_number());
+}
+
+#[test]
+fn stuck_broadcasts_are_fee_bumped_once() {
+	new_test_ext().execute_with(|| {
+		MockTransactionBuilder::<MockEthereum, RuntimeCall>::set_supports_fee_bumps();
+		BlockHeightProvider::<MockEthereum>::set_block_height(INITIATED_AT);
+
+		// Fee bumping is disabled by default.
+		let (unbumped_broadcast_id, _) = start_mock_broadcast_tx_out_id(1);
+		assert!(FeeBumpQueue::<Test, Instance1>::iter().next().is_none());
+
+		assert_ok!(Broadcaster::update_fee_bump_threshold(
+			RuntimeOrigin::root(),
+			Some(FEE_BUMP_THRESHOLD)
+		));
+		let (broadcast_id, _) = start_mock_broadcast_tx_out_id(2);
+		assert_eq!(
+			FeeBumpQueue::<Test, Instance1>::get(INITIATED_AT + FEE_BUMP_THRESHOLD),
+			vec![broadcast_id]
+		);
+
+		// Not stuck for long enough yet.
+		set_target_chain_blocks_since_broadcast(FEE_BUMP_THRESHOLD - 1);
+		assert!(FeeBumps::<Test, Instance1>::get(broadcast_id).is_none());
+
+		set_target_chain_blocks_since_broadcast(FEE_BUMP_THRESHOLD);
+		let fee_bump_broadcast_id = broadcast_id + 1;
+		assert_eq!(FeeBumps::<Test, Instance1>::get(broadcast_id), Some(fee_bump_broadcast_id));
+		assert!(FeeBumps::<Test, Instance1>::get(unbumped_broadcast_id).is_none());
+		assert!(PendingBroadcasts::<Test, Instance1>::get().contains(&fee_bump_broadcast_id));
+		assert!(FeeBumpQueue::<Test, Instance1>::iter().next().is_none());
+		System::assert_last_event(RuntimeEvent::Broadcaster(
+			crate::Event::<Test, Instance1>::BroadcastFeeBumped {
+				broadcast_id,
+				fee_bump_broadcast_id,
+			},
+		));
+
+		// The fee is only bumped once, and the fee bump itself is never bumped.
+		set_target_chain_blocks_since_broadcast(FEE_BUMP_THRESHOLD * 3);
+		assert_eq!(BroadcastIdCounter::<Test, Instance1>::get(), fee_bump_broadcast_id);
+		assert!(FeeBumps::<Test, Instance1>::get(fee_bump_broadcast_id).is_none());
+
+		// Fee bump records are cleaned up with the rest of the broadcast.
+		Broadcaster::clean_up_broadcast_storage(broadcast_id);
+		assert!(FeeBumps::<Test, Instance1>::get(broadcast_id).is_none());
+	});
+}
+
+#[test]
+fn broadcasts_are_not_fee_bumped_if_unsupported() {
+	new_test_ext().execute_with(|| {
+		BlockHeightProvider::<MockEthereum>::set_block_height(INITIATED_AT);
+		assert_ok!(Broadcaster::update_fee_bump_threshold(
+			RuntimeOrigin::root(),
+			Some(FEE_BUMP_THRESHOLD)
+		));
+		let (broadcast_id, _) = start_mock_broadcast();
+
+		set_target_chain_blocks_since_broadcast(FEE_BUMP_THRESHOLD);
+
+		// The broadcast was checked, but no fee bump was started or recorded.
+		assert!(FeeBumpQueue::<Test, Instance1>::iter().next().is_none());
+		assert!(FeeBumps::<Test, Instance1>::get(broadcast_id).is_none());
+		assert_eq!(BroadcastIdCounter::<Test, Instance1>::get(), broadcast_id);
+	});
+}
+
+#[test]
+fn fee_bumps_are_not_started_once_disabled() {
+	new_test_ext().execute_with(|| {
+		MockTransactionBuilder::<MockEthereum, RuntimeCall>::set_supports_fee_bumps();
+		BlockHeightProvider::<MockEthereum>::set_block_height(INITIATED_AT);
+		assert_ok!(Broadcaster::update_fee_bump_threshold(
+			RuntimeOrigin::root(),
+			Some(FEE_BUMP_THRESHOLD)
+		));
+		let (broadcast_id, _) = start_mock_broadcast();
+		assert_ok!(Broadcaster::update_fee_bump_threshold(RuntimeOrigin::root(), None));
+
+		set_target_chain_blocks_since_broadcast(FEE_BUMP_THRESHOLD);
+		assert!(FeeBumpQueue::<Test, Instance1>::iter().next().is_none());
+		assert!(FeeBumps::<Test, Instance1>::get(broadcast_id).is_none());
+		assert_eq!(BroadcastIdCounter::<Test, Instance1>::get(), broadcast_id);
+	});
+}
@@ -37,6 +37,8 @@ pub trait WeightInfo {
 	fn on_signature_ready() -> Weight;
 	fn start_next_broadcast_attempt() -> Weight;
 	fn transaction_succeeded() -> Weight;
+	fn update_fee_bump_threshold() -> Weight;
+	fn bump_fees_of_stuck_broadcasts(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_cf_broadcast using the Substrate node and recommended hardware.
@@ -206,6 +208,51 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	/// Storage: `EthereumBroadcaster::FeeBumpThreshold` (r:0 w:1)
+	/// Proof: `EthereumBroadcaster::FeeBumpThreshold` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_fee_bump_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumChainTracking::CurrentChainState` (r:1 w:0)
+	/// Proof: `EthereumChainTracking::CurrentChainState` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumBroadcaster::FeeBumpThreshold` (r:1 w:0)
+	/// Proof: `EthereumBroadcaster::FeeBumpThreshold` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::NextFeeBumpBlock` (r:1 w:1)
+	/// Proof: `EthereumBroadcaster::NextFeeBumpBlock` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::FeeBumpQueue` (r:1 w:1)
+	/// Proof: `EthereumBroadcaster::FeeBumpQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::PendingBroadcasts` (r:1 w:1)
+	/// Proof: `EthereumBroadcaster::PendingBroadcasts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::FeeBumps` (r:50 w:50)
+	/// Proof: `EthereumBroadcaster::FeeBumps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::ThresholdSignatureData` (r:50 w:0)
+	/// Proof: `EthereumBroadcaster::ThresholdSignatureData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::BroadcastIdCounter` (r:1 w:1)
+	/// Proof: `EthereumBroadcaster::BroadcastIdCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmThresholdSigner::ThresholdSignatureRequestIdCounter` (r:1 w:1)
+	/// Proof: `EvmThresholdSigner::ThresholdSignatureRequestIdCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmThresholdSigner::PendingCeremonies` (r:0 w:50)
+	/// Proof: `EvmThresholdSigner::PendingCeremonies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 50]`.
+	fn bump_fees_of_stuck_broadcasts(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `356 + n * (211 ±0)`
+		//  Estimated: `3821 + n * (2687 ±0)`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_422_318, 3821)
+			// Standard Error: 21_904
+			.saturating_add(Weight::from_parts(41_380_277, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2687).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -374,4 +421,49 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
+	/// Storage: `EthereumBroadcaster::FeeBumpThreshold` (r:0 w:1)
+	/// Proof: `EthereumBroadcaster::FeeBumpThreshold` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_fee_bump_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_000_000 picoseconds.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EthereumChainTracking::CurrentChainState` (r:1 w:0)
+	/// Proof: `EthereumChainTracking::CurrentChainState` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumBroadcaster::FeeBumpThreshold` (r:1 w:0)
+	/// Proof: `EthereumBroadcaster::FeeBumpThreshold` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::NextFeeBumpBlock` (r:1 w:1)
+	/// Proof: `EthereumBroadcaster::NextFeeBumpBlock` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::FeeBumpQueue` (r:1 w:1)
+	/// Proof: `EthereumBroadcaster::FeeBumpQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::PendingBroadcasts` (r:1 w:1)
+	/// Proof: `EthereumBroadcaster::PendingBroadcasts` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::FeeBumps` (r:50 w:50)
+	/// Proof: `EthereumBroadcaster::FeeBumps` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::ThresholdSignatureData` (r:50 w:0)
+	/// Proof: `EthereumBroadcaster::ThresholdSignatureData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumBroadcaster::BroadcastIdCounter` (r:1 w:1)
+	/// Proof: `EthereumBroadcaster::BroadcastIdCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmThresholdSigner::ThresholdSignatureRequestIdCounter` (r:1 w:1)
+	/// Proof: `EvmThresholdSigner::ThresholdSignatureRequestIdCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EvmThresholdSigner::PendingCeremonies` (r:0 w:50)
+	/// Proof: `EvmThresholdSigner::PendingCeremonies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 50]`.
+	fn bump_fees_of_stuck_broadcasts(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `356 + n * (211 ±0)`
+		//  Estimated: `3821 + n * (2687 ±0)`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_422_318, 3821)
+			// Standard Error: 21_904
+			.saturating_add(Weight::from_parts(41_380_277, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2687).saturating_mul(n.into()))
+	}
 }
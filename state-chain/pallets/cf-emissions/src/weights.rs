@@ -118,14 +118,19 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 	/// Proof: `EthereumThresholdSigner::PendingCeremonies` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `EthereumThresholdSigner::RequestCallback` (r:0 w:1)
 	/// Proof: `EthereumThresholdSigner::RequestCallback` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatedStake` (r:1 w:0)
+	/// Proof: `Funding::DelegatedStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::OperatorCommission` (r:1 w:0)
+	/// Proof: `Funding::OperatorCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RewardsPerStake` (r:1 w:1)
+	/// Proof: `Funding::RewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Reserve` (r:1 w:1)
+	/// Proof: `Flip::Reserve` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn rewards_minted() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2187`
-		//  Estimated: `13077`
-		// Minimum execution time: 138_844_000 picoseconds.
-		Weight::from_parts(139_946_000, 13077)
-			.saturating_add(T::DbWeight::get().reads(28_u64))
-			.saturating_add(T::DbWeight::get().writes(12_u64))
+		// NOTE: Placeholder until the benchmarks are run with delegation rewards.
+		Weight::from_parts(150_000_000, 13077)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
 	}
 	/// Storage: `Emissions::CurrentAuthorityEmissionPerBlock` (r:1 w:0)
 	/// Proof: `Emissions::CurrentAuthorityEmissionPerBlock` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
@@ -145,14 +150,19 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 	/// Proof: `Emissions::SupplyUpdateInterval` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Emissions::LastSupplyUpdateBlock` (r:1 w:0)
 	/// Proof: `Emissions::LastSupplyUpdateBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DelegatedStake` (r:1 w:0)
+	/// Proof: `Funding::DelegatedStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::OperatorCommission` (r:1 w:0)
+	/// Proof: `Funding::OperatorCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RewardsPerStake` (r:1 w:1)
+	/// Proof: `Funding::RewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Reserve` (r:1 w:1)
+	/// Proof: `Flip::Reserve` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn rewards_not_minted() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `979`
-		//  Estimated: `6287`
-		// Minimum execution time: 36_570_000 picoseconds.
-		Weight::from_parts(36_825_000, 6287)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		// NOTE: Placeholder until the benchmarks are run with delegation rewards.
+		Weight::from_parts(45_000_000, 6287)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Emissions::SupplyUpdateInterval` (r:0 w:1)
 	/// Proof: `Emissions::SupplyUpdateInterval` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -244,14 +254,19 @@ impl WeightInfo for () {
 	/// Proof: `EthereumThresholdSigner::PendingCeremonies` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `EthereumThresholdSigner::RequestCallback` (r:0 w:1)
 	/// Proof: `EthereumThresholdSigner::RequestCallback` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatedStake` (r:1 w:0)
+	/// Proof: `Funding::DelegatedStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::OperatorCommission` (r:1 w:0)
+	/// Proof: `Funding::OperatorCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RewardsPerStake` (r:1 w:1)
+	/// Proof: `Funding::RewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Reserve` (r:1 w:1)
+	/// Proof: `Flip::Reserve` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn rewards_minted() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2187`
-		//  Estimated: `13077`
-		// Minimum execution time: 138_844_000 picoseconds.
-		Weight::from_parts(139_946_000, 13077)
-			.saturating_add(RocksDbWeight::get().reads(28_u64))
-			.saturating_add(RocksDbWeight::get().writes(12_u64))
+		// NOTE: Placeholder until the benchmarks are run with delegation rewards.
+		Weight::from_parts(150_000_000, 13077)
+			.saturating_add(RocksDbWeight::get().reads(32_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
 	}
 	/// Storage: `Emissions::CurrentAuthorityEmissionPerBlock` (r:1 w:0)
 	/// Proof: `Emissions::CurrentAuthorityEmissionPerBlock` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
@@ -271,14 +286,19 @@ impl WeightInfo for () {
 	/// Proof: `Emissions::SupplyUpdateInterval` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Emissions::LastSupplyUpdateBlock` (r:1 w:0)
 	/// Proof: `Emissions::LastSupplyUpdateBlock` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DelegatedStake` (r:1 w:0)
+	/// Proof: `Funding::DelegatedStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::OperatorCommission` (r:1 w:0)
+	/// Proof: `Funding::OperatorCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RewardsPerStake` (r:1 w:1)
+	/// Proof: `Funding::RewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Reserve` (r:1 w:1)
+	/// Proof: `Flip::Reserve` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn rewards_not_minted() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `979`
-		//  Estimated: `6287`
-		// Minimum execution time: 36_570_000 picoseconds.
-		Weight::from_parts(36_825_000, 6287)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		// NOTE: Placeholder until the benchmarks are run with delegation rewards.
+		Weight::from_parts(45_000_000, 6287)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Emissions::SupplyUpdateInterval` (r:0 w:1)
 	/// Proof: `Emissions::SupplyUpdateInterval` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -36,5 +36,22 @@ mod benchmarks {
 		assert_eq!(ConsolidationParameters::<T>::get(), INITIAL_CONSOLIDATION_PARAMETERS);
 	}
 
+	#[benchmark]
+	fn update_utxo_selection_strategy() {
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+		let strategy = UtxoSelectionStrategy::LowFeeConsolidation {
+			max_sats_per_kilobyte: 5000,
+			max_extra_inputs: 10,
+		};
+		let call = Call::<T>::update_utxo_selection_strategy { strategy };
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		assert_eq!(BitcoinUtxoSelectionStrategy::<T>::get(), strategy);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -6,7 +6,7 @@ use cf_chains::{
 	btc::{
 		api::{SelectedUtxosAndChangeAmount, UtxoSelectionType},
 		deposit_address::DepositAddress,
-		utxo_selection::{select_utxos_for_consolidation, select_utxos_from_pool},
+		utxo_selection::{select_utxos_for_consolidation, UtxoSelectionStrategy, UtxoSelector},
 		Bitcoin, BtcAmount, Utxo, UtxoId, CHANGE_ADDRESS_SALT,
 	},
 	dot::{Polkadot, PolkadotAccountId, PolkadotHash, PolkadotIndex},
@@ -14,7 +14,7 @@ use cf_chains::{
 };
 use cf_primitives::{chains::assets::eth::Asset as EthAsset, NetworkEnvironment, SemVer};
 use cf_traits::{CompatibleCfeVersions, GetBitcoinFeeInfo, NetworkEnvironmentProvider, SafeMode};
-use frame_support::{pallet_prelude::*, traits::StorageVersion};
+use frame_support::{pallet_prelude::*, traits::StorageVersion, Hashable};
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use sp_std::{vec, vec::Vec};
@@ -157,6 +157,11 @@ pub mod pallet {
 	pub type ConsolidationParameters<T> =
 		StorageValue<_, cf_chains::btc::ConsolidationParameters, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn utxo_selection_strategy)]
+	/// The strategy used to select UTXOs for Bitcoin egress transactions.
+	pub type BitcoinUtxoSelectionStrategy<T> = StorageValue<_, UtxoSelectionStrategy, ValueQuery>;
+
 	// OTHER ENVIRONMENT ITEMS
 	#[pallet::storage]
 	#[pallet::getter(fn safe_mode)]
@@ -189,6 +194,8 @@ pub mod pallet {
 		RuntimeSafeModeUpdated { safe_mode: SafeModeUpdate<T> },
 		/// UTXO consolidation parameters has been updated
 		UtxoConsolidationParametersUpdated { params: cf_chains::btc::ConsolidationParameters },
+		/// The UTXO selection strategy has been updated
+		UtxoSelectionStrategyUpdated { strategy: UtxoSelectionStrategy },
 	}
 
 	#[pallet::call]
@@ -306,6 +313,23 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::update_utxo_selection_strategy())]
+		pub fn update_utxo_selection_strategy(
+			origin: OriginFor<T>,
+			strategy: UtxoSelectionStrategy,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			ensure!(strategy.is_valid(), DispatchError::Other("Invalid parameters"));
+
+			BitcoinUtxoSelectionStrategy::<T>::set(strategy);
+
+			Self::deposit_event(Event::<T>::UtxoSelectionStrategyUpdated { strategy });
+
+			Ok(())
+		}
 	}
 
 	#[pallet::genesis_config]
@@ -392,6 +416,17 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Removes the utxo with the given id from the list of available utxos and returns it, if it
+	/// is still available.
+	pub fn take_bitcoin_utxo(utxo_id: &UtxoId) -> Option<Utxo> {
+		BitcoinAvailableUtxos::<T>::mutate(|available_utxos| {
+			available_utxos
+				.iter()
+				.position(|utxo| &utxo.id == utxo_id)
+				.map(|index| available_utxos.remove(index))
+		})
+	}
+
 	// Calculate the selection of utxos, return them and remove them from the list. The fee required
 	// to spend the input utxos are accounted for while selection. The fee required to include
 	// outputs and the minimum constant tx fee is incorporated by adding to the output amount. The
@@ -447,16 +482,25 @@ impl<T: Config> Pallet<T> {
 				.ok(),
 			UtxoSelectionType::Some { output_amount, number_of_outputs } =>
 				BitcoinAvailableUtxos::<T>::try_mutate(|available_utxos| {
-					select_utxos_from_pool(
-						available_utxos,
-						&bitcoin_fee_info,
-						output_amount +
-							number_of_outputs * fee_per_output_utxo +
-							min_fee_required_per_tx,
-					)
-					.ok_or_else(|| {
-						log::error!("Unable to select desired amount from available utxos.");
-					})
+					// Seed for the randomised strategy. The parent hash makes it hard to predict
+					// ahead of time, and the utxo count varies it between selections in the same
+					// block.
+					let seed = u64::from_le_bytes(
+						(frame_system::Pallet::<T>::parent_hash(), available_utxos.len() as u32)
+							.twox_64(),
+					);
+					Self::utxo_selection_strategy()
+						.select_utxos(
+							available_utxos,
+							&bitcoin_fee_info,
+							output_amount +
+								number_of_outputs * fee_per_output_utxo +
+								min_fee_required_per_tx,
+							seed,
+						)
+						.ok_or_else(|| {
+							log::error!("Unable to select desired amount from available utxos.");
+						})
 				})
 				.ok()
 				.map(|(selected_utxos, total_input_spendable_amount)| {
@@ -1,5 +1,8 @@
 #![cfg(test)]
-use cf_chains::btc::{api::UtxoSelectionType, deposit_address::DepositAddress, Utxo};
+use cf_chains::btc::{
+	api::UtxoSelectionType, deposit_address::DepositAddress, utxo_selection::UtxoSelectionStrategy,
+	Utxo,
+};
 use cf_traits::SafeMode;
 use frame_support::{assert_ok, traits::OriginTrait};
 
@@ -190,6 +193,56 @@ fn updating_consolidation_parameters() {
 	});
 }
 
+#[test]
+fn updating_utxo_selection_strategy() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Environment::utxo_selection_strategy(), UtxoSelectionStrategy::SmallestFirst);
+
+		// Should fail with invalid parameters
+		assert!(Environment::update_utxo_selection_strategy(
+			OriginTrait::root(),
+			UtxoSelectionStrategy::LowFeeConsolidation {
+				max_sats_per_kilobyte: 5000,
+				max_extra_inputs: 0
+			}
+		)
+		.is_err());
+
+		assert_ok!(Environment::update_utxo_selection_strategy(
+			OriginTrait::root(),
+			UtxoSelectionStrategy::BranchAndBound
+		));
+		System::assert_last_event(RuntimeEvent::Environment(
+			crate::Event::<Test>::UtxoSelectionStrategyUpdated {
+				strategy: UtxoSelectionStrategy::BranchAndBound,
+			},
+		));
+
+		add_utxo_amount(10000, 1);
+		add_utxo_amount(20000, 2);
+		add_utxo_amount(30000, 3);
+
+		// At the default fee of 10 satoshi per byte, spending the 20000 utxo leaves 19220, and the
+		// transaction needs 18000 + 10*(16 + 2*51) = 19180. Smallest-first would have selected
+		// all three utxos, but branch and bound finds the selection with (almost) no change.
+		assert_eq!(
+			Environment::select_and_take_bitcoin_utxos(UtxoSelectionType::Some {
+				output_amount: 18000,
+				number_of_outputs: 2
+			})
+			.unwrap(),
+			(
+				vec![Utxo {
+					amount: 20000,
+					id: Default::default(),
+					deposit_address: DepositAddress::new(Default::default(), 2),
+				}],
+				40
+			)
+		);
+	});
+}
+
 #[test]
 fn update_safe_mode() {
 	new_test_ext().execute_with(|| {
@@ -34,6 +34,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn update_safe_mode() -> Weight;
 	fn update_consolidation_parameters() -> Weight;
+	fn update_utxo_selection_strategy() -> Weight;
 }
 
 /// Weights for pallet_cf_environment using the Substrate node and recommended hardware.
@@ -58,6 +59,15 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 		// Minimum execution time: 7_000_000 picoseconds.
 		Weight::from_parts(8_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}	/// Storage: `Environment::BitcoinUtxoSelectionStrategy` (r:0 w:1)
+	/// Proof: `Environment::BitcoinUtxoSelectionStrategy` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_utxo_selection_strategy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
@@ -82,5 +92,14 @@ impl WeightInfo for () {
 		// Minimum execution time: 7_000_000 picoseconds.
 		Weight::from_parts(8_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}	/// Storage: `Environment::BitcoinUtxoSelectionStrategy` (r:0 w:1)
+	/// Proof: `Environment::BitcoinUtxoSelectionStrategy` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn update_utxo_selection_strategy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
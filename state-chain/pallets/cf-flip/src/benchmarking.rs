@@ -1,7 +1,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 use super::*;
 
-use frame_benchmarking::v2::*;
+use frame_benchmarking::{account, v2::*};
 use frame_support::{
 	assert_ok,
 	traits::{EnsureOrigin, UnfilteredDispatchable},
@@ -41,5 +41,31 @@ mod benchmarks {
 		assert!(!Account::<T>::contains_key(&caller));
 	}
 
+	#[benchmark]
+	fn remove_storage_items(n: Linear<1, 255>) {
+		for i in 0..n {
+			SlashesApplied::<T>::insert(
+				0,
+				account::<T::AccountId>("slashed", i, 0),
+				(1, T::Balance::from(1u32)),
+			);
+		}
+
+		#[block]
+		{
+			let _ = SlashesApplied::<T>::clear_prefix(0, u32::MAX, None);
+		}
+	}
+
+	#[benchmark]
+	fn on_idle_with_nothing_to_remove() {
+		EpochsToCull::<T>::append(1);
+
+		#[block]
+		{
+			let _weight = Pallet::<T>::on_idle(Default::default(), Default::default());
+		}
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -11,11 +11,14 @@ mod imbalances;
 mod on_charge_transaction;
 
 pub mod weights;
-use cf_primitives::FlipBalance;
+use cf_primitives::{EpochIndex, FlipBalance};
 use scale_info::TypeInfo;
 pub use weights::WeightInfo;
 
-use cf_traits::{AccountInfo, Bonding, FeePayment, FundingInfo, OnAccountFunded, Slashing};
+use cf_traits::{
+	AccountInfo, Bonding, EpochInfo, EpochTransitionHandler, FeePayment, FundingInfo,
+	OnAccountFunded, Slashing,
+};
 pub use imbalances::{Deficit, ImbalanceSource, InternalSource, Surplus};
 pub use on_charge_transaction::FlipTransactionPayment;
 
@@ -45,6 +48,9 @@ pub mod pallet {
 	/// A 4-byte identifier for different reserves.
 	pub type ReserveId = [u8; 4];
 
+	/// Holds the rewards earned by delegators until they are claimed.
+	pub const DELEGATION_REWARDS_RESERVE: ReserveId = *b"DLGR";
+
 	#[pallet::config]
 	#[pallet::disable_frame_system_supertrait_check]
 	pub trait Config: Chainflip<Amount = Self::Balance> {
@@ -114,6 +120,34 @@ pub mod pallet {
 	#[pallet::getter(fn offchain_funds)]
 	pub type OffchainFunds<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
 
+	/// The number of slashes applied to each account in each epoch, and the total amount slashed.
+	#[pallet::storage]
+	pub type SlashesApplied<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EpochIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		(u32, T::Balance),
+		ValueQuery,
+	>;
+
+	/// Expired epochs whose slashing statistics have yet to be culled.
+	#[pallet::storage]
+	pub type EpochsToCull<T: Config> = StorageValue<_, Vec<EpochIndex>, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Culls the slashing statistics of expired epochs, as far as the remaining weight allows.
+		fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			cf_traits::cull_expired_epoch::<EpochsToCull<T>, SlashesApplied<T>, _, _>(
+				remaining_weight,
+				T::WeightInfo::on_idle_with_nothing_to_remove(),
+				T::WeightInfo::remove_storage_items,
+			)
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -530,6 +564,14 @@ impl<T: Config> cf_traits::Funding for Pallet<T> {
 		T::OnAccountFunded::on_account_funded(account_id, Self::balance(account_id));
 		Ok(())
 	}
+
+	fn credit_delegation_rewards(account_id: &Self::AccountId, amount: Self::Balance) {
+		Self::settle(
+			account_id,
+			Self::withdraw_reserves(DELEGATION_REWARDS_RESERVE, amount).into(),
+		);
+		T::OnAccountFunded::on_account_funded(account_id, Self::balance(account_id));
+	}
 }
 
 pub struct BurnFlipAccount<T: Config>(PhantomData<T>);
@@ -548,6 +590,13 @@ impl<T: Config> OnKilledAccount<T::AccountId> for BurnFlipAccount<T> {
 	}
 }
 
+impl<T: Config> EpochTransitionHandler for Pallet<T> {
+	/// Slashing statistics are only kept for unexpired epochs. They are culled in `on_idle`.
+	fn on_expired_epoch(expired: EpochIndex) {
+		EpochsToCull::<T>::append(expired);
+	}
+}
+
 pub struct FlipSlasher<T: Config>(PhantomData<T>);
 
 impl<T: Config> FlipSlasher<T> {
@@ -558,6 +607,14 @@ impl<T: Config> FlipSlasher<T> {
 	) {
 		if !slash_amount.is_zero() && account.can_be_slashed(slash_amount) {
 			Pallet::<T>::settle(account_id, Pallet::<T>::burn(slash_amount).into());
+			SlashesApplied::<T>::mutate(
+				T::EpochInfo::epoch_index(),
+				account_id,
+				|(count, total)| {
+					*count = count.saturating_add(1);
+					*total = total.saturating_add(slash_amount);
+				},
+			);
 			Pallet::<T>::deposit_event(Event::<T>::SlashingPerformed {
 				who: account_id.clone(),
 				amount: slash_amount,
@@ -2,14 +2,17 @@
 
 use super::*;
 use crate::{
-	mock::*, Account, Bonder, Error, FlipIssuance, FlipSlasher, OffchainFunds, Reserve,
-	SlashingRate, TotalIssuance,
+	mock::*, Account, Bonder, EpochsToCull, Error, FlipIssuance, FlipSlasher, OffchainFunds,
+	Reserve, SlashesApplied, SlashingRate, TotalIssuance,
 };
 use cf_primitives::FlipBalance;
-use cf_traits::{AccountInfo, Bonding, Funding, Issuance, Slashing};
+use cf_traits::{
+	AccountInfo, Bonding, EpochInfo, EpochTransitionHandler, Funding, Issuance, Slashing,
+};
 use frame_support::{
 	assert_noop,
-	traits::{HandleLifetime, Imbalance},
+	traits::{HandleLifetime, Hooks, Imbalance},
+	weights::Weight,
 };
 use quickcheck::{Arbitrary, Gen, TestResult};
 use quickcheck_macros::quickcheck;
@@ -466,6 +469,51 @@ fn test_try_debit_from_liquid_funds() {
 	});
 }
 
+#[test]
+fn slashes_are_recorded_per_epoch() {
+	new_test_ext().execute_with(|| {
+		let epoch = MockEpochInfo::epoch_index();
+		Bonder::<Test>::update_bond(&ALICE, 50);
+
+		FlipSlasher::<Test>::slash_balance(&ALICE, 10);
+		FlipSlasher::<Test>::slash_balance(&ALICE, 5);
+		// This slash would take Alice below the minimum and is not applied.
+		FlipSlasher::<Test>::slash_balance(&ALICE, 80);
+		assert_eq!(SlashesApplied::<Test>::get(epoch, ALICE), (2, 15));
+		assert_eq!(SlashesApplied::<Test>::get(epoch, BOB), (0, 0));
+
+		<Flip as EpochTransitionHandler>::on_expired_epoch(epoch);
+		assert!(SlashesApplied::<Test>::contains_key(epoch, ALICE));
+		assert_eq!(EpochsToCull::<Test>::get(), vec![epoch]);
+	});
+}
+
+#[test]
+fn slashes_of_expired_epochs_are_culled_when_idle() {
+	new_test_ext().execute_with(|| {
+		SlashesApplied::<Test>::insert(1, ALICE, (1, 10));
+		SlashesApplied::<Test>::insert(1, BOB, (1, 10));
+		SlashesApplied::<Test>::insert(2, ALICE, (1, 10));
+		<Flip as EpochTransitionHandler>::on_expired_epoch(1);
+
+		// Only one entry can be removed with this weight.
+		Flip::on_idle(
+			1,
+			<Test as Config>::WeightInfo::on_idle_with_nothing_to_remove()
+				.saturating_add(<Test as Config>::WeightInfo::remove_storage_items(1)),
+		);
+		assert_eq!(SlashesApplied::<Test>::iter_prefix(1).count(), 1);
+		assert_eq!(EpochsToCull::<Test>::get(), vec![1]);
+
+		Flip::on_idle(2, Weight::MAX);
+		assert_eq!(SlashesApplied::<Test>::iter_prefix(1).count(), 0);
+		assert!(EpochsToCull::<Test>::get().is_empty());
+
+		// Unexpired epochs are unaffected.
+		assert!(SlashesApplied::<Test>::contains_key(2, ALICE));
+	});
+}
+
 #[cfg(test)]
 mod test_issuance {
 	use super::*;
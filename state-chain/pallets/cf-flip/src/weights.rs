@@ -34,6 +34,8 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn set_slashing_rate() -> Weight;
 	fn reap_one_account() -> Weight;
+	fn remove_storage_items(n: u32, ) -> Weight;
+	fn on_idle_with_nothing_to_remove() -> Weight;
 }
 
 /// Weights for pallet_cf_flip using the Substrate node and recommended hardware.
@@ -62,6 +64,22 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// NOTE: The culling weights below are placeholders until the benchmarks are run.
+
+	/// Storage: `Flip::SlashesApplied` (r:n w:n)
+	/// Proof: `Flip::SlashesApplied` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_storage_items(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(1_500_000, 2_600).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Flip::EpochsToCull` (r:1 w:0)
+	/// Proof: `Flip::EpochsToCull` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_idle_with_nothing_to_remove() -> Weight {
+		Weight::from_parts(3_000_000, 1_600)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -89,4 +107,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	// NOTE: The culling weights below are placeholders until the benchmarks are run.
+
+	/// Storage: `Flip::SlashesApplied` (r:n w:n)
+	/// Proof: `Flip::SlashesApplied` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_storage_items(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(1_500_000, 2_600).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Flip::EpochsToCull` (r:1 w:0)
+	/// Proof: `Flip::EpochsToCull` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_idle_with_nothing_to_remove() -> Weight {
+		Weight::from_parts(3_000_000, 1_600)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
 }
@@ -265,5 +265,96 @@ mod benchmarks {
 		assert!(BoundExecutorAddress::<T>::contains_key(&caller));
 	}
 
+	#[benchmark]
+	fn delegate() {
+		let operator: T::AccountId = account("operator", 0, 0);
+		<T as frame_system::Config>::OnNewAccount::on_new_account(&operator);
+		T::AccountRoleRegistry::register_as_validator(&operator).unwrap();
+		DelegatorCount::<T>::insert(&operator, T::MaxDelegatorsPerOperator::get() - 1);
+
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::Amount::from(100u32);
+		MinimumFunding::<T>::put(amount);
+		assert_ok!(Call::<T>::funded {
+			account_id: caller.clone(),
+			amount,
+			funder: Default::default(),
+			tx_hash: [211u8; 32],
+		}
+		.dispatch_bypass_filter(T::EnsureWitnessed::try_successful_origin().unwrap()));
+
+		#[extrinsic_call]
+		delegate(RawOrigin::Signed(caller.clone()), operator.clone(), amount);
+
+		assert_eq!(Delegations::<T>::get(&operator, &caller), amount);
+	}
+
+	#[benchmark]
+	fn undelegate() {
+		let operator: T::AccountId = account("operator", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::Amount::from(100u32);
+		DelegatedTo::<T>::insert(&caller, &operator);
+		Delegations::<T>::insert(&operator, &caller, amount);
+		DelegatedStake::<T>::insert(&operator, amount);
+		DelegatorCount::<T>::insert(&operator, 1);
+
+		#[extrinsic_call]
+		undelegate(RawOrigin::Signed(caller.clone()), RedemptionAmount::Max);
+
+		assert!(!DelegatedTo::<T>::contains_key(&caller));
+		assert!(PendingUndelegations::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn set_operator_commission() {
+		let caller: T::AccountId = whitelisted_caller();
+		<T as frame_system::Config>::OnNewAccount::on_new_account(&caller);
+		T::AccountRoleRegistry::register_as_validator(&caller).unwrap();
+		let commission = Permill::from_percent(10);
+
+		#[extrinsic_call]
+		set_operator_commission(RawOrigin::Signed(caller.clone()), commission);
+
+		assert_eq!(OperatorCommission::<T>::get(&caller), commission);
+	}
+
+	#[benchmark]
+	fn set_standing_redemption() {
+		let caller: T::AccountId = whitelisted_caller();
+		<T as frame_system::Config>::OnNewAccount::on_new_account(&caller);
+		T::AccountRoleRegistry::register_as_validator(&caller).unwrap();
+
+		#[extrinsic_call]
+		set_standing_redemption(
+			RawOrigin::Signed(caller.clone()),
+			RedemptionSchedule::EveryEpochAbove(T::Amount::from(100u32)),
+			Default::default(),
+			Default::default(),
+		);
+
+		assert!(StandingRedemptions::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn remove_standing_redemption() {
+		let caller: T::AccountId = whitelisted_caller();
+		<T as frame_system::Config>::OnNewAccount::on_new_account(&caller);
+		T::AccountRoleRegistry::register_as_validator(&caller).unwrap();
+		StandingRedemptions::<T>::insert(
+			&caller,
+			StandingRedemption {
+				schedule: RedemptionSchedule::NextEpoch(T::Amount::from(100u32)),
+				redeem_address: Default::default(),
+				executor: Default::default(),
+			},
+		);
+
+		#[extrinsic_call]
+		remove_standing_redemption(RawOrigin::Signed(caller.clone()));
+
+		assert!(!StandingRedemptions::<T>::contains_key(&caller));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
@@ -16,20 +16,22 @@ pub use weights::WeightInfo;
 mod tests;
 
 use cf_chains::{eth::Address as EthereumAddress, RegisterRedemption};
-use cf_primitives::AccountRole;
+use cf_primitives::{AccountRole, EpochIndex};
 use cf_traits::{
 	impl_pallet_safe_mode, AccountInfo, AccountRoleRegistry, Bid, BidderProvider, Broadcaster,
-	Chainflip, EpochInfo, FeePayment, Funding,
+	Chainflip, DelegationInfo, EpochInfo, FeePayment, Funding,
 };
 use codec::{Decode, Encode};
 use frame_support::{
-	dispatch::DispatchResultWithPostInfo,
+	dispatch::{DispatchResult, DispatchResultWithPostInfo},
 	ensure,
 	pallet_prelude::Weight,
 	sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
 		traits::{CheckedSub, UniqueSaturatedInto, Zero},
-		Saturating,
+		FixedPointNumber, FixedU128, Permill, Rounding, Saturating,
 	},
+	storage::with_storage_layer,
 	traits::{EnsureOrigin, HandleLifetime, IsType, OnKilledAccount, StorageVersion, UnixTime},
 };
 use frame_system::pallet_prelude::OriginFor;
@@ -46,6 +48,10 @@ pub enum Pending {
 }
 pub const PALLET_VERSION: StorageVersion = StorageVersion::new(3);
 
+/// The maximum number of standing redemptions that are executed in a single block. The rest are
+/// executed in the following blocks.
+pub const MAX_STANDING_REDEMPTIONS_PER_BLOCK: u32 = 50;
+
 #[derive(Encode, Decode, PartialEq, Debug, TypeInfo)]
 pub struct PendingRedemptionInfo<FlipBalance> {
 	pub total: FlipBalance,
@@ -53,6 +59,33 @@ pub struct PendingRedemptionInfo<FlipBalance> {
 	pub redeem_address: EthereumAddress,
 }
 
+/// Funds that have been undelegated but remain locked until the undelegation delay has passed.
+#[derive(Encode, Decode, PartialEq, Debug, TypeInfo)]
+pub struct PendingUndelegation<FlipBalance> {
+	pub amount: FlipBalance,
+	/// Unix time in seconds.
+	pub unlocks_at: u64,
+}
+
+/// When a standing redemption is executed, and how much it redeems.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, Debug, TypeInfo)]
+pub enum RedemptionSchedule<FlipBalance> {
+	/// Every epoch, redeem everything in excess of the given balance, for example to skim
+	/// rewards.
+	EveryEpochAbove(FlipBalance),
+	/// Redeem the given amount once, in the next epoch.
+	NextEpoch(FlipBalance),
+}
+
+/// Instructions for redeeming funds automatically at the start of each epoch, outside of the
+/// auction phase.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, Debug, TypeInfo)]
+pub struct StandingRedemption<FlipBalance> {
+	pub schedule: RedemptionSchedule<FlipBalance>,
+	pub redeem_address: EthereumAddress,
+	pub executor: Option<EthereumAddress>,
+}
+
 impl_pallet_safe_mode!(PalletSafeMode; redeem_enabled, start_bidding_enabled, stop_bidding_enabled);
 
 #[frame_support::pallet]
@@ -116,6 +149,11 @@ pub mod pallet {
 		/// Safe Mode access.
 		type SafeMode: Get<PalletSafeMode>;
 
+		/// The maximum number of accounts that can delegate to a single operator. Rewards and
+		/// slashes are split between all of them, so this bounds the cost of doing so.
+		#[pallet::constant]
+		type MaxDelegatorsPerOperator: Get<u32>;
+
 		/// Benchmark stuff
 		type WeightInfo: WeightInfo;
 	}
@@ -179,6 +217,78 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type RedemptionTax<T: Config> = StorageValue<_, T::Amount, ValueQuery>;
 
+	/// The operator that each delegator has delegated its funds to.
+	#[pallet::storage]
+	pub type DelegatedTo<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId<T>, AccountId<T>, OptionQuery>;
+
+	/// Funds delegated to each operator, keyed by operator and then by delegator.
+	#[pallet::storage]
+	pub type Delegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountId<T>,
+		Blake2_128Concat,
+		AccountId<T>,
+		FlipBalance<T>,
+		ValueQuery,
+	>;
+
+	/// The number of accounts delegating to each operator.
+	#[pallet::storage]
+	pub type DelegatorCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId<T>, u32, ValueQuery>;
+
+	/// The total funds delegated to each operator.
+	#[pallet::storage]
+	pub type DelegatedStake<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId<T>, FlipBalance<T>, ValueQuery>;
+
+	/// The rewards earned by each unit of stake delegated to an operator, accumulated over time.
+	#[pallet::storage]
+	pub type RewardsPerStake<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId<T>, FixedU128, ValueQuery>;
+
+	/// The value of [RewardsPerStake] for each delegator's operator when the delegator last
+	/// claimed its rewards.
+	#[pallet::storage]
+	pub type DelegatorRewardsPerStake<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId<T>, FixedU128, ValueQuery>;
+
+	/// The share of rewards an operator keeps before the rest is split with its delegators.
+	#[pallet::storage]
+	pub type OperatorCommission<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId<T>, Permill, ValueQuery>;
+
+	/// Standing redemption instructions for each account.
+	#[pallet::storage]
+	pub type StandingRedemptions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountId<T>,
+		StandingRedemption<FlipBalance<T>>,
+		OptionQuery,
+	>;
+
+	/// The last epoch in which standing redemptions were executed.
+	#[pallet::storage]
+	pub type StandingRedemptionsEpoch<T: Config> = StorageValue<_, EpochIndex, ValueQuery>;
+
+	/// The last account whose standing redemption was executed, while there are still standing
+	/// redemptions left to execute for [StandingRedemptionsEpoch].
+	#[pallet::storage]
+	pub type StandingRedemptionsCursor<T: Config> = StorageValue<_, AccountId<T>, OptionQuery>;
+
+	/// Undelegated funds that can't be redeemed or delegated again until they are unlocked.
+	#[pallet::storage]
+	pub type PendingUndelegations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountId<T>,
+		PendingUndelegation<FlipBalance<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -240,6 +350,43 @@ pub mod pallet {
 
 		/// An account has been bound to an executor address.
 		BoundExecutorAddress { account_id: AccountId<T>, address: EthereumAddress },
+
+		/// An account has delegated some of its funds to an operator.
+		Delegated { delegator: AccountId<T>, operator: AccountId<T>, amount: FlipBalance<T> },
+
+		/// An account has undelegated some of its funds from an operator.
+		Undelegated {
+			delegator: AccountId<T>,
+			operator: AccountId<T>,
+			amount: FlipBalance<T>,
+			// Unix time.
+			unlocks_at: u64,
+		},
+
+		/// A delegator has been paid the rewards it earned through its operator.
+		DelegationRewardsClaimed {
+			delegator: AccountId<T>,
+			operator: AccountId<T>,
+			amount: FlipBalance<T>,
+		},
+
+		/// An operator has updated the commission it takes from rewards.
+		OperatorCommissionUpdated { operator: AccountId<T>, commission: Permill },
+
+		/// A standing redemption has been set for an account.
+		StandingRedemptionSet {
+			account_id: AccountId<T>,
+			standing_redemption: StandingRedemption<FlipBalance<T>>,
+		},
+
+		/// A standing redemption has been removed.
+		StandingRedemptionRemoved { account_id: AccountId<T> },
+
+		/// A standing redemption has been executed.
+		StandingRedemptionExecuted { account_id: AccountId<T>, amount: FlipBalance<T> },
+
+		/// A standing redemption could not be executed.
+		StandingRedemptionFailed { account_id: AccountId<T>, error: DispatchError },
 	}
 
 	#[pallet::error]
@@ -294,6 +441,65 @@ pub mod pallet {
 
 		/// The account is already bound to an executor address.
 		ExecutorAddressAlreadyBound,
+
+		/// Funds can only be delegated to validator accounts.
+		NotAnOperator,
+
+		/// Validators bid with their own funds and can't delegate to other operators.
+		ValidatorsCannotDelegate,
+
+		/// The account has already delegated to a different operator.
+		AlreadyDelegating,
+
+		/// The account has not delegated any funds.
+		NotDelegating,
+
+		/// The account doesn't have enough funds that are not already delegated or locked.
+		InsufficientUndelegatedFunds,
+
+		/// The account has not delegated enough funds to undelegate the requested amount.
+		InsufficientDelegatedFunds,
+
+		/// The previous undelegation must be unlocked before a new one can be requested.
+		PendingUndelegation,
+
+		/// Delegated funds, and funds that are still locked after undelegation, can't be redeemed.
+		DelegatedFundsLocked,
+
+		/// The operator already has the maximum number of delegators.
+		TooManyDelegators,
+
+		/// Delegations must be at least the minimum funding amount.
+		BelowMinimumDelegation,
+
+		/// The funds back the operator's bond as a current authority and can't be undelegated
+		/// until the operator's epochs have expired.
+		DelegationBonded,
+
+		/// The account has no standing redemption.
+		NoStandingRedemption,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			if T::EpochInfo::is_auction_phase() {
+				return T::DbWeight::get().reads(1)
+			}
+			let cursor = StandingRedemptionsCursor::<T>::get();
+			if cursor.is_none() {
+				let epoch = T::EpochInfo::epoch_index();
+				if StandingRedemptionsEpoch::<T>::get() >= epoch {
+					return T::DbWeight::get().reads(4)
+				}
+				StandingRedemptionsEpoch::<T>::put(epoch);
+			}
+			let executed = Self::execute_standing_redemptions(cursor);
+			T::WeightInfo::redeem()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_mul(executed as u64)
+				.saturating_add(T::DbWeight::get().reads_writes(4, 2))
+		}
 	}
 
 	#[pallet::call]
@@ -360,135 +566,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let account_id = ensure_signed(origin)?;
 
-			ensure!(T::SafeMode::get().redeem_enabled, Error::<T>::RedeemDisabled);
-
-			// Not allowed to redeem if we are an active bidder in the auction phase
-			if T::EpochInfo::is_auction_phase() {
-				ensure!(!ActiveBidder::<T>::get(&account_id), Error::<T>::AuctionPhase);
-			}
-
-			// The redemption must be executed before a new one can be requested.
-			ensure!(
-				!PendingRedemptions::<T>::contains_key(&account_id),
-				Error::<T>::PendingRedemption
-			);
-
-			let mut restricted_balances = RestrictedBalances::<T>::get(&account_id);
-
-			// Ignore executor binding restrictions for withdrawals of restricted funds.
-			if !restricted_balances.contains_key(&address) {
-				if let Some(bound_executor) = BoundExecutorAddress::<T>::get(&account_id) {
-					ensure!(
-						executor == Some(bound_executor),
-						Error::<T>::ExecutorBindingRestrictionViolated
-					);
-				}
-			}
-
-			let redemption_fee = RedemptionTax::<T>::get();
-
-			if let Some(bound_address) = BoundRedeemAddress::<T>::get(&account_id) {
-				ensure!(
-					bound_address == address || restricted_balances.contains_key(&address),
-					Error::<T>::AccountBindingRestrictionViolated
-				);
-			}
-
-			// In case the balance is lower than the sum of restricted addresses we take this
-			// discrepancy into account so that restricted addresses can still redeem.
-			let restricted_deficit: FlipBalance<T> = restricted_balances
-				.values()
-				.copied()
-				.sum::<FlipBalance<T>>()
-				.saturating_sub(T::Flip::balance(&account_id));
-
-			// The available funds are the total balance minus whichever is larger from:
-			// - The bond.
-			// - The total restricted funds that need to remain in the account after the redemption.
-			let liquid_balance = T::Flip::balance(&account_id).saturating_sub(max(
-				T::Flip::bond(&account_id),
-				restricted_balances.values().copied().sum::<FlipBalance<T>>().saturating_sub(
-					restricted_deficit +
-						restricted_balances.get(&address).copied().unwrap_or_default(),
-				),
-			));
-
-			let (debit_amount, redeem_amount) = match amount {
-				RedemptionAmount::Max =>
-					(liquid_balance, liquid_balance.saturating_sub(redemption_fee)),
-				RedemptionAmount::Exact(amount) => (amount.saturating_add(redemption_fee), amount),
-			};
-
-			ensure!(
-				T::Flip::try_burn_fee(&account_id, redemption_fee).is_ok(),
-				Error::<T>::InsufficientBalance
-			);
-
-			let mut total_restricted_balance: FlipBalance<T> = T::Amount::zero();
-
-			// If necessary, update account restrictions.
-			if let Some(restricted_balance) = restricted_balances.get_mut(&address) {
-				// Use the full debit amount here - fees are paid by restricted funds by default.
-				total_restricted_balance = *restricted_balance;
-				restricted_balance.saturating_reduce(debit_amount);
-				if restricted_balance.is_zero() {
-					restricted_balances.remove(&address);
-				}
-				RestrictedBalances::<T>::insert(&account_id, &restricted_balances);
-			}
-
-			let remaining_balance = T::Flip::balance(&account_id)
-				.checked_sub(&redeem_amount)
-				.ok_or(Error::<T>::InsufficientBalance)?;
-
-			ensure!(
-				remaining_balance == Zero::zero() ||
-					remaining_balance >= MinimumFunding::<T>::get(),
-				Error::<T>::BelowMinimumFunding
-			);
-			ensure!(
-				remaining_balance >=
-					restricted_balances.values().copied().sum::<FlipBalance<T>>() -
-						restricted_deficit,
-				Error::<T>::InsufficientUnrestrictedFunds
-			);
-
-			// Update the account balance.
-			if redeem_amount > Zero::zero() {
-				T::Flip::try_initiate_redemption(&account_id, redeem_amount)?;
-
-				// Send the transaction.
-				let contract_expiry =
-					T::TimeSource::now().as_secs() + RedemptionTTLSeconds::<T>::get();
-				let call = T::RegisterRedemption::new_unsigned(
-					<T as Config>::FunderId::from_ref(&account_id).as_ref(),
-					redeem_amount.unique_saturated_into(),
-					address.as_fixed_bytes(),
-					contract_expiry,
-					executor,
-				);
-
-				PendingRedemptions::<T>::insert(
-					&account_id,
-					PendingRedemptionInfo {
-						total: redeem_amount,
-						restricted: min(
-							total_restricted_balance.saturating_sub(redemption_fee),
-							redeem_amount,
-						),
-						redeem_address: address,
-					},
-				);
-
-				Self::deposit_event(Event::RedemptionRequested {
-					account_id,
-					amount: redeem_amount,
-					broadcast_id: T::Broadcaster::threshold_sign_and_broadcast(call),
-					expiry_time: contract_expiry,
-				});
-			} else {
-				Self::deposit_event(Event::RedemptionAmountZero { account_id })
-			}
+			Self::try_redeem(account_id, amount, address, executor)?;
 
 			Ok(().into())
 		}
@@ -759,6 +837,239 @@ pub mod pallet {
 			});
 			Ok(().into())
 		}
+
+		/// Delegates some of the caller's funds to a validator operator. The funds stay in the
+		/// caller's account, but count towards the operator's auction bid and can't be redeemed
+		/// until they are undelegated. Rewards and slashes of the operator are shared with its
+		/// delegators in proportion to their stake.
+		///
+		/// An account can only delegate to one operator at a time, each delegation must be at least
+		/// the minimum funding amount, and each operator can have at most
+		/// [MaxDelegatorsPerOperator](Config::MaxDelegatorsPerOperator) delegators.
+		///
+		/// ## Events
+		///
+		/// - [Delegated](Event::Delegated)
+		///
+		/// ## Errors
+		///
+		/// - [NotAnOperator](Error::NotAnOperator)
+		/// - [ValidatorsCannotDelegate](Error::ValidatorsCannotDelegate)
+		/// - [AlreadyDelegating](Error::AlreadyDelegating)
+		/// - [InsufficientUndelegatedFunds](Error::InsufficientUndelegatedFunds)
+		/// - [BelowMinimumDelegation](Error::BelowMinimumDelegation)
+		/// - [TooManyDelegators](Error::TooManyDelegators)
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::delegate())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			operator: AccountId<T>,
+			amount: FlipBalance<T>,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+
+			ensure!(
+				T::AccountRoleRegistry::has_account_role(&operator, AccountRole::Validator),
+				Error::<T>::NotAnOperator
+			);
+			ensure!(
+				!T::AccountRoleRegistry::has_account_role(&delegator, AccountRole::Validator),
+				Error::<T>::ValidatorsCannotDelegate
+			);
+			let is_new_delegator = match DelegatedTo::<T>::get(&delegator) {
+				Some(current_operator) => {
+					ensure!(current_operator == operator, Error::<T>::AlreadyDelegating);
+					false
+				},
+				None => true,
+			};
+			ensure!(
+				!amount.is_zero() &&
+					amount <=
+						T::Flip::balance(&delegator)
+							.saturating_sub(Self::locked_by_delegation(&delegator)),
+				Error::<T>::InsufficientUndelegatedFunds
+			);
+			let delegated = Delegations::<T>::get(&operator, &delegator).saturating_add(amount);
+			ensure!(delegated >= MinimumFunding::<T>::get(), Error::<T>::BelowMinimumDelegation);
+
+			if is_new_delegator {
+				DelegatorCount::<T>::try_mutate(&operator, |count| {
+					ensure!(
+						*count < T::MaxDelegatorsPerOperator::get(),
+						Error::<T>::TooManyDelegators
+					);
+					*count += 1;
+					Ok::<_, Error<T>>(())
+				})?;
+				DelegatedTo::<T>::insert(&delegator, &operator);
+			}
+			Self::claim_delegation_rewards(&delegator);
+			Self::set_delegation(&operator, &delegator, delegated);
+
+			Self::deposit_event(Event::Delegated { delegator, operator, amount });
+			Ok(().into())
+		}
+
+		/// Undelegates some of the caller's funds from its operator. The funds no longer count
+		/// towards the operator's bid, but they remain locked for the redemption TTL before they
+		/// can be redeemed or delegated again.
+		///
+		/// As with redemptions, funds can't be undelegated from an active bidder during the
+		/// auction phase. If the operator is an authority, the funds it needs to cover its bond
+		/// remain locked until its epochs have expired, in the same way as the bond restricts the
+		/// operator's own redemptions.
+		///
+		/// ## Events
+		///
+		/// - [Undelegated](Event::Undelegated)
+		///
+		/// ## Errors
+		///
+		/// - [NotDelegating](Error::NotDelegating)
+		/// - [AuctionPhase](Error::AuctionPhase)
+		/// - [PendingUndelegation](Error::PendingUndelegation)
+		/// - [InsufficientDelegatedFunds](Error::InsufficientDelegatedFunds)
+		/// - [BelowMinimumDelegation](Error::BelowMinimumDelegation)
+		/// - [DelegationBonded](Error::DelegationBonded)
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::undelegate())]
+		pub fn undelegate(
+			origin: OriginFor<T>,
+			amount: RedemptionAmount<FlipBalance<T>>,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+			let operator = DelegatedTo::<T>::get(&delegator).ok_or(Error::<T>::NotDelegating)?;
+
+			if T::EpochInfo::is_auction_phase() {
+				ensure!(!ActiveBidder::<T>::get(&operator), Error::<T>::AuctionPhase);
+			}
+
+			let now = T::TimeSource::now().as_secs();
+			ensure!(
+				PendingUndelegations::<T>::get(&delegator)
+					.map_or(true, |pending| pending.unlocks_at <= now),
+				Error::<T>::PendingUndelegation
+			);
+
+			let delegated = Delegations::<T>::get(&operator, &delegator);
+			let amount = match amount {
+				RedemptionAmount::Max => delegated,
+				RedemptionAmount::Exact(amount) => {
+					ensure!(amount <= delegated, Error::<T>::InsufficientDelegatedFunds);
+					amount
+				},
+			};
+
+			let remaining = delegated.saturating_sub(amount);
+			ensure!(
+				remaining.is_zero() || remaining >= MinimumFunding::<T>::get(),
+				Error::<T>::BelowMinimumDelegation
+			);
+
+			// The operator's own funds and the funds delegated to it must continue to cover its
+			// bond.
+			ensure!(
+				T::Flip::balance(&operator)
+					.saturating_add(DelegatedStake::<T>::get(&operator))
+					.saturating_sub(amount) >=
+					T::Flip::bond(&operator),
+				Error::<T>::DelegationBonded
+			);
+
+			Self::claim_delegation_rewards(&delegator);
+			Self::set_delegation(&operator, &delegator, remaining);
+
+			let unlocks_at = now + RedemptionTTLSeconds::<T>::get();
+			PendingUndelegations::<T>::insert(
+				&delegator,
+				PendingUndelegation { amount, unlocks_at },
+			);
+
+			Self::deposit_event(Event::Undelegated { delegator, operator, amount, unlocks_at });
+			Ok(().into())
+		}
+
+		/// Sets the share of rewards the calling operator keeps before the remainder is split
+		/// between the operator and its delegators.
+		///
+		/// ## Events
+		///
+		/// - [OperatorCommissionUpdated](Event::OperatorCommissionUpdated)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_operator_commission())]
+		pub fn set_operator_commission(
+			origin: OriginFor<T>,
+			commission: Permill,
+		) -> DispatchResultWithPostInfo {
+			let operator = T::AccountRoleRegistry::ensure_validator(origin)?;
+			OperatorCommission::<T>::insert(&operator, commission);
+			Self::deposit_event(Event::OperatorCommissionUpdated { operator, commission });
+			Ok(().into())
+		}
+
+		/// Sets standing instructions to redeem the caller's funds automatically, replacing any
+		/// existing ones. Standing redemptions are executed at the start of each epoch, once
+		/// the auction phase is over. They are subject to the same restrictions as calls to
+		/// [redeem](Pallet::redeem), including bound redeem and executor addresses.
+		///
+		/// ## Events
+		///
+		/// - [StandingRedemptionSet](Event::StandingRedemptionSet)
+		///
+		/// ## Errors
+		///
+		/// - [AccountBindingRestrictionViolated](Error::AccountBindingRestrictionViolated)
+		/// - [ExecutorBindingRestrictionViolated](Error::ExecutorBindingRestrictionViolated)
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::set_standing_redemption())]
+		pub fn set_standing_redemption(
+			origin: OriginFor<T>,
+			schedule: RedemptionSchedule<FlipBalance<T>>,
+			address: EthereumAddress,
+			// Only this address can execute the redemptions.
+			executor: Option<EthereumAddress>,
+		) -> DispatchResultWithPostInfo {
+			let account_id = T::AccountRoleRegistry::ensure_validator(origin)?;
+
+			Self::ensure_redemption_bindings(
+				&account_id,
+				&address,
+				executor,
+				&RestrictedBalances::<T>::get(&account_id),
+			)?;
+
+			let standing_redemption =
+				StandingRedemption { schedule, redeem_address: address, executor };
+			StandingRedemptions::<T>::insert(&account_id, &standing_redemption);
+
+			Self::deposit_event(Event::StandingRedemptionSet { account_id, standing_redemption });
+			Ok(().into())
+		}
+
+		/// Removes the caller's standing redemption.
+		///
+		/// ## Events
+		///
+		/// - [StandingRedemptionRemoved](Event::StandingRedemptionRemoved)
+		///
+		/// ## Errors
+		///
+		/// - [NoStandingRedemption](Error::NoStandingRedemption)
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::remove_standing_redemption())]
+		pub fn remove_standing_redemption(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let account_id = T::AccountRoleRegistry::ensure_validator(origin)?;
+			StandingRedemptions::<T>::take(&account_id).ok_or(Error::<T>::NoStandingRedemption)?;
+			Self::deposit_event(Event::StandingRedemptionRemoved { account_id });
+			Ok(().into())
+		}
 	}
 
 	#[pallet::genesis_config]
@@ -802,6 +1113,227 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Requests a redemption on behalf of an account. See [Pallet::redeem].
+	fn try_redeem(
+		account_id: AccountId<T>,
+		amount: RedemptionAmount<FlipBalance<T>>,
+		address: EthereumAddress,
+		executor: Option<EthereumAddress>,
+	) -> DispatchResult {
+		ensure!(T::SafeMode::get().redeem_enabled, Error::<T>::RedeemDisabled);
+
+		// Not allowed to redeem if we are an active bidder in the auction phase
+		if T::EpochInfo::is_auction_phase() {
+			ensure!(!ActiveBidder::<T>::get(&account_id), Error::<T>::AuctionPhase);
+		}
+
+		// The redemption must be executed before a new one can be requested.
+		ensure!(!PendingRedemptions::<T>::contains_key(&account_id), Error::<T>::PendingRedemption);
+
+		// Rewards earned as a delegator can be redeemed straight away.
+		Self::claim_delegation_rewards(&account_id);
+
+		let delegation_lock = Self::locked_by_delegation(&account_id);
+
+		let mut restricted_balances = RestrictedBalances::<T>::get(&account_id);
+
+		Self::ensure_redemption_bindings(&account_id, &address, executor, &restricted_balances)?;
+
+		let redemption_fee = RedemptionTax::<T>::get();
+
+		// In case the balance is lower than the sum of restricted addresses we take this
+		// discrepancy into account so that restricted addresses can still redeem.
+		let restricted_deficit: FlipBalance<T> = restricted_balances
+			.values()
+			.copied()
+			.sum::<FlipBalance<T>>()
+			.saturating_sub(T::Flip::balance(&account_id));
+
+		// The available funds are the total balance minus whichever is larger from:
+		// - The bond.
+		// - The total restricted funds that need to remain in the account after the redemption.
+		let liquid_balance = T::Flip::balance(&account_id)
+			.saturating_sub(max(
+				T::Flip::bond(&account_id),
+				restricted_balances.values().copied().sum::<FlipBalance<T>>().saturating_sub(
+					restricted_deficit +
+						restricted_balances.get(&address).copied().unwrap_or_default(),
+				),
+			))
+			.saturating_sub(delegation_lock);
+
+		let (debit_amount, redeem_amount) = match amount {
+			RedemptionAmount::Max =>
+				(liquid_balance, liquid_balance.saturating_sub(redemption_fee)),
+			RedemptionAmount::Exact(amount) => (amount.saturating_add(redemption_fee), amount),
+		};
+
+		ensure!(
+			T::Flip::try_burn_fee(&account_id, redemption_fee).is_ok(),
+			Error::<T>::InsufficientBalance
+		);
+
+		let mut total_restricted_balance: FlipBalance<T> = T::Amount::zero();
+
+		// If necessary, update account restrictions.
+		if let Some(restricted_balance) = restricted_balances.get_mut(&address) {
+			// Use the full debit amount here - fees are paid by restricted funds by default.
+			total_restricted_balance = *restricted_balance;
+			restricted_balance.saturating_reduce(debit_amount);
+			if restricted_balance.is_zero() {
+				restricted_balances.remove(&address);
+			}
+			RestrictedBalances::<T>::insert(&account_id, &restricted_balances);
+		}
+
+		let remaining_balance = T::Flip::balance(&account_id)
+			.checked_sub(&redeem_amount)
+			.ok_or(Error::<T>::InsufficientBalance)?;
+
+		ensure!(
+			remaining_balance == Zero::zero() || remaining_balance >= MinimumFunding::<T>::get(),
+			Error::<T>::BelowMinimumFunding
+		);
+		ensure!(
+			remaining_balance >=
+				restricted_balances.values().copied().sum::<FlipBalance<T>>() -
+					restricted_deficit,
+			Error::<T>::InsufficientUnrestrictedFunds
+		);
+		ensure!(remaining_balance >= delegation_lock, Error::<T>::DelegatedFundsLocked);
+
+		// Update the account balance.
+		if redeem_amount > Zero::zero() {
+			T::Flip::try_initiate_redemption(&account_id, redeem_amount)?;
+
+			// Send the transaction.
+			let contract_expiry = T::TimeSource::now().as_secs() + RedemptionTTLSeconds::<T>::get();
+			let call = T::RegisterRedemption::new_unsigned(
+				<T as Config>::FunderId::from_ref(&account_id).as_ref(),
+				redeem_amount.unique_saturated_into(),
+				address.as_fixed_bytes(),
+				contract_expiry,
+				executor,
+			);
+
+			PendingRedemptions::<T>::insert(
+				&account_id,
+				PendingRedemptionInfo {
+					total: redeem_amount,
+					restricted: min(
+						total_restricted_balance.saturating_sub(redemption_fee),
+						redeem_amount,
+					),
+					redeem_address: address,
+				},
+			);
+
+			Self::deposit_event(Event::RedemptionRequested {
+				account_id,
+				amount: redeem_amount,
+				broadcast_id: T::Broadcaster::threshold_sign_and_broadcast(call),
+				expiry_time: contract_expiry,
+			});
+		} else {
+			Self::deposit_event(Event::RedemptionAmountZero { account_id })
+		}
+
+		Ok(())
+	}
+
+	/// Ensures that a redemption to `address` respects the account's bound redeem and executor
+	/// addresses. Redemptions of restricted funds to their restricted address are exempt.
+	fn ensure_redemption_bindings(
+		account_id: &AccountId<T>,
+		address: &EthereumAddress,
+		executor: Option<EthereumAddress>,
+		restricted_balances: &BTreeMap<EthereumAddress, FlipBalance<T>>,
+	) -> Result<(), Error<T>> {
+		if !restricted_balances.contains_key(address) {
+			if let Some(bound_executor) = BoundExecutorAddress::<T>::get(account_id) {
+				ensure!(
+					executor == Some(bound_executor),
+					Error::<T>::ExecutorBindingRestrictionViolated
+				);
+			}
+		}
+
+		if let Some(bound_address) = BoundRedeemAddress::<T>::get(account_id) {
+			ensure!(
+				bound_address == *address || restricted_balances.contains_key(address),
+				Error::<T>::AccountBindingRestrictionViolated
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Executes the next batch of at most [MAX_STANDING_REDEMPTIONS_PER_BLOCK] standing
+	/// redemptions, starting after the `cursor` account, and returns the number of accounts
+	/// processed. The cursor is kept until all standing redemptions have been executed. Failed
+	/// redemptions are rolled back and reported in an event.
+	fn execute_standing_redemptions(cursor: Option<AccountId<T>>) -> usize {
+		let standing_redemptions = match cursor {
+			Some(cursor) => StandingRedemptions::<T>::iter_from(
+				StandingRedemptions::<T>::hashed_key_for(cursor),
+			),
+			None => StandingRedemptions::<T>::iter(),
+		}
+		.take(MAX_STANDING_REDEMPTIONS_PER_BLOCK as usize)
+		.collect::<Vec<_>>();
+		let count = standing_redemptions.len();
+
+		match standing_redemptions.last() {
+			Some((account_id, _)) if count == MAX_STANDING_REDEMPTIONS_PER_BLOCK as usize =>
+				StandingRedemptionsCursor::<T>::put(account_id),
+			_ => StandingRedemptionsCursor::<T>::kill(),
+		}
+
+		for (account_id, StandingRedemption { schedule, redeem_address, executor }) in
+			standing_redemptions
+		{
+			let amount = match schedule {
+				RedemptionSchedule::EveryEpochAbove(retained) => {
+					// Leave exactly the retained amount in the account, after paying the tax, but
+					// never redeem the bond or funds locked by delegations.
+					let balance = T::Flip::balance(&account_id);
+					let liquid_balance = balance
+						.saturating_sub(T::Flip::bond(&account_id))
+						.saturating_sub(Self::locked_by_delegation(&account_id));
+					let excess = balance.saturating_sub(retained).min(liquid_balance);
+					let redemption_tax = RedemptionTax::<T>::get();
+					if excess <= redemption_tax {
+						continue
+					}
+					excess - redemption_tax
+				},
+				RedemptionSchedule::NextEpoch(amount) => amount,
+			};
+
+			match with_storage_layer(|| {
+				Self::try_redeem(
+					account_id.clone(),
+					RedemptionAmount::Exact(amount),
+					redeem_address,
+					executor,
+				)?;
+				// One-off redemptions are only consumed once they succeed, so that a failed
+				// attempt is retried next epoch.
+				if matches!(schedule, RedemptionSchedule::NextEpoch(_)) {
+					StandingRedemptions::<T>::remove(&account_id);
+				}
+				Ok::<_, DispatchError>(())
+			}) {
+				Ok(()) =>
+					Self::deposit_event(Event::StandingRedemptionExecuted { account_id, amount }),
+				Err(error) =>
+					Self::deposit_event(Event::StandingRedemptionFailed { account_id, error }),
+			}
+		}
+
+		count
+	}
+
 	/// Add funds to an account, creating the account if it doesn't exist. An account is not
 	/// an implicit bidder and needs to start bidding explicitly.
 	fn add_funds_to_account(account_id: &AccountId<T>, amount: T::Amount) -> T::Amount {
@@ -829,6 +1361,140 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Funds of a delegator that are either delegated or still locked after undelegation.
+	pub fn locked_by_delegation(delegator: &AccountId<T>) -> T::Amount {
+		let delegated = DelegatedTo::<T>::get(delegator)
+			.map(|operator| Delegations::<T>::get(operator, delegator))
+			.unwrap_or_default();
+		let now = T::TimeSource::now().as_secs();
+		let pending_undelegation = PendingUndelegations::<T>::get(delegator)
+			.filter(|pending| pending.unlocks_at > now)
+			.map(|pending| pending.amount)
+			.unwrap_or_default();
+		delegated.saturating_add(pending_undelegation)
+	}
+
+	/// The total stake delegated to an operator.
+	pub fn delegated_stake(operator: &AccountId<T>) -> T::Amount {
+		DelegatedStake::<T>::get(operator)
+	}
+
+	/// Updates the funds a delegator has delegated to its operator, keeping [DelegatedStake] in
+	/// sync. Delegators left with nothing delegated are removed. The delegator's rewards must
+	/// have been claimed first, since they are calculated from the funds delegated.
+	fn set_delegation(operator: &AccountId<T>, delegator: &AccountId<T>, amount: T::Amount) {
+		let previous = Delegations::<T>::get(operator, delegator);
+		DelegatedStake::<T>::mutate(operator, |stake| {
+			*stake = stake.saturating_sub(previous).saturating_add(amount)
+		});
+		if amount.is_zero() {
+			Delegations::<T>::remove(operator, delegator);
+			DelegatedTo::<T>::remove(delegator);
+			DelegatorRewardsPerStake::<T>::remove(delegator);
+			DelegatorCount::<T>::mutate(operator, |count| count.saturating_dec());
+		} else {
+			Delegations::<T>::insert(operator, delegator, amount);
+		}
+	}
+
+	/// Pays a delegator the rewards its delegation has earned since it last claimed them.
+	fn claim_delegation_rewards(delegator: &AccountId<T>) {
+		let Some(operator) = DelegatedTo::<T>::get(delegator) else { return };
+		let rewards_per_stake = RewardsPerStake::<T>::get(&operator);
+		let claimed_per_stake = DelegatorRewardsPerStake::<T>::mutate(delegator, |claimed| {
+			sp_std::mem::replace(claimed, rewards_per_stake)
+		});
+		let amount = rewards_per_stake
+			.saturating_sub(claimed_per_stake)
+			.saturating_mul_int(Delegations::<T>::get(&operator, delegator));
+		if !amount.is_zero() {
+			T::Flip::credit_delegation_rewards(delegator, amount);
+			Self::deposit_event(Event::DelegationRewardsClaimed {
+				delegator: delegator.clone(),
+				operator,
+				amount,
+			});
+		}
+	}
+
+	/// The share of an amount that is due to an operator's delegators, in proportion to stake.
+	fn delegators_share(operator: &AccountId<T>, amount: T::Amount) -> T::Amount {
+		let delegated_stake = DelegatedStake::<T>::get(operator);
+		multiply_by_rational_with_rounding(
+			amount.unique_saturated_into(),
+			delegated_stake.unique_saturated_into(),
+			T::Flip::balance(operator)
+				.saturating_add(delegated_stake)
+				.unique_saturated_into(),
+			Rounding::Down,
+		)
+		.unwrap_or_default()
+		.unique_saturated_into()
+	}
+
+	/// Records a reward earned by an operator, and returns the operator's share and its
+	/// delegators' share. The operator's commission is deducted first, and the rest is split in
+	/// proportion to stake.
+	///
+	/// The delegators' share is only added to [RewardsPerStake], and each delegator claims its
+	/// part when its delegation changes or it redeems, so the cost of a reward doesn't depend on
+	/// the number of delegators. The caller must set the delegators' share aside so that it can
+	/// be paid out with [Funding::credit_delegation_rewards].
+	pub fn record_reward(operator: &AccountId<T>, amount: T::Amount) -> (T::Amount, T::Amount) {
+		let delegated_stake = DelegatedStake::<T>::get(operator);
+		if delegated_stake.is_zero() {
+			return (amount, Zero::zero())
+		}
+		let commission = OperatorCommission::<T>::get(operator) * amount;
+		let delegators_share = Self::delegators_share(operator, amount.saturating_sub(commission));
+		RewardsPerStake::<T>::mutate(operator, |rewards_per_stake| {
+			*rewards_per_stake = rewards_per_stake.saturating_add(
+				FixedU128::saturating_from_rational(delegators_share, delegated_stake),
+			)
+		});
+		(amount.saturating_sub(delegators_share), delegators_share)
+	}
+
+	/// Splits a slash incurred by an operator between the operator and its delegators in
+	/// proportion to stake, and returns the amount to slash from each account. Each delegator's
+	/// delegation is reduced by its share of the slash.
+	pub fn record_slash(
+		operator: &AccountId<T>,
+		amount: T::Amount,
+	) -> Vec<(AccountId<T>, T::Amount)> {
+		let to_delegators = Self::delegators_share(operator, amount);
+		let delegated_stake = DelegatedStake::<T>::get(operator);
+		let mut operator_share = amount;
+		let mut shares = Vec::new();
+		if !to_delegators.is_zero() {
+			for (delegator, delegated) in
+				Delegations::<T>::iter_prefix(operator).collect::<Vec<_>>()
+			{
+				let share: T::Amount = multiply_by_rational_with_rounding(
+					to_delegators.unique_saturated_into(),
+					delegated.unique_saturated_into(),
+					delegated_stake.unique_saturated_into(),
+					Rounding::Down,
+				)
+				.unwrap_or_default()
+				.unique_saturated_into();
+				if share.is_zero() {
+					continue
+				}
+				Self::claim_delegation_rewards(&delegator);
+				Self::set_delegation(operator, &delegator, delegated.saturating_sub(share));
+				operator_share.saturating_reduce(share);
+				shares.push((delegator, share));
+			}
+		}
+
+		// The operator takes any remainder due to rounding.
+		shares.insert(0, (operator.clone(), operator_share));
+		shares
+	}
+}
+
 impl<T: Config> BidderProvider for Pallet<T> {
 	type ValidatorId = <T as frame_system::Config>::AccountId;
 	type Amount = T::Amount;
@@ -837,7 +1503,8 @@ impl<T: Config> BidderProvider for Pallet<T> {
 		ActiveBidder::<T>::iter()
 			.filter_map(|(bidder_id, active)| {
 				if active {
-					let amount = T::Flip::balance(&bidder_id);
+					let amount = T::Flip::balance(&bidder_id)
+						.saturating_add(DelegatedStake::<T>::get(&bidder_id));
 					Some(Bid { bidder_id, amount })
 				} else {
 					None
@@ -847,6 +1514,14 @@ impl<T: Config> BidderProvider for Pallet<T> {
 	}
 }
 
+impl<T: Config> DelegationInfo for Pallet<T> {
+	type AccountId = AccountId<T>;
+
+	fn is_delegating(account_id: &Self::AccountId) -> bool {
+		DelegatedTo::<T>::contains_key(account_id)
+	}
+}
+
 /// Ensure we clean up account specific items that definitely won't be required once the account
 /// leaves the network.
 impl<T: Config> OnKilledAccount<T::AccountId> for Pallet<T> {
@@ -855,5 +1530,19 @@ impl<T: Config> OnKilledAccount<T::AccountId> for Pallet<T> {
 		RestrictedBalances::<T>::remove(account_id);
 		BoundExecutorAddress::<T>::remove(account_id);
 		BoundRedeemAddress::<T>::remove(account_id);
+		if let Some(operator) = DelegatedTo::<T>::get(account_id) {
+			Self::set_delegation(&operator, account_id, Zero::zero());
+		}
+		// Delegators are paid their outstanding rewards before their operator goes away.
+		for delegator in Delegations::<T>::iter_key_prefix(account_id).collect::<Vec<_>>() {
+			Self::claim_delegation_rewards(&delegator);
+			Self::set_delegation(account_id, &delegator, Zero::zero());
+		}
+		DelegatorCount::<T>::remove(account_id);
+		DelegatedStake::<T>::remove(account_id);
+		RewardsPerStake::<T>::remove(account_id);
+		PendingUndelegations::<T>::remove(account_id);
+		StandingRedemptions::<T>::remove(account_id);
+		OperatorCommission::<T>::remove(account_id);
 	}
 }
@@ -8,7 +8,10 @@ use cf_traits::{
 };
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::cell::RefCell;
-use frame_support::{derive_impl, parameter_types, traits::UnfilteredDispatchable};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, UnfilteredDispatchable},
+};
 use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -59,7 +62,7 @@ impl frame_system::Config for Test {
 	type SystemWeightInfo = ();
 	type SS58Prefix = SS58Prefix;
 	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<5>;
+	type MaxConsumers = ConstU32<5>;
 }
 
 impl_mock_chainflip!(Test);
@@ -201,9 +204,11 @@ impl pallet_cf_funding::Config for Test {
 	type EnsureThresholdSigned = NeverFailingOriginCheck<Self>;
 	type SafeMode = MockRuntimeSafeMode;
 	type RegisterRedemption = MockRegisterRedemption;
+	type MaxDelegatorsPerOperator = ConstU32<MAX_DELEGATORS>;
 }
 
 pub const REDEMPTION_TTL_SECS: u64 = 10;
+pub const MAX_DELEGATORS: u32 = 2;
 
 pub const ALICE: AccountId = AccountId32::new([0xa1; 32]);
 pub const BOB: AccountId = AccountId32::new([0xb0; 32]);
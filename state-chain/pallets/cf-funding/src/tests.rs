@@ -1,19 +1,26 @@
 use crate::{
-	mock::*, pallet, ActiveBidder, BoundExecutorAddress, Error, EthereumAddress,
-	PendingRedemptions, RedemptionAmount, RedemptionTax, RestrictedAddresses, RestrictedBalances,
+	mock::*, pallet, ActiveBidder, BoundExecutorAddress, DelegatedTo, Delegations, DelegatorCount,
+	Error, EthereumAddress, PendingRedemptions, PendingUndelegations, RedemptionAmount,
+	RedemptionSchedule, RedemptionTax, RestrictedAddresses, RestrictedBalances,
+	StandingRedemptions, StandingRedemptionsCursor, MAX_STANDING_REDEMPTIONS_PER_BLOCK,
 };
 use cf_primitives::FlipBalance;
 use cf_test_utilities::assert_event_sequence;
 use cf_traits::{
-	mocks::account_role_registry::MockAccountRoleRegistry, AccountInfo, AccountRoleRegistry,
-	Bonding, SetSafeMode, Slashing,
+	mocks::{account_role_registry::MockAccountRoleRegistry, time_source},
+	AccountInfo, AccountRoleRegistry, Bid, BidderProvider, Bonding, Issuance, SetSafeMode,
+	Slashing,
 };
 use sp_core::H160;
 
 use crate::BoundRedeemAddress;
-use frame_support::{assert_noop, assert_ok};
-use pallet_cf_flip::{Bonder, FlipSlasher};
-use sp_runtime::{traits::BadOrigin, DispatchError};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Hooks, Imbalance},
+};
+use pallet_cf_flip::{Bonder, FlipIssuance, FlipSlasher, DELEGATION_REWARDS_RESERVE};
+use sp_runtime::{traits::BadOrigin, DispatchError, Permill};
+use std::time::Duration;
 
 type FlipError = pallet_cf_flip::Error<Test>;
 
@@ -1784,3 +1791,616 @@ fn cannot_redeem_to_non_restricted_address_with_balance_lower_than_restricted_fu
 		);
 	});
 }
+
+fn fund_and_delegate(delegator: AccountId, operator: AccountId, funds: u128, delegated: u128) {
+	assert_ok!(Funding::funded(
+		RuntimeOrigin::root(),
+		delegator.clone(),
+		funds,
+		ETH_ZERO_ADDRESS,
+		TX_HASH
+	));
+	assert_ok!(Funding::delegate(RuntimeOrigin::signed(delegator), operator, delegated));
+}
+
+fn bid_of(operator: &AccountId) -> Option<u128> {
+	Funding::get_bidders()
+		.into_iter()
+		.find(|Bid { bidder_id, .. }| bidder_id == operator)
+		.map(|Bid { amount, .. }| amount)
+}
+
+#[test]
+fn delegated_funds_count_towards_operator_bid() {
+	new_test_ext().execute_with(|| {
+		const FUNDS: u128 = 1_000;
+		const DELEGATED: u128 = 400;
+
+		assert_eq!(bid_of(&CHARLIE), Some(MIN_FUNDING));
+		fund_and_delegate(ALICE, CHARLIE, FUNDS, DELEGATED);
+		assert_eq!(bid_of(&CHARLIE), Some(MIN_FUNDING + DELEGATED));
+		assert_eq!(DelegatedTo::<Test>::get(&ALICE), Some(CHARLIE));
+
+		// Delegating more to the same operator adds to the existing delegation.
+		assert_ok!(Funding::delegate(RuntimeOrigin::signed(ALICE), CHARLIE, DELEGATED));
+		assert_eq!(Delegations::<Test>::get(&CHARLIE, &ALICE), 2 * DELEGATED);
+		assert_eq!(bid_of(&CHARLIE), Some(MIN_FUNDING + 2 * DELEGATED));
+
+		// The delegator keeps custody of the funds.
+		assert_eq!(Flip::total_balance_of(&ALICE), FUNDS);
+		assert_eq!(bid_of(&ALICE), None);
+
+		assert_event_sequence!(
+			Test,
+			RuntimeEvent::System(frame_system::Event::NewAccount { account: ALICE }),
+			RuntimeEvent::Funding(crate::Event::Funded {
+				account_id: ALICE,
+				tx_hash: TX_HASH,
+				funds_added: FUNDS,
+				total_balance: FUNDS
+			}),
+			RuntimeEvent::Funding(crate::Event::Delegated {
+				delegator: ALICE,
+				operator: CHARLIE,
+				amount: DELEGATED
+			}),
+			RuntimeEvent::Funding(crate::Event::Delegated {
+				delegator: ALICE,
+				operator: CHARLIE,
+				amount: DELEGATED
+			}),
+		);
+	});
+}
+
+#[test]
+fn delegation_restrictions() {
+	new_test_ext().execute_with(|| {
+		const FUNDS: u128 = 1_000;
+		assert_ok!(Funding::funded(RuntimeOrigin::root(), ALICE, FUNDS, ETH_ZERO_ADDRESS, TX_HASH));
+
+		// Can only delegate to validators.
+		assert_noop!(
+			Funding::delegate(RuntimeOrigin::signed(ALICE), BOB, FUNDS),
+			Error::<Test>::NotAnOperator
+		);
+		// Validators can't delegate.
+		assert_ok!(<MockAccountRoleRegistry as AccountRoleRegistry<Test>>::register_as_validator(
+			&BOB
+		));
+		assert_noop!(
+			Funding::delegate(RuntimeOrigin::signed(CHARLIE), BOB, MIN_FUNDING),
+			Error::<Test>::ValidatorsCannotDelegate
+		);
+		// Can't delegate more than the account holds, or nothing at all.
+		assert_noop!(
+			Funding::delegate(RuntimeOrigin::signed(ALICE), CHARLIE, FUNDS + 1),
+			Error::<Test>::InsufficientUndelegatedFunds
+		);
+		assert_noop!(
+			Funding::delegate(RuntimeOrigin::signed(ALICE), CHARLIE, 0),
+			Error::<Test>::InsufficientUndelegatedFunds
+		);
+
+		assert_ok!(Funding::delegate(RuntimeOrigin::signed(ALICE), CHARLIE, FUNDS / 2));
+		// Can only delegate to one operator at a time.
+		assert_noop!(
+			Funding::delegate(RuntimeOrigin::signed(ALICE), BOB, FUNDS / 2),
+			Error::<Test>::AlreadyDelegating
+		);
+		// Funds can't be delegated twice.
+		assert_noop!(
+			Funding::delegate(RuntimeOrigin::signed(ALICE), CHARLIE, FUNDS / 2 + 1),
+			Error::<Test>::InsufficientUndelegatedFunds
+		);
+	});
+}
+
+#[test]
+fn delegated_funds_cannot_be_redeemed() {
+	new_test_ext().execute_with(|| {
+		const FUNDS: u128 = 1_000;
+		const DELEGATED: u128 = 600;
+		fund_and_delegate(ALICE, CHARLIE, FUNDS, DELEGATED);
+
+		assert_noop!(
+			Funding::redeem(
+				RuntimeOrigin::signed(ALICE),
+				(FUNDS - DELEGATED).into(),
+				ETH_DUMMY_ADDR,
+				Default::default()
+			),
+			Error::<Test>::DelegatedFundsLocked
+		);
+
+		// Only the funds that are not delegated are redeemed.
+		assert_ok!(Funding::redeem(
+			RuntimeOrigin::signed(ALICE),
+			RedemptionAmount::Max,
+			ETH_DUMMY_ADDR,
+			Default::default()
+		));
+		assert_eq!(
+			PendingRedemptions::<Test>::get(&ALICE).unwrap().total,
+			FUNDS - DELEGATED - REDEMPTION_TAX
+		);
+		assert_eq!(Flip::total_balance_of(&ALICE), DELEGATED);
+	});
+}
+
+#[test]
+fn undelegated_funds_are_locked_until_redemption_ttl_has_passed() {
+	new_test_ext().execute_with(|| {
+		const FUNDS: u128 = 1_000;
+		const DELEGATED: u128 = 600;
+		const UNDELEGATED: u128 = 200;
+		fund_and_delegate(ALICE, CHARLIE, FUNDS, DELEGATED);
+
+		assert_noop!(
+			Funding::undelegate(RuntimeOrigin::signed(BOB), RedemptionAmount::Max),
+			Error::<Test>::NotDelegating
+		);
+		assert_noop!(
+			Funding::undelegate(RuntimeOrigin::signed(ALICE), (DELEGATED + 1).into()),
+			Error::<Test>::InsufficientDelegatedFunds
+		);
+
+		assert_ok!(Funding::undelegate(RuntimeOrigin::signed(ALICE), UNDELEGATED.into()));
+		System::assert_last_event(RuntimeEvent::Funding(crate::Event::Undelegated {
+			delegator: ALICE,
+			operator: CHARLIE,
+			amount: UNDELEGATED,
+			unlocks_at: REDEMPTION_TTL_SECS,
+		}));
+		// Undelegated funds no longer count towards the bid.
+		assert_eq!(bid_of(&CHARLIE), Some(MIN_FUNDING + DELEGATED - UNDELEGATED));
+
+		// But they remain locked.
+		assert_eq!(Funding::locked_by_delegation(&ALICE), DELEGATED);
+		assert_noop!(
+			Funding::undelegate(RuntimeOrigin::signed(ALICE), RedemptionAmount::Max),
+			Error::<Test>::PendingUndelegation
+		);
+		assert_noop!(
+			Funding::redeem(
+				RuntimeOrigin::signed(ALICE),
+				(FUNDS - DELEGATED + 1).into(),
+				ETH_DUMMY_ADDR,
+				Default::default()
+			),
+			Error::<Test>::DelegatedFundsLocked
+		);
+
+		time_source::Mock::tick(Duration::from_secs(REDEMPTION_TTL_SECS));
+		assert_eq!(Funding::locked_by_delegation(&ALICE), DELEGATED - UNDELEGATED);
+		assert_ok!(Funding::redeem(
+			RuntimeOrigin::signed(ALICE),
+			(FUNDS - DELEGATED).into(),
+			ETH_DUMMY_ADDR,
+			Default::default()
+		));
+
+		// Undelegating everything removes the delegation.
+		assert_ok!(Funding::undelegate(RuntimeOrigin::signed(ALICE), RedemptionAmount::Max));
+		assert!(!DelegatedTo::<Test>::contains_key(&ALICE));
+		assert!(!Delegations::<Test>::contains_key(&CHARLIE, &ALICE));
+		assert_eq!(
+			PendingUndelegations::<Test>::get(&ALICE).unwrap().amount,
+			DELEGATED - UNDELEGATED
+		);
+		assert_eq!(bid_of(&CHARLIE), Some(MIN_FUNDING));
+	});
+}
+
+#[test]
+fn cannot_undelegate_from_active_bidder_during_auction() {
+	new_test_ext().execute_with(|| {
+		fund_and_delegate(ALICE, CHARLIE, 1_000, 500);
+
+		MockEpochInfo::set_is_auction_phase(true);
+		assert_noop!(
+			Funding::undelegate(RuntimeOrigin::signed(ALICE), RedemptionAmount::Max),
+			Error::<Test>::AuctionPhase
+		);
+
+		// Once the operator is no longer bidding, its delegators can leave at any time.
+		MockEpochInfo::set_is_auction_phase(false);
+		assert_ok!(Funding::stop_bidding(RuntimeOrigin::signed(CHARLIE)));
+		MockEpochInfo::set_is_auction_phase(true);
+		assert_ok!(Funding::undelegate(RuntimeOrigin::signed(ALICE), RedemptionAmount::Max));
+	});
+}
+
+/// Rewards an operator in the same way as the runtime, setting its delegators' share aside.
+fn reward(operator: &AccountId, amount: u128) -> (u128, u128) {
+	let (operator_share, delegators_share) = Funding::record_reward(operator, amount);
+	Flip::settle(operator, FlipIssuance::<Test>::mint(operator_share).into());
+	let _ = Flip::deposit_reserves(DELEGATION_REWARDS_RESERVE, delegators_share)
+		.offset(FlipIssuance::<Test>::mint(delegators_share));
+	(operator_share, delegators_share)
+}
+
+#[test]
+fn rewards_and_slashes_are_split_pro_rata() {
+	new_test_ext().execute_with(|| {
+		const OPERATOR_FUNDS: u128 = 100;
+		const DELEGATED: u128 = 300;
+		assert_ok!(Funding::funded(
+			RuntimeOrigin::root(),
+			CHARLIE,
+			OPERATOR_FUNDS - MIN_FUNDING,
+			ETH_ZERO_ADDRESS,
+			TX_HASH
+		));
+		fund_and_delegate(ALICE, CHARLIE, DELEGATED, DELEGATED);
+
+		// Without commission, rewards are split in proportion to stake.
+		assert_eq!(reward(&CHARLIE, 1_000), (250, 750));
+
+		// The commission is deducted before the rest is split. The operator now holds 350.
+		assert_noop!(
+			Funding::set_operator_commission(RuntimeOrigin::signed(ALICE), Permill::one()),
+			BadOrigin
+		);
+		assert_ok!(Funding::set_operator_commission(
+			RuntimeOrigin::signed(CHARLIE),
+			Permill::from_percent(10)
+		));
+		assert_eq!(reward(&CHARLIE, 1_300), (760, 540));
+
+		// No commission is taken from slashes, and the delegator's share of the slash is taken
+		// from its delegation, after paying out its rewards. The operator now holds 1_110.
+		assert_eq!(Funding::record_slash(&CHARLIE, 47), vec![(CHARLIE, 37), (ALICE, 10)]);
+		assert_eq!(Flip::total_balance_of(&ALICE), DELEGATED + 750 + 540);
+		assert_eq!(Funding::delegated_stake(&CHARLIE), DELEGATED - 10);
+		assert_eq!(bid_of(&CHARLIE), Some(1_110 + DELEGATED - 10));
+
+		// Operators without delegators keep everything.
+		assert_eq!(Funding::record_reward(&BOB, 1_000), (1_000, 0));
+	});
+}
+
+#[test]
+fn delegation_rewards_are_claimed_when_delegations_change_or_funds_are_redeemed() {
+	new_test_ext().execute_with(|| {
+		const DELEGATED: u128 = 100;
+		fund_and_delegate(ALICE, CHARLIE, DELEGATED, DELEGATED);
+		fund_and_delegate(BOB, CHARLIE, 2 * DELEGATED, 2 * DELEGATED);
+
+		// Delegators' rewards are set aside until they are claimed.
+		assert_eq!(reward(&CHARLIE, MIN_FUNDING + 3 * DELEGATED), (MIN_FUNDING, 3 * DELEGATED));
+		assert_eq!(Flip::total_balance_of(&ALICE), DELEGATED);
+		assert_eq!(Flip::reserved_balance(DELEGATION_REWARDS_RESERVE), 3 * DELEGATED);
+
+		assert_ok!(Funding::undelegate(RuntimeOrigin::signed(ALICE), RedemptionAmount::Max));
+		assert_eq!(Flip::total_balance_of(&ALICE), 2 * DELEGATED);
+		System::assert_has_event(RuntimeEvent::Funding(crate::Event::DelegationRewardsClaimed {
+			delegator: ALICE,
+			operator: CHARLIE,
+			amount: DELEGATED,
+		}));
+
+		// Later rewards are only shared with the remaining delegators.
+		assert_eq!(
+			reward(&CHARLIE, 2 * MIN_FUNDING + 2 * DELEGATED),
+			(2 * MIN_FUNDING, 2 * DELEGATED)
+		);
+
+		// Rewards are claimed before redeeming, and can be redeemed straight away.
+		assert_ok!(Funding::redeem(
+			RuntimeOrigin::signed(BOB),
+			RedemptionAmount::Max,
+			ETH_DUMMY_ADDR,
+			Default::default()
+		));
+		assert_eq!(
+			PendingRedemptions::<Test>::get(&BOB).unwrap().total,
+			4 * DELEGATED - REDEMPTION_TAX
+		);
+		assert_eq!(Flip::reserved_balance(DELEGATION_REWARDS_RESERVE), 0);
+	});
+}
+
+#[test]
+fn delegations_are_bounded() {
+	new_test_ext().execute_with(|| {
+		const DAVE: AccountId = AccountId::new([0xd0; 32]);
+		const FUNDS: u128 = 1_000;
+		for account_id in [ALICE, BOB, DAVE] {
+			assert_ok!(Funding::funded(
+				RuntimeOrigin::root(),
+				account_id,
+				FUNDS,
+				ETH_ZERO_ADDRESS,
+				TX_HASH
+			));
+		}
+
+		// Delegations must be at least the minimum funding amount.
+		assert_noop!(
+			Funding::delegate(RuntimeOrigin::signed(ALICE), CHARLIE, MIN_FUNDING - 1),
+			Error::<Test>::BelowMinimumDelegation
+		);
+		assert_ok!(Funding::delegate(RuntimeOrigin::signed(ALICE), CHARLIE, MIN_FUNDING));
+		// Topping up an existing delegation is fine.
+		assert_ok!(Funding::delegate(RuntimeOrigin::signed(ALICE), CHARLIE, 1));
+		assert_noop!(
+			Funding::undelegate(RuntimeOrigin::signed(ALICE), 2.into()),
+			Error::<Test>::BelowMinimumDelegation
+		);
+
+		// Each operator has a limited number of delegators.
+		assert_ok!(Funding::delegate(RuntimeOrigin::signed(BOB), CHARLIE, MIN_FUNDING));
+		assert_eq!(DelegatorCount::<Test>::get(&CHARLIE), MAX_DELEGATORS);
+		assert_noop!(
+			Funding::delegate(RuntimeOrigin::signed(DAVE), CHARLIE, MIN_FUNDING),
+			Error::<Test>::TooManyDelegators
+		);
+
+		// Existing delegators can still add to their delegation.
+		assert_ok!(Funding::delegate(RuntimeOrigin::signed(BOB), CHARLIE, MIN_FUNDING));
+
+		// Once a delegator leaves, another one can join.
+		assert_ok!(Funding::undelegate(RuntimeOrigin::signed(ALICE), RedemptionAmount::Max));
+		assert_eq!(DelegatorCount::<Test>::get(&CHARLIE), MAX_DELEGATORS - 1);
+		assert_ok!(Funding::delegate(RuntimeOrigin::signed(DAVE), CHARLIE, MIN_FUNDING));
+	});
+}
+
+#[test]
+fn delegated_funds_backing_a_bond_cannot_be_undelegated() {
+	new_test_ext().execute_with(|| {
+		const DELEGATED: u128 = 500;
+		const BOND: u128 = 400;
+		fund_and_delegate(ALICE, CHARLIE, 1_000, DELEGATED);
+
+		// The operator is an authority, and needs the delegated funds to cover its bond.
+		Bonder::<Test>::update_bond(&CHARLIE, BOND);
+		let unbonded = MIN_FUNDING + DELEGATED - BOND;
+		assert_noop!(
+			Funding::undelegate(RuntimeOrigin::signed(ALICE), (unbonded + 1).into()),
+			Error::<Test>::DelegationBonded
+		);
+		assert_ok!(Funding::undelegate(RuntimeOrigin::signed(ALICE), unbonded.into()));
+
+		// Once the operator's epochs have expired, the rest can be undelegated.
+		time_source::Mock::tick(Duration::from_secs(REDEMPTION_TTL_SECS));
+		assert_noop!(
+			Funding::undelegate(RuntimeOrigin::signed(ALICE), RedemptionAmount::Max),
+			Error::<Test>::DelegationBonded
+		);
+		Bonder::<Test>::update_bond(&CHARLIE, 0);
+		assert_ok!(Funding::undelegate(RuntimeOrigin::signed(ALICE), RedemptionAmount::Max));
+	});
+}
+
+fn fund_validator(account_id: AccountId, amount: u128) {
+	assert_ok!(<MockAccountRoleRegistry as AccountRoleRegistry<Test>>::register_as_validator(
+		&account_id
+	));
+	assert_ok!(Funding::funded(
+		RuntimeOrigin::root(),
+		account_id,
+		amount,
+		ETH_ZERO_ADDRESS,
+		TX_HASH
+	));
+}
+
+fn start_epoch(epoch: u32) {
+	MockEpochInfo::set_epoch(epoch);
+	Funding::on_initialize(1);
+}
+
+#[test]
+fn standing_redemption_skims_funds_every_epoch() {
+	new_test_ext().execute_with(|| {
+		const FUNDS: u128 = 1_000;
+		const RETAINED: u128 = 600;
+		const REWARDS: u128 = 100;
+		fund_validator(ALICE, FUNDS);
+
+		assert_noop!(
+			Funding::set_standing_redemption(
+				RuntimeOrigin::signed(BOB),
+				RedemptionSchedule::EveryEpochAbove(RETAINED),
+				ETH_DUMMY_ADDR,
+				None,
+			),
+			BadOrigin
+		);
+		assert_ok!(Funding::set_standing_redemption(
+			RuntimeOrigin::signed(ALICE),
+			RedemptionSchedule::EveryEpochAbove(RETAINED),
+			ETH_DUMMY_ADDR,
+			None,
+		));
+
+		// Nothing happens until the next epoch.
+		Funding::on_initialize(1);
+		assert!(!PendingRedemptions::<Test>::contains_key(&ALICE));
+
+		start_epoch(1);
+		let redeemed = FUNDS - RETAINED - REDEMPTION_TAX;
+		assert_eq!(PendingRedemptions::<Test>::get(&ALICE).unwrap().total, redeemed);
+		assert_eq!(Flip::total_balance_of(&ALICE), RETAINED);
+		System::assert_last_event(RuntimeEvent::Funding(
+			crate::Event::StandingRedemptionExecuted { account_id: ALICE, amount: redeemed },
+		));
+		assert_ok!(Funding::redeemed(RuntimeOrigin::root(), ALICE, redeemed, TX_HASH));
+
+		// Standing redemptions are executed at most once per epoch.
+		assert_ok!(Funding::funded(
+			RuntimeOrigin::root(),
+			ALICE,
+			REWARDS,
+			ETH_ZERO_ADDRESS,
+			TX_HASH
+		));
+		Funding::on_initialize(2);
+		assert!(!PendingRedemptions::<Test>::contains_key(&ALICE));
+
+		// And never during the auction phase.
+		MockEpochInfo::set_is_auction_phase(true);
+		start_epoch(2);
+		assert!(!PendingRedemptions::<Test>::contains_key(&ALICE));
+
+		MockEpochInfo::set_is_auction_phase(false);
+		Funding::on_initialize(3);
+		assert_eq!(
+			PendingRedemptions::<Test>::get(&ALICE).unwrap().total,
+			REWARDS - REDEMPTION_TAX
+		);
+		assert_eq!(Flip::total_balance_of(&ALICE), RETAINED);
+		assert!(StandingRedemptions::<Test>::contains_key(&ALICE));
+	});
+}
+
+#[test]
+fn next_epoch_redemption_is_executed_once() {
+	new_test_ext().execute_with(|| {
+		const FUNDS: u128 = 1_000;
+		const AMOUNT: u128 = 200;
+		fund_validator(ALICE, FUNDS);
+
+		assert_ok!(Funding::set_standing_redemption(
+			RuntimeOrigin::signed(ALICE),
+			RedemptionSchedule::NextEpoch(AMOUNT),
+			ETH_DUMMY_ADDR,
+			None,
+		));
+
+		start_epoch(1);
+		assert_eq!(PendingRedemptions::<Test>::get(&ALICE).unwrap().total, AMOUNT);
+		assert_eq!(Flip::total_balance_of(&ALICE), FUNDS - AMOUNT - REDEMPTION_TAX);
+		assert!(!StandingRedemptions::<Test>::contains_key(&ALICE));
+		assert_eq!(MockBroadcaster::received_requests(), vec![AMOUNT]);
+	});
+}
+
+#[test]
+fn standing_redemptions_never_redeem_the_bond() {
+	new_test_ext().execute_with(|| {
+		const FUNDS: u128 = 1_000;
+		const RETAINED: u128 = 200;
+		const BOND: u128 = 500;
+		fund_validator(ALICE, FUNDS);
+		Bonder::<Test>::update_bond(&ALICE, BOND);
+
+		assert_ok!(Funding::set_standing_redemption(
+			RuntimeOrigin::signed(ALICE),
+			RedemptionSchedule::EveryEpochAbove(RETAINED),
+			ETH_DUMMY_ADDR,
+			None,
+		));
+
+		start_epoch(1);
+		let redeemed = FUNDS - BOND - REDEMPTION_TAX;
+		assert_eq!(PendingRedemptions::<Test>::get(&ALICE).unwrap().total, redeemed);
+		assert_eq!(Flip::total_balance_of(&ALICE), BOND);
+		System::assert_last_event(RuntimeEvent::Funding(
+			crate::Event::StandingRedemptionExecuted { account_id: ALICE, amount: redeemed },
+		));
+	});
+}
+
+#[test]
+fn standing_redemptions_are_executed_in_batches() {
+	new_test_ext().execute_with(|| {
+		const FUNDS: u128 = 1_000;
+		const AMOUNT: u128 = 200;
+		const ACCOUNTS: u32 = MAX_STANDING_REDEMPTIONS_PER_BLOCK + 1;
+		let accounts = (0..ACCOUNTS)
+			.map(|i| {
+				let mut id = [0xdd; 32];
+				id[..4].copy_from_slice(&i.to_be_bytes());
+				AccountId::new(id)
+			})
+			.collect::<Vec<_>>();
+		for account_id in &accounts {
+			fund_validator(account_id.clone(), FUNDS);
+			assert_ok!(Funding::set_standing_redemption(
+				RuntimeOrigin::signed(account_id.clone()),
+				RedemptionSchedule::NextEpoch(AMOUNT),
+				ETH_DUMMY_ADDR,
+				None,
+			));
+		}
+		let pending_redemptions =
+			|| accounts.iter().filter(|a| PendingRedemptions::<Test>::contains_key(a)).count();
+
+		start_epoch(1);
+		assert_eq!(pending_redemptions(), MAX_STANDING_REDEMPTIONS_PER_BLOCK as usize);
+		assert!(StandingRedemptionsCursor::<Test>::exists());
+		assert_eq!(StandingRedemptions::<Test>::iter().count(), 1);
+
+		Funding::on_initialize(2);
+		assert_eq!(pending_redemptions(), ACCOUNTS as usize);
+		assert!(!StandingRedemptionsCursor::<Test>::exists());
+		assert_eq!(StandingRedemptions::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn standing_redemptions_respect_bound_addresses() {
+	new_test_ext().execute_with(|| {
+		const FUNDS: u128 = 1_000;
+		const EXECUTOR_ADDRESS: EthereumAddress = H160([0x01; 20]);
+		fund_validator(ALICE, FUNDS);
+
+		assert_ok!(Funding::bind_redeem_address(RuntimeOrigin::signed(ALICE), ETH_DUMMY_ADDR));
+		assert_noop!(
+			Funding::set_standing_redemption(
+				RuntimeOrigin::signed(ALICE),
+				RedemptionSchedule::NextEpoch(FUNDS / 2),
+				ETH_ZERO_ADDRESS,
+				None,
+			),
+			Error::<Test>::AccountBindingRestrictionViolated
+		);
+		assert_ok!(Funding::set_standing_redemption(
+			RuntimeOrigin::signed(ALICE),
+			RedemptionSchedule::NextEpoch(FUNDS / 2),
+			ETH_DUMMY_ADDR,
+			None,
+		));
+
+		// Bindings are checked again when the redemption is executed.
+		assert_ok!(Funding::bind_executor_address(RuntimeOrigin::signed(ALICE), EXECUTOR_ADDRESS));
+		start_epoch(1);
+		System::assert_last_event(RuntimeEvent::Funding(crate::Event::StandingRedemptionFailed {
+			account_id: ALICE,
+			error: Error::<Test>::ExecutorBindingRestrictionViolated.into(),
+		}));
+		assert!(!PendingRedemptions::<Test>::contains_key(&ALICE));
+		assert_eq!(Flip::total_balance_of(&ALICE), FUNDS);
+		// The one-off redemption is kept so it can be retried.
+		assert!(StandingRedemptions::<Test>::contains_key(&ALICE));
+	});
+}
+
+#[test]
+fn can_remove_standing_redemption() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Funding::remove_standing_redemption(RuntimeOrigin::signed(ALICE)), BadOrigin);
+		fund_validator(ALICE, 1_000);
+
+		assert_noop!(
+			Funding::remove_standing_redemption(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::NoStandingRedemption
+		);
+		assert_ok!(Funding::set_standing_redemption(
+			RuntimeOrigin::signed(ALICE),
+			RedemptionSchedule::EveryEpochAbove(MIN_FUNDING),
+			ETH_DUMMY_ADDR,
+			None,
+		));
+		assert_ok!(Funding::remove_standing_redemption(RuntimeOrigin::signed(ALICE)));
+		System::assert_last_event(RuntimeEvent::Funding(crate::Event::StandingRedemptionRemoved {
+			account_id: ALICE,
+		}));
+
+		start_epoch(1);
+		assert!(!PendingRedemptions::<Test>::contains_key(&ALICE));
+	});
+}
@@ -44,6 +44,11 @@ pub trait WeightInfo {
 	fn bind_redeem_address() -> Weight;
 	fn update_restricted_addresses(a: u32, b: u32, c: u32, ) -> Weight;
 	fn bind_executor_address() -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
+	fn set_operator_commission() -> Weight;
+	fn set_standing_redemption() -> Weight;
+	fn remove_standing_redemption() -> Weight;
 }
 
 /// Weights for pallet_cf_funding using the Substrate node and recommended hardware.
@@ -142,14 +147,19 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 	/// Proof: `EthereumThresholdSigner::RequestCallback` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Flip::PendingRedemptionsReserve` (r:0 w:1)
 	/// Proof: `Flip::PendingRedemptionsReserve` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DelegatedTo` (r:1 w:0)
+	/// Proof: `Funding::DelegatedTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RewardsPerStake` (r:1 w:0)
+	/// Proof: `Funding::RewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatorRewardsPerStake` (r:1 w:1)
+	/// Proof: `Funding::DelegatorRewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Reserve` (r:1 w:1)
+	/// Proof: `Flip::Reserve` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn redeem() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2032`
-		//  Estimated: `12922`
-		// Minimum execution time: 175_786_000 picoseconds.
-		Weight::from_parts(178_599_000, 12922)
-			.saturating_add(T::DbWeight::get().reads(34_u64))
-			.saturating_add(T::DbWeight::get().writes(13_u64))
+		// NOTE: Placeholder until the benchmarks are run with delegation rewards.
+		Weight::from_parts(200_000_000, 12922)
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().writes(15_u64))
 	}
 	/// Storage: `Environment::RuntimeSafeMode` (r:1 w:0)
 	/// Proof: `Environment::RuntimeSafeMode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -375,6 +385,109 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AccountRoles::AccountRoles` (r:2 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DelegatedTo` (r:1 w:1)
+	/// Proof: `Funding::DelegatedTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Account` (r:1 w:0)
+	/// Proof: `Flip::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingUndelegations` (r:1 w:0)
+	/// Proof: `Funding::PendingUndelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::Delegations` (r:1 w:1)
+	/// Proof: `Funding::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::MinimumFunding` (r:1 w:0)
+	/// Proof: `Funding::MinimumFunding` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatorCount` (r:1 w:1)
+	/// Proof: `Funding::DelegatorCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatedStake` (r:1 w:1)
+	/// Proof: `Funding::DelegatedStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RewardsPerStake` (r:1 w:0)
+	/// Proof: `Funding::RewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatorRewardsPerStake` (r:1 w:1)
+	/// Proof: `Funding::DelegatorRewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Reserve` (r:1 w:1)
+	/// Proof: `Flip::Reserve` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		// NOTE: Placeholder until the benchmarks are run with delegation rewards.
+		Weight::from_parts(50_000_000, 6488)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Funding::DelegatedTo` (r:1 w:1)
+	/// Proof: `Funding::DelegatedTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::CurrentRotationPhase` (r:1 w:0)
+	/// Proof: `Validator::CurrentRotationPhase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::PendingUndelegations` (r:1 w:1)
+	/// Proof: `Funding::PendingUndelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::Delegations` (r:1 w:1)
+	/// Proof: `Funding::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::MinimumFunding` (r:1 w:0)
+	/// Proof: `Funding::MinimumFunding` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Account` (r:2 w:1)
+	/// Proof: `Flip::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DelegatorCount` (r:1 w:1)
+	/// Proof: `Funding::DelegatorCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RedemptionTTLSeconds` (r:1 w:0)
+	/// Proof: `Funding::RedemptionTTLSeconds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatedStake` (r:1 w:1)
+	/// Proof: `Funding::DelegatedStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RewardsPerStake` (r:1 w:0)
+	/// Proof: `Funding::RewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatorRewardsPerStake` (r:1 w:1)
+	/// Proof: `Funding::DelegatorRewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Reserve` (r:1 w:1)
+	/// Proof: `Flip::Reserve` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		// NOTE: Placeholder until the benchmarks are run with delegation rewards.
+		Weight::from_parts(60_000_000, 6560)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OperatorCommission` (r:0 w:1)
+	/// Proof: `Funding::OperatorCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_operator_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `236`
+		//  Estimated: `3498`
+		// Minimum execution time: 13_902_000 picoseconds.
+		Weight::from_parts(14_350_000, 3498)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::RestrictedBalances` (r:1 w:0)
+	/// Proof: `Funding::RestrictedBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::BoundExecutorAddress` (r:1 w:0)
+	/// Proof: `Funding::BoundExecutorAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::BoundRedeemAddress` (r:1 w:0)
+	/// Proof: `Funding::BoundRedeemAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::StandingRedemptions` (r:0 w:1)
+	/// Proof: `Funding::StandingRedemptions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_standing_redemption() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `268`
+		//  Estimated: `3733`
+		// Minimum execution time: 21_204_000 picoseconds.
+		Weight::from_parts(21_876_000, 3733)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::StandingRedemptions` (r:1 w:1)
+	/// Proof: `Funding::StandingRedemptions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_standing_redemption() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176`
+		//  Estimated: `3641`
+		// Minimum execution time: 14_030_000 picoseconds.
+		Weight::from_parts(14_502_000, 3641)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -472,14 +585,19 @@ impl WeightInfo for () {
 	/// Proof: `EthereumThresholdSigner::RequestCallback` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Flip::PendingRedemptionsReserve` (r:0 w:1)
 	/// Proof: `Flip::PendingRedemptionsReserve` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DelegatedTo` (r:1 w:0)
+	/// Proof: `Funding::DelegatedTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RewardsPerStake` (r:1 w:0)
+	/// Proof: `Funding::RewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatorRewardsPerStake` (r:1 w:1)
+	/// Proof: `Funding::DelegatorRewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Reserve` (r:1 w:1)
+	/// Proof: `Flip::Reserve` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn redeem() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2032`
-		//  Estimated: `12922`
-		// Minimum execution time: 175_786_000 picoseconds.
-		Weight::from_parts(178_599_000, 12922)
-			.saturating_add(RocksDbWeight::get().reads(34_u64))
-			.saturating_add(RocksDbWeight::get().writes(13_u64))
+		// NOTE: Placeholder until the benchmarks are run with delegation rewards.
+		Weight::from_parts(200_000_000, 12922)
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
 	}
 	/// Storage: `Environment::RuntimeSafeMode` (r:1 w:0)
 	/// Proof: `Environment::RuntimeSafeMode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -705,4 +823,107 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AccountRoles::AccountRoles` (r:2 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DelegatedTo` (r:1 w:1)
+	/// Proof: `Funding::DelegatedTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Account` (r:1 w:0)
+	/// Proof: `Flip::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingUndelegations` (r:1 w:0)
+	/// Proof: `Funding::PendingUndelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::Delegations` (r:1 w:1)
+	/// Proof: `Funding::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::MinimumFunding` (r:1 w:0)
+	/// Proof: `Funding::MinimumFunding` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatorCount` (r:1 w:1)
+	/// Proof: `Funding::DelegatorCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatedStake` (r:1 w:1)
+	/// Proof: `Funding::DelegatedStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RewardsPerStake` (r:1 w:0)
+	/// Proof: `Funding::RewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatorRewardsPerStake` (r:1 w:1)
+	/// Proof: `Funding::DelegatorRewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Reserve` (r:1 w:1)
+	/// Proof: `Flip::Reserve` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		// NOTE: Placeholder until the benchmarks are run with delegation rewards.
+		Weight::from_parts(50_000_000, 6488)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Funding::DelegatedTo` (r:1 w:1)
+	/// Proof: `Funding::DelegatedTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::CurrentRotationPhase` (r:1 w:0)
+	/// Proof: `Validator::CurrentRotationPhase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::PendingUndelegations` (r:1 w:1)
+	/// Proof: `Funding::PendingUndelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::Delegations` (r:1 w:1)
+	/// Proof: `Funding::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::MinimumFunding` (r:1 w:0)
+	/// Proof: `Funding::MinimumFunding` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Account` (r:2 w:1)
+	/// Proof: `Flip::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DelegatorCount` (r:1 w:1)
+	/// Proof: `Funding::DelegatorCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RedemptionTTLSeconds` (r:1 w:0)
+	/// Proof: `Funding::RedemptionTTLSeconds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatedStake` (r:1 w:1)
+	/// Proof: `Funding::DelegatedStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::RewardsPerStake` (r:1 w:0)
+	/// Proof: `Funding::RewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::DelegatorRewardsPerStake` (r:1 w:1)
+	/// Proof: `Funding::DelegatorRewardsPerStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Reserve` (r:1 w:1)
+	/// Proof: `Flip::Reserve` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		// NOTE: Placeholder until the benchmarks are run with delegation rewards.
+		Weight::from_parts(60_000_000, 6560)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OperatorCommission` (r:0 w:1)
+	/// Proof: `Funding::OperatorCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_operator_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `236`
+		//  Estimated: `3498`
+		// Minimum execution time: 13_902_000 picoseconds.
+		Weight::from_parts(14_350_000, 3498)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::RestrictedBalances` (r:1 w:0)
+	/// Proof: `Funding::RestrictedBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::BoundExecutorAddress` (r:1 w:0)
+	/// Proof: `Funding::BoundExecutorAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::BoundRedeemAddress` (r:1 w:0)
+	/// Proof: `Funding::BoundRedeemAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::StandingRedemptions` (r:0 w:1)
+	/// Proof: `Funding::StandingRedemptions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_standing_redemption() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `268`
+		//  Estimated: `3733`
+		// Minimum execution time: 21_204_000 picoseconds.
+		Weight::from_parts(21_876_000, 3733)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::StandingRedemptions` (r:1 w:1)
+	/// Proof: `Funding::StandingRedemptions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_standing_redemption() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176`
+		//  Estimated: `3641`
+		// Minimum execution time: 14_030_000 picoseconds.
+		Weight::from_parts(14_502_000, 3641)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -165,5 +165,42 @@ mod benchmarks {
 		dispatch_whitelisted_call(RawOrigin::Signed(caller.clone()), 1);
 	}
 
+	#[benchmark]
+	fn set_execution_delay() {
+		let call = Call::<T>::set_execution_delay {
+			category: CallCategory::RuntimeUpgrade,
+			delay: 100u32.into(),
+		};
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		assert_eq!(ExecutionDelays::<T>::get(CallCategory::RuntimeUpgrade), 100u32.into());
+	}
+
+	#[benchmark]
+	fn cancel_pending_proposal() {
+		let caller: T::AccountId = whitelisted_caller();
+		<Members<T>>::put(BTreeSet::from([caller.clone()]));
+		let call: <T as Config>::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		PendingExecution::<T>::insert(
+			1,
+			PendingProposal {
+				call: call.encode(),
+				execution: ExecutionMode::Automatic,
+				execution_block: 100u32.into(),
+				cancellations: Default::default(),
+			},
+		);
+
+		#[extrinsic_call]
+		cancel_pending_proposal(RawOrigin::Signed(caller), 1);
+
+		assert!(!PendingExecution::<T>::contains_key(1));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
@@ -10,10 +10,11 @@ use frame_support::{
 	dispatch::GetDispatchInfo,
 	ensure,
 	pallet_prelude::{DispatchResultWithPostInfo, Weight},
-	sp_runtime::{DispatchError, Percent, TransactionOutcome},
+	sp_runtime::{traits::Zero, DispatchError, Percent, TransactionOutcome},
 	storage::with_transaction,
 	traits::{EnsureOrigin, Get, StorageVersion, UnfilteredDispatchable, UnixTime},
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
 use sp_std::{boxed::Box, ops::Add, vec::Vec};
 
@@ -60,6 +61,15 @@ pub mod pallet {
 		Manual,
 	}
 
+	/// Governance calls are grouped into categories, each of which can have its own delay between
+	/// a proposal being approved and it being executed.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+	pub enum CallCategory {
+		RuntimeUpgrade,
+		SafeMode,
+		Other,
+	}
+
 	#[derive(Encode, Decode, TypeInfo, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
 	pub struct ActiveProposal {
 		pub proposal_id: ProposalId,
@@ -77,6 +87,19 @@ pub mod pallet {
 		pub execution: ExecutionMode,
 	}
 
+	/// An approved proposal that is waiting for its timelock to expire.
+	#[derive(Encode, Decode, TypeInfo, Clone, RuntimeDebug, PartialEq, Eq)]
+	pub struct PendingProposal<AccountId, BlockNumber> {
+		/// Encoded representation of a extrinsic.
+		pub call: OpaqueCall,
+		/// Whether the proposal is executed automatically or pre-authorised once released.
+		pub execution: ExecutionMode,
+		/// The block at which the timelock expires.
+		pub execution_block: BlockNumber,
+		/// Members who have voted to cancel the proposal.
+		pub cancellations: BTreeSet<AccountId>,
+	}
+
 	type AccountId<T> = <T as frame_system::Config>::AccountId;
 	type OpaqueCall = Vec<u8>;
 	type Timestamp = u64;
@@ -108,6 +131,8 @@ pub mod pallet {
 		type CompatibleCfeVersions: CompatibleCfeVersions;
 		/// For getting current authorities' CFE versions.
 		type AuthoritiesCfeVersions: AuthoritiesCfeVersions;
+		/// Determines which execution delay applies to a governance call.
+		type CallCategoriser: CategoriseCall<<Self as Config>::RuntimeCall>;
 	}
 
 	#[pallet::pallet]
@@ -152,6 +177,28 @@ pub mod pallet {
 	pub(super) type ExecutionPipeline<T> =
 		StorageValue<_, Vec<(OpaqueCall, ProposalId)>, ValueQuery>;
 
+	/// Approved proposals waiting for their timelock to expire, during which they can still be
+	/// cancelled by a supermajority of members.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_execution)]
+	pub type PendingExecution<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		PendingProposal<T::AccountId, BlockNumberFor<T>>,
+	>;
+
+	/// The timelocked proposals to be released at each block.
+	#[pallet::storage]
+	pub type PendingExecutionByBlock<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<ProposalId>, ValueQuery>;
+
+	/// The number of blocks between approval and execution for each category of call.
+	#[pallet::storage]
+	#[pallet::getter(fn execution_delay)]
+	pub type ExecutionDelays<T: Config> =
+		StorageMap<_, Twox64Concat, CallCategory, BlockNumberFor<T>, ValueQuery>;
+
 	/// Time in seconds until a proposal expires.
 	#[pallet::storage]
 	#[pallet::getter(fn expiry_span)]
@@ -166,11 +213,12 @@ pub mod pallet {
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// on_initialize hook - check the ActiveProposals
 		/// and remove the expired ones for house keeping
-		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			// Check expiry and expire the proposals if needed
 			let active_proposal_weight = Self::check_expiry();
+			let release_weight = Self::release_timelocked_proposals(n);
 			let execution_weight = Self::execute_pending_proposals();
-			active_proposal_weight + execution_weight
+			active_proposal_weight + release_weight + execution_weight
 		}
 	}
 
@@ -195,6 +243,14 @@ pub mod pallet {
 		GovKeyCallHashWhitelisted { call_hash: GovCallHash },
 		/// Failed GovKey call
 		GovKeyCallExecutionFailed { call_hash: GovCallHash, error: DispatchError },
+		/// An approved proposal is timelocked until `execution_block`.
+		PendingExecution { proposal_id: ProposalId, execution_block: BlockNumberFor<T> },
+		/// A member voted to cancel a timelocked proposal \[proposal_id\]
+		CancellationApproved(ProposalId),
+		/// A timelocked proposal was cancelled \[proposal_id\]
+		Cancelled(ProposalId),
+		/// The execution delay for a category of calls was updated.
+		ExecutionDelaySet { category: CallCategory, delay: BlockNumberFor<T> },
 	}
 
 	#[pallet::error]
@@ -215,6 +271,8 @@ pub mod pallet {
 		CallHashNotWhitelisted,
 		/// Insufficient number of CFEs are at the target version to receive the runtime upgrade.
 		NotEnoughAuthoritiesCfesAtTargetVersion,
+		/// An account already voted to cancel a proposal
+		AlreadyApprovedCancellation,
 	}
 
 	#[pallet::call]
@@ -448,6 +506,77 @@ pub mod pallet {
 				Err(Error::<T>::ProposalNotFound.into())
 			}
 		}
+
+		/// **Can only be called via the Governance Origin**
+		///
+		/// Sets the number of blocks an approved proposal of the given category has to wait before
+		/// it is executed. Proposals that are already timelocked are not affected.
+		///
+		/// ## Events
+		///
+		/// - [ExecutionDelaySet](Event::ExecutionDelaySet)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_execution_delay())]
+		pub fn set_execution_delay(
+			origin: OriginFor<T>,
+			category: CallCategory,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+			ExecutionDelays::<T>::insert(category, delay);
+			Self::deposit_event(Event::ExecutionDelaySet { category, delay });
+			Ok(())
+		}
+
+		/// Vote to cancel a proposal that is waiting for its timelock to expire. The proposal is
+		/// cancelled once more than 2/3 of the members have voted to cancel it.
+		///
+		/// ## Events
+		///
+		/// - [CancellationApproved](Event::CancellationApproved)
+		/// - [Cancelled](Event::Cancelled)
+		///
+		/// ## Errors
+		///
+		/// - [NotMember](Error::NotMember)
+		/// - [ProposalNotFound](Error::ProposalNotFound)
+		/// - [AlreadyApprovedCancellation](Error::AlreadyApprovedCancellation)
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::cancel_pending_proposal())]
+		pub fn cancel_pending_proposal(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Members::<T>::get().contains(&who), Error::<T>::NotMember);
+
+			let cancellations = PendingExecution::<T>::try_mutate(proposal_id, |maybe_pending| {
+				let pending = maybe_pending.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
+				ensure!(pending.cancellations.insert(who), Error::<T>::AlreadyApprovedCancellation);
+				Ok::<_, Error<T>>(pending.cancellations.len())
+			})?;
+			Self::deposit_event(Event::CancellationApproved(proposal_id));
+
+			let members =
+				Members::<T>::decode_non_dedup_len().ok_or(Error::<T>::DecodeMembersLenFailed)?;
+			if cancellations * 3 > members * 2 {
+				if let Some(PendingProposal { execution_block, .. }) =
+					PendingExecution::<T>::take(proposal_id)
+				{
+					PendingExecutionByBlock::<T>::mutate(execution_block, |proposal_ids| {
+						proposal_ids.retain(|id| *id != proposal_id)
+					});
+				}
+				Self::deposit_event(Event::Cancelled(proposal_id));
+			}
+
+			// Governance members don't pay transaction fees
+			Ok(Pays::No.into())
+		}
 	}
 
 	/// Genesis definition
@@ -486,6 +615,12 @@ pub mod pallet {
 	}
 }
 
+/// Assigns governance calls to a [CallCategory], which determines how long they are timelocked for
+/// after being approved.
+pub trait CategoriseCall<Call> {
+	fn categorise(call: &Call) -> CallCategory;
+}
+
 /// Custom governance origin
 pub struct EnsureGovernance;
 
@@ -530,10 +665,28 @@ impl<T: Config> Pallet<T> {
 		if proposal.approved.len() >
 			(Members::<T>::decode_non_dedup_len().ok_or(Error::<T>::DecodeMembersLenFailed)? / 2)
 		{
-			if proposal.execution == ExecutionMode::Manual {
-				PreAuthorisedGovCalls::<T>::insert(approved_id, proposal.call);
+			// Calls that fail to decode are released straight away so that the failure is reported.
+			let delay = <T as Config>::RuntimeCall::decode(&mut &(*proposal.call))
+				.map(|call| ExecutionDelays::<T>::get(T::CallCategoriser::categorise(&call)))
+				.unwrap_or_default();
+			if delay.is_zero() {
+				Self::release_for_execution(approved_id, proposal.call, proposal.execution);
 			} else {
-				ExecutionPipeline::<T>::append((proposal.call, approved_id));
+				let execution_block = frame_system::Pallet::<T>::block_number() + delay;
+				PendingExecution::<T>::insert(
+					approved_id,
+					PendingProposal {
+						call: proposal.call,
+						execution: proposal.execution,
+						execution_block,
+						cancellations: Default::default(),
+					},
+				);
+				PendingExecutionByBlock::<T>::append(execution_block, approved_id);
+				Self::deposit_event(Event::PendingExecution {
+					proposal_id: approved_id,
+					execution_block,
+				});
 			}
 			Proposals::<T>::remove(approved_id);
 			ActiveProposals::<T>::mutate(|proposals| {
@@ -566,6 +719,32 @@ impl<T: Config> Pallet<T> {
 		Self::expire_proposals(expired) + T::WeightInfo::on_initialize(num_proposals as u32)
 	}
 
+	fn release_for_execution(proposal_id: ProposalId, call: Vec<u8>, execution: ExecutionMode) {
+		if execution == ExecutionMode::Manual {
+			PreAuthorisedGovCalls::<T>::insert(proposal_id, call);
+		} else {
+			ExecutionPipeline::<T>::append((call, proposal_id));
+		}
+	}
+
+	/// Releases timelocked proposals whose execution block has been reached. Automatically executed
+	/// proposals are executed in the same block.
+	fn release_timelocked_proposals(current_block: BlockNumberFor<T>) -> Weight {
+		let proposal_ids = PendingExecutionByBlock::<T>::take(current_block);
+		let mut reads = 1u64;
+		let mut writes = 1u64;
+		for proposal_id in proposal_ids {
+			reads += 1;
+			if let Some(PendingProposal { call, execution, .. }) =
+				PendingExecution::<T>::take(proposal_id)
+			{
+				Self::release_for_execution(proposal_id, call, execution);
+				writes += 2;
+			}
+		}
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
 	fn execute_pending_proposals() -> Weight {
 		let mut execution_weight = Weight::zero();
 		for (call, id) in ExecutionPipeline::<T>::take() {
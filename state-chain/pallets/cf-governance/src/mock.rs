@@ -1,6 +1,6 @@
 use std::cell::RefCell;
 
-use crate::{self as pallet_cf_governance};
+use crate::{self as pallet_cf_governance, CallCategory, CategoriseCall};
 use cf_primitives::SemVer;
 use cf_traits::{
 	impl_mock_chainflip, mocks::time_source, AuthoritiesCfeVersions, CompatibleCfeVersions,
@@ -117,6 +117,20 @@ impl CompatibleCfeVersions for MockCompatibleCfeVersions {
 	}
 }
 
+pub struct MockCallCategoriser;
+impl CategoriseCall<RuntimeCall> for MockCallCategoriser {
+	fn categorise(call: &RuntimeCall) -> CallCategory {
+		match call {
+			RuntimeCall::Governance(pallet_cf_governance::Call::chainflip_runtime_upgrade {
+				..
+			}) => CallCategory::RuntimeUpgrade,
+			// There is no safe mode in the mock runtime, so remarks stand in for safe mode updates.
+			RuntimeCall::System(frame_system::Call::remark { .. }) => CallCategory::SafeMode,
+			_ => CallCategory::Other,
+		}
+	}
+}
+
 impl pallet_cf_governance::Config for Test {
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
@@ -127,6 +141,7 @@ impl pallet_cf_governance::Config for Test {
 	type RuntimeUpgrade = RuntimeUpgradeMock;
 	type AuthoritiesCfeVersions = MockAuthoritiesCfeVersions;
 	type CompatibleCfeVersions = MockCompatibleCfeVersions;
+	type CallCategoriser = MockCallCategoriser;
 }
 
 pub const ALICE: <Test as frame_system::Config>::AccountId = 123u64;
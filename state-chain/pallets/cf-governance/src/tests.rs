@@ -1,11 +1,11 @@
 use crate::{
-	mock::*, ActiveProposals, Error, ExecutionMode, ExecutionPipeline, ExpiryTime, Members,
-	PreAuthorisedGovCalls, ProposalIdCounter,
+	mock::*, ActiveProposals, CallCategory, Error, ExecutionMode, ExecutionPipeline, ExpiryTime,
+	Members, PendingExecution, PendingExecutionByBlock, PreAuthorisedGovCalls, ProposalIdCounter,
 };
 use cf_primitives::SemVer;
 use cf_test_utilities::last_event;
 use cf_traits::mocks::time_source;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok, traits::Hooks};
 use sp_runtime::Percent;
 use sp_std::collections::btree_set::BTreeSet;
 use std::time::Duration;
@@ -357,3 +357,154 @@ fn replacing_governance_members() {
 		assert_eq!(System::sufficients(&MAX), 0);
 	});
 }
+
+const EXECUTION_DELAY: u64 = 10;
+
+fn set_execution_delay(category: CallCategory, delay: u64) {
+	assert_ok!(Governance::set_execution_delay(
+		crate::RawOrigin::GovernanceApproval.into(),
+		category,
+		delay
+	));
+}
+
+fn propose_and_approve(call: Box<RuntimeCall>, execution: ExecutionMode) {
+	assert_ok!(Governance::propose_governance_extrinsic(
+		RuntimeOrigin::signed(ALICE),
+		call,
+		execution
+	));
+	assert_ok!(Governance::approve(RuntimeOrigin::signed(BOB), ProposalIdCounter::<Test>::get()));
+}
+
+fn run_block(block_number: u64) {
+	System::set_block_number(block_number);
+	<Governance as Hooks<u64>>::on_initialize(block_number);
+}
+
+#[test]
+fn approved_proposal_is_timelocked() {
+	new_test_ext().execute_with(|| {
+		set_execution_delay(CallCategory::Other, EXECUTION_DELAY);
+		let execution_block = System::block_number() + EXECUTION_DELAY;
+
+		propose_and_approve(mock_extrinsic(), ExecutionMode::Automatic);
+		assert_eq!(
+			last_event::<Test>(),
+			RuntimeEvent::Governance(crate::Event::PendingExecution {
+				proposal_id: 1,
+				execution_block
+			}),
+		);
+		assert!(ExecutionPipeline::<Test>::get().is_empty());
+		assert_eq!(PendingExecutionByBlock::<Test>::get(execution_block), vec![1]);
+
+		run_block(execution_block - 1);
+		assert!(PendingExecution::<Test>::contains_key(1));
+		assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([ALICE, BOB, CHARLES]));
+
+		run_block(execution_block);
+		assert!(!PendingExecution::<Test>::contains_key(1));
+		assert!(!PendingExecutionByBlock::<Test>::contains_key(execution_block));
+		assert_eq!(last_event::<Test>(), RuntimeEvent::Governance(crate::Event::Executed(1)));
+		assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([EVE, PETER, MAX]));
+	});
+}
+
+#[test]
+fn execution_delay_depends_on_call_category() {
+	new_test_ext().execute_with(|| {
+		const RUNTIME_UPGRADE_DELAY: u64 = 100;
+		const SAFE_MODE_DELAY: u64 = 5;
+		set_execution_delay(CallCategory::RuntimeUpgrade, RUNTIME_UPGRADE_DELAY);
+		set_execution_delay(CallCategory::SafeMode, SAFE_MODE_DELAY);
+		let current_block = System::block_number();
+
+		propose_and_approve(
+			Box::new(RuntimeCall::Governance(
+				pallet_cf_governance::Call::<Test>::chainflip_runtime_upgrade {
+					cfe_version_restriction: None,
+					code: DUMMY_WASM_BLOB,
+				},
+			)),
+			ExecutionMode::Manual,
+		);
+		assert_eq!(
+			PendingExecution::<Test>::get(1).unwrap().execution_block,
+			current_block + RUNTIME_UPGRADE_DELAY
+		);
+
+		propose_and_approve(
+			Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] })),
+			ExecutionMode::Automatic,
+		);
+		assert_eq!(
+			PendingExecution::<Test>::get(2).unwrap().execution_block,
+			current_block + SAFE_MODE_DELAY
+		);
+
+		// No delay has been set for other calls, so they are executed in the next block as usual.
+		propose_and_approve(mock_extrinsic(), ExecutionMode::Automatic);
+		assert!(!PendingExecution::<Test>::contains_key(3));
+		assert_eq!(ExecutionPipeline::<Test>::decode_len(), Some(1));
+	});
+}
+
+#[test]
+fn manual_proposal_is_pre_authorised_once_timelock_expires() {
+	new_test_ext().execute_with(|| {
+		set_execution_delay(CallCategory::Other, EXECUTION_DELAY);
+		let execution_block = System::block_number() + EXECUTION_DELAY;
+
+		propose_and_approve(mock_extrinsic(), ExecutionMode::Manual);
+		assert!(!PreAuthorisedGovCalls::<Test>::contains_key(1));
+		assert_noop!(
+			Governance::dispatch_whitelisted_call(RuntimeOrigin::signed(CHARLES), 1),
+			<Error<Test>>::ProposalNotFound
+		);
+
+		run_block(execution_block);
+		assert!(PreAuthorisedGovCalls::<Test>::contains_key(1));
+		assert_ok!(Governance::dispatch_whitelisted_call(RuntimeOrigin::signed(CHARLES), 1));
+		assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([EVE, PETER, MAX]));
+	});
+}
+
+#[test]
+fn supermajority_can_cancel_timelocked_proposal() {
+	new_test_ext().execute_with(|| {
+		set_execution_delay(CallCategory::Other, EXECUTION_DELAY);
+		let execution_block = System::block_number() + EXECUTION_DELAY;
+		propose_and_approve(mock_extrinsic(), ExecutionMode::Automatic);
+
+		assert_noop!(
+			Governance::cancel_pending_proposal(RuntimeOrigin::signed(EVE), 1),
+			<Error<Test>>::NotMember
+		);
+		assert_ok!(Governance::cancel_pending_proposal(RuntimeOrigin::signed(ALICE), 1));
+		assert_eq!(
+			last_event::<Test>(),
+			RuntimeEvent::Governance(crate::Event::CancellationApproved(1)),
+		);
+		assert_noop!(
+			Governance::cancel_pending_proposal(RuntimeOrigin::signed(ALICE), 1),
+			<Error<Test>>::AlreadyApprovedCancellation
+		);
+
+		// 2 out of 3 is not a supermajority.
+		assert_ok!(Governance::cancel_pending_proposal(RuntimeOrigin::signed(BOB), 1));
+		assert!(PendingExecution::<Test>::contains_key(1));
+
+		assert_ok!(Governance::cancel_pending_proposal(RuntimeOrigin::signed(CHARLES), 1));
+		assert_eq!(last_event::<Test>(), RuntimeEvent::Governance(crate::Event::Cancelled(1)));
+		assert!(!PendingExecution::<Test>::contains_key(1));
+		assert!(PendingExecutionByBlock::<Test>::get(execution_block).is_empty());
+
+		run_block(execution_block);
+		assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([ALICE, BOB, CHARLES]));
+		assert_noop!(
+			Governance::cancel_pending_proposal(RuntimeOrigin::signed(ALICE), 1),
+			<Error<Test>>::ProposalNotFound
+		);
+	});
+}
@@ -42,6 +42,8 @@ pub trait WeightInfo {
 	fn set_whitelisted_call_hash() -> Weight;
 	fn submit_govkey_call() -> Weight;
 	fn dispatch_whitelisted_call() -> Weight;
+	fn set_execution_delay() -> Weight;
+	fn cancel_pending_proposal() -> Weight;
 }
 
 /// Weights for pallet_cf_governance using the Substrate node and recommended hardware.
@@ -196,6 +198,29 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Governance::ExecutionDelays` (r:0 w:1)
+	/// Proof: `Governance::ExecutionDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_execution_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_127_000 picoseconds.
+		Weight::from_parts(9_514_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Governance::Members` (r:1 w:0)
+	/// Proof: `Governance::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Governance::PendingExecution` (r:1 w:1)
+	/// Proof: `Governance::PendingExecution` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_pending_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298`
+		//  Estimated: `3763`
+		// Minimum execution time: 19_842_000 picoseconds.
+		Weight::from_parts(20_415_000, 3763)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -349,4 +374,27 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Governance::ExecutionDelays` (r:0 w:1)
+	/// Proof: `Governance::ExecutionDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_execution_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_127_000 picoseconds.
+		Weight::from_parts(9_514_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Governance::Members` (r:1 w:0)
+	/// Proof: `Governance::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Governance::PendingExecution` (r:1 w:1)
+	/// Proof: `Governance::PendingExecution` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_pending_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298`
+		//  Estimated: `3763`
+		// Minimum execution time: 19_842_000 picoseconds.
+		Weight::from_parts(20_415_000, 3763)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -1,17 +1,41 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
-use crate::DisabledEgressAssets;
+use crate::{
+	boost_pool::{MAX_BOOSTERS_PER_POOL, MAX_PENDING_BOOSTS_PER_POOL},
+	DisabledEgressAssets,
+};
 use cf_chains::{
 	benchmarking_value::{BenchmarkValue, BenchmarkValueExtended},
 	DepositChannel,
 };
 use frame_benchmarking::v2::*;
 use frame_support::{assert_ok, traits::OriginTrait};
+use frame_system::RawOrigin;
 
 pub(crate) type TargetChainBlockNumber<T, I> =
 	<<T as Config<I>>::TargetChain as Chain>::ChainBlockNumber;
 
+const BOOST_POOL_TIER: BoostPoolTier = 5;
+
+/// A boost pool in its worst case for the boost extrinsics: every booster has funds owed to them
+/// by the maximum number of pending boosts.
+fn full_boost_pool<AccountId: Ord + Clone>(
+	boosters: impl IntoIterator<Item = AccountId>,
+) -> BoostPool<AccountId> {
+	const BOOSTER_FUNDS: AssetAmount = 1_000_000_000;
+	const BOOSTED_AMOUNT: AssetAmount = 1_000_000;
+
+	let mut pool = BoostPool::new(BOOST_POOL_TIER);
+	for booster in boosters {
+		assert_ok!(pool.add_funds(booster, BOOSTER_FUNDS));
+	}
+	for prewitnessed_deposit_id in 0..MAX_PENDING_BOOSTS_PER_POOL as PrewitnessedDepositId {
+		pool.provide_funds_for_boosting(prewitnessed_deposit_id, BOOSTED_AMOUNT);
+	}
+	pool
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -145,6 +169,73 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn create_boost_pools(a: Linear<1, 10>) {
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+		let asset: TargetChainAsset<T, I> = BenchmarkValue::benchmark_value();
+		let new_pools = (1..=a)
+			.map(|tier| BoostPoolId { asset, tier: tier as BoostPoolTier })
+			.collect::<Vec<_>>();
+
+		#[block]
+		{
+			assert_ok!(Pallet::<T, I>::create_boost_pools(origin, new_pools));
+		}
+
+		assert_eq!(BoostPools::<T, I>::iter_prefix(asset).count(), a as usize);
+	}
+
+	#[benchmark]
+	fn add_boost_funds() {
+		let asset: TargetChainAsset<T, I> = BenchmarkValue::benchmark_value();
+		let amount: TargetChainAmount<T, I> = BenchmarkValue::benchmark_value();
+		let booster: T::AccountId = account("booster", 0, 0);
+		assert_ok!(T::AccountRoleRegistry::register_as_liquidity_provider(&booster));
+		assert_ok!(T::LpBalance::try_credit_account(&booster, asset.into(), amount.into()));
+		// The new booster takes the last free place in the pool.
+		BoostPools::<T, I>::insert(
+			asset,
+			BOOST_POOL_TIER,
+			full_boost_pool(
+				(1..MAX_BOOSTERS_PER_POOL).map(|i| account::<T::AccountId>("booster", i, 0)),
+			),
+		);
+
+		#[extrinsic_call]
+		add_boost_funds(RawOrigin::Signed(booster.clone()), asset, amount, BOOST_POOL_TIER);
+
+		assert_eq!(
+			BoostPools::<T, I>::get(asset, BOOST_POOL_TIER)
+				.unwrap()
+				.available_amount_for(&booster),
+			amount.into()
+		);
+	}
+
+	#[benchmark]
+	fn stop_boosting() {
+		let asset: TargetChainAsset<T, I> = BenchmarkValue::benchmark_value();
+		let booster: T::AccountId = account("booster", 0, 0);
+		assert_ok!(T::AccountRoleRegistry::register_as_liquidity_provider(&booster));
+		BoostPools::<T, I>::insert(
+			asset,
+			BOOST_POOL_TIER,
+			full_boost_pool(
+				(0..MAX_BOOSTERS_PER_POOL).map(|i| account::<T::AccountId>("booster", i, 0)),
+			),
+		);
+
+		#[extrinsic_call]
+		stop_boosting(RawOrigin::Signed(booster.clone()), asset, BOOST_POOL_TIER);
+
+		assert_eq!(
+			BoostPools::<T, I>::get(asset, BOOST_POOL_TIER)
+				.unwrap()
+				.available_amount_for(&booster),
+			0
+		);
+	}
+
 	#[cfg(test)]
 	use crate::mock_eth::*;
 
@@ -165,5 +256,14 @@ mod benchmarks {
 		new_test_ext().execute_with(|| {
 			_disable_asset_egress::<Test, ()>(true);
 		});
+		new_test_ext().execute_with(|| {
+			_create_boost_pools::<Test, ()>(10, true);
+		});
+		new_test_ext().execute_with(|| {
+			_add_boost_funds::<Test, ()>(true);
+		});
+		new_test_ext().execute_with(|| {
+			_stop_boosting::<Test, ()>(true);
+		});
 	}
 }
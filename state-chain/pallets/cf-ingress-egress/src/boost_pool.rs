@@ -0,0 +1,396 @@
+use cf_primitives::{AssetAmount, BasisPoints, PrewitnessedDepositId};
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::RuntimeDebug,
+	sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, Rounding, Saturating},
+};
+use scale_info::TypeInfo;
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec::Vec,
+};
+
+const MAX_BASIS_POINTS: u128 = 10_000;
+
+/// The maximum number of boosters that can hold funds in a single pool, including those that have
+/// stopped boosting but still have funds locked in pending boosts.
+pub const MAX_BOOSTERS_PER_POOL: u32 = 50;
+
+/// The maximum number of deposits that a single pool can be boosting at any one time. Once
+/// reached, the pool won't boost any more deposits until some of them are finalised or lost.
+pub const MAX_PENDING_BOOSTS_PER_POOL: u32 = 50;
+
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum BoostPoolError {
+	/// The booster has no funds in the pool, neither available nor pending.
+	AccountNotFoundInPool,
+	/// The pool already holds funds for the maximum number of boosters.
+	TooManyBoosters,
+}
+
+/// The portion of a deposit that a single pool has agreed to boost.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq, Default)]
+pub struct BoostedAmount {
+	/// The part of the deposit amount covered by this pool. This is what the pool will be repaid
+	/// once the deposit is finalised.
+	pub deposit_amount: AssetAmount,
+	/// The part of `deposit_amount` that the pool keeps as its fee.
+	pub fee: AssetAmount,
+}
+
+impl BoostedAmount {
+	/// The amount actually lent out by the pool.
+	pub fn amount_lent(&self) -> AssetAmount {
+		self.deposit_amount.saturating_sub(self.fee)
+	}
+}
+
+/// A pool of funds lent by boosters (LPs) that can be used to credit prewitnessed deposits
+/// before they reach the full witness safety margin. All boosts from a given pool charge the
+/// same fee, set by the pool's tier.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct BoostPool<AccountId: Ord> {
+	/// The fee charged by this pool, in basis points of the boosted amount.
+	fee_bps: BasisPoints,
+	/// Funds that can be used for boosting, per booster.
+	available_amounts: BTreeMap<AccountId, AssetAmount>,
+	/// Amounts owed to each booster for every boosted deposit that has not yet been finalised.
+	pending_boosts: BTreeMap<PrewitnessedDepositId, BTreeMap<AccountId, AssetAmount>>,
+	/// Boosters that have stopped boosting. Their share of any pending boosts is returned to
+	/// their free balance instead of to the pool once the deposit is finalised.
+	pending_withdrawals: BTreeSet<AccountId>,
+}
+
+impl<AccountId: Ord + Clone> BoostPool<AccountId> {
+	pub fn new(fee_bps: BasisPoints) -> Self {
+		Self {
+			fee_bps,
+			available_amounts: Default::default(),
+			pending_boosts: Default::default(),
+			pending_withdrawals: Default::default(),
+		}
+	}
+
+	pub fn fee_bps(&self) -> BasisPoints {
+		self.fee_bps
+	}
+
+	pub fn available_amount(&self) -> AssetAmount {
+		self.available_amounts
+			.values()
+			.fold(0, |acc, amount| acc.saturating_add(*amount))
+	}
+
+	pub fn available_amount_for(&self, booster: &AccountId) -> AssetAmount {
+		self.available_amounts.get(booster).copied().unwrap_or_default()
+	}
+
+	pub fn pending_boosts(&self) -> impl Iterator<Item = &PrewitnessedDepositId> {
+		self.pending_boosts.keys()
+	}
+
+	pub fn add_funds(
+		&mut self,
+		booster: AccountId,
+		amount: AssetAmount,
+	) -> Result<(), BoostPoolError> {
+		if !self.available_amounts.contains_key(&booster) &&
+			!self.pending_withdrawals.contains(&booster) &&
+			self.booster_count() >= MAX_BOOSTERS_PER_POOL as usize
+		{
+			return Err(BoostPoolError::TooManyBoosters)
+		}
+
+		// Adding funds cancels a previous request to stop boosting.
+		self.pending_withdrawals.remove(&booster);
+		self.available_amounts.entry(booster).or_default().saturating_accrue(amount);
+
+		Ok(())
+	}
+
+	/// Uses the available funds to boost (part of) a deposit of `deposit_amount`. Funds are taken
+	/// from every booster in proportion to their available balance.
+	///
+	/// Returns the portion of the deposit that was boosted, which may be less than
+	/// `deposit_amount` if there are not enough funds available. The caller is expected to not
+	/// persist the pool if the boost can't be completed.
+	pub fn provide_funds_for_boosting(
+		&mut self,
+		prewitnessed_deposit_id: PrewitnessedDepositId,
+		deposit_amount: AssetAmount,
+	) -> BoostedAmount {
+		let available_amount = self.available_amount();
+		if available_amount == 0 ||
+			deposit_amount == 0 ||
+			self.pending_boosts.len() >= MAX_PENDING_BOOSTS_PER_POOL as usize
+		{
+			return Default::default()
+		}
+
+		// The largest part of the deposit that can be covered given that the pool only needs to
+		// lend the amount net of its fee.
+		let max_deposit_amount = multiply_by_rational_with_rounding(
+			available_amount,
+			MAX_BASIS_POINTS,
+			MAX_BASIS_POINTS.saturating_sub(self.fee_bps as u128),
+			Rounding::Down,
+		)
+		.unwrap_or(AssetAmount::MAX);
+
+		let boosted_deposit_amount = core::cmp::min(deposit_amount, max_deposit_amount);
+		let boosted = BoostedAmount {
+			deposit_amount: boosted_deposit_amount,
+			fee: fee_for(boosted_deposit_amount, self.fee_bps),
+		};
+		let amount_lent = core::cmp::min(boosted.amount_lent(), available_amount);
+
+		let lent_amounts = split_pro_rata(&self.available_amounts, available_amount, amount_lent);
+		let owed_amounts =
+			split_pro_rata(&self.available_amounts, available_amount, boosted.deposit_amount);
+
+		for (booster, lent) in lent_amounts {
+			if let Some(available) = self.available_amounts.get_mut(&booster) {
+				available.saturating_reduce(lent);
+			}
+		}
+		self.available_amounts.retain(|_, amount| *amount > 0);
+		self.pending_boosts
+			.insert(prewitnessed_deposit_id, owed_amounts.into_iter().collect());
+
+		boosted
+	}
+
+	/// Called when a boosted deposit is finalised. Boosters are repaid their share of the
+	/// deposit, including fees. Returns the amounts owed to boosters who have stopped boosting,
+	/// which should be credited to their free balance.
+	pub fn on_finalised_deposit(
+		&mut self,
+		prewitnessed_deposit_id: PrewitnessedDepositId,
+	) -> Vec<(AccountId, AssetAmount)> {
+		let mut unlocked = Vec::new();
+
+		for (booster, amount) in
+			self.pending_boosts.remove(&prewitnessed_deposit_id).unwrap_or_default()
+		{
+			if self.pending_withdrawals.contains(&booster) {
+				unlocked.push((booster.clone(), amount));
+				if !self.has_pending_boosts(&booster) {
+					self.pending_withdrawals.remove(&booster);
+				}
+			} else {
+				self.available_amounts.entry(booster).or_default().saturating_accrue(amount);
+			}
+		}
+
+		unlocked
+	}
+
+	/// Called when a boosted deposit will never be finalised (the channel expired before the
+	/// deposit was witnessed). The funds lent out for this deposit are lost by its boosters.
+	///
+	/// Returns the total amount that was owed to boosters for this deposit.
+	pub fn on_lost_deposit(
+		&mut self,
+		prewitnessed_deposit_id: PrewitnessedDepositId,
+	) -> AssetAmount {
+		let lost_amounts = self.pending_boosts.remove(&prewitnessed_deposit_id).unwrap_or_default();
+
+		let withdrawn: Vec<_> = self
+			.pending_withdrawals
+			.iter()
+			.filter(|booster| !self.has_pending_boosts(booster))
+			.cloned()
+			.collect();
+		for booster in withdrawn {
+			self.pending_withdrawals.remove(&booster);
+		}
+
+		lost_amounts.values().fold(0, |acc, amount| acc.saturating_add(*amount))
+	}
+
+	/// Removes all of the booster's available funds from the pool. Any funds still locked in
+	/// pending boosts will be returned to the booster when those deposits are finalised.
+	///
+	/// Returns the amount unlocked immediately and the ids of the pending boosts that still hold
+	/// some of the booster's funds.
+	pub fn stop_boosting(
+		&mut self,
+		booster: AccountId,
+	) -> Result<(AssetAmount, BTreeSet<PrewitnessedDepositId>), BoostPoolError> {
+		let pending_deposits: BTreeSet<_> = self
+			.pending_boosts
+			.iter()
+			.filter(|(_, owed_amounts)| owed_amounts.contains_key(&booster))
+			.map(|(id, _)| *id)
+			.collect();
+
+		let unlocked_amount = self.available_amounts.remove(&booster);
+
+		if unlocked_amount.is_none() && pending_deposits.is_empty() {
+			return Err(BoostPoolError::AccountNotFoundInPool)
+		}
+
+		if !pending_deposits.is_empty() {
+			self.pending_withdrawals.insert(booster);
+		}
+
+		Ok((unlocked_amount.unwrap_or_default(), pending_deposits))
+	}
+
+	fn booster_count(&self) -> usize {
+		self.available_amounts.len().saturating_add(self.pending_withdrawals.len())
+	}
+
+	fn has_pending_boosts(&self, booster: &AccountId) -> bool {
+		self.pending_boosts
+			.values()
+			.any(|owed_amounts| owed_amounts.contains_key(booster))
+	}
+}
+
+fn fee_for(amount: AssetAmount, fee_bps: BasisPoints) -> AssetAmount {
+	multiply_by_rational_with_rounding(amount, fee_bps as u128, MAX_BASIS_POINTS, Rounding::Down)
+		.unwrap_or_default()
+}
+
+/// Splits `amount` between the accounts in proportion to their `weights`. Any remainder due to
+/// rounding goes to the account with the largest weight.
+fn split_pro_rata<AccountId: Ord + Clone>(
+	weights: &BTreeMap<AccountId, AssetAmount>,
+	total_weight: AssetAmount,
+	amount: AssetAmount,
+) -> BTreeMap<AccountId, AssetAmount> {
+	let mut shares: BTreeMap<_, _> = weights
+		.iter()
+		.map(|(account, weight)| {
+			(
+				account.clone(),
+				multiply_by_rational_with_rounding(amount, *weight, total_weight, Rounding::Down)
+					.unwrap_or_default(),
+			)
+		})
+		.collect();
+
+	let remainder =
+		amount.saturating_sub(shares.values().fold(0, |acc, share| acc.saturating_add(*share)));
+	if let Some((largest, _)) = weights.iter().max_by_key(|(_, weight)| **weight) {
+		if let Some(share) = shares.get_mut(largest) {
+			share.saturating_accrue(remainder);
+		}
+	}
+
+	shares.retain(|_, share| *share > 0);
+	shares
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const ALICE: u64 = 1;
+	const BOB: u64 = 2;
+
+	#[test]
+	fn boosting_takes_funds_pro_rata() {
+		let mut pool = BoostPool::new(10);
+		pool.add_funds(ALICE, 3_000).unwrap();
+		pool.add_funds(BOB, 1_000).unwrap();
+
+		let boosted = pool.provide_funds_for_boosting(0, 2_000);
+		assert_eq!(boosted, BoostedAmount { deposit_amount: 2_000, fee: 2 });
+
+		// 1_998 is lent out: 3/4 from Alice (plus the rounding remainder), 1/4 from Bob.
+		assert_eq!(pool.available_amount_for(&ALICE), 1_501);
+		assert_eq!(pool.available_amount_for(&BOB), 501);
+
+		let unlocked = pool.on_finalised_deposit(0);
+		assert!(unlocked.is_empty());
+		// Boosters are repaid their share of the full deposit amount, including fees.
+		assert_eq!(pool.available_amount_for(&ALICE), 3_001);
+		assert_eq!(pool.available_amount_for(&BOB), 1_001);
+	}
+
+	#[test]
+	fn boost_is_limited_by_available_funds() {
+		let mut pool = BoostPool::new(100);
+		pool.add_funds(ALICE, 990).unwrap();
+
+		let boosted = pool.provide_funds_for_boosting(0, 5_000);
+		assert_eq!(boosted, BoostedAmount { deposit_amount: 1_000, fee: 10 });
+		assert_eq!(pool.available_amount(), 0);
+	}
+
+	#[test]
+	fn stopped_boosters_are_repaid_on_finalisation() {
+		let mut pool = BoostPool::new(0);
+		pool.add_funds(ALICE, 1_000).unwrap();
+		pool.add_funds(BOB, 1_000).unwrap();
+
+		pool.provide_funds_for_boosting(0, 1_000);
+
+		assert_eq!(pool.stop_boosting(ALICE), Ok((500, BTreeSet::from([0]))));
+		assert_eq!(pool.available_amount(), 500);
+
+		assert_eq!(pool.on_finalised_deposit(0), vec![(ALICE, 500)]);
+		assert_eq!(pool.available_amount(), 1_000);
+		assert_eq!(pool.available_amount_for(&ALICE), 0);
+
+		assert_eq!(pool.stop_boosting(ALICE), Err(BoostPoolError::AccountNotFoundInPool));
+	}
+
+	#[test]
+	fn lost_deposits_are_written_off() {
+		let mut pool = BoostPool::new(50);
+		pool.add_funds(ALICE, 1_000).unwrap();
+
+		let boosted = pool.provide_funds_for_boosting(0, 1_000);
+		assert_eq!(pool.stop_boosting(ALICE), Ok((boosted.fee, BTreeSet::from([0]))));
+
+		assert_eq!(pool.on_lost_deposit(0), 1_000);
+		assert_eq!(pool.pending_boosts().count(), 0);
+		assert_eq!(pool.stop_boosting(ALICE), Err(BoostPoolError::AccountNotFoundInPool));
+	}
+
+	#[test]
+	fn number_of_boosters_is_limited() {
+		let mut pool = BoostPool::new(10);
+		for booster in 0..MAX_BOOSTERS_PER_POOL as u64 {
+			pool.add_funds(booster, 1_000).unwrap();
+		}
+
+		let new_booster = MAX_BOOSTERS_PER_POOL as u64;
+		assert_eq!(pool.add_funds(new_booster, 1_000), Err(BoostPoolError::TooManyBoosters));
+		// Existing boosters can still top up their funds.
+		assert_eq!(pool.add_funds(0, 1_000), Ok(()));
+
+		// Boosters that have stopped boosting keep their place until their pending boosts are
+		// finalised.
+		pool.provide_funds_for_boosting(0, 1_000);
+		assert!(pool.stop_boosting(0).is_ok());
+		assert_eq!(pool.add_funds(new_booster, 1_000), Err(BoostPoolError::TooManyBoosters));
+
+		pool.on_finalised_deposit(0);
+		assert_eq!(pool.add_funds(new_booster, 1_000), Ok(()));
+	}
+
+	#[test]
+	fn number_of_pending_boosts_is_limited() {
+		let mut pool = BoostPool::new(10);
+		pool.add_funds(ALICE, 1_000_000).unwrap();
+
+		for id in 0..MAX_PENDING_BOOSTS_PER_POOL as u64 {
+			assert_eq!(pool.provide_funds_for_boosting(id, 1_000).deposit_amount, 1_000);
+		}
+		assert_eq!(
+			pool.provide_funds_for_boosting(MAX_PENDING_BOOSTS_PER_POOL as u64, 1_000),
+			BoostedAmount::default()
+		);
+
+		pool.on_finalised_deposit(0);
+		assert_eq!(
+			pool.provide_funds_for_boosting(MAX_PENDING_BOOSTS_PER_POOL as u64, 1_000)
+				.deposit_amount,
+			1_000
+		);
+	}
+}
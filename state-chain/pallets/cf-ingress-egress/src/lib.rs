@@ -5,6 +5,7 @@
 
 mod benchmarking;
 
+mod boost_pool;
 pub mod migrations;
 #[cfg(test)]
 mod mock_btc;
@@ -14,6 +15,8 @@ mod mock_eth;
 mod tests;
 pub mod weights;
 
+pub use boost_pool::BoostPool;
+use boost_pool::BoostPoolError;
 use cf_runtime_utilities::log_or_panic;
 use frame_support::{pallet_prelude::OptionQuery, sp_runtime::SaturatedConversion, transactional};
 pub use weights::WeightInfo;
@@ -21,18 +24,19 @@ pub use weights::WeightInfo;
 use cf_chains::{
 	address::{AddressConverter, AddressDerivationApi, AddressDerivationError},
 	AllBatch, AllBatchError, CcmCfParameters, CcmChannelMetadata, CcmDepositMetadata, CcmMessage,
-	Chain, ChannelLifecycleHooks, ConsolidateCall, DepositChannel, ExecutexSwapAndCall,
-	FeeEstimationApi, FetchAssetParams, ForeignChainAddress, SwapOrigin, TransferAssetParams,
+	Chain, ChainCrypto, ChannelLifecycleHooks, ConsolidateCall, DcaParameters, DepositChannel,
+	ExecutexSwapAndCall, FeeEstimationApi, FetchAssetParams, ForeignChainAddress, SwapOrigin,
+	SwapRefundParameters, TransferAssetParams,
 };
 use cf_primitives::{
-	Asset, BasisPoints, BroadcastId, ChannelId, EgressCounter, EgressId, EpochIndex, ForeignChain,
-	SwapId, ThresholdSignatureRequestId,
+	Asset, AssetAmount, BasisPoints, BroadcastId, ChannelId, EgressCounter, EgressId, EpochIndex,
+	ForeignChain, PrewitnessedDepositId, SwapId, ThresholdSignatureRequestId,
 };
 use cf_traits::{
 	liquidity::{LpBalanceApi, LpDepositHandler},
-	AssetConverter, Broadcaster, CcmHandler, CcmSwapIds, Chainflip, DepositApi, DepositHandler,
-	EgressApi, EpochInfo, FeePayment, GetBlockHeight, GetTrackedData, NetworkEnvironmentProvider,
-	ScheduledEgressDetails, SwapDepositHandler,
+	AccountRoleRegistry, AssetConverter, Broadcaster, CcmHandler, CcmSwapIds, Chainflip,
+	DepositApi, DepositHandler, EgressApi, EpochInfo, FeePayment, GetBlockHeight, GetTrackedData,
+	NetworkEnvironmentProvider, ScheduledEgressDetails, SwapDepositHandler,
 };
 use frame_support::{
 	pallet_prelude::*,
@@ -41,7 +45,7 @@ use frame_support::{
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use sp_runtime::traits::UniqueSaturatedInto;
-use sp_std::{vec, vec::Vec};
+use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 
 /// Enum wrapper for fetch and egress requests.
 #[derive(RuntimeDebug, Eq, PartialEq, Clone, Encode, Decode, TypeInfo)]
@@ -100,7 +104,16 @@ impl<C: Chain> CrossChainMessage<C> {
 	}
 }
 
-pub const PALLET_VERSION: StorageVersion = StorageVersion::new(6);
+/// The fee tier of a boost pool, in basis points.
+pub type BoostPoolTier = BasisPoints;
+
+const MAX_BASIS_POINTS: BasisPoints = 10_000;
+
+/// The maximum number of dust deposits tracked per deposit address and asset. Once reached, all of
+/// the dust is recovered, even if it is below the minimum deposit.
+pub const MAX_DUST_DEPOSITS_PER_ADDRESS: u32 = 10;
+
+pub const PALLET_VERSION: StorageVersion = StorageVersion::new(7);
 
 /// Calls to the external chains that has failed to be broadcast/accepted by the target chain.
 /// User can use information stored here to query for relevant information to broadcast
@@ -144,7 +157,7 @@ pub mod pallet {
 		DefaultNoBound,
 	};
 	use frame_system::WeightInfo as SystemWeightInfo;
-	use sp_std::vec::Vec;
+	use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
 	pub(crate) type ChannelRecycleQueue<T, I> =
 		Vec<(TargetChainBlockNumber<T, I>, TargetChainAccount<T, I>)>;
@@ -164,6 +177,36 @@ pub mod pallet {
 		pub deposit_details: C::DepositDetails,
 	}
 
+	#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub struct BoostPoolId<C: Chain> {
+		pub asset: C::ChainAsset,
+		pub tier: BoostPoolTier,
+	}
+
+	/// A deposit that has been credited early using funds from boost pools, and which is awaiting
+	/// finalisation.
+	#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	pub struct BoostedDeposit<C: Chain> {
+		pub prewitnessed_deposit_id: PrewitnessedDepositId,
+		pub amount: C::ChainAmount,
+		pub deposit_details: C::DepositDetails,
+		/// The ingress fee that was withheld when the deposit was boosted.
+		pub ingress_fee: C::ChainAmount,
+		/// The tiers of the pools that contributed funds to the boost.
+		pub pool_tiers: Vec<BoostPoolTier>,
+	}
+
+	/// A deposit that was ignored because it was below the minimum deposit amount, and whose funds
+	/// have not yet been recovered.
+	#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	pub struct DustDeposit<C: Chain> {
+		/// The channel the deposit was made to. Deposit addresses are recycled, so this is used
+		/// to ensure dust is only ever refunded to the owner of the channel it was sent to.
+		pub channel_id: ChannelId,
+		pub amount: C::ChainAmount,
+		pub deposit_details: C::DepositDetails,
+	}
+
 	#[derive(
 		CloneNoBound, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen,
 	)]
@@ -201,6 +244,8 @@ pub mod pallet {
 			destination_address: ForeignChainAddress,
 			broker_id: AccountId,
 			broker_commission_bps: BasisPoints,
+			refund_parameters: Option<SwapRefundParameters>,
+			dca_parameters: Option<DcaParameters>,
 		},
 		LiquidityProvision {
 			lp_account: AccountId,
@@ -209,9 +254,22 @@ pub mod pallet {
 			destination_asset: Asset,
 			destination_address: ForeignChainAddress,
 			channel_metadata: CcmChannelMetadata,
+			refund_parameters: Option<SwapRefundParameters>,
 		},
 	}
 
+	impl<AccountId> ChannelAction<AccountId> {
+		/// The address to which funds should be returned if they can't be processed, if any.
+		pub fn refund_address(&self) -> Option<&ForeignChainAddress> {
+			match self {
+				ChannelAction::Swap { refund_parameters, .. } |
+				ChannelAction::CcmTransfer { refund_parameters, .. } =>
+					refund_parameters.as_ref().map(|params| &params.refund_address),
+				ChannelAction::LiquidityProvision { .. } => None,
+			}
+		}
+	}
+
 	/// Contains identifying information about the particular actions that have occurred for a
 	/// particular deposit.
 	#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
@@ -459,6 +517,47 @@ pub mod pallet {
 	pub type ChannelOpeningFee<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, T::Amount, ValueQuery>;
 
+	/// Pools of funds provided by LPs to boost deposits, per asset and fee tier.
+	#[pallet::storage]
+	pub type BoostPools<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TargetChainAsset<T, I>,
+		Twox64Concat,
+		BoostPoolTier,
+		BoostPool<T::AccountId>,
+	>;
+
+	/// Stores the latest prewitnessed deposit id used.
+	#[pallet::storage]
+	pub type PrewitnessedDepositIdCounter<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, PrewitnessedDepositId, ValueQuery>;
+
+	/// Deposits that have been boosted but not yet finalised, keyed by deposit address. Only one
+	/// deposit per channel can be boosted at a time.
+	#[pallet::storage]
+	pub type BoostedDeposits<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		TargetChainAccount<T, I>,
+		BoostedDeposit<T::TargetChain>,
+		OptionQuery,
+	>;
+
+	/// Deposits that were ignored for being below the minimum deposit amount and that have not yet
+	/// been swept into the withheld fees or refunded, keyed by deposit address and asset. Holds at
+	/// most [MAX_DUST_DEPOSITS_PER_ADDRESS] entries per key.
+	#[pallet::storage]
+	pub type DustDeposits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		TargetChainAccount<T, I>,
+		Twox64Concat,
+		TargetChainAsset<T, I>,
+		Vec<DustDeposit<T::TargetChain>>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -541,6 +640,87 @@ pub mod pallet {
 		ChannelOpeningFeeSet {
 			fee: T::Amount,
 		},
+		BoostPoolCreated {
+			boost_pool: BoostPoolId<T::TargetChain>,
+		},
+		BoostFundsAdded {
+			booster_id: T::AccountId,
+			boost_pool: BoostPoolId<T::TargetChain>,
+			amount: TargetChainAmount<T, I>,
+		},
+		/// The booster's available funds have been returned to their free balance. Funds locked
+		/// in `pending_boosts` will be returned once those deposits are finalised.
+		StoppedBoosting {
+			booster_id: T::AccountId,
+			boost_pool: BoostPoolId<T::TargetChain>,
+			unlocked_amount: TargetChainAmount<T, I>,
+			pending_boosts: BTreeSet<PrewitnessedDepositId>,
+		},
+		/// A prewitnessed deposit has been credited using funds from the boost pools.
+		DepositBoosted {
+			deposit_address: TargetChainAccount<T, I>,
+			asset: TargetChainAsset<T, I>,
+			// The part of the deposit amount covered by each boost pool, keyed by tier.
+			amounts: BTreeMap<BoostPoolTier, TargetChainAmount<T, I>>,
+			prewitnessed_deposit_id: PrewitnessedDepositId,
+			deposit_details: <T::TargetChain as Chain>::DepositDetails,
+			ingress_fee: TargetChainAmount<T, I>,
+			boost_fee: TargetChainAmount<T, I>,
+			action: DepositAction<T::AccountId>,
+		},
+		/// A prewitnessed deposit could not be boosted because there were not enough funds in
+		/// the boost pools.
+		InsufficientBoostLiquidity {
+			prewitnessed_deposit_id: PrewitnessedDepositId,
+			asset: TargetChainAsset<T, I>,
+			amount_attempted: TargetChainAmount<T, I>,
+		},
+		/// A boosted deposit has been witnessed and the boost pools have been repaid.
+		BoostedDepositFinalised {
+			deposit_address: TargetChainAccount<T, I>,
+			asset: TargetChainAsset<T, I>,
+			amount: TargetChainAmount<T, I>,
+			prewitnessed_deposit_id: PrewitnessedDepositId,
+		},
+		/// A boosted deposit was never witnessed before its channel was recycled. The funds lent
+		/// by the boost pools are lost.
+		BoostedDepositLost {
+			deposit_address: TargetChainAccount<T, I>,
+			asset: TargetChainAsset<T, I>,
+			amount: TargetChainAmount<T, I>,
+			prewitnessed_deposit_id: PrewitnessedDepositId,
+		},
+		/// Dust accumulated in a deposit address has been fetched and added to the withheld
+		/// transaction fees.
+		DustSwept {
+			deposit_address: TargetChainAccount<T, I>,
+			asset: TargetChainAsset<T, I>,
+			amount: TargetChainAmount<T, I>,
+		},
+		/// Dust accumulated in a deposit address has been fetched and refunded to the refund
+		/// address of the channel.
+		DustRefunded {
+			deposit_address: TargetChainAccount<T, I>,
+			asset: TargetChainAsset<T, I>,
+			amount: TargetChainAmount<T, I>,
+			refund_address: TargetChainAccount<T, I>,
+			egress_id: EgressId,
+		},
+		/// Dust accumulated in a deposit address could not be refunded, usually because it
+		/// doesn't cover the fees. The dust is kept until it can be recovered.
+		DustRefundFailed {
+			deposit_address: TargetChainAccount<T, I>,
+			asset: TargetChainAsset<T, I>,
+			amount: TargetChainAmount<T, I>,
+			error: DispatchError,
+		},
+		/// The channel holding some dust expired and its address is discarded without ever having
+		/// been fetched, so the dust can't be recovered.
+		DustLost {
+			deposit_address: TargetChainAccount<T, I>,
+			asset: TargetChainAsset<T, I>,
+			amount: TargetChainAmount<T, I>,
+		},
 	}
 
 	#[derive(CloneNoBound, PartialEqNoBound, EqNoBound)]
@@ -560,6 +740,16 @@ pub mod pallet {
 		BitcoinChannelIdTooLarge,
 		/// The amount is below the minimum egress amount.
 		BelowEgressDustLimit,
+		/// No boost pool exists for the given asset and tier.
+		BoostPoolDoesNotExist,
+		/// A boost pool already exists for the given asset and tier.
+		BoostPoolAlreadyExists,
+		/// Boost pool tiers must be greater than zero and less than 100%.
+		InvalidBoostPoolTier,
+		/// The account has no funds in the boost pool.
+		AccountNotFoundInBoostPool,
+		/// The boost pool already holds funds for the maximum number of boosters.
+		TooManyBoostersInPool,
 	}
 
 	#[pallet::hooks]
@@ -583,8 +773,20 @@ pub mod pallet {
 				)
 			});
 
+			let mut dust_recovery_weight = Weight::zero();
 			for address in can_recycle.iter() {
 				if let Some(details) = DepositChannelLookup::<T, I>::take(address) {
+					if let Some(boosted_deposit) = BoostedDeposits::<T, I>::take(address) {
+						Self::on_boosted_deposit_lost(
+							address.clone(),
+							details.deposit_channel.asset,
+							boosted_deposit,
+						);
+					}
+					if Self::on_channel_with_dust_expired(address, &details) {
+						dust_recovery_weight
+							.saturating_accrue(T::WeightInfo::process_single_deposit());
+					}
 					if let Some(state) = details.deposit_channel.state.maybe_recycle() {
 						DepositChannelPool::<T, I>::insert(
 							details.deposit_channel.channel_id,
@@ -594,7 +796,9 @@ pub mod pallet {
 				}
 			}
 
-			read_write_weight.saturating_mul(can_recycle.len() as u64)
+			read_write_weight
+				.saturating_mul(can_recycle.len() as u64)
+				.saturating_add(dust_recovery_weight)
 		}
 
 		/// Take all scheduled Egress and send them out
@@ -866,6 +1070,160 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Called when funds have been deposited into the given address but the deposit has not
+		/// yet reached the witness safety margin. Deposits into channels that accept boosting are
+		/// credited immediately, using funds from the boost pools.
+		///
+		/// Requires `EnsureWitnessed` origin.
+		///
+		/// ## Events
+		///
+		/// - [On success](Event::DepositBoosted)
+		/// - [Not enough funds](Event::InsufficientBoostLiquidity)
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::process_single_deposit().saturating_mul(deposit_witnesses.len() as u64))]
+		pub fn add_prewitnessed_deposits(
+			origin: OriginFor<T>,
+			deposit_witnesses: Vec<DepositWitness<T::TargetChain>>,
+			block_height: TargetChainBlockNumber<T, I>,
+		) -> DispatchResult {
+			T::EnsureWitnessed::ensure_origin(origin)?;
+
+			for ref deposit_witness @ DepositWitness {
+				ref deposit_address,
+				asset,
+				amount,
+				ref deposit_details,
+			} in deposit_witnesses
+			{
+				Self::process_single_prewitness(
+					deposit_address.clone(),
+					asset,
+					amount,
+					deposit_details.clone(),
+					block_height,
+				)
+				.unwrap_or_else(|e| {
+					Self::deposit_event(Event::<T, I>::DepositWitnessRejected {
+						reason: e,
+						deposit_witness: deposit_witness.clone(),
+					});
+				})
+			}
+			Ok(())
+		}
+
+		/// Moves funds from the LP's free balance into a boost pool, where they can be used to
+		/// boost deposits.
+		///
+		/// Requires the LiquidityProvider role.
+		///
+		/// ## Events
+		///
+		/// - [On success](Event::BoostFundsAdded)
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::add_boost_funds())]
+		pub fn add_boost_funds(
+			origin: OriginFor<T>,
+			asset: TargetChainAsset<T, I>,
+			amount: TargetChainAmount<T, I>,
+			pool_tier: BoostPoolTier,
+		) -> DispatchResult {
+			let booster_id = T::AccountRoleRegistry::ensure_liquidity_provider(origin)?;
+
+			BoostPools::<T, I>::try_mutate(asset, pool_tier, |pool| {
+				let pool = pool.as_mut().ok_or(Error::<T, I>::BoostPoolDoesNotExist)?;
+				T::LpBalance::try_debit_account(&booster_id, asset.into(), amount.into())?;
+				pool.add_funds(booster_id.clone(), amount.into()).map_err(Error::<T, I>::from)?;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::<T, I>::BoostFundsAdded {
+				booster_id,
+				boost_pool: BoostPoolId { asset, tier: pool_tier },
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Returns the LP's available funds in a boost pool to their free balance. Funds that are
+		/// currently lent out are returned once the boosted deposits are finalised.
+		///
+		/// Requires the LiquidityProvider role.
+		///
+		/// ## Events
+		///
+		/// - [On success](Event::StoppedBoosting)
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::stop_boosting())]
+		pub fn stop_boosting(
+			origin: OriginFor<T>,
+			asset: TargetChainAsset<T, I>,
+			pool_tier: BoostPoolTier,
+		) -> DispatchResult {
+			let booster_id = T::AccountRoleRegistry::ensure_liquidity_provider(origin)?;
+
+			let (unlocked_amount, pending_boosts) =
+				BoostPools::<T, I>::try_mutate(asset, pool_tier, |pool| {
+					pool.as_mut()
+						.ok_or(Error::<T, I>::BoostPoolDoesNotExist)?
+						.stop_boosting(booster_id.clone())
+						.map_err(Error::<T, I>::from)
+				})?;
+
+			T::LpBalance::try_credit_account(&booster_id, asset.into(), unlocked_amount)?;
+
+			Self::deposit_event(Event::<T, I>::StoppedBoosting {
+				booster_id,
+				boost_pool: BoostPoolId { asset, tier: pool_tier },
+				unlocked_amount: unlocked_amount.unique_saturated_into(),
+				pending_boosts,
+			});
+
+			Ok(())
+		}
+
+		/// Creates new boost pools.
+		///
+		/// Requires Governance.
+		///
+		/// ## Events
+		///
+		/// - [On success](Event::BoostPoolCreated)
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::create_boost_pools(new_pools.len() as u32))]
+		pub fn create_boost_pools(
+			origin: OriginFor<T>,
+			new_pools: Vec<BoostPoolId<T::TargetChain>>,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			for boost_pool in new_pools {
+				ensure!(
+					boost_pool.tier > 0 && boost_pool.tier < MAX_BASIS_POINTS,
+					Error::<T, I>::InvalidBoostPoolTier
+				);
+				BoostPools::<T, I>::try_mutate_exists(boost_pool.asset, boost_pool.tier, |pool| {
+					ensure!(pool.is_none(), Error::<T, I>::BoostPoolAlreadyExists);
+					*pool = Some(BoostPool::new(boost_pool.tier));
+					Ok::<_, Error<T, I>>(())
+				})?;
+				Self::deposit_event(Event::<T, I>::BoostPoolCreated { boost_pool });
+			}
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> From<BoostPoolError> for Error<T, I> {
+	fn from(error: BoostPoolError) -> Self {
+		match error {
+			BoostPoolError::AccountNotFoundInPool => Error::<T, I>::AccountNotFoundInBoostPool,
+			BoostPoolError::TooManyBoosters => Error::<T, I>::TooManyBoostersInPool,
+		}
 	}
 }
 
@@ -1064,26 +1422,86 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		);
 
 		if deposit_amount < MinimumDeposit::<T, I>::get(asset) {
-			// If the deposit amount is below the minimum allowed, the deposit is ignored.
-			// TODO: track these funds somewhere, for example add them to the withheld fees.
+			// If the deposit amount is below the minimum allowed, the deposit is ignored. The funds
+			// are tracked as dust until there is enough to recover them, or until too many dust
+			// deposits have accumulated.
+			let dust_deposit_count =
+				DustDeposits::<T, I>::decode_len(&deposit_address, asset).unwrap_or_default();
+			DustDeposits::<T, I>::append(
+				&deposit_address,
+				asset,
+				DustDeposit {
+					channel_id,
+					amount: deposit_amount,
+					deposit_details: deposit_details.clone(),
+				},
+			);
 			Self::deposit_event(Event::<T, I>::DepositIgnored {
-				deposit_address,
+				deposit_address: deposit_address.clone(),
 				asset,
 				amount: deposit_amount,
 				deposit_details,
 				reason: DepositIgnoredReason::BelowMinimumDeposit,
 			});
+			if let Some(dust_amount) = Self::recover_dust(
+				&deposit_address,
+				asset,
+				&deposit_channel_details,
+				dust_deposit_count.saturating_add(1) >= MAX_DUST_DEPOSITS_PER_ADDRESS as usize,
+			) {
+				ScheduledEgressFetchOrTransfer::<T, I>::append(
+					FetchOrTransfer::<T::TargetChain>::Fetch {
+						asset,
+						deposit_address,
+						deposit_fetch_id: None,
+						amount: dust_amount,
+					},
+				);
+				Self::deposit_event(Event::<T, I>::DepositFetchesScheduled { channel_id, asset });
+			}
 			return Ok(())
 		}
 
+		// Any dust in the deposit address is fetched along with the deposit.
+		let dust_amount =
+			Self::recover_dust(&deposit_address, asset, &deposit_channel_details, true)
+				.unwrap_or_default();
+
 		ScheduledEgressFetchOrTransfer::<T, I>::append(FetchOrTransfer::<T::TargetChain>::Fetch {
 			asset,
 			deposit_address: deposit_address.clone(),
 			deposit_fetch_id: None,
-			amount: deposit_amount,
+			amount: deposit_amount.saturating_add(dust_amount),
 		});
 		Self::deposit_event(Event::<T, I>::DepositFetchesScheduled { channel_id, asset });
 
+		if let Some(boosted_deposit) =
+			BoostedDeposits::<T, I>::get(&deposit_address).filter(|boosted_deposit| {
+				boosted_deposit.amount == deposit_amount &&
+					boosted_deposit.deposit_details == deposit_details
+			}) {
+			// The deposit has already been credited when it was boosted, so we only need to repay
+			// the boost pools.
+			BoostedDeposits::<T, I>::remove(&deposit_address);
+			T::DepositHandler::on_deposit_made(
+				deposit_details,
+				deposit_amount,
+				deposit_channel_details.deposit_channel,
+			);
+			DepositBalances::<T, I>::mutate(asset, |deposits| {
+				deposits
+					.register_deposit(deposit_amount.saturating_sub(boosted_deposit.ingress_fee))
+			});
+			Self::repay_boost_pools(asset, &boosted_deposit)?;
+			Self::deposit_event(Event::<T, I>::BoostedDepositFinalised {
+				deposit_address,
+				asset,
+				amount: deposit_amount,
+				prewitnessed_deposit_id: boosted_deposit.prewitnessed_deposit_id,
+			});
+			return Ok(())
+		}
+
 		let AmountAndFeesWithheld { amount_after_fees, fees_withheld } =
 			Self::withhold_transaction_fee(
 				IngressOrEgress::Ingress,
@@ -1110,62 +1528,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				reason: DepositIgnoredReason::NotEnoughToPayFees,
 			});
 		} else {
-			let deposit_action = match deposit_channel_details.action {
-				ChannelAction::LiquidityProvision { lp_account, .. } => {
-					T::LpBalance::add_deposit(&lp_account, asset.into(), amount_after_fees.into())?;
-
-					DepositAction::LiquidityProvision { lp_account }
-				},
-				ChannelAction::Swap {
-					destination_address,
-					destination_asset,
-					broker_id,
-					broker_commission_bps,
-					..
-				} => DepositAction::Swap {
-					swap_id: T::SwapDepositHandler::schedule_swap_from_channel(
-						deposit_address.clone().into(),
-						block_height.into(),
-						asset.into(),
-						destination_asset,
-						amount_after_fees.into(),
-						destination_address,
-						broker_id,
-						broker_commission_bps,
-						channel_id,
-					),
-				},
-				ChannelAction::CcmTransfer {
-					destination_asset,
-					destination_address,
-					channel_metadata,
-					..
-				} => {
-					if let Ok(CcmSwapIds { principal_swap_id, gas_swap_id }) =
-						T::CcmHandler::on_ccm_deposit(
-							asset.into(),
-							amount_after_fees.into(),
-							destination_asset,
-							destination_address,
-							CcmDepositMetadata {
-								source_chain: asset.into(),
-								source_address: None,
-								channel_metadata,
-							},
-							SwapOrigin::DepositChannel {
-								deposit_address: T::AddressConverter::to_encoded_address(
-									deposit_address.clone().into(),
-								),
-								channel_id,
-								deposit_block_height: block_height.into(),
-							},
-						) {
-						DepositAction::CcmTransfer { principal_swap_id, gas_swap_id }
-					} else {
-						DepositAction::NoAction
-					}
-				},
-			};
+			let deposit_action = Self::perform_channel_action(
+				deposit_channel_details.action,
+				deposit_address.clone(),
+				asset,
+				amount_after_fees,
+				block_height,
+				channel_id,
+			)?;
 
 			Self::deposit_event(Event::DepositReceived {
 				deposit_address,
@@ -1180,6 +1550,427 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Attempts to recover the dust held in a deposit address. If the channel has a refund address
+	/// the channel's own dust is refunded, provided it is enough to cover the fees, and kept
+	/// otherwise. Any other dust is swept into the withheld transaction fees once it reaches the
+	/// minimum deposit amount, or along with a refund. If `recover_all` is set, all of the dust is
+	/// recovered, for example because the address is being fetched anyway.
+	///
+	/// Returns the amount recovered, if any. The caller is responsible for scheduling the fetch.
+	fn recover_dust(
+		deposit_address: &TargetChainAccount<T, I>,
+		asset: TargetChainAsset<T, I>,
+		deposit_channel_details: &DepositChannelDetails<T, I>,
+		recover_all: bool,
+	) -> Option<TargetChainAmount<T, I>> {
+		let dust_deposits = DustDeposits::<T, I>::get(deposit_address, asset);
+		let total_dust =
+			dust_deposits.iter().fold(TargetChainAmount::<T, I>::zero(), |total, dust| {
+				total.saturating_add(dust.amount)
+			});
+		if total_dust.is_zero() {
+			return None
+		}
+
+		let channel_id = deposit_channel_details.deposit_channel.channel_id;
+		// Dust left behind by a previous owner of a recycled address is never refunded.
+		let own_dust = dust_deposits
+			.iter()
+			.filter(|dust| dust.channel_id == channel_id)
+			.fold(TargetChainAmount::<T, I>::zero(), |total, dust| {
+				total.saturating_add(dust.amount)
+			});
+		let refund_address: Option<TargetChainAccount<T, I>> = deposit_channel_details
+			.action
+			.refund_address()
+			.and_then(|address| address.clone().try_into().ok());
+
+		let refunded = match refund_address {
+			Some(refund_address) if !own_dust.is_zero() => {
+				match Self::refund_dust(deposit_address.clone(), asset, own_dust, refund_address) {
+					Ok(()) => own_dust,
+					Err(error) => {
+						Self::deposit_event(Event::<T, I>::DustRefundFailed {
+							deposit_address: deposit_address.clone(),
+							asset,
+							amount: own_dust,
+							error,
+						});
+						if !recover_all {
+							return None
+						}
+						Zero::zero()
+					},
+				}
+			},
+			_ => Zero::zero(),
+		};
+
+		if refunded.is_zero() && !recover_all && total_dust < MinimumDeposit::<T, I>::get(asset) {
+			return None
+		}
+		let unrefunded = total_dust.saturating_sub(refunded);
+		if !unrefunded.is_zero() {
+			Self::sweep_dust(deposit_address.clone(), asset, unrefunded);
+		}
+
+		DustDeposits::<T, I>::remove(deposit_address, asset);
+		for dust in dust_deposits {
+			T::DepositHandler::on_deposit_made(
+				dust.deposit_details,
+				dust.amount,
+				deposit_channel_details.deposit_channel.clone(),
+			);
+		}
+
+		Some(total_dust)
+	}
+
+	/// Deals with the dust left in the address of an expired channel. If the address is recycled,
+	/// the dust stays with it and is recovered the next time the address is fetched. On UTXO
+	/// chains the dust can be spent without fetching the address, so it is recovered straight
+	/// away. Otherwise the address is discarded and the dust is lost.
+	///
+	/// Returns true if there was any dust to deal with.
+	fn on_channel_with_dust_expired(
+		deposit_address: &TargetChainAccount<T, I>,
+		deposit_channel_details: &DepositChannelDetails<T, I>,
+	) -> bool {
+		let asset = deposit_channel_details.deposit_channel.asset;
+		if !DustDeposits::<T, I>::contains_key(deposit_address, asset) ||
+			deposit_channel_details.deposit_channel.state.clone().maybe_recycle().is_some()
+		{
+			return false
+		}
+
+		if <<T::TargetChain as Chain>::ChainCrypto as ChainCrypto>::UtxoChain::get() {
+			Self::recover_dust(deposit_address, asset, deposit_channel_details, true);
+		} else {
+			let amount = DustDeposits::<T, I>::take(deposit_address, asset)
+				.iter()
+				.fold(TargetChainAmount::<T, I>::zero(), |total, dust| {
+					total.saturating_add(dust.amount)
+				});
+			Self::deposit_event(Event::<T, I>::DustLost {
+				deposit_address: deposit_address.clone(),
+				asset,
+				amount,
+			});
+		}
+
+		true
+	}
+
+	/// Refunds dust to the given address, net of ingress and egress fees. Fails without side
+	/// effects if the dust is not enough to cover the fees.
+	#[transactional]
+	fn refund_dust(
+		deposit_address: TargetChainAccount<T, I>,
+		asset: TargetChainAsset<T, I>,
+		amount: TargetChainAmount<T, I>,
+		refund_address: TargetChainAccount<T, I>,
+	) -> DispatchResult {
+		let AmountAndFeesWithheld { amount_after_fees, .. } =
+			Self::withhold_transaction_fee(IngressOrEgress::Ingress, asset, amount);
+		DepositBalances::<T, I>::mutate(asset, |deposits| {
+			deposits.register_deposit(amount_after_fees)
+		});
+
+		let ScheduledEgressDetails { egress_id, egress_amount, .. } =
+			Self::schedule_egress(asset, amount_after_fees, refund_address.clone(), None)?;
+
+		Self::deposit_event(Event::<T, I>::DustRefunded {
+			deposit_address,
+			asset,
+			amount: egress_amount,
+			refund_address,
+			egress_id,
+		});
+
+		Ok(())
+	}
+
+	/// Adds dust to the withheld transaction fees.
+	fn sweep_dust(
+		deposit_address: TargetChainAccount<T, I>,
+		asset: TargetChainAsset<T, I>,
+		amount: TargetChainAmount<T, I>,
+	) {
+		DepositBalances::<T, I>::mutate(asset, |deposits| deposits.register_deposit(amount));
+		WithheldTransactionFees::<T, I>::mutate(asset, |fees| fees.saturating_accrue(amount));
+
+		Self::deposit_event(Event::<T, I>::DustSwept { deposit_address, asset, amount });
+	}
+
+	/// Returns the total dust that has not yet been recovered, per deposit address, asset and
+	/// channel. Deposit addresses are recycled, so an address can hold dust sent to the channels
+	/// of previous owners.
+	pub fn unrecovered_dust() -> Vec<(ForeignChainAddress, Asset, ChannelId, AssetAmount)> {
+		DustDeposits::<T, I>::iter()
+			.flat_map(|(deposit_address, asset, dust_deposits)| {
+				dust_deposits
+					.into_iter()
+					.fold(BTreeMap::<ChannelId, AssetAmount>::new(), |mut totals, dust| {
+						totals
+							.entry(dust.channel_id)
+							.or_default()
+							.saturating_accrue(dust.amount.into());
+						totals
+					})
+					.into_iter()
+					.map(move |(channel_id, amount)| {
+						(deposit_address.clone().into(), asset.into(), channel_id, amount)
+					})
+			})
+			.collect()
+	}
+
+	/// Credits a prewitnessed deposit using funds from the boost pools, if the deposit channel
+	/// allows it. Boosted deposits are finalised by [Self::process_single_deposit].
+	#[transactional]
+	fn process_single_prewitness(
+		deposit_address: TargetChainAccount<T, I>,
+		asset: TargetChainAsset<T, I>,
+		deposit_amount: TargetChainAmount<T, I>,
+		deposit_details: <T::TargetChain as Chain>::DepositDetails,
+		block_height: TargetChainBlockNumber<T, I>,
+	) -> DispatchResult {
+		let deposit_channel_details = DepositChannelLookup::<T, I>::get(&deposit_address)
+			.ok_or(Error::<T, I>::InvalidDepositAddress)?;
+
+		ensure!(
+			deposit_channel_details.deposit_channel.asset == asset,
+			Error::<T, I>::AssetMismatch
+		);
+
+		if deposit_channel_details.boost_fee == 0 ||
+			BoostedDeposits::<T, I>::contains_key(&deposit_address) ||
+			deposit_amount < MinimumDeposit::<T, I>::get(asset)
+		{
+			return Ok(())
+		}
+
+		let prewitnessed_deposit_id = PrewitnessedDepositIdCounter::<T, I>::mutate(|id| {
+			*id = id.saturating_add(1);
+			*id
+		});
+
+		let Some((amounts, boost_fee)) = Self::try_boosting(
+			asset,
+			deposit_amount,
+			deposit_channel_details.boost_fee,
+			prewitnessed_deposit_id,
+		) else {
+			Self::deposit_event(Event::<T, I>::InsufficientBoostLiquidity {
+				prewitnessed_deposit_id,
+				asset,
+				amount_attempted: deposit_amount,
+			});
+			return Ok(())
+		};
+
+		let AmountAndFeesWithheld { amount_after_fees, fees_withheld } =
+			Self::withhold_transaction_fee(
+				IngressOrEgress::Ingress,
+				asset,
+				deposit_amount.saturating_sub(boost_fee),
+			);
+
+		let action = if amount_after_fees.is_zero() {
+			DepositAction::NoAction
+		} else {
+			Self::perform_channel_action(
+				deposit_channel_details.action,
+				deposit_address.clone(),
+				asset,
+				amount_after_fees,
+				block_height,
+				deposit_channel_details.deposit_channel.channel_id,
+			)?
+		};
+
+		BoostedDeposits::<T, I>::insert(
+			&deposit_address,
+			BoostedDeposit {
+				prewitnessed_deposit_id,
+				amount: deposit_amount,
+				deposit_details: deposit_details.clone(),
+				ingress_fee: fees_withheld,
+				pool_tiers: amounts.keys().copied().collect(),
+			},
+		);
+
+		Self::deposit_event(Event::<T, I>::DepositBoosted {
+			deposit_address,
+			asset,
+			amounts,
+			prewitnessed_deposit_id,
+			deposit_details,
+			ingress_fee: fees_withheld,
+			boost_fee,
+			action,
+		});
+
+		Ok(())
+	}
+
+	/// Attempts to cover the full deposit amount using the boost pools whose fee does not exceed
+	/// `max_boost_fee`, starting with the cheapest. The pools are only updated if the whole
+	/// amount can be covered.
+	///
+	/// Returns the part of the deposit covered by each pool and the total boost fee.
+	fn try_boosting(
+		asset: TargetChainAsset<T, I>,
+		deposit_amount: TargetChainAmount<T, I>,
+		max_boost_fee: BasisPoints,
+		prewitnessed_deposit_id: PrewitnessedDepositId,
+	) -> Option<(BTreeMap<BoostPoolTier, TargetChainAmount<T, I>>, TargetChainAmount<T, I>)> {
+		let mut pools: Vec<_> = BoostPools::<T, I>::iter_prefix(asset)
+			.filter(|(tier, _)| *tier <= max_boost_fee)
+			.collect();
+		pools.sort_by_key(|(tier, _)| *tier);
+
+		let mut remaining_amount: AssetAmount = deposit_amount.into();
+		let mut total_fee: AssetAmount = 0;
+		let mut amounts = BTreeMap::new();
+
+		for (tier, pool) in pools.iter_mut() {
+			if remaining_amount == 0 {
+				break
+			}
+			let boosted =
+				pool.provide_funds_for_boosting(prewitnessed_deposit_id, remaining_amount);
+			if boosted.deposit_amount > 0 {
+				remaining_amount.saturating_reduce(boosted.deposit_amount);
+				total_fee.saturating_accrue(boosted.fee);
+				amounts.insert(*tier, boosted.deposit_amount.unique_saturated_into());
+			}
+		}
+
+		if remaining_amount > 0 {
+			return None
+		}
+
+		for (tier, pool) in pools {
+			if amounts.contains_key(&tier) {
+				BoostPools::<T, I>::insert(asset, tier, pool);
+			}
+		}
+
+		Some((amounts, total_fee.unique_saturated_into()))
+	}
+
+	/// Repays the boost pools once a boosted deposit has been finalised. Boosters that have
+	/// stopped boosting have their share credited directly to their free balance.
+	fn repay_boost_pools(
+		asset: TargetChainAsset<T, I>,
+		boosted_deposit: &BoostedDeposit<T::TargetChain>,
+	) -> DispatchResult {
+		for tier in &boosted_deposit.pool_tiers {
+			let unlocked = BoostPools::<T, I>::mutate(asset, tier, |pool| {
+				pool.as_mut()
+					.map(|pool| pool.on_finalised_deposit(boosted_deposit.prewitnessed_deposit_id))
+					.unwrap_or_default()
+			});
+			for (booster_id, amount) in unlocked {
+				T::LpBalance::try_credit_account(&booster_id, asset.into(), amount)?;
+			}
+		}
+		Ok(())
+	}
+
+	fn on_boosted_deposit_lost(
+		deposit_address: TargetChainAccount<T, I>,
+		asset: TargetChainAsset<T, I>,
+		boosted_deposit: BoostedDeposit<T::TargetChain>,
+	) {
+		for tier in &boosted_deposit.pool_tiers {
+			BoostPools::<T, I>::mutate(asset, tier, |pool| {
+				if let Some(pool) = pool.as_mut() {
+					pool.on_lost_deposit(boosted_deposit.prewitnessed_deposit_id);
+				}
+			});
+		}
+		Self::deposit_event(Event::<T, I>::BoostedDepositLost {
+			deposit_address,
+			asset,
+			amount: boosted_deposit.amount,
+			prewitnessed_deposit_id: boosted_deposit.prewitnessed_deposit_id,
+		});
+	}
+
+	/// Performs the action associated with a deposit channel, using the deposit amount net of
+	/// any fees.
+	fn perform_channel_action(
+		action: ChannelAction<T::AccountId>,
+		deposit_address: TargetChainAccount<T, I>,
+		asset: TargetChainAsset<T, I>,
+		amount_after_fees: TargetChainAmount<T, I>,
+		block_height: TargetChainBlockNumber<T, I>,
+		channel_id: ChannelId,
+	) -> Result<DepositAction<T::AccountId>, DispatchError> {
+		Ok(match action {
+			ChannelAction::LiquidityProvision { lp_account, .. } => {
+				T::LpBalance::add_deposit(&lp_account, asset.into(), amount_after_fees.into())?;
+
+				DepositAction::LiquidityProvision { lp_account }
+			},
+			ChannelAction::Swap {
+				destination_address,
+				destination_asset,
+				broker_id,
+				broker_commission_bps,
+				refund_parameters,
+				dca_parameters,
+			} => DepositAction::Swap {
+				swap_id: T::SwapDepositHandler::schedule_swap_from_channel(
+					deposit_address.into(),
+					block_height.into(),
+					asset.into(),
+					destination_asset,
+					amount_after_fees.into(),
+					destination_address,
+					broker_id,
+					broker_commission_bps,
+					channel_id,
+					refund_parameters,
+					dca_parameters,
+				),
+			},
+			ChannelAction::CcmTransfer {
+				destination_asset,
+				destination_address,
+				channel_metadata,
+				refund_parameters,
+			} => {
+				if let Ok(CcmSwapIds { principal_swap_id, gas_swap_id }) =
+					T::CcmHandler::on_ccm_deposit(
+						asset.into(),
+						amount_after_fees.into(),
+						destination_asset,
+						destination_address,
+						CcmDepositMetadata {
+							source_chain: asset.into(),
+							source_address: None,
+							channel_metadata,
+							refund_parameters,
+						},
+						SwapOrigin::DepositChannel {
+							deposit_address: T::AddressConverter::to_encoded_address(
+								deposit_address.into(),
+							),
+							channel_id,
+							deposit_block_height: block_height.into(),
+						},
+					) {
+					DepositAction::CcmTransfer { principal_swap_id, gas_swap_id }
+				} else {
+					DepositAction::NoAction
+				}
+			},
+		})
+	}
+
 	fn expiry_and_recycle_block_height(
 	) -> (TargetChainBlockNumber<T, I>, TargetChainBlockNumber<T, I>, TargetChainBlockNumber<T, I>)
 	{
@@ -1324,7 +2115,7 @@ impl<T: Config<I>, I: 'static> EgressApi<T::TargetChain> for Pallet<T, I> {
 
 			match maybe_ccm_with_gas_budget {
 				Some((
-					CcmDepositMetadata { source_chain, source_address, channel_metadata },
+					CcmDepositMetadata { source_chain, source_address, channel_metadata, .. },
 					gas_budget,
 				)) => {
 					ScheduledEgressCcm::<T, I>::append(CrossChainMessage {
@@ -1419,6 +2210,8 @@ impl<T: Config<I>, I: 'static> DepositApi<T::TargetChain> for Pallet<T, I> {
 		broker_id: T::AccountId,
 		channel_metadata: Option<CcmChannelMetadata>,
 		boost_fee: BasisPoints,
+		refund_parameters: Option<SwapRefundParameters>,
+		dca_parameters: Option<DcaParameters>,
 	) -> Result<
 		(ChannelId, ForeignChainAddress, <T::TargetChain as Chain>::ChainBlockNumber),
 		DispatchError,
@@ -1431,12 +2224,15 @@ impl<T: Config<I>, I: 'static> DepositApi<T::TargetChain> for Pallet<T, I> {
 					destination_asset,
 					destination_address,
 					channel_metadata: msg,
+					refund_parameters,
 				},
 				None => ChannelAction::Swap {
 					destination_asset,
 					destination_address,
 					broker_commission_bps,
 					broker_id: broker_id.clone(),
+					refund_parameters,
+					dca_parameters,
 				},
 			},
 			boost_fee,
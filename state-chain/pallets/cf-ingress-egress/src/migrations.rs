@@ -1,4 +1,5 @@
 pub mod btc_deposit_channels;
+pub mod channel_refund_parameters;
 pub mod deposit_channels_with_boost_fee;
 pub mod remove_old_storage;
 pub mod set_dust_limit;
@@ -10,4 +11,5 @@ pub type PalletMigration<T, I> = (
 	VersionedMigration<crate::Pallet<T, I>, set_dust_limit::Migration<T, I>, 3, 4>,
 	VersionedMigration<crate::Pallet<T, I>, deposit_channels_with_boost_fee::Migration<T, I>, 4, 5>,
 	VersionedMigration<crate::Pallet<T, I>, remove_old_storage::Migration<T, I>, 5, 6>,
+	VersionedMigration<crate::Pallet<T, I>, channel_refund_parameters::Migration<T, I>, 6, 7>,
 );
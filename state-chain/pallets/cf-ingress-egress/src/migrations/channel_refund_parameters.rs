@@ -0,0 +1,167 @@
+use crate::*;
+use cf_chains::DepositChannel;
+use frame_support::traits::OnRuntimeUpgrade;
+pub struct Migration<T: Config<I>, I: 'static>(PhantomData<(T, I)>);
+
+mod old {
+	use super::*;
+
+	#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	pub enum ChannelAction<AccountId> {
+		Swap {
+			destination_asset: Asset,
+			destination_address: ForeignChainAddress,
+			broker_id: AccountId,
+			broker_commission_bps: BasisPoints,
+		},
+		LiquidityProvision {
+			lp_account: AccountId,
+		},
+		CcmTransfer {
+			destination_asset: Asset,
+			destination_address: ForeignChainAddress,
+			channel_metadata: CcmChannelMetadata,
+		},
+	}
+
+	#[derive(CloneNoBound, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	#[scale_info(skip_type_params(T, I))]
+	pub struct DepositChannelDetails<T: Config<I>, I: 'static> {
+		pub deposit_channel: DepositChannel<T::TargetChain>,
+		pub opened_at: TargetChainBlockNumber<T, I>,
+		pub expires_at: TargetChainBlockNumber<T, I>,
+		pub action: ChannelAction<T::AccountId>,
+		pub boost_fee: BasisPoints,
+	}
+
+	#[frame_support::storage_alias]
+	pub type DepositChannelLookup<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Twox64Concat,
+		TargetChainAccount<T, I>,
+		old::DepositChannelDetails<T, I>,
+		OptionQuery,
+	>;
+}
+
+impl<AccountId> From<old::ChannelAction<AccountId>> for ChannelAction<AccountId> {
+	fn from(old_action: old::ChannelAction<AccountId>) -> Self {
+		match old_action {
+			old::ChannelAction::Swap {
+				destination_asset,
+				destination_address,
+				broker_id,
+				broker_commission_bps,
+			} => ChannelAction::Swap {
+				destination_asset,
+				destination_address,
+				broker_id,
+				broker_commission_bps,
+				refund_parameters: None,
+				dca_parameters: None,
+			},
+			old::ChannelAction::LiquidityProvision { lp_account } =>
+				ChannelAction::LiquidityProvision { lp_account },
+			old::ChannelAction::CcmTransfer {
+				destination_asset,
+				destination_address,
+				channel_metadata,
+			} => ChannelAction::CcmTransfer {
+				destination_asset,
+				destination_address,
+				channel_metadata,
+				refund_parameters: None,
+			},
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for Migration<T, I> {
+	fn on_runtime_upgrade() -> Weight {
+		DepositChannelLookup::<T, I>::translate(
+			|_address, old_channel: old::DepositChannelDetails<T, I>| {
+				Some(DepositChannelDetails::<T, I> {
+					deposit_channel: old_channel.deposit_channel,
+					opened_at: old_channel.opened_at,
+					expires_at: old_channel.expires_at,
+					action: old_channel.action.into(),
+					boost_fee: old_channel.boost_fee,
+				})
+			},
+		);
+		Weight::zero()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		let number_of_channels_in_lookup =
+			old::DepositChannelLookup::<T, I>::iter_keys().count() as u32;
+
+		Ok(number_of_channels_in_lookup.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), DispatchError> {
+		let number_of_channels_in_lookup_pre_migration = <u32>::decode(&mut &state[..]).unwrap();
+		ensure!(
+			DepositChannelLookup::<T, I>::iter_keys().count() as u32 ==
+				number_of_channels_in_lookup_pre_migration,
+			"DepositChannelLookup migration failed."
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod migration_tests {
+	use super::*;
+	use crate::mock_eth::{new_test_ext, Test};
+	use cf_chains::{evm::DeploymentStatus, Ethereum};
+	use sp_core::H160;
+
+	#[test]
+	fn test_migration() {
+		new_test_ext().execute_with(|| {
+			let address = H160::from([1u8; 20]);
+			let destination_address = ForeignChainAddress::Eth(H160::from([2u8; 20]));
+
+			old::DepositChannelLookup::<Test, ()>::insert(
+				address,
+				old::DepositChannelDetails::<Test, ()> {
+					deposit_channel: DepositChannel {
+						channel_id: 123,
+						address,
+						asset: <Ethereum as Chain>::ChainAsset::Eth,
+						state: DeploymentStatus::Undeployed,
+					},
+					opened_at: Default::default(),
+					expires_at: Default::default(),
+					action: old::ChannelAction::Swap {
+						destination_asset: Asset::Flip,
+						destination_address: destination_address.clone(),
+						broker_id: 1,
+						broker_commission_bps: 10,
+					},
+					boost_fee: 5,
+				},
+			);
+
+			crate::migrations::channel_refund_parameters::Migration::<Test, ()>::on_runtime_upgrade(
+			);
+
+			let channel = DepositChannelLookup::<Test, ()>::get(address).unwrap();
+			assert_eq!(channel.boost_fee, 5);
+			assert_eq!(
+				channel.action,
+				ChannelAction::Swap {
+					destination_asset: Asset::Flip,
+					destination_address,
+					broker_id: 1,
+					broker_commission_bps: 10,
+					refund_parameters: None,
+					dca_parameters: None,
+				}
+			);
+		});
+	}
+}
@@ -85,6 +85,8 @@ impl SwapDepositHandler for MockSwapDepositHandlerBtc {
 		_broker_id: Self::AccountId,
 		_broker_commission_bps: cf_primitives::BasisPoints,
 		_channel_id: ChannelId,
+		_refund_parameters: Option<cf_chains::SwapRefundParameters>,
+		_dca_parameters: Option<cf_chains::DcaParameters>,
 	) -> SwapId {
 		unimplemented!()
 	}
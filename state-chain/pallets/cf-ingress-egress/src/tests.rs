@@ -1,14 +1,14 @@
 use crate::{
 	mock_eth::*, Call as PalletCall, ChannelAction, ChannelIdCounter, ChannelOpeningFee,
 	CrossChainMessage, DepositAction, DepositChannelLookup, DepositChannelPool,
-	DepositIgnoredReason, DepositWitness, DisabledEgressAssets, EgressDustLimit,
+	DepositIgnoredReason, DepositWitness, DisabledEgressAssets, DustDeposits, EgressDustLimit,
 	Event as PalletEvent, FailedForeignChainCall, FailedForeignChainCalls, FetchOrTransfer,
 	MinimumDeposit, Pallet, PalletConfigUpdate, ScheduledEgressCcm, ScheduledEgressFetchOrTransfer,
-	TargetChainAccount,
+	TargetChainAccount, WithheldTransactionFees,
 };
 use cf_chains::{
 	address::AddressConverter, evm::EvmFetchId, mocks::MockEthereum, CcmChannelMetadata,
-	DepositChannel, ExecutexSwapAndCall, SwapOrigin, TransferAssetParams,
+	DepositChannel, ExecutexSwapAndCall, SwapOrigin, SwapRefundParameters, TransferAssetParams,
 };
 use cf_primitives::{chains::assets::eth, ChannelId, ForeignChain};
 use cf_test_utilities::assert_has_event;
@@ -102,6 +102,7 @@ fn blacklisted_asset_will_not_egress_via_ccm() {
 				gas_budget: 1_000,
 				cf_parameters: vec![].try_into().unwrap(),
 			},
+			refund_parameters: None,
 		};
 
 		assert!(DisabledEgressAssets::<Test>::get(asset).is_none());
@@ -468,6 +469,7 @@ fn can_process_ccm_deposit() {
 			source_chain: ForeignChain::Ethereum,
 			source_address: None,
 			channel_metadata: channel_metadata.clone(),
+			refund_parameters: None,
 		};
 		let amount = 5_000;
 
@@ -481,6 +483,8 @@ fn can_process_ccm_deposit() {
 			1,
 			Some(channel_metadata),
 			0,
+			None,
+			None,
 		)
 		.unwrap();
 
@@ -532,7 +536,8 @@ fn can_egress_ccm() {
 				message: vec![0x00, 0x01, 0x02].try_into().unwrap(),
 				gas_budget: GAS_BUDGET,
 				cf_parameters: vec![].try_into().unwrap(),
-			}
+			},
+			refund_parameters: None,
 		};
 
 		let amount = 5_000;
@@ -973,6 +978,175 @@ fn deposits_ingress_fee_exceeding_deposit_amount_rejected() {
 	});
 }
 
+fn set_minimum_deposit(asset: eth::Asset, minimum_deposit: u128) {
+	assert_ok!(IngressEgress::update_pallet_config(
+		RuntimeOrigin::root(),
+		vec![PalletConfigUpdate::<Test, _>::SetMinimumDeposit { asset, minimum_deposit }]
+			.try_into()
+			.unwrap()
+	));
+}
+
+#[test]
+fn dust_is_swept_once_it_reaches_minimum_deposit() {
+	new_test_ext().execute_with(|| {
+		set_minimum_deposit(ETH_ETH, 1_500);
+
+		let (channel_id, deposit_address) = request_address_and_deposit(ALICE, ETH_ETH);
+		assert_eq!(DustDeposits::<Test>::get(deposit_address, ETH_ETH).len(), 1);
+		assert_eq!(
+			IngressEgress::unrecovered_dust(),
+			vec![(ForeignChainAddress::Eth(deposit_address), Asset::Eth, channel_id, 1_000)]
+		);
+		assert!(ScheduledEgressFetchOrTransfer::<Test>::get().is_empty());
+
+		// The accumulated dust now exceeds the minimum deposit, so it is swept.
+		assert_ok!(IngressEgress::process_single_deposit(
+			deposit_address,
+			ETH_ETH,
+			800,
+			(),
+			Default::default()
+		));
+		assert_has_event::<Test>(RuntimeEvent::IngressEgress(crate::Event::DustSwept {
+			deposit_address,
+			asset: ETH_ETH,
+			amount: 1_800,
+		}));
+		assert_eq!(WithheldTransactionFees::<Test>::get(ETH_ETH), 1_800);
+		assert!(!DustDeposits::<Test>::contains_key(deposit_address, ETH_ETH));
+		assert!(IngressEgress::unrecovered_dust().is_empty());
+		assert!(matches!(
+			&ScheduledEgressFetchOrTransfer::<Test>::get()[..],
+			&[FetchOrTransfer::<Ethereum>::Fetch { asset: ETH_ETH, amount: 1_800, .. }]
+		));
+	});
+}
+
+#[test]
+fn dust_is_fetched_with_the_next_valid_deposit() {
+	new_test_ext().execute_with(|| {
+		set_minimum_deposit(ETH_ETH, 1_500);
+
+		let (_, deposit_address) = request_address_and_deposit(ALICE, ETH_ETH);
+		assert_ok!(IngressEgress::process_single_deposit(
+			deposit_address,
+			ETH_ETH,
+			2_000,
+			(),
+			Default::default()
+		));
+
+		assert_has_event::<Test>(RuntimeEvent::IngressEgress(crate::Event::DustSwept {
+			deposit_address,
+			asset: ETH_ETH,
+			amount: 1_000,
+		}));
+		System::assert_last_event(RuntimeEvent::IngressEgress(
+			crate::Event::<Test>::DepositReceived {
+				deposit_address,
+				asset: ETH_ETH,
+				amount: 2_000,
+				deposit_details: (),
+				ingress_fee: 0,
+				action: DepositAction::LiquidityProvision { lp_account: ALICE },
+			},
+		));
+		assert_eq!(WithheldTransactionFees::<Test>::get(ETH_ETH), 1_000);
+		assert!(IngressEgress::unrecovered_dust().is_empty());
+		assert!(matches!(
+			&ScheduledEgressFetchOrTransfer::<Test>::get()[..],
+			&[FetchOrTransfer::<Ethereum>::Fetch { asset: ETH_ETH, amount: 3_000, .. }]
+		));
+	});
+}
+
+fn request_address_with_refund_address() -> (ChannelId, TargetChainAccount<Test, ()>) {
+	let (channel_id, deposit_address, ..) = IngressEgress::request_swap_deposit_address(
+		ETH_ETH,
+		Asset::Flip,
+		ForeignChainAddress::Eth(BOB_ETH_ADDRESS),
+		0,
+		BROKER,
+		None,
+		0,
+		Some(SwapRefundParameters {
+			min_output: 1,
+			refund_address: ForeignChainAddress::Eth(ALICE_ETH_ADDRESS),
+		}),
+		None,
+	)
+	.unwrap();
+	(channel_id, deposit_address.try_into().unwrap())
+}
+
+#[test]
+fn dust_is_refunded_once_it_covers_the_fees() {
+	const DUST_AMOUNT: u128 = 500;
+
+	new_test_ext().execute_with(|| {
+		set_minimum_deposit(ETH_ETH, 1_500);
+		TrackedDataProvider::<Ethereum>::set_tracked_data(cf_chains::eth::EthereumTrackedData {
+			base_fee: 100,
+			priority_fee: 0,
+		});
+
+		let (channel_id, deposit_address) = request_address_with_refund_address();
+
+		// The dust can't cover the fees, so it is kept until more funds arrive.
+		assert_ok!(IngressEgress::process_single_deposit(
+			deposit_address,
+			ETH_ETH,
+			DUST_AMOUNT,
+			(),
+			Default::default()
+		));
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::IngressEgress(crate::Event::DustRefundFailed { amount: DUST_AMOUNT, .. })
+		)));
+		assert_eq!(
+			IngressEgress::unrecovered_dust(),
+			vec![(ForeignChainAddress::Eth(deposit_address), Asset::Eth, channel_id, DUST_AMOUNT)]
+		);
+		assert!(ScheduledEgressFetchOrTransfer::<Test>::get().is_empty());
+		assert_eq!(WithheldTransactionFees::<Test>::get(ETH_ETH), 0);
+
+		TrackedDataProvider::<Ethereum>::set_tracked_data(cf_chains::eth::EthereumTrackedData {
+			base_fee: 0,
+			priority_fee: 0,
+		});
+		assert_ok!(IngressEgress::process_single_deposit(
+			deposit_address,
+			ETH_ETH,
+			DUST_AMOUNT,
+			(),
+			Default::default()
+		));
+
+		System::assert_has_event(RuntimeEvent::IngressEgress(crate::Event::DustRefunded {
+			deposit_address,
+			asset: ETH_ETH,
+			amount: 2 * DUST_AMOUNT,
+			refund_address: ALICE_ETH_ADDRESS,
+			egress_id: (ForeignChain::Ethereum, 1),
+		}));
+		assert!(IngressEgress::unrecovered_dust().is_empty());
+		assert!(matches!(
+			&ScheduledEgressFetchOrTransfer::<Test>::get()[..],
+			&[
+				FetchOrTransfer::<Ethereum>::Transfer {
+					asset: ETH_ETH,
+					amount: 1_000,
+					destination_address: ALICE_ETH_ADDRESS,
+					..
+				},
+				FetchOrTransfer::<Ethereum>::Fetch { asset: ETH_ETH, amount: 1_000, .. },
+			]
+		));
+	});
+}
+
 #[test]
 fn handle_pending_deployment() {
 	const ETH: eth::Asset = eth::Asset::Eth;
@@ -1576,3 +1750,480 @@ fn can_update_multiple_items_at_once() {
 		assert_eq!(MinimumDeposit::<Test, _>::get(eth::Asset::Eth), 200);
 	});
 }
+
+mod boost {
+	use super::*;
+	use crate::{BoostPoolId, BoostPoolTier, BoostPools, BoostedDeposits, Error};
+	use cf_primitives::BasisPoints;
+	use cf_traits::{
+		mocks::{account_role_registry::MockAccountRoleRegistry, lp_balance::MockBalance},
+		AccountRoleRegistry, LpBalanceApi,
+	};
+	use frame_support::assert_noop;
+	use std::collections::{BTreeMap, BTreeSet};
+
+	const BOOSTER: u64 = 789;
+	const LP_ACCOUNT: u64 = 790;
+	const TIER_5_BPS: BoostPoolTier = 5;
+	const TIER_10_BPS: BoostPoolTier = 10;
+
+	fn setup_boost_pools(funds: &[(BoostPoolTier, u128)]) {
+		MockBalance::enforce_balances();
+		assert_ok!(IngressEgress::create_boost_pools(
+			RuntimeOrigin::root(),
+			vec![
+				BoostPoolId { asset: ETH_ETH, tier: TIER_5_BPS },
+				BoostPoolId { asset: ETH_ETH, tier: TIER_10_BPS },
+			]
+		));
+		assert_ok!(
+			<MockAccountRoleRegistry as AccountRoleRegistry<Test>>::register_as_liquidity_provider(
+				&BOOSTER
+			)
+		);
+
+		for (tier, amount) in funds {
+			assert_ok!(MockBalance::try_credit_account(&BOOSTER, ETH_ETH.into(), *amount));
+			assert_ok!(IngressEgress::add_boost_funds(
+				RuntimeOrigin::signed(BOOSTER),
+				ETH_ETH,
+				*amount,
+				*tier
+			));
+		}
+	}
+
+	fn open_boosted_lp_channel(boost_fee: BasisPoints) -> TargetChainAccount<Test, ()> {
+		let (_, address, ..) =
+			IngressEgress::request_liquidity_deposit_address(LP_ACCOUNT, ETH_ETH, boost_fee)
+				.unwrap();
+		address.try_into().unwrap()
+	}
+
+	fn witness(
+		deposit_address: TargetChainAccount<Test, ()>,
+		amount: u128,
+	) -> Vec<DepositWitness<Ethereum>> {
+		vec![DepositWitness { deposit_address, asset: ETH_ETH, amount, deposit_details: () }]
+	}
+
+	fn available_in_pool(tier: BoostPoolTier) -> u128 {
+		BoostPools::<Test>::get(ETH_ETH, tier).unwrap().available_amount()
+	}
+
+	#[test]
+	fn can_create_boost_pools() {
+		new_test_ext().execute_with(|| {
+			let boost_pool = BoostPoolId { asset: ETH_ETH, tier: TIER_5_BPS };
+			assert_ok!(IngressEgress::create_boost_pools(
+				RuntimeOrigin::root(),
+				vec![boost_pool.clone()]
+			));
+			System::assert_last_event(RuntimeEvent::IngressEgress(PalletEvent::BoostPoolCreated {
+				boost_pool: boost_pool.clone(),
+			}));
+
+			assert_noop!(
+				IngressEgress::create_boost_pools(RuntimeOrigin::root(), vec![boost_pool]),
+				Error::<Test, ()>::BoostPoolAlreadyExists
+			);
+			for tier in [0, 10_000] {
+				assert_noop!(
+					IngressEgress::create_boost_pools(
+						RuntimeOrigin::root(),
+						vec![BoostPoolId { asset: ETH_ETH, tier }]
+					),
+					Error::<Test, ()>::InvalidBoostPoolTier
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn adding_boost_funds_requires_pool_and_balance() {
+		new_test_ext().execute_with(|| {
+			setup_boost_pools(&[]);
+
+			assert_noop!(
+				IngressEgress::add_boost_funds(RuntimeOrigin::signed(BOOSTER), ETH_ETH, 1_000, 7),
+				Error::<Test, ()>::BoostPoolDoesNotExist
+			);
+			assert!(IngressEgress::add_boost_funds(
+				RuntimeOrigin::signed(BOOSTER),
+				ETH_ETH,
+				1_000,
+				TIER_5_BPS
+			)
+			.is_err());
+
+			assert_ok!(MockBalance::try_credit_account(&BOOSTER, ETH_ETH.into(), 1_000));
+			assert_ok!(IngressEgress::add_boost_funds(
+				RuntimeOrigin::signed(BOOSTER),
+				ETH_ETH,
+				1_000,
+				TIER_5_BPS
+			));
+			assert_eq!(MockBalance::get_balance(&BOOSTER, ETH_ETH.into()), 0);
+			assert_eq!(available_in_pool(TIER_5_BPS), 1_000);
+		});
+	}
+
+	#[test]
+	fn can_boost_deposit_and_repay_boosters() {
+		const DEPOSIT_AMOUNT: u128 = 250_000;
+		new_test_ext().execute_with(|| {
+			setup_boost_pools(&[(TIER_5_BPS, 200_000), (TIER_10_BPS, 100_000)]);
+			let deposit_address = open_boosted_lp_channel(TIER_10_BPS);
+
+			assert_ok!(IngressEgress::add_prewitnessed_deposits(
+				RuntimeOrigin::root(),
+				witness(deposit_address, DEPOSIT_AMOUNT),
+				Default::default(),
+			));
+
+			// The cheapest pool is used first: it covers 200_100 for a fee of 100. The remaining
+			// 49_900 is covered by the 10bps pool for a fee of 49.
+			System::assert_last_event(RuntimeEvent::IngressEgress(PalletEvent::DepositBoosted {
+				deposit_address,
+				asset: ETH_ETH,
+				amounts: BTreeMap::from([(TIER_5_BPS, 200_100), (TIER_10_BPS, 49_900)]),
+				prewitnessed_deposit_id: 1,
+				deposit_details: (),
+				ingress_fee: 0,
+				boost_fee: 149,
+				action: DepositAction::LiquidityProvision { lp_account: LP_ACCOUNT },
+			}));
+			assert_eq!(MockBalance::get_balance(&LP_ACCOUNT, ETH_ETH.into()), DEPOSIT_AMOUNT - 149);
+			assert_eq!(available_in_pool(TIER_5_BPS), 0);
+			assert_eq!(available_in_pool(TIER_10_BPS), 100_000 - 49_851);
+
+			// Prewitnessing the same deposit again has no effect.
+			assert_ok!(IngressEgress::add_prewitnessed_deposits(
+				RuntimeOrigin::root(),
+				witness(deposit_address, DEPOSIT_AMOUNT),
+				Default::default(),
+			));
+			assert_eq!(MockBalance::get_balance(&LP_ACCOUNT, ETH_ETH.into()), DEPOSIT_AMOUNT - 149);
+
+			assert_ok!(IngressEgress::process_deposits(
+				RuntimeOrigin::root(),
+				witness(deposit_address, DEPOSIT_AMOUNT),
+				Default::default(),
+			));
+			System::assert_last_event(RuntimeEvent::IngressEgress(
+				PalletEvent::BoostedDepositFinalised {
+					deposit_address,
+					asset: ETH_ETH,
+					amount: DEPOSIT_AMOUNT,
+					prewitnessed_deposit_id: 1,
+				},
+			));
+
+			// The LP is not credited twice, and the boosters are repaid including fees.
+			assert_eq!(MockBalance::get_balance(&LP_ACCOUNT, ETH_ETH.into()), DEPOSIT_AMOUNT - 149);
+			assert_eq!(available_in_pool(TIER_5_BPS), 200_100);
+			assert_eq!(available_in_pool(TIER_10_BPS), 100_049);
+			assert!(BoostedDeposits::<Test>::get(deposit_address).is_none());
+		});
+	}
+
+	#[test]
+	fn pools_above_channel_boost_fee_are_not_used() {
+		new_test_ext().execute_with(|| {
+			setup_boost_pools(&[(TIER_5_BPS, 1_000), (TIER_10_BPS, 100_000)]);
+			let deposit_address = open_boosted_lp_channel(TIER_5_BPS);
+
+			assert_ok!(IngressEgress::add_prewitnessed_deposits(
+				RuntimeOrigin::root(),
+				witness(deposit_address, 10_000),
+				Default::default(),
+			));
+			System::assert_last_event(RuntimeEvent::IngressEgress(
+				PalletEvent::InsufficientBoostLiquidity {
+					prewitnessed_deposit_id: 1,
+					asset: ETH_ETH,
+					amount_attempted: 10_000,
+				},
+			));
+			assert_eq!(available_in_pool(TIER_5_BPS), 1_000);
+			assert_eq!(available_in_pool(TIER_10_BPS), 100_000);
+
+			// The deposit is processed as usual once it is witnessed.
+			assert_ok!(IngressEgress::process_deposits(
+				RuntimeOrigin::root(),
+				witness(deposit_address, 10_000),
+				Default::default(),
+			));
+			assert_eq!(MockBalance::get_balance(&LP_ACCOUNT, ETH_ETH.into()), 10_000);
+		});
+	}
+
+	#[test]
+	fn channels_without_boost_fee_are_not_boosted() {
+		new_test_ext().execute_with(|| {
+			setup_boost_pools(&[(TIER_5_BPS, 100_000)]);
+			let deposit_address = open_boosted_lp_channel(0);
+
+			assert_ok!(IngressEgress::add_prewitnessed_deposits(
+				RuntimeOrigin::root(),
+				witness(deposit_address, 10_000),
+				Default::default(),
+			));
+			assert!(BoostedDeposits::<Test>::get(deposit_address).is_none());
+			assert_eq!(MockBalance::get_balance(&LP_ACCOUNT, ETH_ETH.into()), 0);
+		});
+	}
+
+	#[test]
+	fn stopped_boosters_are_repaid_on_finalisation() {
+		new_test_ext().execute_with(|| {
+			setup_boost_pools(&[(TIER_5_BPS, 30_000)]);
+			let deposit_address = open_boosted_lp_channel(TIER_5_BPS);
+
+			assert_ok!(IngressEgress::add_prewitnessed_deposits(
+				RuntimeOrigin::root(),
+				witness(deposit_address, 20_000),
+				Default::default(),
+			));
+
+			assert_ok!(IngressEgress::stop_boosting(
+				RuntimeOrigin::signed(BOOSTER),
+				ETH_ETH,
+				TIER_5_BPS
+			));
+			System::assert_last_event(RuntimeEvent::IngressEgress(PalletEvent::StoppedBoosting {
+				booster_id: BOOSTER,
+				boost_pool: BoostPoolId { asset: ETH_ETH, tier: TIER_5_BPS },
+				unlocked_amount: 10_010,
+				pending_boosts: BTreeSet::from([1]),
+			}));
+			assert_eq!(MockBalance::get_balance(&BOOSTER, ETH_ETH.into()), 10_010);
+
+			assert_ok!(IngressEgress::process_deposits(
+				RuntimeOrigin::root(),
+				witness(deposit_address, 20_000),
+				Default::default(),
+			));
+			assert_eq!(MockBalance::get_balance(&BOOSTER, ETH_ETH.into()), 30_010);
+			assert_eq!(available_in_pool(TIER_5_BPS), 0);
+
+			assert_noop!(
+				IngressEgress::stop_boosting(RuntimeOrigin::signed(BOOSTER), ETH_ETH, TIER_5_BPS),
+				Error::<Test, ()>::AccountNotFoundInBoostPool
+			);
+		});
+	}
+
+	#[test]
+	fn boosted_deposit_is_lost_if_never_witnessed() {
+		new_test_ext().execute_with(|| {
+			setup_boost_pools(&[(TIER_5_BPS, 30_000)]);
+			let deposit_address = open_boosted_lp_channel(TIER_5_BPS);
+
+			assert_ok!(IngressEgress::add_prewitnessed_deposits(
+				RuntimeOrigin::root(),
+				witness(deposit_address, 20_000),
+				Default::default(),
+			));
+
+			let recycle_block = IngressEgress::expiry_and_recycle_block_height().2;
+			BlockHeightProvider::<MockEthereum>::set_block_height(recycle_block);
+			IngressEgress::on_idle(1, Weight::MAX);
+
+			System::assert_has_event(RuntimeEvent::IngressEgress(
+				PalletEvent::BoostedDepositLost {
+					deposit_address,
+					asset: ETH_ETH,
+					amount: 20_000,
+					prewitnessed_deposit_id: 1,
+				},
+			));
+			assert!(BoostedDeposits::<Test>::get(deposit_address).is_none());
+			assert_eq!(
+				BoostPools::<Test>::get(ETH_ETH, TIER_5_BPS).unwrap().pending_boosts().count(),
+				0
+			);
+			assert_eq!(available_in_pool(TIER_5_BPS), 10_010);
+		});
+	}
+}
+
+#[test]
+fn dust_of_previous_owners_is_reported_and_swept_but_not_refunded() {
+	const PREVIOUS_CHANNEL_ID: ChannelId = 100;
+
+	new_test_ext().execute_with(|| {
+		set_minimum_deposit(ETH_ETH, 1_500);
+
+		let (channel_id, deposit_address) = request_address_with_refund_address();
+		// Dust sent to the address while it belonged to a previous channel.
+		DustDeposits::<Test>::append(
+			deposit_address,
+			ETH_ETH,
+			crate::DustDeposit::<Ethereum> {
+				channel_id: PREVIOUS_CHANNEL_ID,
+				amount: 300,
+				deposit_details: (),
+			},
+		);
+		assert_eq!(
+			IngressEgress::unrecovered_dust(),
+			vec![(ForeignChainAddress::Eth(deposit_address), Asset::Eth, PREVIOUS_CHANNEL_ID, 300)]
+		);
+
+		assert_ok!(IngressEgress::process_single_deposit(
+			deposit_address,
+			ETH_ETH,
+			500,
+			(),
+			Default::default()
+		));
+
+		// Only the channel's own dust is refunded, the rest is swept since it is fetched anyway.
+		System::assert_has_event(RuntimeEvent::IngressEgress(crate::Event::DustRefunded {
+			deposit_address,
+			asset: ETH_ETH,
+			amount: 500,
+			refund_address: ALICE_ETH_ADDRESS,
+			egress_id: (ForeignChain::Ethereum, 1),
+		}));
+		assert_has_event::<Test>(RuntimeEvent::IngressEgress(crate::Event::DustSwept {
+			deposit_address,
+			asset: ETH_ETH,
+			amount: 300,
+		}));
+		assert_eq!(WithheldTransactionFees::<Test>::get(ETH_ETH), 300);
+		assert!(IngressEgress::unrecovered_dust().is_empty());
+		assert!(ScheduledEgressFetchOrTransfer::<Test>::get().iter().any(
+			|fetch_or_transfer| matches!(
+				fetch_or_transfer,
+				FetchOrTransfer::<Ethereum>::Fetch { asset: ETH_ETH, amount: 800, .. }
+			)
+		));
+		assert_ne!(channel_id, PREVIOUS_CHANNEL_ID);
+	});
+}
+
+#[test]
+fn dust_is_recovered_once_the_maximum_number_of_dust_deposits_is_reached() {
+	const DUST_AMOUNT: u128 = 1_000;
+	const MAX_DUST_DEPOSITS: u128 = crate::MAX_DUST_DEPOSITS_PER_ADDRESS as u128;
+
+	new_test_ext().execute_with(|| {
+		set_minimum_deposit(ETH_ETH, 1_000_000);
+
+		let (_, deposit_address) = request_address_and_deposit(ALICE, ETH_ETH);
+		for _ in 1..MAX_DUST_DEPOSITS - 1 {
+			assert_ok!(IngressEgress::process_single_deposit(
+				deposit_address,
+				ETH_ETH,
+				DUST_AMOUNT,
+				(),
+				Default::default()
+			));
+		}
+		assert_eq!(
+			DustDeposits::<Test>::get(deposit_address, ETH_ETH).len() as u128,
+			MAX_DUST_DEPOSITS - 1
+		);
+		assert!(ScheduledEgressFetchOrTransfer::<Test>::get().is_empty());
+
+		// The dust is still below the minimum deposit, but no more dust deposits can be tracked.
+		assert_ok!(IngressEgress::process_single_deposit(
+			deposit_address,
+			ETH_ETH,
+			DUST_AMOUNT,
+			(),
+			Default::default()
+		));
+		assert_has_event::<Test>(RuntimeEvent::IngressEgress(crate::Event::DustSwept {
+			deposit_address,
+			asset: ETH_ETH,
+			amount: MAX_DUST_DEPOSITS * DUST_AMOUNT,
+		}));
+		assert!(!DustDeposits::<Test>::contains_key(deposit_address, ETH_ETH));
+		assert!(matches!(
+			&ScheduledEgressFetchOrTransfer::<Test>::get()[..],
+			&[FetchOrTransfer::<Ethereum>::Fetch { asset: ETH_ETH, amount, .. }]
+				if amount == MAX_DUST_DEPOSITS * DUST_AMOUNT
+		));
+	});
+}
+
+#[test]
+fn dust_is_lost_when_the_channel_expires_and_its_address_is_discarded() {
+	new_test_ext().execute_with(|| {
+		set_minimum_deposit(ETH_ETH, 1_500);
+
+		// The address is never fetched, so it is still undeployed when the channel expires.
+		let (_, deposit_address) = request_address_and_deposit(ALICE, ETH_ETH);
+
+		let recycle_block = IngressEgress::expiry_and_recycle_block_height().2;
+		BlockHeightProvider::<MockEthereum>::set_block_height(recycle_block);
+		IngressEgress::on_idle(1, Weight::MAX);
+
+		System::assert_has_event(RuntimeEvent::IngressEgress(crate::Event::DustLost {
+			deposit_address,
+			asset: ETH_ETH,
+			amount: 1_000,
+		}));
+		assert!(IngressEgress::unrecovered_dust().is_empty());
+		expect_size_of_address_pool(0);
+	});
+}
+
+mod btc_dust {
+	use crate::{
+		mock_btc::{new_test_ext, IngressEgress, RuntimeEvent, System, Test},
+		DustDeposits, Instance3, MinimumDeposit, WithheldTransactionFees,
+	};
+	use cf_chains::{
+		btc::{ScriptPubkey, UtxoId},
+		Bitcoin,
+	};
+	use cf_primitives::chains::assets::btc;
+	use cf_traits::{mocks::block_height_provider::BlockHeightProvider, DepositApi};
+	use frame_support::{assert_ok, traits::Hooks, weights::Weight};
+
+	const LP_ACCOUNT: u64 = 1;
+
+	#[test]
+	fn dust_is_swept_when_a_bitcoin_channel_expires() {
+		new_test_ext().execute_with(|| {
+			MinimumDeposit::<Test, Instance3>::insert(btc::Asset::Btc, 1_500);
+
+			let (_, deposit_address, ..) =
+				IngressEgress::request_liquidity_deposit_address(LP_ACCOUNT, btc::Asset::Btc, 0)
+					.unwrap();
+			let deposit_address: ScriptPubkey = deposit_address.try_into().unwrap();
+			assert_ok!(IngressEgress::process_single_deposit(
+				deposit_address.clone(),
+				btc::Asset::Btc,
+				1_000,
+				UtxoId::default(),
+				Default::default()
+			));
+			assert!(DustDeposits::<Test, Instance3>::contains_key(
+				&deposit_address,
+				btc::Asset::Btc
+			));
+
+			// Bitcoin addresses are never recycled, but the dust can be spent without fetching
+			// the address, so it is recovered when the channel expires.
+			let recycle_block = IngressEgress::expiry_and_recycle_block_height().2;
+			BlockHeightProvider::<Bitcoin>::set_block_height(recycle_block);
+			IngressEgress::on_idle(1, Weight::MAX);
+
+			System::assert_has_event(RuntimeEvent::IngressEgress(crate::Event::DustSwept {
+				deposit_address: deposit_address.clone(),
+				asset: btc::Asset::Btc,
+				amount: 1_000,
+			}));
+			assert!(!DustDeposits::<Test, Instance3>::contains_key(
+				&deposit_address,
+				btc::Asset::Btc
+			));
+			assert_eq!(WithheldTransactionFees::<Test, Instance3>::get(btc::Asset::Btc), 1_000);
+		});
+	}
+}
@@ -37,6 +37,9 @@ pub trait WeightInfo {
 	fn finalise_ingress(a: u32, ) -> Weight;
 	fn vault_transfer_failed() -> Weight;
 	fn ccm_broadcast_failed() -> Weight;
+	fn add_boost_funds() -> Weight;
+	fn stop_boosting() -> Weight;
+	fn create_boost_pools(a: u32, ) -> Weight;
 }
 
 /// Weights for pallet_cf_ingress_egress using the Substrate node and recommended hardware.
@@ -103,6 +106,42 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 	fn ccm_broadcast_failed() -> Weight {
 		Weight::from_parts(1_000_000, 1_000)
 	}
+
+	// NOTE: The boost weights below are placeholders until the benchmarks are run. They assume
+	// a pool at its maximum size, i.e. `MAX_BOOSTERS_PER_POOL` boosters owed funds by
+	// `MAX_PENDING_BOOSTS_PER_POOL` pending boosts (about 125kB encoded).
+
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumIngressEgress::BoostPools` (r:1 w:1)
+	/// Proof: `EthereumIngressEgress::BoostPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityProvider::FreeBalances` (r:1 w:1)
+	/// Proof: `LiquidityProvider::FreeBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_boost_funds() -> Weight {
+		Weight::from_parts(250_000_000, 130_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumIngressEgress::BoostPools` (r:1 w:1)
+	/// Proof: `EthereumIngressEgress::BoostPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityProvider::FreeBalances` (r:1 w:1)
+	/// Proof: `LiquidityProvider::FreeBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn stop_boosting() -> Weight {
+		Weight::from_parts(250_000_000, 130_000)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumIngressEgress::BoostPools` (r:10 w:10)
+	/// Proof: `EthereumIngressEgress::BoostPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `a` is `[1, 10]`.
+	fn create_boost_pools(a: u32, ) -> Weight {
+		Weight::from_parts(10_000_000, 3_500)
+			.saturating_add(Weight::from_parts(15_000_000, 2_500).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+	}
 }
 
 // For backwards compatibility and tests
@@ -167,4 +206,40 @@ impl WeightInfo for () {
 	fn ccm_broadcast_failed() -> Weight {
 		Weight::from_parts(1_000_000, 1_000)
 	}
+
+	// NOTE: The boost weights below are placeholders until the benchmarks are run. They assume
+	// a pool at its maximum size, i.e. `MAX_BOOSTERS_PER_POOL` boosters owed funds by
+	// `MAX_PENDING_BOOSTS_PER_POOL` pending boosts (about 125kB encoded).
+
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumIngressEgress::BoostPools` (r:1 w:1)
+	/// Proof: `EthereumIngressEgress::BoostPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityProvider::FreeBalances` (r:1 w:1)
+	/// Proof: `LiquidityProvider::FreeBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_boost_funds() -> Weight {
+		Weight::from_parts(250_000_000, 130_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumIngressEgress::BoostPools` (r:1 w:1)
+	/// Proof: `EthereumIngressEgress::BoostPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityProvider::FreeBalances` (r:1 w:1)
+	/// Proof: `LiquidityProvider::FreeBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn stop_boosting() -> Weight {
+		Weight::from_parts(250_000_000, 130_000)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumIngressEgress::BoostPools` (r:10 w:10)
+	/// Proof: `EthereumIngressEgress::BoostPools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `a` is `[1, 10]`.
+	fn create_boost_pools(a: u32, ) -> Weight {
+		Weight::from_parts(10_000_000, 3_500)
+			.saturating_add(Weight::from_parts(15_000_000, 2_500).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+	}
 }
@@ -140,6 +140,7 @@ mod benchmarks {
 			0,
 			Some(100),
 			IncreaseOrDecrease::Increase(1_000_000),
+			CloseOrderAt::Block(frame_system::Pallet::<T>::block_number() + One::one()),
 		);
 	}
 
@@ -165,6 +166,7 @@ mod benchmarks {
 			0,
 			Some(100),
 			1_000,
+			CloseOrderAt::Block(frame_system::Pallet::<T>::block_number() + One::one()),
 		);
 	}
 
@@ -188,6 +190,7 @@ mod benchmarks {
 			0,
 			Some(0),
 			10_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_ok!(Pallet::<T>::set_limit_order(
 			RawOrigin::Signed(caller.clone()).into(),
@@ -197,6 +200,7 @@ mod benchmarks {
 			1,
 			Some(0),
 			10_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_ok!(Pallet::<T>::swap_with_network_fee(STABLE_ASSET, Asset::Eth, 1_000));
 		let fee = 1_000;
@@ -244,6 +248,7 @@ mod benchmarks {
 				id: 0,
 				option_tick: Some(0),
 				sell_amount: 100,
+				close_order_at: CloseOrderAt::Unchanged,
 			}),
 			BlockNumberFor::<T>::from(5u32),
 		);
@@ -251,5 +256,92 @@ mod benchmarks {
 		assert!(!ScheduledLimitOrderUpdates::<T>::get(BlockNumberFor::<T>::from(5u32)).is_empty());
 	}
 
+	#[benchmark]
+	fn batch_orders() {
+		let caller = new_lp_account::<T>();
+
+		// The batched calls are weighed separately, so this only measures the batch itself.
+		#[extrinsic_call]
+		batch_orders(RawOrigin::Signed(caller), Default::default());
+	}
+
+	#[benchmark]
+	fn close_all_orders(n: Linear<1, MAX_ORDERS_TO_CLOSE>, p: Linear<1, MAX_POOLS>) {
+		let caller = new_lp_account::<T>();
+		// The orders are all placed in the Eth pool, but every pool has to be scanned.
+		for asset in Asset::all().filter(|asset| *asset != STABLE_ASSET).take(p as usize) {
+			assert_ok!(Pallet::<T>::new_pool(
+				T::EnsureGovernance::try_successful_origin().unwrap(),
+				asset,
+				STABLE_ASSET,
+				0,
+				price_at_tick(0).unwrap()
+			));
+		}
+		assert_ok!(T::LpBalance::try_credit_account(&caller, Asset::Eth, 1_000_000,));
+		assert_ok!(T::LpBalance::try_credit_account(&caller, Asset::Usdc, 1_000_000,));
+		for id in 0..n {
+			assert_ok!(Pallet::<T>::set_limit_order(
+				RawOrigin::Signed(caller.clone()).into(),
+				Asset::Eth,
+				Asset::Usdc,
+				Side::Sell,
+				id.into(),
+				Some(100),
+				1_000,
+				CloseOrderAt::Unchanged,
+			));
+		}
+
+		#[extrinsic_call]
+		close_all_orders(RawOrigin::Signed(caller.clone()), None);
+
+		assert_eq!(
+			Pools::<T>::get(AssetPair::new(Asset::Eth, Asset::Usdc).unwrap())
+				.unwrap()
+				.order_count(&caller),
+			0
+		);
+	}
+
+	#[benchmark]
+	fn on_initialize_close_expired_orders(n: Linear<0, MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK>) {
+		let caller = new_lp_account::<T>();
+		assert_ok!(Pallet::<T>::new_pool(
+			T::EnsureGovernance::try_successful_origin().unwrap(),
+			Asset::Eth,
+			Asset::Usdc,
+			0,
+			price_at_tick(0).unwrap()
+		));
+		assert_ok!(T::LpBalance::try_credit_account(&caller, Asset::Eth, 1_000_000,));
+		let close_order_at = frame_system::Pallet::<T>::block_number() + One::one();
+		for id in 0..n {
+			assert_ok!(Pallet::<T>::set_limit_order(
+				RawOrigin::Signed(caller.clone()).into(),
+				Asset::Eth,
+				Asset::Usdc,
+				Side::Sell,
+				id.into(),
+				Some(100),
+				1_000,
+				CloseOrderAt::Block(close_order_at),
+			));
+		}
+		NextLimitOrderExpiryBlock::<T>::put(close_order_at);
+
+		#[block]
+		{
+			Pallet::<T>::close_expired_limit_orders(close_order_at);
+		}
+
+		assert_eq!(
+			Pools::<T>::get(AssetPair::new(Asset::Eth, Asset::Usdc).unwrap())
+				.unwrap()
+				.order_count(&caller),
+			0
+		);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
@@ -119,7 +119,24 @@ impl<T> AskBidMap<T> {
 	}
 }
 
-pub const PALLET_VERSION: StorageVersion = StorageVersion::new(2);
+pub const PALLET_VERSION: StorageVersion = StorageVersion::new(3);
+
+/// The maximum number of order updates that can be submitted in a single batch.
+pub const MAX_ORDERS_PER_BATCH: u32 = 100;
+
+/// The maximum number of orders that can be closed at once by `close_all_orders`.
+pub const MAX_ORDERS_TO_CLOSE: u32 = 50;
+
+/// The maximum number of pools: there is at most one for each asset other than the stable asset.
+pub const MAX_POOLS: u32 = 4;
+
+/// The maximum number of limit orders that can be set to close at the same block. This is also
+/// the maximum number of expired limit orders that are closed in a single block.
+pub const MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK: u32 = 50;
+
+/// The maximum number of blocks whose expired limit orders are checked in a single block, when
+/// catching up after limit order updates have been disabled.
+pub const MAX_LIMIT_ORDER_EXPIRY_BLOCKS_PER_BLOCK: u32 = 20;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -143,6 +160,16 @@ pub mod pallet {
 		pub call: Call<T>,
 	}
 
+	/// Identifies a limit order across all pools.
+	#[derive(CloneNoBound, DebugNoBound, PartialEqNoBound, EqNoBound, Encode, Decode, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct LimitOrderKey<T: Config> {
+		pub lp: T::AccountId,
+		pub asset_pair: AssetPair,
+		pub side: Side,
+		pub id: OrderId,
+	}
+
 	#[derive(Clone, Debug, Encode, Decode, TypeInfo)]
 	#[scale_info(skip_type_params(T))]
 	pub struct Pool<T: Config> {
@@ -155,6 +182,20 @@ pub mod pallet {
 		pub pool_state: PoolState<(T::AccountId, OrderId)>,
 	}
 
+	impl<T: Config> Pool<T> {
+		/// The number of range and limit orders the LP has in this pool.
+		pub fn order_count(&self, lp: &T::AccountId) -> u32 {
+			let limit_orders = [Side::Buy, Side::Sell]
+				.into_iter()
+				.map(|side| {
+					self.limit_orders_cache[side.to_sold_pair()].get(lp).map_or(0, BTreeMap::len)
+				})
+				.sum::<usize>();
+			let range_orders = self.range_orders_cache.get(lp).map_or(0, BTreeMap::len);
+			(limit_orders + range_orders).unique_saturated_into()
+		}
+	}
+
 	pub type OrderId = u64;
 
 	pub type AssetAmounts = PoolPairsMap<AssetAmount>;
@@ -246,6 +287,32 @@ pub mod pallet {
 		}
 	}
 
+	/// How an order update changes the block at which a limit order is closed automatically.
+	#[derive(
+		Copy,
+		Clone,
+		Debug,
+		Default,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+		PartialEq,
+		Eq,
+		Deserialize,
+		Serialize,
+	)]
+	#[serde(rename_all = "snake_case")]
+	pub enum CloseOrderAt<BlockNumber> {
+		/// Keep the order's current expiry, if it has one.
+		#[default]
+		Unchanged,
+		/// Close the order at the start of the given block.
+		Block(BlockNumber),
+		/// Remove the order's expiry, so that it stays open until it is closed explicitly.
+		Never,
+	}
+
 	#[pallet::config]
 	#[pallet::disable_frame_system_supertrait_check]
 	pub trait Config: Chainflip {
@@ -291,6 +358,26 @@ pub mod pallet {
 	pub(super) type ScheduledLimitOrderUpdates<T: Config> =
 		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<LimitOrderUpdate<T>>, ValueQuery>;
 
+	/// Queue of limit orders, indexed by the block number at which they are to be closed.
+	#[pallet::storage]
+	pub(super) type LimitOrderExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<LimitOrderKey<T>, ConstU32<MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK>>,
+		ValueQuery,
+	>;
+
+	/// The earliest block whose expired limit orders have not all been closed yet.
+	#[pallet::storage]
+	pub(super) type NextLimitOrderExpiryBlock<T: Config> =
+		StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The block number at which each expiring limit order is to be closed.
+	#[pallet::storage]
+	pub(super) type LimitOrderCloseAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, LimitOrderKey<T>, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub flip_buy_interval: BlockNumberFor<T>,
@@ -362,6 +449,9 @@ pub mod pallet {
 				});
 				weight_used.saturating_accrue(call_weight);
 			}
+
+			weight_used.saturating_accrue(Self::close_expired_limit_orders(current_block));
+
 			weight_used
 		}
 	}
@@ -409,6 +499,10 @@ pub mod pallet {
 		UnsupportedCall,
 		/// The update can't be scheduled because it has expired (dispatch_at is in the past).
 		LimitOrderUpdateExpired,
+		/// The block at which to close the order must be in the future.
+		InvalidCloseOrderAt,
+		/// Too many limit orders are already set to close at the given block.
+		TooManyLimitOrderExpiries,
 	}
 
 	#[pallet::event]
@@ -482,6 +576,21 @@ pub mod pallet {
 			order_id: OrderId,
 			dispatch_at: BlockNumberFor<T>,
 		},
+		/// A limit order reached the block set by `close_order_at` and was closed.
+		LimitOrderExpired {
+			lp: T::AccountId,
+			base_asset: Asset,
+			quote_asset: Asset,
+			side: Side,
+			id: OrderId,
+		},
+		/// Some or all of an LP's orders were closed by `close_all_orders`. If any orders remain,
+		/// `close_all_orders` can be called again to close them.
+		OrdersClosed {
+			lp: T::AccountId,
+			orders_closed: u32,
+			orders_remaining: u32,
+		},
 	}
 
 	#[pallet::call]
@@ -725,6 +834,11 @@ pub mod pallet {
 		/// for buy and sell limit orders i.e. those in different directions are independent.
 		/// Therefore you may have two limit orders with the same order_id in the same pool, one to
 		/// buy Eth and one to sell Eth for example.
+		///
+		/// If `close_order_at` is a block, the order will be closed automatically at the start of
+		/// that block, and its liquidity returned to your balance. At most
+		/// [MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK] orders can be closed at the same block. The order's
+		/// current expiry is kept unless `close_order_at` replaces or removes it.
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::update_limit_order())]
 		pub fn update_limit_order(
@@ -735,12 +849,14 @@ pub mod pallet {
 			id: OrderId,
 			option_tick: Option<Tick>,
 			amount_change: IncreaseOrDecrease<AssetAmount>,
+			close_order_at: CloseOrderAt<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			ensure!(
 				T::SafeMode::get().limit_order_update_enabled,
 				Error::<T>::UpdatingLimitOrdersDisabled
 			);
 			let lp = T::AccountRoleRegistry::ensure_liquidity_provider(origin)?;
+			Self::ensure_valid_close_order_at(close_order_at)?;
 			Self::try_mutate_order(&lp, base_asset, quote_asset, |asset_pair, pool| {
 				let tick = match (
 					pool.limit_orders_cache[side.to_sold_pair()]
@@ -788,6 +904,7 @@ pub mod pallet {
 					amount_change.map(|amount| amount.into()),
 					/* allow_noop */ false,
 				)?;
+				Self::set_limit_order_expiry(pool, &lp, asset_pair, side, id, close_order_at)?;
 
 				Ok(())
 			})
@@ -801,6 +918,11 @@ pub mod pallet {
 		/// limit orders i.e. those in different directions are independent. Therefore you may have
 		/// two limit orders with the same order_id in the same pool, one to buy Eth and one to sell
 		/// Eth for example.
+		///
+		/// If `close_order_at` is a block, the order will be closed automatically at the start of
+		/// that block, and its liquidity returned to your balance. At most
+		/// [MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK] orders can be closed at the same block. The order's
+		/// current expiry is kept unless `close_order_at` replaces or removes it.
 		#[pallet::call_index(6)]
 		#[pallet::weight(T::WeightInfo::set_limit_order())]
 		pub fn set_limit_order(
@@ -811,12 +933,14 @@ pub mod pallet {
 			id: OrderId,
 			option_tick: Option<Tick>,
 			sell_amount: AssetAmount,
+			close_order_at: CloseOrderAt<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			ensure!(
 				T::SafeMode::get().limit_order_update_enabled,
 				Error::<T>::UpdatingLimitOrdersDisabled
 			);
 			let lp = T::AccountRoleRegistry::ensure_liquidity_provider(origin)?;
+			Self::ensure_valid_close_order_at(close_order_at)?;
 			Self::try_mutate_order(&lp, base_asset, quote_asset, |asset_pair, pool| {
 				let tick = match (
 					pool.limit_orders_cache[side.to_sold_pair()]
@@ -852,6 +976,7 @@ pub mod pallet {
 					IncreaseOrDecrease::Increase(sell_amount.into()),
 					/* allow noop */ true,
 				)?;
+				Self::set_limit_order_expiry(pool, &lp, asset_pair, side, id, close_order_at)?;
 
 				Ok(())
 			})
@@ -962,6 +1087,118 @@ pub mod pallet {
 				_ => Err(Error::<T>::UnsupportedCall)?,
 			}
 		}
+
+		/// Applies a list of range and limit order updates, possibly across different pools, as
+		/// a single atomic operation: if any of the updates fails, none of them are applied.
+		///
+		/// Each call must be one of `set_range_order`, `update_range_order`, `set_limit_order` or
+		/// `update_limit_order`, and is dispatched as if it had been submitted on its own.
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::BadOrigin)
+		/// - [UnsupportedCall](pallet_cf_pools::Error::UnsupportedCall)
+		/// - Any error of the batched calls.
+		#[pallet::call_index(9)]
+		#[pallet::weight(orders.iter().fold(T::WeightInfo::batch_orders(), |total, call| {
+			total.saturating_add(call.get_dispatch_info().weight)
+		}))]
+		#[transactional]
+		pub fn batch_orders(
+			origin: OriginFor<T>,
+			orders: BoundedVec<Call<T>, ConstU32<MAX_ORDERS_PER_BATCH>>,
+		) -> DispatchResult {
+			let lp = T::AccountRoleRegistry::ensure_liquidity_provider(origin)?;
+
+			for call in orders {
+				match call {
+					Call::set_range_order { .. } |
+					Call::update_range_order { .. } |
+					Call::set_limit_order { .. } |
+					Call::update_limit_order { .. } => {
+						call.dispatch_bypass_filter(OriginTrait::signed(lp.clone()))
+							.map_err(|err| err.error)?;
+					},
+					_ => Err(Error::<T>::UnsupportedCall)?,
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Closes all of the caller's range and limit orders, or only those in the given pool if
+		/// an asset pair is specified. The liquidity of the closed orders, along with any fees and
+		/// bought amounts collected, is credited back to the caller's free balance.
+		///
+		/// At most [MAX_ORDERS_TO_CLOSE] orders are closed at once. If the caller has more orders
+		/// than that, the number left open is reported and the call can be repeated to close them.
+		///
+		/// ## Events
+		///
+		/// - [RangeOrderUpdated](Event::RangeOrderUpdated) for each closed range order.
+		/// - [LimitOrderUpdated](Event::LimitOrderUpdated) for each closed limit order.
+		/// - [OrdersClosed](Event::OrdersClosed)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::BadOrigin)
+		/// - [UpdatingRangeOrdersDisabled](pallet_cf_pools::Error::UpdatingRangeOrdersDisabled)
+		/// - [UpdatingLimitOrdersDisabled](pallet_cf_pools::Error::UpdatingLimitOrdersDisabled)
+		/// - [PoolDoesNotExist](pallet_cf_pools::Error::PoolDoesNotExist)
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::close_all_orders(
+			MAX_ORDERS_TO_CLOSE,
+			if asset_pair.is_some() { 1 } else { MAX_POOLS },
+		))]
+		pub fn close_all_orders(
+			origin: OriginFor<T>,
+			asset_pair: Option<PoolPairsMap<Asset>>,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				T::SafeMode::get().range_order_update_enabled,
+				Error::<T>::UpdatingRangeOrdersDisabled
+			);
+			ensure!(
+				T::SafeMode::get().limit_order_update_enabled,
+				Error::<T>::UpdatingLimitOrdersDisabled
+			);
+			let lp = T::AccountRoleRegistry::ensure_liquidity_provider(origin)?;
+
+			let asset_pairs = match asset_pair {
+				Some(assets) => {
+					let asset_pair = AssetPair::try_new::<T>(assets.base, assets.quote)?;
+					ensure!(Pools::<T>::contains_key(asset_pair), Error::<T>::PoolDoesNotExist);
+					sp_std::vec![asset_pair]
+				},
+				None => Pools::<T>::iter_keys().collect(),
+			};
+
+			let order_count = asset_pairs
+				.iter()
+				.filter_map(Pools::<T>::get)
+				.map(|pool| pool.order_count(&lp))
+				.sum::<u32>();
+
+			let pool_count = asset_pairs.len() as u32;
+			let mut orders_closed = 0;
+			for asset_pair in asset_pairs {
+				if orders_closed >= MAX_ORDERS_TO_CLOSE {
+					break
+				}
+				let max_orders = MAX_ORDERS_TO_CLOSE - orders_closed;
+				orders_closed += Self::try_mutate_pool(asset_pair, |asset_pair, pool| {
+					Self::inner_close_orders(pool, &lp, asset_pair, max_orders)
+				})?;
+			}
+
+			Self::deposit_event(Event::<T>::OrdersClosed {
+				lp,
+				orders_closed,
+				orders_remaining: order_count.saturating_sub(orders_closed),
+			});
+
+			Ok(Some(T::WeightInfo::close_all_orders(orders_closed, pool_count)).into())
+		}
 	}
 }
 
@@ -1196,6 +1433,195 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Closes up to `max_orders` of the LP's orders in the pool, returning the number closed.
+	fn inner_close_orders(
+		pool: &mut Pool<T>,
+		lp: &T::AccountId,
+		asset_pair: &AssetPair,
+		max_orders: u32,
+	) -> Result<u32, DispatchError> {
+		let mut orders_closed = 0u32;
+		for (id, tick_range) in pool
+			.range_orders_cache
+			.get(lp)
+			.cloned()
+			.unwrap_or_default()
+			.into_iter()
+			.take(max_orders as usize)
+		{
+			Self::inner_update_range_order(
+				pool,
+				lp,
+				asset_pair,
+				id,
+				tick_range,
+				IncreaseOrDecrease::Decrease(range_orders::Size::Liquidity {
+					liquidity: Liquidity::MAX,
+				}),
+				/* allow_noop */ false,
+			)?;
+			orders_closed += 1;
+		}
+
+		for side in [Side::Buy, Side::Sell] {
+			for (id, tick) in pool.limit_orders_cache[side.to_sold_pair()]
+				.get(lp)
+				.cloned()
+				.unwrap_or_default()
+				.into_iter()
+				.take(max_orders.saturating_sub(orders_closed) as usize)
+			{
+				Self::inner_update_limit_order(
+					pool,
+					lp,
+					asset_pair,
+					side,
+					id,
+					tick,
+					IncreaseOrDecrease::Decrease(cf_amm::common::Amount::MAX),
+					/* allow_noop */ false,
+				)?;
+				Self::remove_limit_order_expiry(&LimitOrderKey {
+					lp: lp.clone(),
+					asset_pair: *asset_pair,
+					side,
+					id,
+				});
+				orders_closed += 1;
+			}
+		}
+
+		Ok(orders_closed)
+	}
+
+	fn ensure_valid_close_order_at(
+		close_order_at: CloseOrderAt<BlockNumberFor<T>>,
+	) -> Result<(), Error<T>> {
+		if let CloseOrderAt::Block(close_order_at) = close_order_at {
+			ensure!(
+				close_order_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidCloseOrderAt
+			);
+		}
+		Ok(())
+	}
+
+	/// Updates the block at which the order is to be closed. The expiry is always removed if the
+	/// order no longer exists after the update.
+	fn set_limit_order_expiry(
+		pool: &Pool<T>,
+		lp: &T::AccountId,
+		asset_pair: &AssetPair,
+		side: Side,
+		id: OrderId,
+		close_order_at: CloseOrderAt<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let order = LimitOrderKey { lp: lp.clone(), asset_pair: *asset_pair, side, id };
+		let order_exists = pool.limit_orders_cache[side.to_sold_pair()]
+			.get(lp)
+			.is_some_and(|limit_orders| limit_orders.contains_key(&id));
+
+		match close_order_at {
+			_ if !order_exists => Self::remove_limit_order_expiry(&order),
+			CloseOrderAt::Unchanged => (),
+			CloseOrderAt::Never => Self::remove_limit_order_expiry(&order),
+			CloseOrderAt::Block(close_order_at) => {
+				Self::remove_limit_order_expiry(&order);
+				LimitOrderExpiries::<T>::try_append(close_order_at, &order)
+					.map_err(|_| Error::<T>::TooManyLimitOrderExpiries)?;
+				LimitOrderCloseAt::<T>::insert(order, close_order_at);
+			},
+		}
+		Ok(())
+	}
+
+	fn remove_limit_order_expiry(order: &LimitOrderKey<T>) {
+		if let Some(close_order_at) = LimitOrderCloseAt::<T>::take(order) {
+			LimitOrderExpiries::<T>::mutate(close_order_at, |orders| {
+				orders.retain(|queued_order| queued_order != order)
+			});
+		}
+	}
+
+	/// Closes the limit orders that have reached their expiry, starting from the earliest block
+	/// whose orders haven't all been closed yet. At most [MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK]
+	/// orders are closed per block, and the rest are left for the next block. Nothing is closed
+	/// while limit order updates are disabled.
+	pub(crate) fn close_expired_limit_orders(current_block: BlockNumberFor<T>) -> Weight {
+		let mut weight_used = T::DbWeight::get().reads_writes(1, 1);
+		let mut block = NextLimitOrderExpiryBlock::<T>::get().unwrap_or(current_block);
+
+		if T::SafeMode::get().limit_order_update_enabled {
+			let mut remaining_orders = MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK as usize;
+			let mut remaining_blocks = MAX_LIMIT_ORDER_EXPIRY_BLOCKS_PER_BLOCK;
+			while block <= current_block && remaining_blocks > 0 {
+				remaining_blocks -= 1;
+				let mut orders = LimitOrderExpiries::<T>::take(block).into_inner();
+				let deferred_orders = orders.split_off(orders.len().min(remaining_orders));
+				remaining_orders -= orders.len();
+				weight_used.saturating_accrue(T::WeightInfo::on_initialize_close_expired_orders(
+					orders.len() as u32,
+				));
+
+				for order in orders {
+					// Orders whose expiry was changed are removed from the queue, but this
+					// ensures an order is never closed early.
+					if LimitOrderCloseAt::<T>::get(&order) != Some(block) {
+						continue
+					}
+					LimitOrderCloseAt::<T>::remove(&order);
+					if let Err(e) = with_storage_layer(|| Self::close_expired_limit_order(&order)) {
+						log::warn!("Unable to close expired limit order {order:?}: {e:?}");
+					}
+				}
+
+				if !deferred_orders.is_empty() {
+					// This can't exceed the bound since it's a subset of the queued orders.
+					LimitOrderExpiries::<T>::insert(
+						block,
+						BoundedVec::truncate_from(deferred_orders),
+					);
+					break
+				}
+				block.saturating_inc();
+			}
+		}
+
+		NextLimitOrderExpiryBlock::<T>::put(block);
+		weight_used
+	}
+
+	fn close_expired_limit_order(order: &LimitOrderKey<T>) -> DispatchResult {
+		Self::try_mutate_pool(order.asset_pair, |asset_pair, pool| {
+			let Some(tick) = pool.limit_orders_cache[order.side.to_sold_pair()]
+				.get(&order.lp)
+				.and_then(|limit_orders| limit_orders.get(&order.id))
+				.cloned()
+			else {
+				// The order has already been closed.
+				return Ok(())
+			};
+			Self::inner_update_limit_order(
+				pool,
+				&order.lp,
+				asset_pair,
+				order.side,
+				order.id,
+				tick,
+				IncreaseOrDecrease::Decrease(cf_amm::common::Amount::MAX),
+				/* allow_noop */ false,
+			)?;
+			Self::deposit_event(Event::<T>::LimitOrderExpired {
+				lp: order.lp.clone(),
+				base_asset: asset_pair.assets().base,
+				quote_asset: asset_pair.assets().quote,
+				side: order.side,
+				id: order.id,
+			});
+			Ok(())
+		})
+	}
+
 	#[allow(clippy::too_many_arguments)]
 	fn inner_update_limit_order(
 		pool: &mut Pool<T>,
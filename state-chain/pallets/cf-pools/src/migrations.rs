@@ -1,5 +1,6 @@
-use crate::Pallet;
+mod limit_order_expiry;
 
-use cf_runtime_upgrade_utilities::{migration_template::Migration, VersionedMigration};
+use cf_runtime_upgrade_utilities::VersionedMigration;
 
-pub type PalletMigration<T> = VersionedMigration<Pallet<T>, Migration<T>, 1, 2>;
+pub type PalletMigration<T> =
+	VersionedMigration<crate::Pallet<T>, limit_order_expiry::Migration<T>, 2, 3>;
@@ -0,0 +1,195 @@
+use crate::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+pub struct Migration<T: Config>(PhantomData<T>);
+
+mod old {
+	use super::*;
+
+	/// The limit order calls as they were encoded before `close_order_at` was added. These are the
+	/// only calls that can be scheduled.
+	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	pub enum Call {
+		#[codec(index = 5)]
+		UpdateLimitOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			side: Side,
+			id: OrderId,
+			option_tick: Option<Tick>,
+			amount_change: IncreaseOrDecrease<AssetAmount>,
+		},
+		#[codec(index = 6)]
+		SetLimitOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			side: Side,
+			id: OrderId,
+			option_tick: Option<Tick>,
+			sell_amount: AssetAmount,
+		},
+	}
+
+	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	pub struct LimitOrderUpdate<AccountId> {
+		pub lp: AccountId,
+		pub id: OrderId,
+		pub call: Call,
+	}
+
+	#[frame_support::storage_alias]
+	pub type ScheduledLimitOrderUpdates<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Vec<LimitOrderUpdate<<T as frame_system::Config>::AccountId>>,
+		ValueQuery,
+	>;
+}
+
+impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		ScheduledLimitOrderUpdates::<T>::translate_values::<
+			Vec<old::LimitOrderUpdate<T::AccountId>>,
+			_,
+		>(|old_updates| {
+			Some(
+				old_updates
+					.into_iter()
+					.map(|old::LimitOrderUpdate { lp, id, call }| LimitOrderUpdate {
+						lp,
+						id,
+						call: match call {
+							old::Call::UpdateLimitOrder {
+								base_asset,
+								quote_asset,
+								side,
+								id,
+								option_tick,
+								amount_change,
+							} => Call::update_limit_order {
+								base_asset,
+								quote_asset,
+								side,
+								id,
+								option_tick,
+								amount_change,
+								close_order_at: CloseOrderAt::Unchanged,
+							},
+							old::Call::SetLimitOrder {
+								base_asset,
+								quote_asset,
+								side,
+								id,
+								option_tick,
+								sell_amount,
+							} => Call::set_limit_order {
+								base_asset,
+								quote_asset,
+								side,
+								id,
+								option_tick,
+								sell_amount,
+								close_order_at: CloseOrderAt::Unchanged,
+							},
+						},
+					})
+					.collect(),
+			)
+		});
+
+		Weight::zero()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		let number_of_updates = old::ScheduledLimitOrderUpdates::<T>::iter_values()
+			.map(|updates| updates.len() as u32)
+			.sum::<u32>();
+		Ok(number_of_updates.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+		let number_of_updates =
+			u32::decode(&mut &state[..]).map_err(|_| "Failed to decode pre-upgrade state.")?;
+
+		ensure!(
+			number_of_updates ==
+				ScheduledLimitOrderUpdates::<T>::iter_values()
+					.map(|updates| updates.len() as u32)
+					.sum::<u32>(),
+			"Scheduled limit order update count mismatch!"
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod migration_tests {
+	use super::*;
+	use crate::mock::{new_test_ext, Test, ALICE};
+
+	#[test]
+	fn test_migration() {
+		new_test_ext().execute_with(|| {
+			old::ScheduledLimitOrderUpdates::<Test>::insert(
+				5,
+				vec![
+					old::LimitOrderUpdate {
+						lp: ALICE,
+						id: 1,
+						call: old::Call::SetLimitOrder {
+							base_asset: Asset::Eth,
+							quote_asset: STABLE_ASSET,
+							side: Side::Sell,
+							id: 1,
+							option_tick: Some(0),
+							sell_amount: 100,
+						},
+					},
+					old::LimitOrderUpdate {
+						lp: ALICE,
+						id: 2,
+						call: old::Call::UpdateLimitOrder {
+							base_asset: Asset::Eth,
+							quote_asset: STABLE_ASSET,
+							side: Side::Buy,
+							id: 2,
+							option_tick: None,
+							amount_change: IncreaseOrDecrease::Decrease(50),
+						},
+					},
+				],
+			);
+
+			Migration::<Test>::on_runtime_upgrade();
+
+			let updates = ScheduledLimitOrderUpdates::<Test>::get(5);
+			assert_eq!(updates.len(), 2);
+			assert!(matches!(
+				updates[0].call,
+				Call::set_limit_order {
+					id: 1,
+					sell_amount: 100,
+					close_order_at: CloseOrderAt::Unchanged,
+					..
+				}
+			));
+			assert!(matches!(
+				updates[1].call,
+				Call::update_limit_order {
+					id: 2,
+					amount_change: IncreaseOrDecrease::Decrease(50),
+					close_order_at: CloseOrderAt::Unchanged,
+					..
+				}
+			));
+		});
+	}
+}
@@ -1,7 +1,9 @@
 use crate::{
-	self as pallet_cf_pools, mock::*, utilities, AskBidMap, AssetAmounts, AssetPair,
-	CollectedNetworkFee, Error, Event, FlipBuyInterval, FlipToBurn, LimitOrder, PoolInfo,
-	PoolOrders, PoolPairsMap, Pools, RangeOrder, RangeOrderSize, ScheduledLimitOrderUpdates,
+	self as pallet_cf_pools, mock::*, utilities, AskBidMap, AssetAmounts, AssetPair, CloseOrderAt,
+	CollectedNetworkFee, Error, Event, FlipBuyInterval, FlipToBurn, IncreaseOrDecrease, LimitOrder,
+	LimitOrderCloseAt, LimitOrderExpiries, LimitOrderKey, NextLimitOrderExpiryBlock,
+	PalletSafeMode, PoolInfo, PoolOrders, PoolPairsMap, Pools, RangeOrder, RangeOrderSize,
+	ScheduledLimitOrderUpdates, MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK, MAX_ORDERS_TO_CLOSE, MAX_POOLS,
 	STABLE_ASSET,
 };
 use cf_amm::common::{price_at_tick, tick_at_price, Side, Tick, PRICE_FRACTIONAL_BITS};
@@ -154,6 +156,7 @@ fn test_sweeping() {
 			0,
 			Some(TICK),
 			POSITION_0_SIZE,
+			CloseOrderAt::Unchanged,
 		));
 
 		assert_eq!(AliceCollectedEth::get(), 0);
@@ -176,6 +179,7 @@ fn test_sweeping() {
 			1,
 			Some(TICK),
 			POSITION_1_SIZE,
+			CloseOrderAt::Unchanged,
 		));
 
 		assert_eq!(AliceCollectedEth::get(), SWAP_AMOUNT);
@@ -211,6 +215,7 @@ fn test_buy_back_flip() {
 					tick_at_price(U256::from(FLIP_PRICE_IN_USDC) << PRICE_FRACTIONAL_BITS).unwrap()
 				),
 				1_000_000_000,
+				CloseOrderAt::Unchanged,
 			));
 		}
 
@@ -306,6 +311,7 @@ fn can_update_pool_liquidity_fee_and_collect_for_limit_order() {
 			0,
 			Some(0),
 			5_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_ok!(LiquidityPools::set_limit_order(
 			RuntimeOrigin::signed(ALICE),
@@ -315,6 +321,7 @@ fn can_update_pool_liquidity_fee_and_collect_for_limit_order() {
 			1,
 			Some(0),
 			1_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_ok!(LiquidityPools::set_limit_order(
 			RuntimeOrigin::signed(BOB),
@@ -324,6 +331,7 @@ fn can_update_pool_liquidity_fee_and_collect_for_limit_order() {
 			0,
 			Some(0),
 			10_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_ok!(LiquidityPools::set_limit_order(
 			RuntimeOrigin::signed(BOB),
@@ -333,6 +341,7 @@ fn can_update_pool_liquidity_fee_and_collect_for_limit_order() {
 			1,
 			Some(0),
 			10_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_eq!(
 			LiquidityPools::pool_orders(Asset::Eth, STABLE_ASSET, Some(ALICE)),
@@ -530,6 +539,7 @@ fn pallet_limit_order_is_in_sync_with_pool() {
 			0,
 			Some(0),
 			100,
+			CloseOrderAt::Unchanged,
 		));
 		assert_ok!(LiquidityPools::set_limit_order(
 			RuntimeOrigin::signed(BOB),
@@ -539,6 +549,7 @@ fn pallet_limit_order_is_in_sync_with_pool() {
 			0,
 			Some(tick),
 			100_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_ok!(LiquidityPools::set_limit_order(
 			RuntimeOrigin::signed(BOB),
@@ -548,6 +559,7 @@ fn pallet_limit_order_is_in_sync_with_pool() {
 			1,
 			Some(tick),
 			10_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_eq!(
 			LiquidityPools::pool_orders(Asset::Eth, STABLE_ASSET, Some(ALICE)),
@@ -781,6 +793,7 @@ fn can_execute_scheduled_limit_order() {
 				id: order_id,
 				option_tick: Some(100),
 				sell_amount: 55,
+				close_order_at: CloseOrderAt::Unchanged,
 			}),
 			6
 		));
@@ -840,6 +853,7 @@ fn cant_schedule_in_the_past() {
 					id: 0,
 					option_tick: Some(0),
 					sell_amount: 55,
+					close_order_at: CloseOrderAt::Unchanged,
 				}),
 				9
 			),
@@ -848,6 +862,124 @@ fn cant_schedule_in_the_past() {
 	});
 }
 
+#[test]
+fn can_batch_orders_across_pools() {
+	new_test_ext().execute_with(|| {
+		for base_asset in [Asset::Eth, Asset::Flip] {
+			assert_ok!(LiquidityPools::new_pool(
+				RuntimeOrigin::root(),
+				base_asset,
+				STABLE_ASSET,
+				Default::default(),
+				price_at_tick(0).unwrap(),
+			));
+		}
+
+		assert_ok!(LiquidityPools::batch_orders(
+			RuntimeOrigin::signed(ALICE),
+			vec![
+				pallet_cf_pools::Call::<Test>::set_range_order {
+					base_asset: Asset::Eth,
+					quote_asset: STABLE_ASSET,
+					id: 0,
+					option_tick_range: Some(-100..100),
+					size: RangeOrderSize::Liquidity { liquidity: 100_000 },
+				},
+				pallet_cf_pools::Call::<Test>::set_limit_order {
+					base_asset: Asset::Eth,
+					quote_asset: STABLE_ASSET,
+					side: Side::Sell,
+					id: 1,
+					option_tick: Some(10),
+					sell_amount: 1_000,
+					close_order_at: CloseOrderAt::Unchanged,
+				},
+				pallet_cf_pools::Call::<Test>::set_limit_order {
+					base_asset: Asset::Flip,
+					quote_asset: STABLE_ASSET,
+					side: Side::Buy,
+					id: 2,
+					option_tick: Some(-10),
+					sell_amount: 2_000,
+					close_order_at: CloseOrderAt::Unchanged,
+				},
+			]
+			.try_into()
+			.unwrap(),
+		));
+
+		let eth_pool =
+			Pools::<Test>::get(AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap()).unwrap();
+		assert_eq!(eth_pool.range_orders_cache[&ALICE][&0], -100..100);
+		assert_eq!(eth_pool.limit_orders_cache[Side::Sell.to_sold_pair()][&ALICE][&1], 10);
+		let flip_pool =
+			Pools::<Test>::get(AssetPair::new(Asset::Flip, STABLE_ASSET).unwrap()).unwrap();
+		assert_eq!(flip_pool.limit_orders_cache[Side::Buy.to_sold_pair()][&ALICE][&2], -10);
+	});
+}
+
+#[test]
+fn batch_orders_is_atomic() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+
+		// The second order targets a pool that doesn't exist, so the first must not be applied.
+		assert_noop!(
+			LiquidityPools::batch_orders(
+				RuntimeOrigin::signed(ALICE),
+				vec![
+					pallet_cf_pools::Call::<Test>::set_limit_order {
+						base_asset: Asset::Eth,
+						quote_asset: STABLE_ASSET,
+						side: Side::Sell,
+						id: 0,
+						option_tick: Some(0),
+						sell_amount: 1_000,
+						close_order_at: CloseOrderAt::Unchanged,
+					},
+					pallet_cf_pools::Call::<Test>::set_limit_order {
+						base_asset: Asset::Flip,
+						quote_asset: STABLE_ASSET,
+						side: Side::Sell,
+						id: 1,
+						option_tick: Some(0),
+						sell_amount: 1_000,
+						close_order_at: CloseOrderAt::Unchanged,
+					},
+				]
+				.try_into()
+				.unwrap(),
+			),
+			Error::<Test>::PoolDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn batch_orders_rejects_unsupported_calls() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LiquidityPools::batch_orders(
+				RuntimeOrigin::signed(ALICE),
+				vec![pallet_cf_pools::Call::<Test>::set_pool_fees {
+					base_asset: Asset::Eth,
+					quote_asset: STABLE_ASSET,
+					fee_hundredth_pips: 0,
+				}]
+				.try_into()
+				.unwrap(),
+			),
+			Error::<Test>::UnsupportedCall
+		);
+	});
+}
+
 #[test]
 fn can_get_all_pool_orders() {
 	new_test_ext().execute_with(|| {
@@ -905,6 +1037,7 @@ fn can_get_all_pool_orders() {
 			4,
 			Some(100),
 			500_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_ok!(LiquidityPools::set_limit_order(
 			RuntimeOrigin::signed(ALICE),
@@ -914,6 +1047,7 @@ fn can_get_all_pool_orders() {
 			5,
 			Some(1000),
 			600_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_ok!(LiquidityPools::set_limit_order(
 			RuntimeOrigin::signed(ALICE),
@@ -923,6 +1057,7 @@ fn can_get_all_pool_orders() {
 			6,
 			Some(100),
 			700_000,
+			CloseOrderAt::Unchanged,
 		));
 		assert_ok!(LiquidityPools::set_limit_order(
 			RuntimeOrigin::signed(ALICE),
@@ -932,6 +1067,7 @@ fn can_get_all_pool_orders() {
 			7,
 			Some(1000),
 			800_000,
+			CloseOrderAt::Unchanged,
 		));
 
 		assert_eq!(
@@ -1116,8 +1252,466 @@ fn fees_are_getting_recorded() {
 			6,
 			Some(100),
 			700_000,
+			CloseOrderAt::Unchanged,
 		));
 
 		MockBalance::assert_fees_recorded(&BOB);
 	});
 }
+
+#[test]
+fn can_close_all_orders() {
+	new_test_ext().execute_with(|| {
+		for base_asset in [Asset::Eth, Asset::Flip] {
+			assert_ok!(LiquidityPools::new_pool(
+				RuntimeOrigin::root(),
+				base_asset,
+				STABLE_ASSET,
+				Default::default(),
+				price_at_tick(0).unwrap(),
+			));
+			for lp in [ALICE, BOB] {
+				assert_ok!(LiquidityPools::set_range_order(
+					RuntimeOrigin::signed(lp),
+					base_asset,
+					STABLE_ASSET,
+					0,
+					Some(-100..100),
+					RangeOrderSize::Liquidity { liquidity: 100_000 },
+				));
+				assert_ok!(LiquidityPools::set_limit_order(
+					RuntimeOrigin::signed(lp),
+					base_asset,
+					STABLE_ASSET,
+					Side::Sell,
+					0,
+					Some(10),
+					1_000,
+					CloseOrderAt::Unchanged,
+				));
+				assert_ok!(LiquidityPools::set_limit_order(
+					RuntimeOrigin::signed(lp),
+					base_asset,
+					STABLE_ASSET,
+					Side::Buy,
+					0,
+					Some(-10),
+					1_000,
+					CloseOrderAt::Unchanged,
+				));
+			}
+		}
+		let pool = |base_asset| {
+			Pools::<Test>::get(AssetPair::new(base_asset, STABLE_ASSET).unwrap()).unwrap()
+		};
+
+		// Only close the orders in the Eth pool.
+		let collected_eth = AliceCollectedEth::get();
+		assert_ok!(LiquidityPools::close_all_orders(
+			RuntimeOrigin::signed(ALICE),
+			Some(PoolPairsMap { base: Asset::Eth, quote: STABLE_ASSET }),
+		));
+		assert_eq!(pool(Asset::Eth).order_count(&ALICE), 0);
+		assert_eq!(pool(Asset::Eth).order_count(&BOB), 3);
+		assert_eq!(pool(Asset::Flip).order_count(&ALICE), 3);
+		assert!(AliceCollectedEth::get() > collected_eth);
+		assert_events_match!(
+			Test,
+			RuntimeEvent::LiquidityPools(Event::RangeOrderUpdated {
+				lp: ALICE,
+				base_asset: Asset::Eth,
+				liquidity_total: 0,
+				..
+			}) => (),
+			RuntimeEvent::LiquidityPools(Event::LimitOrderUpdated {
+				lp: ALICE,
+				base_asset: Asset::Eth,
+				side: Side::Buy,
+				sell_amount_total: 0,
+				..
+			}) => (),
+			RuntimeEvent::LiquidityPools(Event::LimitOrderUpdated {
+				lp: ALICE,
+				base_asset: Asset::Eth,
+				side: Side::Sell,
+				sell_amount_total: 0,
+				..
+			}) => (),
+			RuntimeEvent::LiquidityPools(Event::OrdersClosed {
+				lp: ALICE,
+				orders_closed: 3,
+				orders_remaining: 0,
+			}) => ()
+		);
+
+		// Close the remaining orders.
+		assert_ok!(LiquidityPools::close_all_orders(RuntimeOrigin::signed(ALICE), None));
+		assert_eq!(pool(Asset::Flip).order_count(&ALICE), 0);
+		assert_eq!(pool(Asset::Flip).order_count(&BOB), 3);
+
+		// Closing when there are no orders is a no-op.
+		assert_ok!(LiquidityPools::close_all_orders(RuntimeOrigin::signed(ALICE), None));
+
+		assert_noop!(
+			LiquidityPools::close_all_orders(
+				RuntimeOrigin::signed(ALICE),
+				Some(PoolPairsMap { base: Asset::Dot, quote: STABLE_ASSET }),
+			),
+			Error::<Test>::PoolDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn orders_beyond_the_limit_are_left_open_to_be_closed_by_another_call() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		for id in 0..=MAX_ORDERS_TO_CLOSE {
+			assert_ok!(LiquidityPools::set_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				STABLE_ASSET,
+				Side::Sell,
+				id.into(),
+				Some(0),
+				1_000,
+				CloseOrderAt::Unchanged,
+			));
+		}
+
+		let order_count = || {
+			Pools::<Test>::get(AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap())
+				.unwrap()
+				.order_count(&ALICE)
+		};
+
+		assert_ok!(LiquidityPools::close_all_orders(RuntimeOrigin::signed(ALICE), None));
+		assert_eq!(order_count(), 1);
+		assert_has_event::<Test>(RuntimeEvent::LiquidityPools(Event::OrdersClosed {
+			lp: ALICE,
+			orders_closed: MAX_ORDERS_TO_CLOSE,
+			orders_remaining: 1,
+		}));
+
+		assert_ok!(LiquidityPools::close_all_orders(RuntimeOrigin::signed(ALICE), None));
+		assert_eq!(order_count(), 0);
+		assert_eq!(
+			last_event::<Test>(),
+			RuntimeEvent::LiquidityPools(Event::OrdersClosed {
+				lp: ALICE,
+				orders_closed: 1,
+				orders_remaining: 0,
+			})
+		);
+	});
+}
+
+#[test]
+fn max_pools_matches_the_number_of_assets() {
+	assert_eq!(Asset::all().filter(|asset| *asset != STABLE_ASSET).count() as u32, MAX_POOLS);
+}
+
+#[test]
+fn limit_orders_are_closed_at_close_order_at() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		let limit_orders = |side: Side| {
+			Pools::<Test>::get(AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap())
+				.unwrap()
+				.limit_orders_cache[side.to_sold_pair()]
+			.get(&ALICE)
+			.map(|orders| orders.keys().cloned().collect::<Vec<_>>())
+			.unwrap_or_default()
+		};
+
+		for id in 0..3 {
+			assert_ok!(LiquidityPools::set_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				STABLE_ASSET,
+				Side::Sell,
+				id,
+				Some(0),
+				1_000,
+				CloseOrderAt::Block(10),
+			));
+		}
+		// Order 1's expiry is removed and order 2's is pushed back.
+		assert_ok!(LiquidityPools::update_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Sell,
+			1,
+			None,
+			IncreaseOrDecrease::Increase(100),
+			CloseOrderAt::Never,
+		));
+		assert_ok!(LiquidityPools::update_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Sell,
+			2,
+			None,
+			IncreaseOrDecrease::Increase(100),
+			CloseOrderAt::Block(20),
+		));
+		assert_eq!(LimitOrderExpiries::<Test>::get(10).len(), 1);
+		assert_eq!(LimitOrderExpiries::<Test>::get(20).len(), 1);
+
+		let collected_eth = AliceCollectedEth::get();
+		LiquidityPools::on_initialize(10);
+		assert_eq!(limit_orders(Side::Sell), vec![1, 2]);
+		assert_eq!(AliceCollectedEth::get(), collected_eth + 1_000);
+		assert_events_match!(
+			Test,
+			RuntimeEvent::LiquidityPools(Event::LimitOrderUpdated {
+				id: 0,
+				sell_amount_change: Some(IncreaseOrDecrease::Decrease(1_000)),
+				sell_amount_total: 0,
+				..
+			}) => (),
+			RuntimeEvent::LiquidityPools(Event::LimitOrderExpired {
+				lp: ALICE,
+				base_asset: Asset::Eth,
+				quote_asset: STABLE_ASSET,
+				side: Side::Sell,
+				id: 0,
+			}) => ()
+		);
+
+		LiquidityPools::on_initialize(20);
+		assert_eq!(limit_orders(Side::Sell), vec![1]);
+		assert_eq!(
+			last_event::<Test>(),
+			RuntimeEvent::LiquidityPools(Event::LimitOrderExpired {
+				lp: ALICE,
+				base_asset: Asset::Eth,
+				quote_asset: STABLE_ASSET,
+				side: Side::Sell,
+				id: 2,
+			})
+		);
+	});
+}
+
+#[test]
+fn expired_limit_orders_are_closed_once_updates_are_enabled() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		assert_ok!(LiquidityPools::set_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Buy,
+			0,
+			Some(0),
+			1_000,
+			CloseOrderAt::Block(10),
+		));
+
+		MockRuntimeSafeMode::set_safe_mode(MockRuntimeSafeMode {
+			pools: PalletSafeMode {
+				range_order_update_enabled: true,
+				limit_order_update_enabled: false,
+			},
+		});
+		LiquidityPools::on_initialize(10);
+		assert_eq!(LimitOrderExpiries::<Test>::get(10).len(), 1);
+		assert_eq!(NextLimitOrderExpiryBlock::<Test>::get(), Some(10));
+
+		MockRuntimeSafeMode::set_safe_mode(MockRuntimeSafeMode {
+			pools: PalletSafeMode {
+				range_order_update_enabled: true,
+				limit_order_update_enabled: true,
+			},
+		});
+		LiquidityPools::on_initialize(11);
+		assert!(LimitOrderExpiries::<Test>::get(10).is_empty());
+		assert_eq!(NextLimitOrderExpiryBlock::<Test>::get(), Some(12));
+		assert_eq!(
+			last_event::<Test>(),
+			RuntimeEvent::LiquidityPools(Event::LimitOrderExpired {
+				lp: ALICE,
+				base_asset: Asset::Eth,
+				quote_asset: STABLE_ASSET,
+				side: Side::Buy,
+				id: 0,
+			})
+		);
+	});
+}
+
+#[test]
+fn close_order_at_must_be_in_the_future() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		assert_noop!(
+			LiquidityPools::set_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				STABLE_ASSET,
+				Side::Sell,
+				0,
+				Some(0),
+				1_000,
+				CloseOrderAt::Block(System::block_number()),
+			),
+			Error::<Test>::InvalidCloseOrderAt
+		);
+	});
+}
+
+#[test]
+fn updating_a_limit_order_keeps_its_expiry_unless_changed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		assert_ok!(LiquidityPools::set_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Sell,
+			0,
+			Some(0),
+			1_000,
+			CloseOrderAt::Block(10),
+		));
+		let order = LimitOrderKey::<Test> {
+			lp: ALICE,
+			asset_pair: AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap(),
+			side: Side::Sell,
+			id: 0,
+		};
+
+		assert_ok!(LiquidityPools::update_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Sell,
+			0,
+			None,
+			IncreaseOrDecrease::Increase(100),
+			CloseOrderAt::Unchanged,
+		));
+		assert_ok!(LiquidityPools::set_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Sell,
+			0,
+			Some(0),
+			2_000,
+			CloseOrderAt::Unchanged,
+		));
+		assert_eq!(LimitOrderCloseAt::<Test>::get(&order), Some(10));
+		assert_eq!(LimitOrderExpiries::<Test>::get(10).into_inner(), vec![order.clone()]);
+
+		assert_ok!(LiquidityPools::update_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Sell,
+			0,
+			None,
+			IncreaseOrDecrease::Increase(100),
+			CloseOrderAt::Never,
+		));
+		assert_eq!(LimitOrderCloseAt::<Test>::get(&order), None);
+		assert!(LimitOrderExpiries::<Test>::get(10).is_empty());
+	});
+}
+
+#[test]
+fn limit_order_expiries_per_block_are_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		for id in 0..MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK as u64 {
+			assert_ok!(LiquidityPools::set_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				STABLE_ASSET,
+				Side::Sell,
+				id,
+				Some(0),
+				1_000,
+				CloseOrderAt::Block(10),
+			));
+		}
+		assert_noop!(
+			LiquidityPools::set_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				STABLE_ASSET,
+				Side::Sell,
+				MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK as u64,
+				Some(0),
+				1_000,
+				CloseOrderAt::Block(10),
+			),
+			Error::<Test>::TooManyLimitOrderExpiries
+		);
+		assert_ok!(LiquidityPools::set_limit_order(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			Side::Sell,
+			MAX_LIMIT_ORDER_EXPIRIES_PER_BLOCK as u64,
+			Some(0),
+			1_000,
+			CloseOrderAt::Block(11),
+		));
+
+		// Orders beyond the limit for a single block are closed in the following block.
+		LiquidityPools::on_initialize(9);
+		LiquidityPools::on_initialize(11);
+		assert!(LimitOrderExpiries::<Test>::get(10).is_empty());
+		assert_eq!(LimitOrderExpiries::<Test>::get(11).len(), 1);
+		assert_eq!(NextLimitOrderExpiryBlock::<Test>::get(), Some(11));
+
+		LiquidityPools::on_initialize(12);
+		assert!(LimitOrderExpiries::<Test>::get(11).is_empty());
+		assert_eq!(NextLimitOrderExpiryBlock::<Test>::get(), Some(13));
+		assert!(Pools::<Test>::get(AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap())
+			.unwrap()
+			.limit_orders_cache[Side::Sell.to_sold_pair()]
+		.get(&ALICE)
+		.map_or(true, |orders| orders.is_empty()));
+	});
+}
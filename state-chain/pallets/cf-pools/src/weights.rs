@@ -41,6 +41,9 @@ pub trait WeightInfo {
 	fn set_limit_order() -> Weight;
 	fn set_pool_fees() -> Weight;
 	fn schedule() -> Weight;
+	fn batch_orders() -> Weight;
+	fn close_all_orders(n: u32, p: u32, ) -> Weight;
+	fn on_initialize_close_expired_orders(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_cf_pools using the Substrate node and recommended hardware.
@@ -180,6 +183,64 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn batch_orders() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `403`
+		//  Estimated: `3498`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3498)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidityPools::Pools` (r:8 w:4)
+	/// Proof: `LiquidityPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityProvider::FreeBalances` (r:2 w:2)
+	/// Proof: `LiquidityProvider::FreeBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 50]`.
+	/// The range of component `p` is `[1, 4]`.
+	fn close_all_orders(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1140 + n * (112 ±0) + p * (381 ±0)`
+		//  Estimated: `4605 + n * (112 ±0) + p * (2856 ±0)`
+		// Minimum execution time: 83_000_000 picoseconds.
+		Weight::from_parts(68_000_000, 4605)
+			// Standard Error: 12_000_000
+			.saturating_add(Weight::from_parts(31_000_000, 0).saturating_mul(n.into()))
+			// Standard Error: 41_000_000
+			.saturating_add(Weight::from_parts(17_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 112).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2856).saturating_mul(p.into()))
+	}
+	/// Storage: `LiquidityPools::LimitOrderExpiries` (r:1 w:1)
+	/// Proof: `LiquidityPools::LimitOrderExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::LimitOrderCloseAt` (r:50 w:50)
+	/// Proof: `LiquidityPools::LimitOrderCloseAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::Pools` (r:1 w:1)
+	/// Proof: `LiquidityPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityProvider::FreeBalances` (r:50 w:50)
+	/// Proof: `LiquidityProvider::FreeBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 50]`.
+	fn on_initialize_close_expired_orders(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1402 + n * (166 ±0)`
+		//  Estimated: `4867 + n * (2641 ±0)`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_412_000, 4867)
+			// Standard Error: 14_000_000
+			.saturating_add(Weight::from_parts(38_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2641).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -318,4 +379,62 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	fn batch_orders() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `403`
+		//  Estimated: `3498`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 3498)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `LiquidityPools::Pools` (r:8 w:4)
+	/// Proof: `LiquidityPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityProvider::FreeBalances` (r:2 w:2)
+	/// Proof: `LiquidityProvider::FreeBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 50]`.
+	/// The range of component `p` is `[1, 4]`.
+	fn close_all_orders(n: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1140 + n * (112 ±0) + p * (381 ±0)`
+		//  Estimated: `4605 + n * (112 ±0) + p * (2856 ±0)`
+		// Minimum execution time: 83_000_000 picoseconds.
+		Weight::from_parts(68_000_000, 4605)
+			// Standard Error: 12_000_000
+			.saturating_add(Weight::from_parts(31_000_000, 0).saturating_mul(n.into()))
+			// Standard Error: 41_000_000
+			.saturating_add(Weight::from_parts(17_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 112).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2856).saturating_mul(p.into()))
+	}
+	/// Storage: `LiquidityPools::LimitOrderExpiries` (r:1 w:1)
+	/// Proof: `LiquidityPools::LimitOrderExpiries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::LimitOrderCloseAt` (r:50 w:50)
+	/// Proof: `LiquidityPools::LimitOrderCloseAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::Pools` (r:1 w:1)
+	/// Proof: `LiquidityPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityProvider::FreeBalances` (r:50 w:50)
+	/// Proof: `LiquidityProvider::FreeBalances` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[0, 50]`.
+	fn on_initialize_close_expired_orders(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1402 + n * (166 ±0)`
+		//  Estimated: `4867 + n * (2641 ±0)`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(9_412_000, 4867)
+			// Standard Error: 14_000_000
+			.saturating_add(Weight::from_parts(38_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2641).saturating_mul(n.into()))
+	}
 }
@@ -3,7 +3,7 @@
 use super::*;
 
 use cf_traits::{AccountRoleRegistry, EpochInfo};
-use frame_benchmarking::v2::*;
+use frame_benchmarking::{account, v2::*};
 use frame_support::{
 	assert_ok,
 	traits::{OnNewAccount, UnfilteredDispatchable},
@@ -109,5 +109,27 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn remove_storage_items(n: Linear<1, 255>) {
+		for i in 0..n {
+			MissedHeartbeatIntervals::<T>::insert(0, account::<T::ValidatorId>("offline", i, 0), 1);
+		}
+
+		#[block]
+		{
+			let _ = MissedHeartbeatIntervals::<T>::clear_prefix(0, u32::MAX, None);
+		}
+	}
+
+	#[benchmark]
+	fn on_idle_with_nothing_to_remove() {
+		EpochsToCull::<T>::append(1);
+
+		#[block]
+		{
+			let _weight = Pallet::<T>::on_idle(Default::default(), Default::default());
+		}
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
@@ -18,9 +18,10 @@ pub use reputation::*;
 pub mod weights;
 pub use weights::WeightInfo;
 
+use cf_primitives::EpochIndex;
 use cf_traits::{
-	impl_pallet_safe_mode, offence_reporting::*, Chainflip, Heartbeat, NetworkState, QualifyNode,
-	ReputationResetter, Slashing,
+	impl_pallet_safe_mode, offence_reporting::*, Chainflip, EpochInfo, EpochTransitionHandler,
+	Heartbeat, NetworkState, QualifyNode, ReputationResetter, Slashing,
 };
 use frame_support::{
 	pallet_prelude::*,
@@ -142,12 +143,22 @@ pub mod pallet {
 					// Reputation depends on heartbeats
 					let offline_authorities = Self::current_network_state().offline;
 					let num_offline_authorities = offline_authorities.len() as u32;
+					Self::record_heartbeat_interval(&offline_authorities);
 					Self::penalise_offline_authorities(offline_authorities);
 					return T::WeightInfo::submit_network_state(num_offline_authorities)
 				}
 			}
 			T::WeightInfo::on_initialize_no_action()
 		}
+
+		/// Culls the heartbeat statistics of expired epochs, as far as the remaining weight allows.
+		fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			cf_traits::cull_expired_epoch::<EpochsToCull<T>, MissedHeartbeatIntervals<T>, _, _>(
+				remaining_weight,
+				T::WeightInfo::on_idle_with_nothing_to_remove(),
+				T::WeightInfo::remove_storage_items,
+			)
+		}
 	}
 
 	/// The ratio at which one accrues Reputation points for online blocks.
@@ -190,6 +201,28 @@ pub mod pallet {
 	pub type LastHeartbeat<T: Config> =
 		StorageMap<_, Twox64Concat, T::ValidatorId, BlockNumberFor<T>, OptionQuery>;
 
+	/// The number of heartbeat intervals that have been evaluated in each epoch.
+	#[pallet::storage]
+	pub type HeartbeatIntervals<T: Config> =
+		StorageMap<_, Twox64Concat, EpochIndex, u32, ValueQuery>;
+
+	/// The number of heartbeat intervals in each epoch at the end of which an authority was
+	/// considered offline.
+	#[pallet::storage]
+	pub type MissedHeartbeatIntervals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EpochIndex,
+		Blake2_128Concat,
+		T::ValidatorId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Expired epochs whose heartbeat statistics have yet to be culled.
+	#[pallet::storage]
+	pub type EpochsToCull<T: Config> = StorageValue<_, Vec<EpochIndex>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -423,6 +456,15 @@ impl<T: Config> OffenceReporter for Pallet<T> {
 	}
 }
 
+impl<T: Config> EpochTransitionHandler for Pallet<T> {
+	/// Heartbeat statistics are only kept for unexpired epochs. The missed intervals of each
+	/// authority are culled in `on_idle`.
+	fn on_expired_epoch(expired: EpochIndex) {
+		HeartbeatIntervals::<T>::remove(expired);
+		EpochsToCull::<T>::append(expired);
+	}
+}
+
 pub trait OffenceList<T: Config> {
 	const OFFENCES: &'static [T::Offence];
 }
@@ -447,6 +489,20 @@ impl<T: Config, L: OffenceList<T>> QualifyNode<T::ValidatorId> for ExclusionList
 }
 
 impl<T: Config> Pallet<T> {
+	/// Records the outcome of a heartbeat interval against the current epoch, so that the uptime of
+	/// each authority can be derived from the number of intervals it missed.
+	fn record_heartbeat_interval(offline_authorities: &[T::ValidatorId]) {
+		let epoch_index = T::EpochInfo::epoch_index();
+		HeartbeatIntervals::<T>::mutate(epoch_index, |intervals| {
+			*intervals = intervals.saturating_add(1)
+		});
+		for validator_id in offline_authorities {
+			MissedHeartbeatIntervals::<T>::mutate(epoch_index, validator_id, |missed| {
+				*missed = missed.saturating_add(1)
+			});
+		}
+	}
+
 	pub fn penalise_offline_authorities(offline_authorities: Vec<T::ValidatorId>) {
 		<Self as OffenceReporter>::report_many(
 			PalletOffence::MissedHeartbeat,
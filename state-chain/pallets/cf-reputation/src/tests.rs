@@ -1,6 +1,10 @@
 use crate::{mock::*, *};
 use cf_traits::{offence_reporting::*, EpochInfo, QualifyNode, SafeMode, SetSafeMode};
-use frame_support::{assert_noop, assert_ok, traits::OnInitialize};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Hooks, OnInitialize},
+	weights::Weight,
+};
 
 fn reputation_points(who: &<Test as frame_system::Config>::AccountId) -> ReputationPoints {
 	ReputationPallet::reputation(who).reputation_points
@@ -498,3 +502,32 @@ fn in_safe_mode_you_dont_lose_reputation_for_being_offline() {
 		assert_eq!(reputation, reputation_points(&BOB));
 	});
 }
+
+#[test]
+fn heartbeat_uptime_is_recorded_per_epoch() {
+	new_test_ext().execute_with(|| {
+		let epoch = MockEpochInfo::epoch_index();
+
+		// Alice is online for the first interval, then misses the next two.
+		assert_ok!(ReputationPallet::heartbeat(RuntimeOrigin::signed(ALICE)));
+		for _ in 1..HEARTBEAT_BLOCK_INTERVAL {
+			advance_by_block();
+		}
+		assert_eq!(HeartbeatIntervals::<Test>::get(epoch), 1);
+		assert_eq!(MissedHeartbeatIntervals::<Test>::get(epoch, ALICE), 0);
+
+		advance_by_hearbeat_intervals(2);
+		assert_eq!(HeartbeatIntervals::<Test>::get(epoch), 3);
+		assert_eq!(MissedHeartbeatIntervals::<Test>::get(epoch, ALICE), 2);
+		// Bob is not an authority so is not tracked.
+		assert_eq!(MissedHeartbeatIntervals::<Test>::get(epoch, BOB), 0);
+
+		<ReputationPallet as EpochTransitionHandler>::on_expired_epoch(epoch);
+		assert_eq!(HeartbeatIntervals::<Test>::get(epoch), 0);
+		assert_eq!(EpochsToCull::<Test>::get(), vec![epoch]);
+
+		ReputationPallet::on_idle(System::block_number(), Weight::MAX);
+		assert!(!MissedHeartbeatIntervals::<Test>::contains_key(epoch, ALICE));
+		assert!(EpochsToCull::<Test>::get().is_empty());
+	});
+}
@@ -38,6 +38,8 @@ pub trait WeightInfo {
 	fn heartbeat() -> Weight;
 	fn submit_network_state(o: u32) -> Weight;
 	fn on_initialize_no_action() -> Weight;
+	fn remove_storage_items(n: u32, ) -> Weight;
+	fn on_idle_with_nothing_to_remove() -> Weight;
 }
 
 /// Weights for pallet_cf_reputation using the Substrate node and recommended hardware.
@@ -132,6 +134,22 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 		Weight::from_parts(4_025_000, 1820)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	// NOTE: The culling weights below are placeholders until the benchmarks are run.
+
+	/// Storage: `Reputation::MissedHeartbeatIntervals` (r:n w:n)
+	/// Proof: `Reputation::MissedHeartbeatIntervals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_storage_items(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(1_500_000, 2_600).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Reputation::EpochsToCull` (r:1 w:0)
+	/// Proof: `Reputation::EpochsToCull` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_idle_with_nothing_to_remove() -> Weight {
+		Weight::from_parts(3_000_000, 1_600)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -225,4 +243,20 @@ impl WeightInfo for () {
 		Weight::from_parts(4_025_000, 1820)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	// NOTE: The culling weights below are placeholders until the benchmarks are run.
+
+	/// Storage: `Reputation::MissedHeartbeatIntervals` (r:n w:n)
+	/// Proof: `Reputation::MissedHeartbeatIntervals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_storage_items(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(1_500_000, 2_600).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `Reputation::EpochsToCull` (r:1 w:0)
+	/// Proof: `Reputation::EpochsToCull` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_idle_with_nothing_to_remove() -> Weight {
+		Weight::from_parts(3_000_000, 1_600)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
 }
@@ -34,6 +34,8 @@ mod benchmarks {
 			broker_commission_bps: 0,
 			boost_fee: 0,
 			channel_metadata: None,
+			refund_parameters: None,
+			dca_parameters: None,
 		};
 		#[block]
 		{
@@ -92,6 +94,7 @@ mod benchmarks {
 				Asset::Eth,
 				deposit_amount,
 				SwapType::Swap(ForeignChainAddress::benchmark_value()),
+				None,
 			)]
 		);
 	}
@@ -107,6 +110,7 @@ mod benchmarks {
 				gas_budget: 1,
 				cf_parameters: Default::default(),
 			},
+			refund_parameters: None,
 		};
 		let call = Call::<T>::ccm_deposit {
 			source_asset: Asset::Usdc,
@@ -127,8 +131,8 @@ mod benchmarks {
 				<frame_system::Pallet<T>>::block_number() + SWAP_DELAY_BLOCKS.into()
 			),
 			vec![
-				Swap::new(1, Asset::Usdc, Asset::Eth, 1_000 - 1, SwapType::CcmPrincipal(1),),
-				Swap::new(2, Asset::Usdc, Asset::Eth, 1, SwapType::CcmGas(1),)
+				Swap::new(1, Asset::Usdc, Asset::Eth, 1_000 - 1, SwapType::CcmPrincipal(1), None),
+				Swap::new(2, Asset::Usdc, Asset::Eth, 1, SwapType::CcmGas(1), None)
 			]
 		);
 	}
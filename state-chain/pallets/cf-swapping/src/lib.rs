@@ -2,7 +2,7 @@
 
 use cf_chains::{
 	address::{AddressConverter, ForeignChainAddress},
-	CcmChannelMetadata, CcmDepositMetadata, SwapOrigin,
+	CcmChannelMetadata, CcmDepositMetadata, DcaParameters, SwapOrigin, SwapRefundParameters,
 };
 use cf_primitives::{
 	Asset, AssetAmount, ChannelId, ForeignChain, SwapId, SwapLeg, TransactionHash, STABLE_ASSET,
@@ -15,12 +15,17 @@ use frame_support::{
 		traits::{Get, Saturating},
 		DispatchError, Permill,
 	},
+	storage::{with_transaction, TransactionOutcome},
 	transactional,
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use sp_arithmetic::{helpers_128bit::multiply_by_rational_with_rounding, traits::Zero, Rounding};
-use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec,
+	vec::Vec,
+};
 #[cfg(test)]
 mod mock;
 
@@ -33,12 +38,15 @@ pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
-pub const PALLET_VERSION: StorageVersion = StorageVersion::new(2);
+pub const PALLET_VERSION: StorageVersion = StorageVersion::new(3);
 
 const BASIS_POINTS_PER_MILLION: u32 = 100;
 
 pub const SWAP_DELAY_BLOCKS: u32 = 2;
 
+/// The maximum number of blocks over which the chunks of a DCA swap can be spread.
+pub const MAX_DCA_DURATION_BLOCKS: u32 = 14_400;
+
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub enum SwapType {
 	Swap(ForeignChainAddress),
@@ -55,6 +63,7 @@ pub struct Swap {
 	pub stable_amount: Option<AssetAmount>,
 	pub final_output: Option<AssetAmount>,
 	pub fee_taken: bool,
+	pub refund_parameters: Option<SwapRefundParameters>,
 }
 
 impl Swap {
@@ -64,6 +73,7 @@ impl Swap {
 		to: Asset,
 		amount: AssetAmount,
 		swap_type: SwapType,
+		refund_parameters: Option<SwapRefundParameters>,
 	) -> Self {
 		Self {
 			swap_id,
@@ -74,6 +84,15 @@ impl Swap {
 			stable_amount: if from == STABLE_ASSET { Some(amount) } else { None },
 			final_output: if from == to { Some(amount) } else { None },
 			fee_taken: false,
+			refund_parameters,
+		}
+	}
+
+	/// Returns true if the swap output falls short of the minimum set in its refund parameters.
+	fn is_below_min_output(&self) -> bool {
+		match (&self.refund_parameters, self.final_output) {
+			(Some(SwapRefundParameters { min_output, .. }), Some(output)) => output < *min_output,
+			_ => false,
 		}
 	}
 
@@ -114,6 +133,88 @@ impl Swap {
 	}
 }
 
+/// Identifies a chunk of a DCA swap, since all chunks share the swap id of the original swap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct DcaChunk {
+	/// Starts at 0 for the first chunk.
+	pub index: u32,
+	pub number_of_chunks: u32,
+}
+
+/// Tracks the progress of a swap that is split into chunks (Dollar-Cost Averaging). All chunks
+/// are scheduled under the swap id of the original swap.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct DcaState {
+	/// The total input of the swap, across all chunks.
+	pub total_input: AssetAmount,
+	/// The number of chunks the swap is split into.
+	pub number_of_chunks: u32,
+	/// The input that has not yet been scheduled.
+	pub remaining_input: AssetAmount,
+	/// The number of chunks that have not yet been scheduled.
+	pub remaining_chunks: u32,
+	pub chunk_interval: u32,
+	/// The combined output of the chunks executed so far.
+	pub accumulated_output: AssetAmount,
+	/// The refund parameters of the whole swap. The minimum output is applied to each chunk pro
+	/// rata.
+	pub refund_parameters: Option<SwapRefundParameters>,
+}
+
+impl DcaState {
+	fn new(
+		total_input: AssetAmount,
+		DcaParameters { number_of_chunks, chunk_interval }: DcaParameters,
+		refund_parameters: Option<SwapRefundParameters>,
+	) -> Self {
+		// Every chunk must have a non-zero input.
+		let number_of_chunks =
+			sp_std::cmp::min(number_of_chunks as AssetAmount, total_input) as u32;
+		Self {
+			total_input,
+			number_of_chunks,
+			remaining_input: total_input,
+			remaining_chunks: number_of_chunks,
+			chunk_interval: sp_std::cmp::max(chunk_interval, 1),
+			accumulated_output: 0,
+			refund_parameters,
+		}
+	}
+
+	/// Takes the input of the next chunk off the remaining input.
+	fn take_next_chunk(&mut self) -> AssetAmount {
+		let chunk_input = if self.remaining_chunks > 1 {
+			self.remaining_input / self.remaining_chunks as AssetAmount
+		} else {
+			self.remaining_input
+		};
+		self.remaining_input.saturating_reduce(chunk_input);
+		self.remaining_chunks.saturating_reduce(1);
+		chunk_input
+	}
+
+	/// The chunk that was scheduled most recently, i.e. the one that is being executed.
+	fn current_chunk(&self) -> DcaChunk {
+		DcaChunk {
+			index: self.number_of_chunks.saturating_sub(self.remaining_chunks).saturating_sub(1),
+			number_of_chunks: self.number_of_chunks,
+		}
+	}
+
+	fn chunk_refund_parameters(&self, chunk_input: AssetAmount) -> Option<SwapRefundParameters> {
+		self.refund_parameters.clone().map(|params| SwapRefundParameters {
+			min_output: multiply_by_rational_with_rounding(
+				params.min_output,
+				chunk_input,
+				self.total_input,
+				Rounding::Down,
+			)
+			.unwrap_or_default(),
+			..params
+		})
+	}
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum CcmSwapLeg {
 	Principal,
@@ -280,6 +381,10 @@ pub mod pallet {
 	#[pallet::getter(fn maximum_swap_amount)]
 	pub type MaximumSwapAmount<T: Config> = StorageMap<_, Twox64Concat, Asset, AssetAmount>;
 
+	/// The progress of swaps that are executed in chunks, keyed by swap id.
+	#[pallet::storage]
+	pub(crate) type DcaStates<T: Config> = StorageMap<_, Twox64Concat, SwapId, DcaState>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -294,6 +399,8 @@ pub mod pallet {
 			channel_metadata: Option<CcmChannelMetadata>,
 			source_chain_expiry_block: <AnyChain as Chain>::ChainBlockNumber,
 			boost_fee: BasisPoints,
+			refund_parameters: Option<SwapRefundParameters<EncodedAddress>>,
+			dca_parameters: Option<DcaParameters>,
 		},
 		/// A swap deposit has been received.
 		SwapScheduled {
@@ -319,6 +426,9 @@ pub mod pallet {
 			egress_amount: AssetAmount,
 			swap_output: AssetAmount,
 			intermediate_amount: Option<AssetAmount>,
+			/// Set if the swap is split into chunks, in which case this event is emitted for each
+			/// chunk, with the chunk's input and output.
+			dca_chunk: Option<DcaChunk>,
 		},
 		/// A swap egress has been scheduled.
 		SwapEgressScheduled {
@@ -376,6 +486,22 @@ pub mod pallet {
 			amount: AssetAmount,
 			reason: DispatchError,
 		},
+		/// A swap would have yielded less than its minimum output, so its input has been
+		/// refunded instead.
+		SwapRefunded {
+			swap_id: SwapId,
+			egress_id: EgressId,
+			asset: Asset,
+			amount: AssetAmount,
+			fee: AssetAmount,
+			refund_address: EncodedAddress,
+		},
+		/// The next chunk of a DCA swap has been scheduled.
+		DcaChunkScheduled {
+			swap_id: SwapId,
+			chunk_input: AssetAmount,
+			execute_at: BlockNumberFor<T>,
+		},
 	}
 	#[pallet::error]
 	pub enum Error<T> {
@@ -400,6 +526,10 @@ pub mod pallet {
 		BrokerRegistrationDisabled,
 		/// Broker commission bps is limited to 1000 points.
 		BrokerCommissionBpsTooHigh,
+		/// The refund address could not be decoded or is not on the source asset's chain.
+		InvalidRefundAddress,
+		/// The DCA parameters are out of range, or were requested for a CCM channel.
+		InvalidDcaParameters,
 	}
 
 	#[pallet::hooks]
@@ -411,10 +541,11 @@ pub mod pallet {
 			}
 
 			let mut block_to_process = FirstUnprocessedBlock::<T>::get();
+			let mut used_weight = Weight::zero();
 
 			// NOTE: we iterate manually because BlockNumberFor<T> does not implement Step:
 			while block_to_process <= current_block {
-				match Self::process_swaps_for_block(block_to_process) {
+				match Self::process_swaps_for_block(block_to_process, &mut used_weight) {
 					Err(BatchExecutionError::SwapLegFailed { asset, direction, amount }) => {
 						Self::deposit_event(Event::<T>::BatchSwapFailed {
 							asset,
@@ -438,6 +569,11 @@ pub mod pallet {
 				}
 			}
 			FirstUnprocessedBlock::<T>::set(block_to_process);
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				used_weight,
+				DispatchClass::Mandatory,
+			);
 		}
 	}
 
@@ -445,11 +581,18 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Request a swap deposit address.
 		///
+		/// If `refund_parameters` are provided, any swap from the channel that would yield less
+		/// than `min_output` of the destination asset is refunded to the `refund_address` instead.
+		///
+		/// If `dca_parameters` are provided, swaps from the channel are split into
+		/// `number_of_chunks` chunks, executed `chunk_interval` blocks apart.
+		///
 		/// ## Events
 		///
 		/// - [SwapDepositAddressReady](Event::SwapDepositAddressReady)
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::request_swap_deposit_address())]
+		#[allow(clippy::too_many_arguments)]
 		pub fn request_swap_deposit_address(
 			origin: OriginFor<T>,
 			source_asset: Asset,
@@ -458,6 +601,8 @@ pub mod pallet {
 			broker_commission_bps: BasisPoints,
 			channel_metadata: Option<CcmChannelMetadata>,
 			boost_fee: BasisPoints,
+			refund_parameters: Option<SwapRefundParameters<EncodedAddress>>,
+			dca_parameters: Option<DcaParameters>,
 		) -> DispatchResult {
 			ensure!(T::SafeMode::get().deposits_enabled, Error::<T>::DepositsDisabled);
 			let broker = T::AccountRoleRegistry::ensure_broker(origin)?;
@@ -466,6 +611,16 @@ pub mod pallet {
 			let destination_address_internal =
 				Self::validate_destination_address(&destination_address, destination_asset)?;
 
+			let refund_parameters_internal = refund_parameters
+				.clone()
+				.map(|params| {
+					params.try_map_address(|refund_address| {
+						Self::validate_destination_address(&refund_address, source_asset)
+							.map_err(|_| Error::<T>::InvalidRefundAddress)
+					})
+				})
+				.transpose()?;
+
 			if channel_metadata.is_some() {
 				// Currently only Ethereum supports CCM.
 				ensure!(
@@ -474,6 +629,16 @@ pub mod pallet {
 				);
 			}
 
+			if let Some(DcaParameters { number_of_chunks, chunk_interval }) = dca_parameters {
+				ensure!(
+					channel_metadata.is_none() &&
+						number_of_chunks > 0 && chunk_interval > 0 &&
+						(number_of_chunks - 1).saturating_mul(chunk_interval) <=
+							MAX_DCA_DURATION_BLOCKS,
+					Error::<T>::InvalidDcaParameters
+				);
+			}
+
 			let (channel_id, deposit_address, expiry_height) =
 				T::DepositHandler::request_swap_deposit_address(
 					source_asset,
@@ -483,6 +648,8 @@ pub mod pallet {
 					broker,
 					channel_metadata.clone(),
 					boost_fee,
+					refund_parameters_internal,
+					dca_parameters,
 				)?;
 
 			Self::deposit_event(Event::<T>::SwapDepositAddressReady {
@@ -495,6 +662,8 @@ pub mod pallet {
 				channel_metadata,
 				source_chain_expiry_block: expiry_height,
 				boost_fee,
+				refund_parameters,
+				dca_parameters,
 			});
 
 			Ok(())
@@ -569,6 +738,8 @@ pub mod pallet {
 				to,
 				deposit_amount,
 				SwapType::Swap(destination_address_internal.clone()),
+				None,
+				None,
 			);
 
 			Self::deposit_event(Event::<T>::SwapScheduled {
@@ -659,29 +830,59 @@ pub mod pallet {
 
 	impl<T: Config> Pallet<T> {
 		// Transactional ensures that any failed swap will rollback all storage changes.
+		//
+		// The weight of every execution of the batch is added to `used_weight`, which is not
+		// rolled back with the storage.
 		#[transactional]
-		fn process_swaps_for_block(block: BlockNumberFor<T>) -> Result<(), BatchExecutionError> {
+		fn process_swaps_for_block(
+			block: BlockNumberFor<T>,
+			used_weight: &mut Weight,
+		) -> Result<(), BatchExecutionError> {
 			let mut swaps = SwapQueue::<T>::take(block);
 
 			if swaps.is_empty() {
 				return Ok(())
 			}
 
-			// Swap into Stable asset first.
-			Self::do_group_and_swap(&mut swaps, SwapLeg::ToStable)?;
+			// Swaps that fall below their minimum output are taken out of the batch and the rest of
+			// the batch is executed again, since removing swaps changes the price for the others.
+			// This is only done once: if the re-run leaves further swaps below their minimum, every
+			// swap with a minimum output is refunded so that the final execution can't fail.
+			let mut refunded_swaps = Vec::new();
+			let mut is_rerun = false;
+			let swaps = loop {
+				let mut batch = swaps.clone();
+				used_weight.saturating_accrue(T::WeightInfo::execute_batch(batch.len() as u32));
+				let swap_ids_to_refund =
+					with_transaction(|| match Self::execute_batch(&mut batch) {
+						Ok(()) => {
+							let swap_ids_to_refund =
+								Self::swaps_to_refund(&batch, Swap::is_below_min_output);
+							if swap_ids_to_refund.is_empty() {
+								TransactionOutcome::Commit(Ok(swap_ids_to_refund))
+							} else {
+								TransactionOutcome::Rollback(Ok(swap_ids_to_refund))
+							}
+						},
+						Err(err) => TransactionOutcome::Rollback(Err(err)),
+					})?;
 
-			// Take NetworkFee for all swaps
-			for swap in swaps.iter_mut() {
-				debug_assert!(
-					swap.stable_amount.is_some(),
-					"All swaps should have Stable amount set here"
-				);
-				let stable_amount = swap.stable_amount.get_or_insert_with(Default::default);
-				*stable_amount = T::SwappingApi::take_network_fee(*stable_amount);
-			}
+				if swap_ids_to_refund.is_empty() {
+					break batch
+				}
 
-			// Swap from Stable asset, and complete the swap logic.
-			Self::do_group_and_swap(&mut swaps, SwapLeg::FromStable)?;
+				let swap_ids_to_refund = if is_rerun {
+					Self::swaps_to_refund(&batch, |swap| swap.refund_parameters.is_some())
+				} else {
+					swap_ids_to_refund
+				};
+				is_rerun = true;
+
+				let (to_refund, remaining): (Vec<_>, Vec<_>) =
+					swaps.into_iter().partition(|swap| swap_ids_to_refund.contains(&swap.swap_id));
+				refunded_swaps.extend(to_refund);
+				swaps = remaining;
+			};
 
 			for swap in swaps {
 				if let Some(swap_output) = swap.final_output {
@@ -694,38 +895,21 @@ pub mod pallet {
 						egress_amount: swap_output,
 						swap_output,
 						intermediate_amount: swap.intermediate_amount(),
+						dca_chunk: DcaStates::<T>::get(swap.swap_id)
+							.map(|dca_state| dca_state.current_chunk()),
 					});
 					// Handle swap completion logic.
 					match &swap.swap_type {
-						SwapType::Swap(destination_address) =>
-							match T::EgressHandler::schedule_egress(
-								swap.to,
-								swap_output,
-								destination_address.clone(),
-								None,
-							) {
-								Ok(ScheduledEgressDetails {
-									egress_id,
-									egress_amount,
-									fee_withheld,
-								}) => {
-									Self::deposit_event(Event::<T>::SwapEgressScheduled {
-										swap_id: swap.swap_id,
-										egress_id,
-										asset: swap.to,
-										amount: egress_amount,
-										fee: fee_withheld,
-									});
-								},
-								Err(err) => {
-									Self::deposit_event(Event::<T>::SwapEgressIgnored {
-										swap_id: swap.swap_id,
-										asset: swap.to,
-										amount: swap_output,
-										reason: err.into(),
-									});
-								},
-							},
+						SwapType::Swap(destination_address) => {
+							if let Some(total_output) = Self::complete_chunk(&swap, swap_output) {
+								Self::schedule_swap_egress(
+									swap.swap_id,
+									swap.to,
+									total_output,
+									destination_address.clone(),
+								);
+							}
+						},
 						SwapType::CcmPrincipal(ccm_id) => {
 							Self::handle_ccm_swap_result(
 								*ccm_id,
@@ -742,9 +926,197 @@ pub mod pallet {
 				}
 			}
 
+			Self::refund_swaps(refunded_swaps);
+
 			Ok(())
 		}
 
+		/// Executes both legs of a batch of swaps, taking the network fee in between. The swaps
+		/// are updated in-place.
+		fn execute_batch(swaps: &mut Vec<Swap>) -> Result<(), BatchExecutionError> {
+			// Swap into Stable asset first.
+			Self::do_group_and_swap(swaps, SwapLeg::ToStable)?;
+
+			// Take NetworkFee for all swaps
+			for swap in swaps.iter_mut() {
+				debug_assert!(
+					swap.stable_amount.is_some(),
+					"All swaps should have Stable amount set here"
+				);
+				let stable_amount = swap.stable_amount.get_or_insert_with(Default::default);
+				*stable_amount = T::SwappingApi::take_network_fee(*stable_amount);
+			}
+
+			// Swap from Stable asset, and complete the swap logic.
+			Self::do_group_and_swap(swaps, SwapLeg::FromStable)
+		}
+
+		/// Returns the ids of the swaps that should be refunded. The gas swap of a CCM is included
+		/// if the CCM's principal swap should be refunded.
+		fn swaps_to_refund(
+			swaps: &[Swap],
+			should_refund: impl Fn(&Swap) -> bool,
+		) -> BTreeSet<SwapId> {
+			let failed_ccms = swaps
+				.iter()
+				.filter(|swap| should_refund(swap))
+				.filter_map(|swap| match swap.swap_type {
+					SwapType::CcmPrincipal(ccm_id) => Some(ccm_id),
+					_ => None,
+				})
+				.collect::<BTreeSet<_>>();
+
+			swaps
+				.iter()
+				.filter(|swap| {
+					should_refund(swap) ||
+						matches!(swap.swap_type, SwapType::CcmGas(ccm_id) if failed_ccms.contains(&ccm_id))
+				})
+				.map(|swap| swap.swap_id)
+				.collect()
+		}
+
+		/// Refunds the input of swaps that were taken out of a batch for falling below their
+		/// minimum output. A CCM is refunded as a whole, including its gas budget.
+		fn refund_swaps(refunded_swaps: Vec<Swap>) {
+			let mut refunded_gas = BTreeMap::<u64, AssetAmount>::new();
+			for swap in &refunded_swaps {
+				if let SwapType::CcmGas(ccm_id) = swap.swap_type {
+					refunded_gas.entry(ccm_id).or_default().saturating_accrue(swap.amount);
+				}
+			}
+
+			for swap in refunded_swaps {
+				let Some(SwapRefundParameters { refund_address, .. }) = swap.refund_parameters
+				else {
+					continue
+				};
+				let refund_amount = match swap.swap_type {
+					SwapType::Swap(ref destination_address) =>
+						match DcaStates::<T>::take(swap.swap_id) {
+							Some(dca_state) => {
+								// The output of the chunks that were already executed is not
+								// refunded.
+								if !dca_state.accumulated_output.is_zero() {
+									Self::schedule_swap_egress(
+										swap.swap_id,
+										swap.to,
+										dca_state.accumulated_output,
+										destination_address.clone(),
+									);
+								}
+								swap.amount.saturating_add(dca_state.remaining_input)
+							},
+							None => swap.amount,
+						},
+					SwapType::CcmPrincipal(ccm_id) => {
+						CcmOutputs::<T>::remove(ccm_id);
+						let Some(ccm_swap) = PendingCcms::<T>::take(ccm_id) else {
+							log_or_panic!("Refunded CCM {ccm_id} must be pending.");
+							continue
+						};
+						// Without a gas swap, the gas budget was held back in the source asset.
+						let gas_amount = match ccm_swap.gas_swap_id {
+							Some(_) => refunded_gas.get(&ccm_id).copied().unwrap_or_default(),
+							None => ccm_swap.deposit_metadata.channel_metadata.gas_budget,
+						};
+						swap.amount.saturating_add(gas_amount)
+					},
+					SwapType::CcmGas(_) => continue,
+				};
+
+				match T::EgressHandler::schedule_egress(
+					swap.from,
+					refund_amount,
+					refund_address.clone(),
+					None,
+				) {
+					Ok(ScheduledEgressDetails { egress_id, egress_amount, fee_withheld }) => {
+						Self::deposit_event(Event::<T>::SwapRefunded {
+							swap_id: swap.swap_id,
+							egress_id,
+							asset: swap.from,
+							amount: egress_amount,
+							fee: fee_withheld,
+							refund_address: T::AddressConverter::to_encoded_address(refund_address),
+						});
+					},
+					Err(err) => {
+						Self::deposit_event(Event::<T>::SwapEgressIgnored {
+							swap_id: swap.swap_id,
+							asset: swap.from,
+							amount: refund_amount,
+							reason: err.into(),
+						});
+					},
+				}
+			}
+		}
+
+		/// Adds the output of a chunk to its DCA swap and schedules the next chunk, if any. Returns
+		/// the total output of the swap once all chunks have been executed. Swaps that are not
+		/// split into chunks are complete after a single execution.
+		fn complete_chunk(swap: &Swap, chunk_output: AssetAmount) -> Option<AssetAmount> {
+			DcaStates::<T>::mutate_exists(swap.swap_id, |maybe_dca_state| {
+				let Some(dca_state) = maybe_dca_state.as_mut() else { return Some(chunk_output) };
+				dca_state.accumulated_output.saturating_accrue(chunk_output);
+
+				if dca_state.remaining_chunks == 0 {
+					let total_output = dca_state.accumulated_output;
+					*maybe_dca_state = None;
+					return Some(total_output)
+				}
+
+				let chunk_input = dca_state.take_next_chunk();
+				let execute_at =
+					frame_system::Pallet::<T>::block_number() + dca_state.chunk_interval.into();
+				SwapQueue::<T>::append(
+					execute_at,
+					Swap::new(
+						swap.swap_id,
+						swap.from,
+						swap.to,
+						chunk_input,
+						swap.swap_type.clone(),
+						dca_state.chunk_refund_parameters(chunk_input),
+					),
+				);
+				Self::deposit_event(Event::<T>::DcaChunkScheduled {
+					swap_id: swap.swap_id,
+					chunk_input,
+					execute_at,
+				});
+				None
+			})
+		}
+
+		fn schedule_swap_egress(
+			swap_id: SwapId,
+			asset: Asset,
+			amount: AssetAmount,
+			destination_address: ForeignChainAddress,
+		) {
+			match T::EgressHandler::schedule_egress(asset, amount, destination_address, None) {
+				Ok(ScheduledEgressDetails { egress_id, egress_amount, fee_withheld }) => {
+					Self::deposit_event(Event::<T>::SwapEgressScheduled {
+						swap_id,
+						egress_id,
+						asset,
+						amount: egress_amount,
+						fee: fee_withheld,
+					});
+				},
+				Err(err) => {
+					Self::deposit_event(Event::<T>::SwapEgressIgnored {
+						swap_id,
+						asset,
+						amount,
+						reason: err.into(),
+					});
+				},
+			}
+		}
+
 		pub fn principal_and_gas_amounts(
 			deposit_amount: AssetAmount,
 			channel_metadata: &CcmChannelMetadata,
@@ -934,12 +1306,14 @@ pub mod pallet {
 		}
 
 		/// Schedule the swap, assuming all checks already passed. Return swap_id along
-		/// with the block at which the swap is scheduled to be executed.
+		/// with the block at which the swap (or its first chunk) is scheduled to be executed.
 		fn schedule_swap_internal(
 			from: Asset,
 			to: Asset,
 			amount: AssetAmount,
 			swap_type: SwapType,
+			refund_parameters: Option<SwapRefundParameters>,
+			dca_parameters: Option<DcaParameters>,
 		) -> (u64, BlockNumberFor<T>) {
 			let swap_id = SwapIdCounter::<T>::mutate(|id| {
 				id.saturating_accrue(1);
@@ -964,9 +1338,23 @@ pub mod pallet {
 
 			let execute_at = frame_system::Pallet::<T>::block_number() + SWAP_DELAY_BLOCKS.into();
 
+			let (swap_amount, refund_parameters) = match dca_parameters
+				.filter(|params| params.number_of_chunks > 1)
+			{
+				Some(dca_parameters) => {
+					let mut dca_state =
+						DcaState::new(swap_amount, dca_parameters, refund_parameters);
+					let chunk_input = dca_state.take_next_chunk();
+					let chunk_refund_parameters = dca_state.chunk_refund_parameters(chunk_input);
+					DcaStates::<T>::insert(swap_id, dca_state);
+					(chunk_input, chunk_refund_parameters)
+				},
+				None => (swap_amount, refund_parameters),
+			};
+
 			SwapQueue::<T>::append(
 				execute_at,
-				Swap::new(swap_id, from, to, swap_amount, swap_type),
+				Swap::new(swap_id, from, to, swap_amount, swap_type, refund_parameters),
 			);
 
 			(swap_id, execute_at)
@@ -987,6 +1375,8 @@ pub mod pallet {
 			broker_id: Self::AccountId,
 			broker_commission_bps: BasisPoints,
 			channel_id: ChannelId,
+			refund_parameters: Option<SwapRefundParameters>,
+			dca_parameters: Option<DcaParameters>,
 		) -> SwapId {
 			// Permill maxes out at 100% so this is safe.
 			let fee = Permill::from_parts(broker_commission_bps as u32 * BASIS_POINTS_PER_MILLION) *
@@ -1008,6 +1398,8 @@ pub mod pallet {
 				to,
 				net_amount,
 				SwapType::Swap(destination_address.clone()),
+				refund_parameters,
+				dca_parameters,
 			);
 			EarnedBrokerFees::<T>::mutate(&broker_id, from, |earned_fees| {
 				earned_fees.saturating_accrue(fee)
@@ -1082,6 +1474,8 @@ pub mod pallet {
 						destination_asset,
 						principal_swap_amount,
 						SwapType::CcmPrincipal(ccm_id),
+						deposit_metadata.refund_parameters.clone(),
+						None,
 					);
 					Self::deposit_event(Event::<T>::SwapScheduled {
 						swap_id,
@@ -1103,6 +1497,8 @@ pub mod pallet {
 					other_gas_asset,
 					gas_budget,
 					SwapType::CcmGas(ccm_id),
+					None,
+					None,
 				);
 				Self::deposit_event(Event::<T>::SwapScheduled {
 					swap_id,
@@ -1,6 +1,9 @@
 mod schedule_swaps;
+mod swap_refund_parameters;
 
 use cf_runtime_upgrade_utilities::VersionedMigration;
 
-pub type PalletMigration<T> =
-	(VersionedMigration<crate::Pallet<T>, schedule_swaps::Migration<T>, 1, 2>,);
+pub type PalletMigration<T> = (
+	VersionedMigration<crate::Pallet<T>, schedule_swaps::Migration<T>, 1, 2>,
+	VersionedMigration<crate::Pallet<T>, swap_refund_parameters::Migration<T>, 2, 3>,
+);
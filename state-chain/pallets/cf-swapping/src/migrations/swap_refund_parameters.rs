@@ -0,0 +1,152 @@
+use crate::*;
+use frame_support::traits::OnRuntimeUpgrade;
+use sp_std::marker::PhantomData;
+
+pub struct Migration<T: Config>(PhantomData<T>);
+
+mod old {
+	use super::*;
+
+	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	pub struct Swap {
+		pub swap_id: SwapId,
+		pub from: Asset,
+		pub to: Asset,
+		pub amount: AssetAmount,
+		pub swap_type: SwapType,
+		pub stable_amount: Option<AssetAmount>,
+		pub final_output: Option<AssetAmount>,
+		pub fee_taken: bool,
+	}
+
+	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	pub struct CcmDepositMetadata {
+		pub source_chain: ForeignChain,
+		pub source_address: Option<ForeignChainAddress>,
+		pub channel_metadata: CcmChannelMetadata,
+	}
+
+	#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	pub struct CcmSwap {
+		pub source_asset: Asset,
+		pub deposit_amount: AssetAmount,
+		pub destination_asset: Asset,
+		pub destination_address: ForeignChainAddress,
+		pub deposit_metadata: CcmDepositMetadata,
+		pub principal_swap_id: Option<SwapId>,
+		pub gas_swap_id: Option<SwapId>,
+	}
+
+	#[frame_support::storage_alias]
+	pub type SwapQueue<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, BlockNumberFor<T>, Vec<Swap>, ValueQuery>;
+}
+
+impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		SwapQueue::<T>::translate_values::<Vec<old::Swap>, _>(|old_swaps| {
+			Some(
+				old_swaps
+					.into_iter()
+					.map(|old_swap| Swap {
+						swap_id: old_swap.swap_id,
+						from: old_swap.from,
+						to: old_swap.to,
+						amount: old_swap.amount,
+						swap_type: old_swap.swap_type,
+						stable_amount: old_swap.stable_amount,
+						final_output: old_swap.final_output,
+						fee_taken: old_swap.fee_taken,
+						refund_parameters: None,
+					})
+					.collect(),
+			)
+		});
+
+		PendingCcms::<T>::translate_values::<old::CcmSwap, _>(|old_ccm| {
+			Some(CcmSwap {
+				source_asset: old_ccm.source_asset,
+				deposit_amount: old_ccm.deposit_amount,
+				destination_asset: old_ccm.destination_asset,
+				destination_address: old_ccm.destination_address,
+				deposit_metadata: CcmDepositMetadata {
+					source_chain: old_ccm.deposit_metadata.source_chain,
+					source_address: old_ccm.deposit_metadata.source_address,
+					channel_metadata: old_ccm.deposit_metadata.channel_metadata,
+					refund_parameters: None,
+				},
+				principal_swap_id: old_ccm.principal_swap_id,
+				gas_swap_id: old_ccm.gas_swap_id,
+			})
+		});
+
+		Weight::zero()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		let number_of_swaps = SwapQueue::<T>::iter_keys()
+			.map(|block| SwapQueue::<T>::decode_len(block).unwrap_or_default() as u32)
+			.sum::<u32>();
+		let number_of_ccms = PendingCcms::<T>::iter_keys().count() as u32;
+		Ok((number_of_swaps, number_of_ccms).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+		let (number_of_swaps, number_of_ccms) = <(u32, u32)>::decode(&mut &state[..])
+			.map_err(|_| "Failed to decode pre-upgrade state.")?;
+
+		ensure!(
+			number_of_swaps ==
+				SwapQueue::<T>::iter_values().map(|swaps| swaps.len() as u32).sum::<u32>(),
+			"Swap count mismatch!"
+		);
+		ensure!(
+			number_of_ccms == PendingCcms::<T>::iter_values().count() as u32,
+			"Pending CCM count mismatch!"
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod migration_tests {
+	use super::*;
+	use crate::mock::{new_test_ext, Test};
+
+	#[test]
+	fn test_migration() {
+		new_test_ext().execute_with(|| {
+			let destination_address = ForeignChainAddress::Eth([2; 20].into());
+
+			old::SwapQueue::<Test>::insert(
+				5,
+				vec![old::Swap {
+					swap_id: 1,
+					from: Asset::Flip,
+					to: Asset::Usdc,
+					amount: 100,
+					swap_type: SwapType::Swap(destination_address.clone()),
+					stable_amount: None,
+					final_output: None,
+					fee_taken: false,
+				}],
+			);
+
+			Migration::<Test>::on_runtime_upgrade();
+
+			assert_eq!(
+				SwapQueue::<Test>::get(5),
+				vec![Swap::new(
+					1,
+					Asset::Flip,
+					Asset::Usdc,
+					100,
+					SwapType::Swap(destination_address),
+					None
+				)]
+			);
+		});
+	}
+}
@@ -132,6 +132,10 @@ impl WeightInfo for MockWeightInfo {
 	fn register_as_broker() -> Weight {
 		Weight::from_parts(100, 0)
 	}
+
+	fn execute_batch(a: u32) -> Weight {
+		Weight::from_parts(100, 0).saturating_mul(a.into())
+	}
 }
 
 impl pallet_cf_swapping::Config for Test {
@@ -2,14 +2,14 @@ use super::*;
 use crate::{
 	mock::{RuntimeEvent, *},
 	CcmFailReason, CcmIdCounter, CcmOutputs, CcmSwap, CcmSwapOutput, CollectedRejectedFunds,
-	EarnedBrokerFees, Error, Event, MaximumSwapAmount, Pallet, PendingCcms, Swap, SwapOrigin,
-	SwapQueue, SwapType,
+	DcaStates, EarnedBrokerFees, Error, Event, MaximumSwapAmount, Pallet, PendingCcms, Swap,
+	SwapOrigin, SwapQueue, SwapType,
 };
 use cf_chains::{
 	address::{to_encoded_address, AddressConverter, EncodedAddress, ForeignChainAddress},
 	btc::{BitcoinNetwork, ScriptPubkey},
 	dot::PolkadotAccountId,
-	AnyChain, CcmChannelMetadata, CcmDepositMetadata,
+	AnyChain, CcmChannelMetadata, CcmDepositMetadata, DcaParameters, SwapRefundParameters,
 };
 use cf_primitives::{Asset, AssetAmount, BasisPoints, ForeignChain, NetworkEnvironment};
 use cf_test_utilities::assert_event_sequence;
@@ -49,6 +49,7 @@ fn generate_test_swaps() -> Vec<Swap> {
 			Asset::Usdc,
 			100,
 			SwapType::Swap(ForeignChainAddress::Eth([2; 20].into())),
+			None,
 		),
 		// USDC -> asset
 		Swap::new(
@@ -57,6 +58,7 @@ fn generate_test_swaps() -> Vec<Swap> {
 			Asset::Usdc,
 			40,
 			SwapType::Swap(ForeignChainAddress::Eth([9; 20].into())),
+			None,
 		),
 		// Both assets are on the Eth chain
 		Swap::new(
@@ -65,6 +67,7 @@ fn generate_test_swaps() -> Vec<Swap> {
 			Asset::Eth,
 			500,
 			SwapType::Swap(ForeignChainAddress::Eth([2; 20].into())),
+			None,
 		),
 		// Cross chain
 		Swap::new(
@@ -73,6 +76,7 @@ fn generate_test_swaps() -> Vec<Swap> {
 			Asset::Dot,
 			600,
 			SwapType::Swap(ForeignChainAddress::Dot(PolkadotAccountId::from_aliased([4; 32]))),
+			None,
 		),
 	]
 }
@@ -116,6 +120,8 @@ fn insert_swaps(swaps: &[Swap]) {
 				broker_id as u64,
 				2,
 				1,
+				None,
+				None,
 			);
 		}
 	}
@@ -133,6 +139,7 @@ fn generate_ccm_deposit() -> CcmDepositMetadata {
 		source_chain: ForeignChain::Ethereum,
 		source_address: Some(ForeignChainAddress::Eth([0xcf; 20].into())),
 		channel_metadata: generate_ccm_channel(),
+		refund_parameters: None,
 	}
 }
 
@@ -151,7 +158,9 @@ fn request_swap_success_with_valid_parameters() {
 			EncodedAddress::Eth(Default::default()),
 			0,
 			None,
-			0
+			0,
+			None,
+			None,
 		));
 	});
 }
@@ -200,6 +209,8 @@ fn expect_earned_fees_to_be_recorded() {
 			ALICE,
 			200,
 			1,
+			None,
+			None,
 		);
 		assert_eq!(EarnedBrokerFees::<Test>::get(ALICE, cf_primitives::Asset::Flip), 2);
 		<Pallet<Test> as SwapDepositHandler>::schedule_swap_from_channel(
@@ -212,6 +223,8 @@ fn expect_earned_fees_to_be_recorded() {
 			ALICE,
 			200,
 			1,
+			None,
+			None,
 		);
 		assert_eq!(EarnedBrokerFees::<Test>::get(ALICE, cf_primitives::Asset::Flip), 4);
 	});
@@ -232,6 +245,8 @@ fn cannot_swap_with_incorrect_destination_address_type() {
 			ALICE,
 			2,
 			1,
+			None,
+			None,
 		);
 
 		assert_swaps_queue_is_empty();
@@ -250,7 +265,9 @@ fn expect_swap_id_to_be_emitted() {
 				EncodedAddress::Eth(Default::default()),
 				0,
 				None,
-				0
+				0,
+				None,
+				None,
 			));
 
 			const AMOUNT: AssetAmount = 500;
@@ -265,6 +282,8 @@ fn expect_swap_id_to_be_emitted() {
 				ALICE,
 				0,
 				1,
+				None,
+				None,
 			);
 			// 3. Process swaps -> SwapExecuted, SwapEgressScheduled
 			Swapping::on_finalize(1);
@@ -443,7 +462,9 @@ fn rejects_invalid_swap_deposit() {
 				EncodedAddress::Dot(Default::default()),
 				0,
 				Some(ccm.clone()),
-				0
+				0,
+				None,
+				None,
 			),
 			Error::<Test>::IncompatibleAssetAndAddress
 		);
@@ -456,7 +477,9 @@ fn rejects_invalid_swap_deposit() {
 				EncodedAddress::Dot(Default::default()),
 				0,
 				Some(ccm),
-				0
+				0,
+				None,
+				None,
 			),
 			Error::<Test>::CcmUnsupportedForTargetChain
 		);
@@ -520,7 +543,9 @@ fn can_process_ccms_via_swap_deposit_address() {
 			EncodedAddress::Eth(Default::default()),
 			0,
 			Some(request_ccm),
-			0
+			0,
+			None,
+			None,
 		));
 		assert_ok!(Swapping::on_ccm_deposit(
 			Asset::Dot,
@@ -555,8 +580,9 @@ fn can_process_ccms_via_swap_deposit_address() {
 					Asset::Eth,
 					deposit_amount - gas_budget,
 					SwapType::CcmPrincipal(1),
+					None,
 				),
-				Swap::new(2, Asset::Dot, Asset::Eth, gas_budget, SwapType::CcmGas(1)),
+				Swap::new(2, Asset::Dot, Asset::Eth, gas_budget, SwapType::CcmGas(1), None),
 			]
 		);
 
@@ -631,8 +657,9 @@ fn can_process_ccms_via_extrinsic() {
 					Asset::Usdc,
 					deposit_amount - gas_budget,
 					SwapType::CcmPrincipal(1),
+					None,
 				),
-				Swap::new(2, Asset::Btc, Asset::Eth, gas_budget, SwapType::CcmGas(1))
+				Swap::new(2, Asset::Btc, Asset::Eth, gas_budget, SwapType::CcmGas(1), None)
 			]
 		);
 		assert_eq!(CcmOutputs::<Test>::get(1), Some(CcmSwapOutput { principal: None, gas: None }));
@@ -711,6 +738,7 @@ fn can_handle_ccms_with_non_native_gas_asset() {
 				Asset::Usdc,
 				deposit_amount - gas_budget,
 				SwapType::CcmPrincipal(1),
+				None,
 			)]
 		);
 		assert_eq!(
@@ -786,7 +814,7 @@ fn can_handle_ccms_with_native_gas_asset() {
 		let execute_at = System::block_number() + u64::from(SWAP_DELAY_BLOCKS);
 		assert_eq!(
 			SwapQueue::<Test>::get(execute_at),
-			vec![Swap::new(1, Asset::Usdc, Asset::Eth, gas_budget, SwapType::CcmGas(1),)]
+			vec![Swap::new(1, Asset::Usdc, Asset::Eth, gas_budget, SwapType::CcmGas(1), None)]
 		);
 		assert_eq!(
 			CcmOutputs::<Test>::get(1),
@@ -908,6 +936,7 @@ fn swap_by_witnesser_happy_path() {
 				to,
 				amount,
 				SwapType::Swap(ForeignChainAddress::Eth(Default::default()),),
+				None,
 			)]
 		);
 		System::assert_last_event(RuntimeEvent::Swapping(Event::<Test>::SwapScheduled {
@@ -944,6 +973,8 @@ fn swap_by_deposit_happy_path() {
 			Default::default(),
 			Default::default(),
 			1,
+			None,
+			None,
 		);
 
 		let execute_at = System::block_number() + u64::from(SWAP_DELAY_BLOCKS);
@@ -957,6 +988,7 @@ fn swap_by_deposit_happy_path() {
 				to,
 				amount,
 				SwapType::Swap(ForeignChainAddress::Eth(Default::default())),
+				None,
 			)]
 		);
 		System::assert_last_event(RuntimeEvent::Swapping(Event::<Test>::SwapScheduled {
@@ -1096,10 +1128,17 @@ fn process_all_into_stable_swaps_first() {
 		assert_eq!(
 			SwapQueue::<Test>::get(execute_at),
 			vec![
-				Swap::new(1, Asset::Flip, Asset::Eth, amount, SwapType::Swap(address.clone()),),
-				Swap::new(2, Asset::Btc, Asset::Eth, amount, SwapType::Swap(address.clone()),),
-				Swap::new(3, Asset::Dot, Asset::Eth, amount, SwapType::Swap(address.clone()),),
-				Swap::new(4, Asset::Usdc, Asset::Eth, amount, SwapType::Swap(address)),
+				Swap::new(
+					1,
+					Asset::Flip,
+					Asset::Eth,
+					amount,
+					SwapType::Swap(address.clone()),
+					None
+				),
+				Swap::new(2, Asset::Btc, Asset::Eth, amount, SwapType::Swap(address.clone()), None),
+				Swap::new(3, Asset::Dot, Asset::Eth, amount, SwapType::Swap(address.clone()), None),
+				Swap::new(4, Asset::Usdc, Asset::Eth, amount, SwapType::Swap(address), None),
 			]
 		);
 
@@ -1364,6 +1403,7 @@ fn can_handle_ccm_with_zero_swap_outputs() {
 					swap_input: 99_000,
 					swap_output: 9,
 					intermediate_amount: None,
+					dca_chunk: None,
 				}),
 				RuntimeEvent::Swapping(Event::<Test>::SwapExecuted {
 					swap_id: 2,
@@ -1374,6 +1414,7 @@ fn can_handle_ccm_with_zero_swap_outputs() {
 					swap_input: 1_000,
 					swap_output: 0,
 					intermediate_amount: None,
+					dca_chunk: None,
 				}),
 			);
 
@@ -1399,6 +1440,8 @@ fn can_handle_swaps_with_zero_outputs() {
 				Default::default(),
 				0,
 				0,
+				None,
+				None,
 			);
 			Swapping::schedule_swap_from_channel(
 				eth_address.clone(),
@@ -1410,6 +1453,8 @@ fn can_handle_swaps_with_zero_outputs() {
 				Default::default(),
 				0,
 				0,
+				None,
+				None,
 			);
 
 			// Change the swap rate so swap output will be 0
@@ -1496,6 +1541,8 @@ fn swap_excess_are_confiscated_ccm_via_deposit() {
 			0,
 			Some(request_ccm),
 			0,
+			None,
+			None,
 		));
 
 		assert_ok!(Swapping::on_ccm_deposit(
@@ -1689,6 +1736,8 @@ fn swap_excess_are_confiscated_for_swap_via_deposit() {
 			ALICE,
 			0,
 			0,
+			None,
+			None,
 		);
 
 		// Excess fee is confiscated
@@ -1894,6 +1943,8 @@ fn swap_with_custom_broker_fee(
 		ALICE,
 		broker_fee,
 		1,
+		None,
+		None,
 	);
 }
 
@@ -1994,6 +2045,8 @@ fn broker_bps_is_limited() {
 				1001,
 				None,
 				0,
+				None,
+				None,
 			),
 			Error::<Test>::BrokerCommissionBpsTooHigh
 		);
@@ -2132,7 +2185,9 @@ fn deposit_address_ready_event_contain_correct_boost_fee_value() {
 			EncodedAddress::Eth(Default::default()),
 			0,
 			None,
-			BOOST_FEE
+			BOOST_FEE,
+			None,
+			None,
 		));
 		assert_event_sequence!(
 			Test,
@@ -2159,3 +2214,313 @@ fn can_update_multiple_items_at_once() {
 		assert_eq!(MaximumSwapAmount::<Test>::get(Asset::Dot), Some(200));
 	});
 }
+
+#[test]
+fn swaps_below_minimum_output_are_refunded() {
+	new_test_ext().execute_with(|| {
+		const AMOUNT: AssetAmount = 1_000;
+		SwapRate::set(0.5f64);
+		let destination_address = ForeignChainAddress::Eth([0xbb; 20].into());
+		let refund_address = ForeignChainAddress::Eth([0xaa; 20].into());
+
+		for min_output in [AMOUNT / 2 + 1, AMOUNT / 2] {
+			<Pallet<Test> as SwapDepositHandler>::schedule_swap_from_channel(
+				ForeignChainAddress::Eth(Default::default()),
+				Default::default(),
+				Asset::Flip,
+				Asset::Usdc,
+				AMOUNT,
+				destination_address.clone(),
+				ALICE,
+				0,
+				1,
+				Some(SwapRefundParameters { min_output, refund_address: refund_address.clone() }),
+				None,
+			);
+		}
+
+		Swapping::on_finalize(System::block_number() + u64::from(SWAP_DELAY_BLOCKS));
+
+		// The batch is executed a second time without the swap that is refunded.
+		assert_eq!(
+			*System::block_weight().get(frame_support::dispatch::DispatchClass::Mandatory),
+			MockWeightInfo::execute_batch(2) + MockWeightInfo::execute_batch(1)
+		);
+
+		// Only the swap that meets its minimum output is executed.
+		assert_eq!(
+			MockEgressHandler::<AnyChain>::get_scheduled_egresses(),
+			vec![
+				MockEgressParameter::Swap {
+					asset: Asset::Usdc,
+					amount: AMOUNT / 2,
+					fee: 0,
+					destination_address,
+				},
+				MockEgressParameter::Swap {
+					asset: Asset::Flip,
+					amount: AMOUNT,
+					fee: 0,
+					destination_address: refund_address.clone(),
+				},
+			]
+		);
+		System::assert_has_event(RuntimeEvent::Swapping(Event::SwapRefunded {
+			swap_id: 1,
+			egress_id: (ForeignChain::Ethereum, 2),
+			asset: Asset::Flip,
+			amount: AMOUNT,
+			fee: 0,
+			refund_address: MockAddressConverter::to_encoded_address(refund_address),
+		}));
+	});
+}
+
+#[test]
+fn ccm_below_minimum_output_is_refunded_with_gas_budget() {
+	new_test_ext().execute_with(|| {
+		const DEPOSIT_AMOUNT: AssetAmount = 10_000;
+		SwapRate::set(0.5f64);
+		let refund_address = ForeignChainAddress::Dot(PolkadotAccountId::from_aliased([0xaa; 32]));
+
+		assert_ok!(Swapping::on_ccm_deposit(
+			Asset::Dot,
+			DEPOSIT_AMOUNT,
+			Asset::Eth,
+			ForeignChainAddress::Eth(Default::default()),
+			CcmDepositMetadata {
+				refund_parameters: Some(SwapRefundParameters {
+					min_output: DEPOSIT_AMOUNT,
+					refund_address: refund_address.clone(),
+				}),
+				..generate_ccm_deposit()
+			},
+			SwapOrigin::Vault { tx_hash: Default::default() },
+		));
+
+		Swapping::on_finalize(System::block_number() + u64::from(SWAP_DELAY_BLOCKS));
+
+		// Both the principal and the gas budget are refunded in the source asset.
+		assert_eq!(
+			MockEgressHandler::<AnyChain>::get_scheduled_egresses(),
+			vec![MockEgressParameter::Swap {
+				asset: Asset::Dot,
+				amount: DEPOSIT_AMOUNT,
+				fee: 0,
+				destination_address: refund_address,
+			}]
+		);
+		System::assert_has_event(RuntimeEvent::Swapping(Event::SwapRefunded {
+			swap_id: 1,
+			egress_id: (ForeignChain::Polkadot, 1),
+			asset: Asset::Dot,
+			amount: DEPOSIT_AMOUNT,
+			fee: 0,
+			refund_address: MockAddressConverter::to_encoded_address(refund_address),
+		}));
+		assert_eq!(PendingCcms::<Test>::get(1), None);
+		assert_eq!(CcmOutputs::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn refund_address_must_match_source_chain() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Swapping::request_swap_deposit_address(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				Asset::Usdc,
+				EncodedAddress::Eth(Default::default()),
+				0,
+				None,
+				0,
+				Some(SwapRefundParameters {
+					min_output: 1,
+					refund_address: EncodedAddress::Dot(Default::default()),
+				}),
+				None,
+			),
+			Error::<Test>::InvalidRefundAddress
+		);
+	});
+}
+
+fn process_swaps_until(block: u64) {
+	while System::block_number() < block {
+		System::set_block_number(System::block_number() + 1);
+		Swapping::on_finalize(System::block_number());
+	}
+}
+
+#[test]
+fn dca_swap_is_executed_in_chunks() {
+	new_test_ext().execute_with(|| {
+		const AMOUNT: AssetAmount = 1_000;
+		const CHUNK_INTERVAL: u32 = 2;
+		let destination_address = ForeignChainAddress::Eth([0xbb; 20].into());
+
+		<Pallet<Test> as SwapDepositHandler>::schedule_swap_from_channel(
+			ForeignChainAddress::Eth(Default::default()),
+			Default::default(),
+			Asset::Flip,
+			Asset::Usdc,
+			AMOUNT,
+			destination_address.clone(),
+			ALICE,
+			0,
+			1,
+			None,
+			Some(DcaParameters { number_of_chunks: 3, chunk_interval: CHUNK_INTERVAL }),
+		);
+		let first_chunk_block = System::block_number() + u64::from(SWAP_DELAY_BLOCKS);
+		assert_eq!(SwapQueue::<Test>::get(first_chunk_block)[0].amount, 333);
+
+		process_swaps_until(first_chunk_block);
+		System::assert_has_event(RuntimeEvent::Swapping(Event::DcaChunkScheduled {
+			swap_id: 1,
+			chunk_input: 333,
+			execute_at: first_chunk_block + u64::from(CHUNK_INTERVAL),
+		}));
+		// Nothing is egressed until all chunks have been executed.
+		assert!(MockEgressHandler::<AnyChain>::get_scheduled_egresses().is_empty());
+
+		process_swaps_until(first_chunk_block + u64::from(CHUNK_INTERVAL));
+		System::assert_has_event(RuntimeEvent::Swapping(Event::DcaChunkScheduled {
+			swap_id: 1,
+			chunk_input: 334,
+			execute_at: first_chunk_block + 2 * u64::from(CHUNK_INTERVAL),
+		}));
+
+		process_swaps_until(first_chunk_block + 2 * u64::from(CHUNK_INTERVAL));
+		assert_eq!(
+			System::events()
+				.into_iter()
+				.filter_map(|record| match record.event {
+					RuntimeEvent::Swapping(Event::SwapExecuted {
+						swap_id: 1,
+						swap_input,
+						dca_chunk,
+						..
+					}) => Some((swap_input, dca_chunk)),
+					_ => None,
+				})
+				.collect::<Vec<_>>(),
+			(0..3)
+				.zip([333, 333, 334])
+				.map(|(index, swap_input)| (
+					swap_input,
+					Some(DcaChunk { index, number_of_chunks: 3 })
+				))
+				.collect::<Vec<_>>()
+		);
+		assert_eq!(
+			MockEgressHandler::<AnyChain>::get_scheduled_egresses(),
+			vec![MockEgressParameter::Swap {
+				asset: Asset::Usdc,
+				amount: AMOUNT,
+				fee: 0,
+				destination_address,
+			}]
+		);
+		assert_eq!(DcaStates::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn dca_chunk_below_minimum_output_refunds_remaining_input() {
+	new_test_ext().execute_with(|| {
+		const AMOUNT: AssetAmount = 1_000;
+		let destination_address = ForeignChainAddress::Eth([0xbb; 20].into());
+		let refund_address = ForeignChainAddress::Eth([0xaa; 20].into());
+
+		<Pallet<Test> as SwapDepositHandler>::schedule_swap_from_channel(
+			ForeignChainAddress::Eth(Default::default()),
+			Default::default(),
+			Asset::Flip,
+			Asset::Usdc,
+			AMOUNT,
+			destination_address.clone(),
+			ALICE,
+			0,
+			1,
+			Some(SwapRefundParameters {
+				min_output: AMOUNT / 2,
+				refund_address: refund_address.clone(),
+			}),
+			Some(DcaParameters { number_of_chunks: 3, chunk_interval: 1 }),
+		);
+		let first_chunk_block = System::block_number() + u64::from(SWAP_DELAY_BLOCKS);
+		process_swaps_until(first_chunk_block);
+
+		// The price drops below the minimum output for the second chunk.
+		SwapRate::set(0.1f64);
+		process_swaps_until(first_chunk_block + 1);
+
+		// The output of the first chunk is egressed, the rest of the input is refunded.
+		assert_eq!(
+			MockEgressHandler::<AnyChain>::get_scheduled_egresses(),
+			vec![
+				MockEgressParameter::Swap {
+					asset: Asset::Usdc,
+					amount: 333,
+					fee: 0,
+					destination_address,
+				},
+				MockEgressParameter::Swap {
+					asset: Asset::Flip,
+					amount: AMOUNT - 333,
+					fee: 0,
+					destination_address: refund_address,
+				},
+			]
+		);
+		assert_eq!(DcaStates::<Test>::get(1), None);
+		assert!(SwapQueue::<Test>::iter_values().all(|swaps| swaps.is_empty()));
+	});
+}
+
+#[test]
+fn invalid_dca_parameters_are_rejected() {
+	new_test_ext().execute_with(|| {
+		for (dca_parameters, channel_metadata) in [
+			(DcaParameters { number_of_chunks: 0, chunk_interval: 1 }, None),
+			(DcaParameters { number_of_chunks: 2, chunk_interval: 0 }, None),
+			(
+				DcaParameters { number_of_chunks: 2, chunk_interval: MAX_DCA_DURATION_BLOCKS + 1 },
+				None,
+			),
+			(
+				DcaParameters { number_of_chunks: 2, chunk_interval: 1 },
+				Some(generate_ccm_channel()),
+			),
+		] {
+			assert_noop!(
+				Swapping::request_swap_deposit_address(
+					RuntimeOrigin::signed(ALICE),
+					Asset::Eth,
+					Asset::Usdc,
+					EncodedAddress::Eth(Default::default()),
+					0,
+					channel_metadata,
+					0,
+					None,
+					Some(dca_parameters),
+				),
+				Error::<Test>::InvalidDcaParameters
+			);
+		}
+
+		assert_ok!(Swapping::request_swap_deposit_address(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			Asset::Usdc,
+			EncodedAddress::Eth(Default::default()),
+			0,
+			None,
+			0,
+			None,
+			Some(DcaParameters { number_of_chunks: 2, chunk_interval: MAX_DCA_DURATION_BLOCKS }),
+		));
+	});
+}
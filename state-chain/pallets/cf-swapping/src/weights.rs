@@ -37,6 +37,7 @@ pub trait WeightInfo {
 	fn register_as_broker() -> Weight;
 	fn schedule_swap_from_contract() -> Weight;
 	fn ccm_deposit() -> Weight;
+	fn execute_batch(a: u32, ) -> Weight;
 }
 
 /// Weights for pallet_cf_swapping using the Substrate node and recommended hardware.
@@ -139,6 +140,23 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	// NOTE: Placeholder weight until a benchmark for batch execution exists. It assumes both
+	// legs of every swap hit a different pool.
+	/// Storage: `LiquidityPools::Pools` (r:2 w:2)
+	/// Proof: `LiquidityPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::FlipBuyInterval` (r:1 w:0)
+	/// Proof: `LiquidityPools::FlipBuyInterval` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::CollectedNetworkFee` (r:1 w:1)
+	/// Proof: `LiquidityPools::CollectedNetworkFee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `a` is `[1, 100]`.
+	fn execute_batch(a: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 5_000)
+			.saturating_add(Weight::from_parts(60_000_000, 10_000).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
+	}
 }
 
 // For backwards compatibility and tests
@@ -240,4 +258,21 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	// NOTE: Placeholder weight until a benchmark for batch execution exists. It assumes both
+	// legs of every swap hit a different pool.
+	/// Storage: `LiquidityPools::Pools` (r:2 w:2)
+	/// Proof: `LiquidityPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::FlipBuyInterval` (r:1 w:0)
+	/// Proof: `LiquidityPools::FlipBuyInterval` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::CollectedNetworkFee` (r:1 w:1)
+	/// Proof: `LiquidityPools::CollectedNetworkFee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `a` is `[1, 100]`.
+	fn execute_batch(a: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 5_000)
+			.saturating_add(Weight::from_parts(60_000_000, 10_000).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(a.into())))
+	}
 }
@@ -11,7 +11,7 @@ use cf_traits::{
 use frame_benchmarking::{account, v2::*, whitelist_account, whitelisted_caller};
 use frame_support::{
 	assert_ok,
-	traits::{IsType, OnInitialize, OnNewAccount, UnfilteredDispatchable},
+	traits::{Hooks, IsType, OnInitialize, OnNewAccount, UnfilteredDispatchable},
 };
 use frame_system::RawOrigin;
 use pallet_cf_validator::CurrentAuthorities;
@@ -340,5 +340,35 @@ mod benchmarks {
 
 		assert_eq!(KeygenResponseTimeout::<T, I>::get(), new_timeout);
 	}
+
+	#[benchmark]
+	fn remove_storage_items(n: Linear<1, 255>) {
+		for i in 0..n {
+			CeremonyParticipationStats::<T, I>::insert(
+				0,
+				account::<T::ValidatorId>("participant", i, SEED),
+				CeremonyParticipation {
+					keygen_ceremonies: 1,
+					signing_ceremonies: 1,
+					times_blamed: 1,
+				},
+			);
+		}
+
+		#[block]
+		{
+			let _ = CeremonyParticipationStats::<T, I>::clear_prefix(0, u32::MAX, None);
+		}
+	}
+
+	#[benchmark]
+	fn on_idle_with_nothing_to_remove() {
+		EpochsToCull::<T, I>::append(1);
+
+		#[block]
+		{
+			let _weight = Pallet::<T, I>::on_idle(Default::default(), Default::default());
+		}
+	}
 	// NOTE: Test suite not included because of dependency mismatch between benchmarks and mocks.
 }
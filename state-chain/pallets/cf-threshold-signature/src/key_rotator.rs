@@ -16,6 +16,9 @@ impl<T: Config<I>, I: 'static> KeyRotator for Pallet<T, I> {
 		assert_ne!(Self::status(), AsyncResult::Pending);
 
 		let ceremony_id = Self::increment_ceremony_id();
+		Self::record_participation(candidates.clone(), |stats| {
+			stats.keygen_ceremonies.saturating_accrue(1)
+		});
 
 		PendingKeyRotation::<T, I>::put(KeyRotationStatus::AwaitingKeygen {
 			ceremony_id,
@@ -62,8 +65,11 @@ impl<T: Config<I>, I: 'static> KeyRotator for Pallet<T, I> {
 
 						// from the SC's perspective, we don't care what set they're in, they get
 						// reported the same and each participant only gets one vote, like keygen.
-						let all_participants =
+						let all_participants: BTreeSet<_> =
 							sharing_participants.union(&receiving_participants).cloned().collect();
+						Self::record_participation(all_participants.clone(), |stats| {
+							stats.keygen_ceremonies.saturating_accrue(1)
+						});
 
 						PendingKeyRotation::<T, I>::put(KeyRotationStatus::AwaitingKeyHandover {
 							ceremony_id,
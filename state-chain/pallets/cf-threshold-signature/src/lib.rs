@@ -27,8 +27,8 @@ use cf_primitives::{
 use cf_runtime_utilities::{log_or_panic, EnumVariant};
 use cf_traits::{
 	offence_reporting::OffenceReporter, AsyncResult, CfeMultisigRequest, Chainflip,
-	CurrentEpochIndex, EpochInfo, EpochKey, KeyProvider, KeyRotator, SafeMode, Slashing,
-	ThresholdSigner, ThresholdSignerNomination,
+	CurrentEpochIndex, EpochInfo, EpochKey, EpochTransitionHandler, KeyProvider, KeyRotator,
+	SafeMode, Slashing, ThresholdSigner, ThresholdSignerNomination,
 };
 use cfe_events::ThresholdSignatureRequest;
 use frame_support::{
@@ -88,6 +88,19 @@ pub enum PalletOffence {
 	FailedKeyHandover,
 }
 
+/// A validator's participation in the ceremonies of a single epoch.
+#[derive(
+	Copy, Clone, Default, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen,
+)]
+pub struct CeremonyParticipation {
+	/// The number of keygen and key handover ceremonies the validator was selected for.
+	pub keygen_ceremonies: u32,
+	/// The number of signing ceremonies, including retries, the validator was nominated for.
+	pub signing_ceremonies: u32,
+	/// The number of times the validator was reported as an offender after a failed ceremony.
+	pub times_blamed: u32,
+}
+
 #[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub enum RequestType<Key, Participants> {
 	/// Uses the provided key and selects new participants from the provided epoch.
@@ -521,6 +534,24 @@ pub mod pallet {
 	pub type CeremonyIdCounter<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, CeremonyId, ValueQuery>;
 
+	/// Ceremony participation of each validator, indexed by the epoch in which the ceremonies were
+	/// started.
+	#[pallet::storage]
+	pub type CeremonyParticipationStats<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EpochIndex,
+		Blake2_128Concat,
+		T::ValidatorId,
+		CeremonyParticipation,
+		ValueQuery,
+	>;
+
+	/// Expired epochs whose ceremony participation statistics have yet to be culled.
+	#[pallet::storage]
+	pub type EpochsToCull<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, Vec<EpochIndex>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
 		pub key: Option<AggKeyFor<T, I>>,
@@ -794,7 +825,7 @@ pub mod pallet {
 								);
 							},
 							|offenders| {
-								T::OffenceReporter::report_many(
+								Self::report_offenders(
 									PalletOffence::FailedKeyHandover,
 									offenders.clone(),
 								);
@@ -836,7 +867,7 @@ pub mod pallet {
 
 					Self::deposit_event(match threshold_ceremony_type {
 						ThresholdCeremonyType::Standard => {
-							T::OffenceReporter::report_many(
+							Self::report_offenders(
 								PalletOffence::ParticipateSigningFailed,
 								offenders,
 							);
@@ -877,6 +908,21 @@ pub mod pallet {
 				T::Weights::on_initialize(T::EpochInfo::current_authority_count(), num_retries) +
 				T::Weights::report_offenders(num_offenders as AuthorityCount)
 		}
+
+		/// Culls the ceremony participation statistics of expired epochs, as far as the remaining
+		/// weight allows.
+		fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			cf_traits::cull_expired_epoch::<
+				EpochsToCull<T, I>,
+				CeremonyParticipationStats<T, I>,
+				_,
+				_,
+			>(
+				remaining_weight,
+				T::Weights::on_idle_with_nothing_to_remove(),
+				T::Weights::remove_storage_items,
+			)
+		}
 	}
 
 	#[pallet::origin]
@@ -1246,6 +1292,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Self::deposit_event(match maybe_epoch_key_and_participants {
 			Ok((epoch, key, participants)) => {
 				let ceremony_id = Self::increment_ceremony_id();
+				Self::record_participation(participants.clone(), |stats| {
+					stats.signing_ceremonies.saturating_accrue(1)
+				});
 				PendingCeremonies::<T, I>::insert(ceremony_id, {
 					CeremonyContext {
 						request_context: RequestContext {
@@ -1421,7 +1470,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		offenders: impl IntoIterator<Item = T::ValidatorId> + Clone,
 		event: Event<T, I>,
 	) {
-		T::OffenceReporter::report_many(PalletOffence::FailedKeygen, offenders.clone());
+		Self::report_offenders(PalletOffence::FailedKeygen, offenders.clone());
 		if T::SafeMode::get().slashing_enabled {
 			offenders.clone().into_iter().for_each(|offender| {
 				T::Slasher::slash_balance(&offender, KeygenSlashAmount::<T, I>::get());
@@ -1508,6 +1557,32 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			*id
 		})
 	}
+
+	fn record_participation(
+		participants: impl IntoIterator<Item = T::ValidatorId>,
+		update: impl Fn(&mut CeremonyParticipation),
+	) {
+		let epoch_index = T::EpochInfo::epoch_index();
+		for validator_id in participants {
+			CeremonyParticipationStats::<T, I>::mutate(epoch_index, validator_id, &update);
+		}
+	}
+
+	/// Reports the offenders of a failed ceremony and counts the blame against each of them.
+	fn report_offenders(
+		offence: PalletOffence,
+		offenders: impl IntoIterator<Item = T::ValidatorId> + Clone,
+	) {
+		T::OffenceReporter::report_many(offence, offenders.clone());
+		Self::record_participation(offenders, |stats| stats.times_blamed.saturating_accrue(1));
+	}
+}
+
+impl<T: Config<I>, I: 'static> EpochTransitionHandler for Pallet<T, I> {
+	/// Ceremony statistics are only kept for unexpired epochs. They are culled in `on_idle`.
+	fn on_expired_epoch(expired: EpochIndex) {
+		EpochsToCull::<T, I>::append(expired);
+	}
 }
 
 pub struct EnsureThresholdSigned<T: Config<I>, I: 'static = ()>(PhantomData<(T, I)>);
@@ -2,11 +2,11 @@ use core::marker::PhantomData;
 use std::collections::{BTreeMap, BTreeSet};
 
 use crate::{
-	mock::*, AttemptCount, AuthorityCount, CeremonyContext, CeremonyId, Error,
-	Event as PalletEvent, KeyHandoverResolutionPendingSince, KeyRotationStatus,
-	KeygenFailureVoters, KeygenOutcomeFor, KeygenResolutionPendingSince, KeygenResponseTimeout,
-	KeygenSuccessVoters, PalletOffence, PendingKeyRotation, RequestContext, RequestId,
-	ThresholdSignatureResponseTimeout,
+	mock::*, AttemptCount, AuthorityCount, CeremonyContext, CeremonyId, CeremonyParticipation,
+	CeremonyParticipationStats, EpochsToCull, Error, Event as PalletEvent,
+	KeyHandoverResolutionPendingSince, KeyRotationStatus, KeygenFailureVoters, KeygenOutcomeFor,
+	KeygenResolutionPendingSince, KeygenResponseTimeout, KeygenSuccessVoters, PalletOffence,
+	PendingKeyRotation, RequestContext, RequestId, ThresholdSignatureResponseTimeout, WeightInfo,
 };
 
 use cf_chains::mocks::{MockAggKey, MockEthereumChainCrypto};
@@ -17,8 +17,8 @@ use cf_traits::{
 		cfe_interface_mock::{MockCfeEvent, MockCfeInterface},
 		signer_nomination::MockNominator,
 	},
-	AccountRoleRegistry, AsyncResult, Chainflip, EpochInfo, EpochKey, KeyProvider,
-	KeyRotationStatusOuter, KeyRotator, SetSafeMode,
+	AccountRoleRegistry, AsyncResult, Chainflip, EpochInfo, EpochKey, EpochTransitionHandler,
+	KeyProvider, KeyRotationStatusOuter, KeyRotator, SetSafeMode,
 };
 pub use frame_support::traits::Get;
 
@@ -28,6 +28,7 @@ use frame_support::{
 	instances::Instance1,
 	pallet_prelude::DispatchResultWithPostInfo,
 	traits::{Hooks, OnInitialize},
+	weights::Weight,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::traits::BlockNumberProvider;
@@ -350,6 +351,29 @@ fn fail_path_with_timeout() {
 			// Participant 1 was reported for not responding.
 			MockOffenceReporter::assert_reported(PalletOffence::ParticipateSigningFailed, vec![1]);
 
+			// Both nominees took part in both attempts, and participant 1 was blamed once.
+			let epoch = <Test as Chainflip>::EpochInfo::epoch_index();
+			assert_eq!(
+				CeremonyParticipationStats::<Test, Instance1>::get(epoch, 1),
+				CeremonyParticipation {
+					keygen_ceremonies: 0,
+					signing_ceremonies: 2,
+					times_blamed: 1
+				}
+			);
+			assert_eq!(
+				CeremonyParticipationStats::<Test, Instance1>::get(epoch, 2),
+				CeremonyParticipation {
+					keygen_ceremonies: 0,
+					signing_ceremonies: 2,
+					times_blamed: 0
+				}
+			);
+			assert_eq!(
+				CeremonyParticipationStats::<Test, Instance1>::get(epoch, 3),
+				CeremonyParticipation::default()
+			);
+
 			// We have a new request pending: New ceremony_id, same request context.
 			assert_eq!(
 				get_ceremony_context(ceremony_id + 1, request_id, attempt_count + 1)
@@ -946,6 +970,42 @@ fn test_keygen_failure() {
 	});
 }
 
+#[test]
+fn keygen_failure_is_recorded_in_ceremony_participation() {
+	new_test_ext().execute_with(|| {
+		let epoch = <Test as Chainflip>::EpochInfo::epoch_index();
+		keygen_failure([BOB, CHARLIE]);
+
+		for candidate in ALL_CANDIDATES {
+			assert_eq!(
+				CeremonyParticipationStats::<Test, Instance1>::get(epoch, candidate),
+				CeremonyParticipation {
+					keygen_ceremonies: 1,
+					signing_ceremonies: 0,
+					times_blamed: if *candidate == ALICE { 0 } else { 1 },
+				}
+			);
+		}
+
+		<EthereumThresholdSigner as EpochTransitionHandler>::on_expired_epoch(epoch);
+		assert_eq!(EpochsToCull::<Test, Instance1>::get(), vec![epoch]);
+
+		// Each idle block culls as many entries as its remaining weight allows.
+		EthereumThresholdSigner::on_idle(
+			System::block_number(),
+			<() as WeightInfo>::on_idle_with_nothing_to_remove()
+				.saturating_add(<() as WeightInfo>::remove_storage_items(1)),
+		);
+		assert_eq!(
+			CeremonyParticipationStats::<Test, Instance1>::iter_prefix(epoch).count(),
+			ALL_CANDIDATES.len() - 1
+		);
+		EthereumThresholdSigner::on_idle(System::block_number(), Weight::MAX);
+		assert!(!CeremonyParticipationStats::<Test, Instance1>::contains_key(epoch, BOB));
+		assert!(EpochsToCull::<Test, Instance1>::get().is_empty());
+	});
+}
+
 // This happens when the threshold signer reports failure (through its status) to the validator
 // pallet. Once all threshold signers have reported some AsyncResult::Ready status (see
 // all_keys_rotator) then the validator pallet will call keygen() again
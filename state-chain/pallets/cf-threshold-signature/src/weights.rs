@@ -42,6 +42,8 @@ pub trait WeightInfo {
 	fn set_keygen_response_timeout() -> Weight;
 	fn on_initialize_failure(b: u32, ) -> Weight;
 	fn on_initialize_success() -> Weight;
+	fn remove_storage_items(n: u32, ) -> Weight;
+	fn on_idle_with_nothing_to_remove() -> Weight;
 }
 
 /// Weights for pallet_cf_threshold_signature using the Substrate node and recommended hardware.
@@ -211,6 +213,22 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(9))
 	}
+	// NOTE: The culling weights below are placeholders until the benchmarks are run.
+
+	/// Storage: `EthereumThresholdSigner::CeremonyParticipationStats` (r:n w:n)
+	/// Proof: `EthereumThresholdSigner::CeremonyParticipationStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_storage_items(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(1_500_000, 2_600).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `EthereumThresholdSigner::EpochsToCull` (r:1 w:0)
+	/// Proof: `EthereumThresholdSigner::EpochsToCull` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_idle_with_nothing_to_remove() -> Weight {
+		Weight::from_parts(3_000_000, 1_600)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -375,4 +393,20 @@ impl WeightInfo for () {
 				.saturating_add(RocksDbWeight::get().reads(1))
 				.saturating_add(RocksDbWeight::get().writes(1))
 		}
+	// NOTE: The culling weights below are placeholders until the benchmarks are run.
+
+	/// Storage: `EthereumThresholdSigner::CeremonyParticipationStats` (r:n w:n)
+	/// Proof: `EthereumThresholdSigner::CeremonyParticipationStats` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_storage_items(n: u32, ) -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(1_500_000, 2_600).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `EthereumThresholdSigner::EpochsToCull` (r:1 w:0)
+	/// Proof: `EthereumThresholdSigner::EpochsToCull` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn on_idle_with_nothing_to_remove() -> Weight {
+		Weight::from_parts(3_000_000, 1_600)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
 }
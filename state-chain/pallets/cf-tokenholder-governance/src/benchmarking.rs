@@ -2,13 +2,16 @@
 
 use super::*;
 
+use cf_chains::benchmarking_value::BenchmarkValue;
 use cf_traits::{Chainflip, FeePayment};
 use frame_benchmarking::v2::*;
-use frame_support::sp_runtime::traits::UniqueSaturatedFrom;
+use frame_support::{
+	assert_ok, sp_runtime::traits::UniqueSaturatedFrom, traits::UnfilteredDispatchable,
+};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_std::collections::btree_set::BTreeSet;
 
-fn generate_proposal() -> Proposal {
+fn generate_proposal<T: Config>() -> Proposal<T> {
 	Proposal::SetGovernanceKey(ForeignChain::Ethereum, vec![1; 32])
 }
 
@@ -20,7 +23,7 @@ mod benchmarks {
 	fn on_initialize_resolve_votes(a: Linear<10, 1_000>) {
 		// a: Number of backers
 
-		let proposal = generate_proposal();
+		let proposal = generate_proposal::<T>();
 		Proposals::<T>::insert(BlockNumberFor::<T>::from(1u32), proposal.clone());
 		let backers = (0..a).map(|i| account("doogle", i, 0)).collect::<BTreeSet<_>>();
 		for account in &backers {
@@ -60,7 +63,7 @@ mod benchmarks {
 		T::FeePayment::mint_to_account(&caller, T::ProposalFee::get());
 
 		#[extrinsic_call]
-		submit_proposal(RawOrigin::Signed(whitelisted_caller()), generate_proposal());
+		submit_proposal(RawOrigin::Signed(whitelisted_caller()), generate_proposal::<T>());
 
 		assert!(Proposals::<T>::contains_key(
 			<frame_system::Pallet<T>>::block_number() + T::VotingPeriod::get()
@@ -70,7 +73,7 @@ mod benchmarks {
 	#[benchmark]
 	fn back_proposal(a: Linear<1, 1_000>) {
 		let caller: T::AccountId = whitelisted_caller();
-		let proposal = generate_proposal();
+		let proposal = generate_proposal::<T>();
 		let backers = (0..a)
 			.map(|i| account::<T::AccountId>("signers", i, 0))
 			.collect::<BTreeSet<_>>();
@@ -86,5 +89,23 @@ mod benchmarks {
 		assert!(Backers::<T>::get(proposal).contains(&caller));
 	}
 
+	#[benchmark]
+	fn veto_proposal() {
+		let enactment_block = BlockNumberFor::<T>::from(1u32);
+		ParameterChangesAwaitingEnactment::<T>::insert(
+			enactment_block,
+			T::ParameterChange::benchmark_value(),
+		);
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+		let call = Call::<T>::veto_proposal { enactment_block };
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		assert!(!ParameterChangesAwaitingEnactment::<T>::contains_key(enactment_block));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
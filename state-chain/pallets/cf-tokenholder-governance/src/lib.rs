@@ -1,8 +1,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use cf_chains::{eth::Address, ForeignChain};
-use cf_traits::{BroadcastAnyChainGovKey, Chainflip, CommKeyBroadcaster, FeePayment, FundingInfo};
+use cf_traits::{
+	BroadcastAnyChainGovKey, Chainflip, CommKeyBroadcaster, FeePayment, FundingInfo,
+	TokenholderParameterChange,
+};
 use codec::{Decode, Encode};
-use frame_support::{pallet_prelude::*, traits::StorageVersion, RuntimeDebugNoBound};
+use frame_support::{
+	pallet_prelude::*, sp_runtime::RuntimeDebug, traits::StorageVersion, CloneNoBound, EqNoBound,
+	PartialEqNoBound, RuntimeDebugNoBound,
+};
 use sp_std::{cmp::PartialEq, vec, vec::Vec};
 
 pub use pallet::*;
@@ -17,15 +23,46 @@ mod tests;
 pub mod weights;
 pub use weights::WeightInfo;
 
-#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebugNoBound)]
+#[derive(
+	CloneNoBound, PartialEqNoBound, EqNoBound, Encode, Decode, TypeInfo, RuntimeDebugNoBound,
+)]
 #[scale_info(skip_type_params(T))]
-pub enum Proposal {
+pub enum Proposal<T: Config> {
 	SetGovernanceKey(ForeignChain, Vec<u8>),
 	SetCommunityKey(Address),
+	/// Change one of the whitelisted runtime parameters.
+	UpdateParameter(T::ParameterChange),
+}
+
+/// How a proposal was resolved.
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum ProposalOutcome {
+	/// The proposal didn't get enough backing before the end of the voting period.
+	Rejected,
+	/// The proposal passed and was enacted.
+	Enacted,
+	/// The proposal passed but could not be enacted.
+	EnactmentFailed,
+	/// The proposal passed but was vetoed by governance during the enactment delay.
+	Vetoed,
+}
+
+/// The current status of a proposal, as returned by [Pallet::proposal_statuses].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum ProposalStatus<BlockNumber, Amount> {
+	/// The proposal is open for backing until `resolution_block`.
+	Voting { resolution_block: BlockNumber, backers: u32, backed_funds: Amount },
+	/// The proposal passed and will be enacted at `enactment_block` unless it is vetoed first.
+	AwaitingEnactment { enactment_block: BlockNumber },
+	/// The proposal was resolved at `block`.
+	Resolved { block: BlockNumber, outcome: ProposalOutcome },
 }
 
 pub const PALLET_VERSION: StorageVersion = StorageVersion::new(1);
 
+/// How many blocks the outcome of a proposal is kept for after it is resolved (two weeks).
+pub const PROPOSAL_OUTCOME_RETENTION_PERIOD: u32 = 14 * 24 * 3600 / 6;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -35,6 +72,7 @@ pub mod pallet {
 	#[pallet::storage_version(PALLET_VERSION)]
 	pub struct Pallet<T>(_);
 
+	use frame_support::sp_runtime::traits::CheckedSub;
 	use frame_system::pallet_prelude::*;
 	use sp_std::collections::btree_set::BTreeSet;
 	#[pallet::config]
@@ -56,22 +94,25 @@ pub mod pallet {
 		/// The cost of a proposal in FLIPPERINOS.
 		#[pallet::constant]
 		type ProposalFee: Get<Self::Amount>;
-		/// Delay in blocks after a successfully backed proposal gets executed.
+		/// Delay in blocks after a successfully backed proposal gets executed. Parameter changes
+		/// can be vetoed by governance during this period.
 		#[pallet::constant]
 		type EnactmentDelay: Get<BlockNumberFor<Self>>;
+		/// The runtime parameter changes that tokenholders can propose.
+		type ParameterChange: TokenholderParameterChange;
 	}
 
 	/// All unresolved proposals that are open for backing, indexed by the block at which the vote
 	/// will be resolved.
 	#[pallet::storage]
 	#[pallet::getter(fn proposals)]
-	pub type Proposals<T: Config> = StorageMap<_, Twox64Concat, BlockNumberFor<T>, Proposal>;
+	pub type Proposals<T: Config> = StorageMap<_, Twox64Concat, BlockNumberFor<T>, Proposal<T>>;
 
 	/// The accounts currently backing each proposal.
 	#[pallet::storage]
 	#[pallet::getter(fn backers)]
 	pub type Backers<T: Config> =
-		StorageMap<_, Twox64Concat, Proposal, BTreeSet<T::AccountId>, ValueQuery>;
+		StorageMap<_, Twox64Concat, Proposal<T>, BTreeSet<T::AccountId>, ValueQuery>;
 
 	/// The Government key proposal currently awaiting enactment, if any. Indexed by the block
 	/// number we will attempt to enact this update.
@@ -85,21 +126,42 @@ pub mod pallet {
 	pub type CommKeyUpdateAwaitingEnactment<T> =
 		StorageValue<_, (BlockNumberFor<T>, Address), OptionQuery>;
 
+	/// Parameter changes that have passed and are awaiting enactment, indexed by the block number
+	/// at which we will attempt to enact them.
+	#[pallet::storage]
+	pub type ParameterChangesAwaitingEnactment<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, T::ParameterChange>;
+
 	/// Current Governance keys for foreign chains.
 	#[pallet::storage]
 	pub type GovKeys<T> = StorageMap<_, Twox64Concat, ForeignChain, Vec<u8>>;
 
+	/// The proposals resolved at each block and their outcomes. Pruned after
+	/// [PROPOSAL_OUTCOME_RETENTION_PERIOD] blocks.
+	#[pallet::storage]
+	pub type ProposalOutcomes<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Vec<(Proposal<T>, ProposalOutcome)>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A proposal has been submitted.
-		ProposalSubmitted { proposal: Proposal },
+		ProposalSubmitted { proposal: Proposal<T> },
 		/// A proposal has passed.
-		ProposalPassed { proposal: Proposal },
+		ProposalPassed { proposal: Proposal<T> },
 		/// A proposal was rejected.
-		ProposalRejected { proposal: Proposal },
+		ProposalRejected { proposal: Proposal<T> },
 		/// A proposal was enacted.
-		ProposalEnacted { proposal: Proposal },
+		ProposalEnacted { proposal: Proposal<T> },
+		/// A proposal passed but could not be enacted.
+		ProposalEnactmentFailed { proposal: Proposal<T>, error: DispatchError },
+		/// A proposal was vetoed by governance before it could be enacted.
+		ProposalVetoed { proposal: Proposal<T> },
 		/// Update of GOV key has failed.
 		GovKeyUpdatedHasFailed { chain: ForeignChain, key: Vec<u8> },
 		/// Update of GOV key was successful.
@@ -114,6 +176,8 @@ pub mod pallet {
 		ProposalDoesntExist,
 		/// The proposed governance key is incompatible with the proposed chain.
 		IncompatibleGovkey,
+		/// There is no parameter change awaiting enactment at the given block.
+		ProposalNotAwaitingEnactment,
 	}
 
 	#[pallet::hooks]
@@ -129,7 +193,7 @@ pub mod pallet {
 				GovKeyUpdateAwaitingEnactment::<T>::get()
 			{
 				if enactment_block == current_block {
-					if T::AnyChainGovKeyBroadcaster::broadcast_gov_key(
+					let outcome = if T::AnyChainGovKeyBroadcaster::broadcast_gov_key(
 						chain,
 						GovKeys::<T>::get(chain),
 						new_key.clone(),
@@ -141,15 +205,17 @@ pub mod pallet {
 							chain,
 							key: new_key.clone(),
 						});
+						ProposalOutcome::Enacted
 					} else {
 						Self::deposit_event(Event::<T>::GovKeyUpdatedHasFailed {
 							chain,
 							key: new_key.clone(),
 						});
-					}
-					Self::deposit_event(Event::<T>::ProposalEnacted {
-						proposal: Proposal::SetGovernanceKey(chain, new_key),
-					});
+						ProposalOutcome::EnactmentFailed
+					};
+					let proposal = Proposal::SetGovernanceKey(chain, new_key);
+					Self::record_outcome(current_block, &proposal, outcome);
+					Self::deposit_event(Event::<T>::ProposalEnacted { proposal });
 					GovKeyUpdateAwaitingEnactment::<T>::kill();
 					weight.saturating_accrue(T::WeightInfo::on_initialize_execute_proposal());
 				}
@@ -157,13 +223,42 @@ pub mod pallet {
 			if let Some((enactment_block, new_key)) = CommKeyUpdateAwaitingEnactment::<T>::get() {
 				if enactment_block == current_block {
 					T::CommKeyBroadcaster::broadcast(new_key);
-					Self::deposit_event(Event::<T>::ProposalEnacted {
-						proposal: Proposal::SetCommunityKey(new_key),
-					});
+					let proposal = Proposal::SetCommunityKey(new_key);
+					Self::record_outcome(current_block, &proposal, ProposalOutcome::Enacted);
+					Self::deposit_event(Event::<T>::ProposalEnacted { proposal });
 					CommKeyUpdateAwaitingEnactment::<T>::kill();
 					weight.saturating_accrue(T::WeightInfo::on_initialize_execute_proposal());
 				}
 			}
+			if let Some(change) = ParameterChangesAwaitingEnactment::<T>::take(current_block) {
+				weight.saturating_accrue(
+					T::WeightInfo::on_initialize_execute_proposal().saturating_add(change.weight()),
+				);
+				let proposal = Proposal::UpdateParameter(change.clone());
+				match change.enact() {
+					Ok(()) => {
+						Self::record_outcome(current_block, &proposal, ProposalOutcome::Enacted);
+						Self::deposit_event(Event::<T>::ProposalEnacted { proposal });
+					},
+					Err(error) => {
+						Self::record_outcome(
+							current_block,
+							&proposal,
+							ProposalOutcome::EnactmentFailed,
+						);
+						Self::deposit_event(Event::<T>::ProposalEnactmentFailed {
+							proposal,
+							error,
+						});
+					},
+				}
+			}
+			if let Some(expired_block) =
+				current_block.checked_sub(&PROPOSAL_OUTCOME_RETENTION_PERIOD.into())
+			{
+				ProposalOutcomes::<T>::remove(expired_block);
+				weight.saturating_accrue(T::DbWeight::get().writes(1));
+			}
 			weight
 		}
 	}
@@ -185,7 +280,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::submit_proposal())]
 		pub fn submit_proposal(
 			origin: OriginFor<T>,
-			proposal: Proposal,
+			proposal: Proposal<T>,
 		) -> DispatchResultWithPostInfo {
 			let proposer = ensure_signed(origin)?;
 			if let Proposal::SetGovernanceKey(chain, ref key) = proposal {
@@ -215,7 +310,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::back_proposal(Backers::<T>::decode_non_dedup_len(proposal).unwrap_or_default() as u32))]
 		pub fn back_proposal(
 			origin: OriginFor<T>,
-			proposal: Proposal,
+			proposal: Proposal<T>,
 		) -> DispatchResultWithPostInfo {
 			let backer = ensure_signed(origin)?;
 			Backers::<T>::try_mutate_exists(proposal, |maybe_backers| match maybe_backers {
@@ -229,13 +324,43 @@ pub mod pallet {
 			})?;
 			Ok(().into())
 		}
+
+		/// Vetoes a parameter change that has passed but is still awaiting enactment. Governance
+		/// key and community key updates can't be vetoed.
+		///
+		/// ## Events
+		///
+		/// - [ProposalVetoed](Event::ProposalVetoed)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::BadOrigin)
+		/// - [ProposalNotAwaitingEnactment](Error::ProposalNotAwaitingEnactment)
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::veto_proposal())]
+		pub fn veto_proposal(
+			origin: OriginFor<T>,
+			enactment_block: BlockNumberFor<T>,
+		) -> DispatchResultWithPostInfo {
+			T::EnsureGovernance::ensure_origin(origin)?;
+			let change = ParameterChangesAwaitingEnactment::<T>::take(enactment_block)
+				.ok_or(Error::<T>::ProposalNotAwaitingEnactment)?;
+			let proposal = Proposal::UpdateParameter(change);
+			Self::record_outcome(
+				<frame_system::Pallet<T>>::block_number(),
+				&proposal,
+				ProposalOutcome::Vetoed,
+			);
+			Self::deposit_event(Event::<T>::ProposalVetoed { proposal });
+			Ok(().into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		pub fn resolve_vote(proposal: Proposal) -> usize {
+		pub fn resolve_vote(proposal: Proposal<T>) -> usize {
 			let backers = Backers::<T>::take(&proposal);
 			Self::deposit_event(
-				if backers.iter().map(T::FundingInfo::total_balance_of).sum::<T::Amount>() >
+				if Self::backed_funds(&backers) >
 					(T::FundingInfo::total_onchain_funds() / 3u32.into()) * 2u32.into()
 				{
 					let enactment_block =
@@ -250,13 +375,77 @@ pub mod pallet {
 						Proposal::SetCommunityKey(key) => {
 							CommKeyUpdateAwaitingEnactment::<T>::put((enactment_block, key));
 						},
+						Proposal::UpdateParameter(change) => {
+							ParameterChangesAwaitingEnactment::<T>::insert(enactment_block, change);
+						},
 					}
 					Event::<T>::ProposalPassed { proposal }
 				} else {
+					Self::record_outcome(
+						<frame_system::Pallet<T>>::block_number(),
+						&proposal,
+						ProposalOutcome::Rejected,
+					);
 					Event::<T>::ProposalRejected { proposal }
 				},
 			);
 			backers.len()
 		}
+
+		fn record_outcome(
+			block: BlockNumberFor<T>,
+			proposal: &Proposal<T>,
+			outcome: ProposalOutcome,
+		) {
+			ProposalOutcomes::<T>::append(block, (proposal, outcome));
+		}
+
+		fn backed_funds(backers: &BTreeSet<T::AccountId>) -> T::Amount {
+			backers.iter().map(T::FundingInfo::total_balance_of).sum()
+		}
+
+		/// Returns every proposal that is open for backing, awaiting enactment or resolved within
+		/// the last [PROPOSAL_OUTCOME_RETENTION_PERIOD] blocks, along with its current status.
+		pub fn proposal_statuses(
+		) -> Vec<(Proposal<T>, ProposalStatus<BlockNumberFor<T>, T::Amount>)> {
+			Proposals::<T>::iter()
+				.map(|(resolution_block, proposal)| {
+					let backers = Backers::<T>::get(&proposal);
+					let status = ProposalStatus::Voting {
+						resolution_block,
+						backers: backers.len() as u32,
+						backed_funds: Self::backed_funds(&backers),
+					};
+					(proposal, status)
+				})
+				.chain(GovKeyUpdateAwaitingEnactment::<T>::get().map(
+					|(enactment_block, (chain, key))| {
+						(
+							Proposal::SetGovernanceKey(chain, key),
+							ProposalStatus::AwaitingEnactment { enactment_block },
+						)
+					},
+				))
+				.chain(CommKeyUpdateAwaitingEnactment::<T>::get().map(|(enactment_block, key)| {
+					(
+						Proposal::SetCommunityKey(key),
+						ProposalStatus::AwaitingEnactment { enactment_block },
+					)
+				}))
+				.chain(ParameterChangesAwaitingEnactment::<T>::iter().map(
+					|(enactment_block, change)| {
+						(
+							Proposal::UpdateParameter(change),
+							ProposalStatus::AwaitingEnactment { enactment_block },
+						)
+					},
+				))
+				.chain(ProposalOutcomes::<T>::iter().flat_map(|(block, outcomes)| {
+					outcomes.into_iter().map(move |(proposal, outcome)| {
+						(proposal, ProposalStatus::Resolved { block, outcome })
+					})
+				}))
+				.collect()
+		}
 	}
 }
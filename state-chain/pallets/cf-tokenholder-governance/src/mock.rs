@@ -3,13 +3,17 @@ use cf_chains::{Chain, ChainCrypto, Ethereum, ForeignChain};
 use cf_traits::{
 	impl_mock_chainflip, impl_mock_ensure_witnessed_for_origin, impl_mock_on_account_funded,
 	impl_mock_waived_fees, mocks::fee_payment::MockFeePayment, BroadcastAnyChainGovKey,
-	CommKeyBroadcaster, WaivedFees,
+	CommKeyBroadcaster, TokenholderParameterChange, WaivedFees,
 };
 use codec::{Decode, Encode};
-use frame_support::{derive_impl, parameter_types, traits::HandleLifetime};
+use frame_support::{derive_impl, parameter_types, traits::HandleLifetime, weights::Weight};
 use frame_system as system;
+use scale_info::TypeInfo;
 use sp_core::H256;
-use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError, DispatchResult,
+};
 
 use system::pallet_prelude::BlockNumberFor;
 
@@ -135,6 +139,44 @@ impl CommKeyBroadcaster for MockBroadcaster {
 	}
 }
 
+#[frame_support::storage_alias]
+type MockParameter = StorageValue<Mock, u32>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum MockParameterChange {
+	SetParameter(u32),
+	Fail,
+}
+
+impl MockParameterChange {
+	pub fn parameter() -> Option<u32> {
+		MockParameter::get()
+	}
+}
+
+impl TokenholderParameterChange for MockParameterChange {
+	fn enact(self) -> DispatchResult {
+		match self {
+			Self::SetParameter(value) => {
+				MockParameter::put(value);
+				Ok(())
+			},
+			Self::Fail => Err(DispatchError::Other("MockParameterChange::Fail")),
+		}
+	}
+
+	fn weight(&self) -> Weight {
+		Weight::zero()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl cf_chains::benchmarking_value::BenchmarkValue for MockParameterChange {
+	fn benchmark_value() -> Self {
+		Self::SetParameter(1)
+	}
+}
+
 impl pallet_cf_tokenholder_governance::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type FeePayment = MockFeePayment<Self>;
@@ -144,6 +186,7 @@ impl pallet_cf_tokenholder_governance::Config for Test {
 	type VotingPeriod = VotingPeriod;
 	type EnactmentDelay = EnactmentDelay;
 	type ProposalFee = ProposalFee;
+	type ParameterChange = MockParameterChange;
 }
 
 // Accounts
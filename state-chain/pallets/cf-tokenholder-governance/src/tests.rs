@@ -14,7 +14,7 @@ fn awaiting_gov_key() -> Vec<u8> {
 	awaiting_key
 }
 
-fn submit_and_pass_proposal(proposal: Proposal) {
+fn submit_and_pass_proposal(proposal: Proposal<Test>) {
 	assert_ok!(TokenholderGovernance::submit_proposal(
 		RuntimeOrigin::signed(ALICE),
 		proposal.clone()
@@ -178,6 +178,10 @@ fn not_enough_backed_liquidity_for_proposal_enactment() {
 		));
 		assert!(!Backers::<Test>::contains_key(&proposal));
 		assert!(GovKeyUpdateAwaitingEnactment::<Test>::get().is_none());
+		assert_eq!(
+			ProposalOutcomes::<Test>::get(<frame_system::Pallet<Test>>::block_number()),
+			vec![(proposal.clone(), ProposalOutcome::Rejected)]
+		);
 		assert_eq!(
 			last_event::<Test>(),
 			mock::RuntimeEvent::TokenholderGovernance(crate::Event::ProposalRejected { proposal }),
@@ -190,7 +194,7 @@ fn replace_proposal_during_enactment_period() {
 	new_test_ext().execute_with(|| {
 		let proposed_key_1 = vec![1; 32];
 		let proposed_key_2 = vec![2; 32];
-		fn create_and_back_proposal(proposal: Proposal) {
+		fn create_and_back_proposal(proposal: Proposal<Test>) {
 			assert_ok!(TokenholderGovernance::submit_proposal(
 				RuntimeOrigin::signed(ALICE),
 				proposal.clone()
@@ -255,3 +259,160 @@ fn govkey_broadcast_to_correct_chain() {
 		);
 	});
 }
+
+fn parameter_change_enactment_block() -> u64 {
+	<frame_system::Pallet<Test>>::block_number() + <mock::Test as Config>::EnactmentDelay::get()
+}
+
+#[test]
+fn update_parameter_via_onchain_proposal() {
+	new_test_ext().execute_with(|| {
+		let proposal = Proposal::UpdateParameter(MockParameterChange::SetParameter(7));
+		submit_and_pass_proposal(proposal.clone());
+
+		let enactment_block = parameter_change_enactment_block();
+		assert_eq!(
+			ParameterChangesAwaitingEnactment::<Test>::get(enactment_block),
+			Some(MockParameterChange::SetParameter(7))
+		);
+		assert_eq!(MockParameterChange::parameter(), None);
+
+		TokenholderGovernance::on_initialize(enactment_block);
+		assert!(!ParameterChangesAwaitingEnactment::<Test>::contains_key(enactment_block));
+		assert_eq!(MockParameterChange::parameter(), Some(7));
+		assert_eq!(
+			ProposalOutcomes::<Test>::get(enactment_block),
+			vec![(proposal.clone(), ProposalOutcome::Enacted)]
+		);
+		assert_eq!(
+			last_event::<Test>(),
+			mock::RuntimeEvent::TokenholderGovernance(crate::Event::ProposalEnacted { proposal }),
+		);
+	});
+}
+
+#[test]
+fn failed_parameter_change_is_recorded() {
+	new_test_ext().execute_with(|| {
+		let proposal = Proposal::UpdateParameter(MockParameterChange::Fail);
+		submit_and_pass_proposal(proposal.clone());
+
+		let enactment_block = parameter_change_enactment_block();
+		TokenholderGovernance::on_initialize(enactment_block);
+		assert_eq!(
+			ProposalOutcomes::<Test>::get(enactment_block),
+			vec![(proposal.clone(), ProposalOutcome::EnactmentFailed)]
+		);
+		assert_eq!(
+			last_event::<Test>(),
+			mock::RuntimeEvent::TokenholderGovernance(crate::Event::ProposalEnactmentFailed {
+				proposal,
+				error: sp_runtime::DispatchError::Other("MockParameterChange::Fail"),
+			}),
+		);
+	});
+}
+
+#[test]
+fn governance_can_veto_parameter_change() {
+	new_test_ext().execute_with(|| {
+		let proposal = Proposal::UpdateParameter(MockParameterChange::SetParameter(7));
+		submit_and_pass_proposal(proposal.clone());
+
+		let enactment_block = parameter_change_enactment_block();
+		assert_ok!(TokenholderGovernance::veto_proposal(RuntimeOrigin::root(), enactment_block));
+		assert_eq!(
+			last_event::<Test>(),
+			mock::RuntimeEvent::TokenholderGovernance(crate::Event::ProposalVetoed {
+				proposal: proposal.clone()
+			}),
+		);
+		assert_eq!(
+			ProposalOutcomes::<Test>::get(<frame_system::Pallet<Test>>::block_number()),
+			vec![(proposal.clone(), ProposalOutcome::Vetoed)]
+		);
+
+		// The vetoed change is not enacted.
+		TokenholderGovernance::on_initialize(enactment_block);
+		assert_eq!(MockParameterChange::parameter(), None);
+
+		assert_noop!(
+			TokenholderGovernance::veto_proposal(RuntimeOrigin::root(), enactment_block),
+			Error::<Test>::ProposalNotAwaitingEnactment
+		);
+	});
+}
+
+#[test]
+fn proposal_statuses_follow_the_proposal_lifecycle() {
+	new_test_ext().execute_with(|| {
+		let proposal = Proposal::UpdateParameter(MockParameterChange::SetParameter(7));
+		assert_ok!(TokenholderGovernance::submit_proposal(
+			RuntimeOrigin::signed(ALICE),
+			proposal.clone()
+		));
+		let resolution_block = <frame_system::Pallet<Test>>::block_number() +
+			<mock::Test as Config>::VotingPeriod::get();
+		assert_eq!(
+			TokenholderGovernance::proposal_statuses(),
+			vec![(
+				proposal.clone(),
+				ProposalStatus::Voting {
+					resolution_block,
+					backers: 1,
+					backed_funds: 500 - ProposalFee::get()
+				}
+			)]
+		);
+
+		assert_ok!(TokenholderGovernance::back_proposal(
+			RuntimeOrigin::signed(BOB),
+			proposal.clone()
+		));
+		assert_ok!(TokenholderGovernance::back_proposal(
+			RuntimeOrigin::signed(CHARLES),
+			proposal.clone()
+		));
+		TokenholderGovernance::on_initialize(resolution_block);
+		let enactment_block = parameter_change_enactment_block();
+		assert_eq!(
+			TokenholderGovernance::proposal_statuses(),
+			vec![(proposal.clone(), ProposalStatus::AwaitingEnactment { enactment_block })]
+		);
+
+		TokenholderGovernance::on_initialize(enactment_block);
+		assert_eq!(
+			TokenholderGovernance::proposal_statuses(),
+			vec![(
+				proposal,
+				ProposalStatus::Resolved {
+					block: enactment_block,
+					outcome: ProposalOutcome::Enacted
+				}
+			)]
+		);
+	});
+}
+
+#[test]
+fn proposal_outcomes_are_pruned_after_the_retention_period() {
+	new_test_ext().execute_with(|| {
+		let proposal = Proposal::UpdateParameter(MockParameterChange::SetParameter(7));
+		submit_and_pass_proposal(proposal.clone());
+
+		let enactment_block = parameter_change_enactment_block();
+		TokenholderGovernance::on_initialize(enactment_block);
+		assert_eq!(TokenholderGovernance::proposal_statuses().len(), 1);
+
+		let retention_period = PROPOSAL_OUTCOME_RETENTION_PERIOD as u64;
+		TokenholderGovernance::on_initialize(enactment_block + retention_period - 1);
+		assert_eq!(
+			ProposalOutcomes::<Test>::get(enactment_block),
+			vec![(proposal, ProposalOutcome::Enacted)]
+		);
+
+		TokenholderGovernance::on_initialize(enactment_block + retention_period);
+		assert!(!ProposalOutcomes::<Test>::contains_key(enactment_block));
+		assert!(TokenholderGovernance::proposal_statuses().is_empty());
+	});
+}
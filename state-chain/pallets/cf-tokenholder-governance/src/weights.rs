@@ -36,6 +36,7 @@ pub trait WeightInfo {
 	fn on_initialize_execute_proposal() -> Weight;
 	fn submit_proposal() -> Weight;
 	fn back_proposal(a: u32, ) -> Weight;
+	fn veto_proposal() -> Weight;
 }
 
 /// Weights for pallet_cf_tokenholder_governance using the Substrate node and recommended hardware.
@@ -150,6 +151,19 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(a.into()))
 	}
+	/// Storage: `TokenholderGovernance::ParameterChangesAwaitingEnactment` (r:1 w:1)
+	/// Proof: `TokenholderGovernance::ParameterChangesAwaitingEnactment` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TokenholderGovernance::ProposalOutcomes` (r:0 w:1)
+	/// Proof: `TokenholderGovernance::ProposalOutcomes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn veto_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `185`
+		//  Estimated: `3650`
+		// Minimum execution time: 12_201_000 picoseconds.
+		Weight::from_parts(12_634_000, 3650)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -263,4 +277,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(a.into()))
 	}
+	/// Storage: `TokenholderGovernance::ParameterChangesAwaitingEnactment` (r:1 w:1)
+	/// Proof: `TokenholderGovernance::ParameterChangesAwaitingEnactment` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TokenholderGovernance::ProposalOutcomes` (r:0 w:1)
+	/// Proof: `TokenholderGovernance::ProposalOutcomes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn veto_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `185`
+		//  Estimated: `3650`
+		// Minimum execution time: 12_201_000 picoseconds.
+		Weight::from_parts(12_634_000, 3650)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -22,7 +22,7 @@ use cf_primitives::{
 };
 use cf_traits::{
 	impl_pallet_safe_mode, offence_reporting::OffenceReporter, AsyncResult, AuthoritiesCfeVersions,
-	Bid, BidderProvider, Bonding, CfePeerRegistration, Chainflip, EpochInfo,
+	Bid, BidderProvider, Bonding, CfePeerRegistration, Chainflip, DelegationInfo, EpochInfo,
 	EpochTransitionHandler, ExecutionCondition, FundingInfo, HistoricalEpoch, KeyRotator,
 	MissedAuthorshipSlots, OnAccountFunded, QualifyNode, ReputationResetter, SetSafeMode,
 };
@@ -130,6 +130,9 @@ pub mod pallet {
 			Amount = Self::Amount,
 		>;
 
+		/// Used to prevent accounts that have delegated their funds from bidding with them as well.
+		type DelegationInfo: DelegationInfo<AccountId = Self::AccountId>;
+
 		/// Criteria that need to be fulfilled to qualify as a validator node (authority or backup).
 		type KeygenQualification: QualifyNode<<Self as Chainflip>::ValidatorId>;
 
@@ -351,6 +354,8 @@ pub mod pallet {
 		NotEnoughFunds,
 		/// Rotations are currently disabled through SafeMode.
 		RotationsDisabled,
+		/// Accounts that have delegated funds can't register as validators.
+		AccountIsDelegating,
 	}
 
 	/// Pallet implements [`Hooks`] trait
@@ -756,6 +761,10 @@ pub mod pallet {
 		#[pallet::weight(T::ValidatorWeightInfo::register_as_validator())]
 		pub fn register_as_validator(origin: OriginFor<T>) -> DispatchResult {
 			let account_id: T::AccountId = ensure_signed(origin)?;
+			ensure!(
+				!T::DelegationInfo::is_delegating(&account_id),
+				Error::<T>::AccountIsDelegating
+			);
 			if Self::current_authority_count() >= AuctionParameters::<T>::get().max_size {
 				ensure!(
 					T::FundingInfo::total_balance_of(&account_id) >=
@@ -18,7 +18,10 @@ use sp_runtime::{
 	testing::UintAuthorityId,
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
 };
-use std::{cell::RefCell, collections::HashMap};
+use std::{
+	cell::RefCell,
+	collections::{HashMap, HashSet},
+};
 
 pub type Amount = u128;
 pub type ValidatorId = u64;
@@ -107,6 +110,7 @@ impl EpochTransitionHandler for TestEpochTransitionHandler {}
 thread_local! {
 	pub static MISSED_SLOTS: RefCell<(u64, u64)> = RefCell::new(Default::default());
 	pub static BIDDERS: RefCell<Vec<Bid<ValidatorId, Amount>>> = RefCell::new(Default::default());
+	pub static DELEGATORS: RefCell<HashSet<ValidatorId>> = RefCell::new(Default::default());
 }
 
 pub struct MockMissedAuthorshipSlots;
@@ -183,6 +187,22 @@ impl BidderProvider for MockBidderProvider {
 	}
 }
 
+pub struct MockDelegationInfo;
+
+impl MockDelegationInfo {
+	pub fn set_delegating(account_id: ValidatorId) {
+		DELEGATORS.with(|cell| cell.borrow_mut().insert(account_id));
+	}
+}
+
+impl DelegationInfo for MockDelegationInfo {
+	type AccountId = ValidatorId;
+
+	fn is_delegating(account_id: &Self::AccountId) -> bool {
+		DELEGATORS.with(|cell| cell.borrow().contains(account_id))
+	}
+}
+
 impl_mock_runtime_safe_mode!(validator: PalletSafeMode);
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -191,6 +211,7 @@ impl Config for Test {
 	type KeyRotator = MockKeyRotatorA;
 	type MissedAuthorshipSlots = MockMissedAuthorshipSlots;
 	type BidderProvider = MockBidderProvider;
+	type DelegationInfo = MockDelegationInfo;
 	type OffenceReporter = MockOffenceReporter;
 	type Bonder = MockBonder;
 	type ReputationResetter = MockReputationResetter<Self>;
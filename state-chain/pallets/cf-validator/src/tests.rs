@@ -743,6 +743,19 @@ fn test_expect_validator_register_fails() {
 	});
 }
 
+#[test]
+fn delegators_cannot_register_as_validators() {
+	new_test_ext().then_execute_with_checks(|| {
+		const ID: u64 = 42;
+		MockFundingInfo::<Test>::credit_funds(&ID, GENESIS_BOND);
+		MockDelegationInfo::set_delegating(ID);
+		assert_noop!(
+			Pallet::<Test>::register_as_validator(RuntimeOrigin::signed(ID)),
+			crate::Error::<Test>::AccountIsDelegating
+		);
+	});
+}
+
 const CANDIDATES: Range<u64> = 4..14;
 const AUTHORITIES: Range<u64> = 0..10;
 
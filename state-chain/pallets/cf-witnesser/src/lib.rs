@@ -14,7 +14,7 @@ mod mock;
 mod tests;
 
 use bitvec::prelude::*;
-use cf_primitives::EpochIndex;
+use cf_primitives::{EpochIndex, ForeignChain};
 use cf_traits::{
 	offence_reporting::OffenceReporter, AccountRoleRegistry, CallDispatchFilter, Chainflip,
 	EpochInfo, SafeMode,
@@ -25,6 +25,7 @@ use frame_support::{
 	dispatch::GetDispatchInfo,
 	ensure,
 	pallet_prelude::{DispatchResultWithPostInfo, Member, RuntimeDebug},
+	sp_runtime::traits::Saturating,
 	storage::with_storage_layer,
 	traits::{EnsureOrigin, Get, UnfilteredDispatchable},
 	Hashable,
@@ -72,6 +73,12 @@ pub trait WitnessDataExtraction {
 	fn combine_and_inject(&mut self, data: &mut [Vec<u8>]);
 }
 
+pub trait WitnessedChain {
+	/// The external chain on which the event represented by this call occurred, if any. Used to
+	/// break down witnessing participation by chain.
+	fn witnessed_chain(&self) -> Option<ForeignChain>;
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
 pub enum PalletOffence {
 	FailedToWitnessInTime,
@@ -99,7 +106,8 @@ pub mod pallet {
 			+ From<frame_system::Call<Self>>
 			+ UnfilteredDispatchable<RuntimeOrigin = <Self as Config>::RuntimeOrigin>
 			+ GetDispatchInfo
-			+ WitnessDataExtraction;
+			+ WitnessDataExtraction
+			+ WitnessedChain;
 
 		/// Safe Mode access.
 		type SafeMode: Get<PalletSafeMode<Self::CallDispatchPermission>>;
@@ -170,6 +178,30 @@ pub mod pallet {
 	pub type WitnessDeadline<T: Config> =
 		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<(EpochIndex, CallHash)>, ValueQuery>;
 
+	/// The chain of each dispatched call that is awaiting its witness deadline.
+	#[pallet::storage]
+	pub type CallChain<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EpochIndex, Identity, CallHash, ForeignChain>;
+
+	/// The number of calls on each chain whose witness deadline has passed, indexed by the epoch at
+	/// which they were witnessed.
+	#[pallet::storage]
+	pub type WitnessedCallCount<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EpochIndex, Twox64Concat, ForeignChain, u32, ValueQuery>;
+
+	/// The number of calls on each chain that an authority failed to witness before the deadline,
+	/// indexed by the epoch at which they were witnessed.
+	#[pallet::storage]
+	pub type MissedWitnessCount<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EpochIndex,
+		Blake2_128Concat,
+		(T::ValidatorId, ForeignChain),
+		u32,
+		ValueQuery,
+	>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
@@ -230,6 +262,7 @@ pub mod pallet {
 			let mut deletions_count_remaining = max_deletions_count_remaining;
 			let (mut cleared_votes, mut cleared_extra_call_data, mut cleared_call_hash) =
 				(false, false, false);
+			let (mut cleared_witnessed_counts, mut cleared_missed_counts) = (false, false);
 
 			// Cull the Votes storage
 			let remove_result =
@@ -262,6 +295,8 @@ pub mod pallet {
 					deletions_count_remaining as u32,
 					None,
 				);
+				deletions_count_remaining =
+					deletions_count_remaining.saturating_sub(remove_result.backend as u64);
 				used_weight
 					.saturating_accrue(T::WeightInfo::remove_storage_items(remove_result.backend));
 				if remove_result.maybe_cursor.is_none() {
@@ -269,8 +304,43 @@ pub mod pallet {
 				}
 			}
 
+			// Cull the `WitnessedCallCount` storage
+			if deletions_count_remaining > 0 {
+				let remove_result = WitnessedCallCount::<T>::clear_prefix(
+					epoch,
+					deletions_count_remaining as u32,
+					None,
+				);
+				deletions_count_remaining =
+					deletions_count_remaining.saturating_sub(remove_result.backend as u64);
+				used_weight
+					.saturating_accrue(T::WeightInfo::remove_storage_items(remove_result.backend));
+				if remove_result.maybe_cursor.is_none() {
+					cleared_witnessed_counts = true;
+				}
+			}
+
+			// Cull the `MissedWitnessCount` storage
+			if deletions_count_remaining > 0 {
+				let remove_result = MissedWitnessCount::<T>::clear_prefix(
+					epoch,
+					deletions_count_remaining as u32,
+					None,
+				);
+				used_weight
+					.saturating_accrue(T::WeightInfo::remove_storage_items(remove_result.backend));
+				if remove_result.maybe_cursor.is_none() {
+					cleared_missed_counts = true;
+				}
+			}
+
 			// If all storages have been cleared, update storage.
-			if cleared_votes && cleared_extra_call_data && cleared_call_hash {
+			if cleared_votes &&
+				cleared_extra_call_data &&
+				cleared_call_hash &&
+				cleared_witnessed_counts &&
+				cleared_missed_counts
+			{
 				EpochsToCull::<T>::put(epochs_to_cull);
 			}
 			used_weight
@@ -281,6 +351,7 @@ pub mod pallet {
 			// Cache the authorities to avoid repeated storage lookups.
 			let mut authorities_cache = BTreeMap::new();
 			for (epoch, call_hash) in WitnessDeadline::<T>::take(n) {
+				let maybe_chain = CallChain::<T>::take(epoch, call_hash);
 				if let Some(votes) = Votes::<T>::get(epoch, call_hash) {
 					let authorities = authorities_cache.entry(epoch).or_insert_with(|| {
 						T::EpochInfo::authorities_at_epoch(epoch).into_iter().collect::<Vec<_>>()
@@ -300,6 +371,10 @@ pub mod pallet {
 						.cloned()
 						.collect::<Vec<_>>();
 
+					if let Some(chain) = maybe_chain {
+						Self::record_witness_participation(epoch, chain, &failed_witnessers);
+					}
+
 					// Report these nodes for failed to witness in time.
 					if !failed_witnessers.is_empty() {
 						T::OffenceReporter::report_many(
@@ -525,6 +600,7 @@ impl<T: Config> Pallet<T> {
 		call: <T as Config>::RuntimeCall,
 		call_hash: CallHash,
 	) {
+		let maybe_chain = call.witnessed_chain();
 		let _result = with_storage_layer(move || {
 			call.dispatch_bypass_filter(
 				(if witnessed_at_epoch == current_epoch {
@@ -539,6 +615,9 @@ impl<T: Config> Pallet<T> {
 			Self::deposit_event(Event::<T>::WitnessExecutionFailed { call_hash, error: e.error });
 		});
 		CallHashExecuted::<T>::insert(witnessed_at_epoch, call_hash, ());
+		if let Some(chain) = maybe_chain {
+			CallChain::<T>::insert(witnessed_at_epoch, call_hash, chain);
+		}
 
 		// Add a deadline for witnessing this call. Nodes that don't witness after the deadlines are
 		// punished.
@@ -548,6 +627,19 @@ impl<T: Config> Pallet<T> {
 		);
 	}
 
+	fn record_witness_participation(
+		epoch: EpochIndex,
+		chain: ForeignChain,
+		failed_witnessers: &[<T as Chainflip>::ValidatorId],
+	) {
+		WitnessedCallCount::<T>::mutate(epoch, chain, |count| count.saturating_accrue(1));
+		for validator_id in failed_witnessers {
+			MissedWitnessCount::<T>::mutate(epoch, (validator_id.clone(), chain), |count| {
+				count.saturating_accrue(1)
+			});
+		}
+	}
+
 	pub fn count_votes(
 		epoch: EpochIndex,
 		call_hash: CallHash,
@@ -1,6 +1,7 @@
 #![cfg(test)]
 
-use crate::{self as pallet_cf_witness, PalletOffence, WitnessDataExtraction};
+use crate::{self as pallet_cf_witness, PalletOffence, WitnessDataExtraction, WitnessedChain};
+use cf_primitives::ForeignChain;
 use cf_traits::{
 	impl_mock_chainflip, impl_mock_runtime_safe_mode,
 	mocks::offence_reporting::MockOffenceReporter, AccountRoleRegistry, CallDispatchFilter,
@@ -106,6 +107,15 @@ impl WitnessDataExtraction for RuntimeCall {
 	}
 }
 
+impl WitnessedChain for RuntimeCall {
+	fn witnessed_chain(&self) -> Option<ForeignChain> {
+		match self {
+			RuntimeCall::Dummy(_) => Some(ForeignChain::Ethereum),
+			_ => None,
+		}
+	}
+}
+
 pub const ALISSA: <Test as frame_system::Config>::AccountId = 1u64;
 pub const BOBSON: <Test as frame_system::Config>::AccountId = 2u64;
 pub const CHARLEMAGNE: <Test as frame_system::Config>::AccountId = 3u64;
@@ -3,9 +3,11 @@
 use crate::{
 	mock::{dummy::pallet as pallet_dummy, *},
 	weights::WeightInfo,
-	CallHash, CallHashExecuted, Config, EpochsToCull, Error, ExtraCallData, PalletOffence,
-	PalletSafeMode, VoteMask, Votes, WitnessDeadline, WitnessedCallsScheduledForDispatch,
+	CallChain, CallHash, CallHashExecuted, Config, EpochsToCull, Error, ExtraCallData,
+	MissedWitnessCount, PalletOffence, PalletSafeMode, VoteMask, Votes, WitnessDeadline,
+	WitnessedCallCount, WitnessedCallsScheduledForDispatch,
 };
+use cf_primitives::ForeignChain;
 use cf_test_utilities::assert_event_sequence;
 use cf_traits::{
 	mocks::account_role_registry::MockAccountRoleRegistry, AccountRoleRegistry, EpochInfo,
@@ -548,16 +550,29 @@ fn can_punish_failed_witnesser() {
 			OffenceReporter::assert_reported(PalletOffence::FailedToWitnessInTime, vec![]);
 			call_hash
 		})
-		.then_execute_at_block(target, |_| {})
-		.then_execute_with(|_| {
+		.then_execute_at_block(target, |call_hash| call_hash)
+		.then_execute_with(|call_hash| {
 			// After deadline has passed, all nodes that are late are reported.
 			OffenceReporter::assert_reported(
 				PalletOffence::FailedToWitnessInTime,
 				success_threshold..100u64,
 			);
 
+			// Witnessing participation is recorded against the chain of the call.
+			let epoch = MockEpochInfo::epoch_index();
+			assert_eq!(WitnessedCallCount::<Test>::get(epoch, ForeignChain::Ethereum), 1);
+			assert_eq!(MissedWitnessCount::<Test>::get(epoch, (0, ForeignChain::Ethereum)), 0);
+			assert_eq!(MissedWitnessCount::<Test>::get(epoch, (99, ForeignChain::Ethereum)), 1);
+
 			// storage is cleaned up.
 			assert_eq!(WitnessDeadline::<Test>::decode_len(target), None);
+			assert!(!CallChain::<Test>::contains_key(epoch, call_hash));
+
+			// Participation stats are culled along with the rest of the epoch's data.
+			Witnesser::on_expired_epoch(epoch);
+			Witnesser::on_idle(target + 1, Weight::from_parts(1_000_000_000_000u64, 0));
+			assert_eq!(WitnessedCallCount::<Test>::get(epoch, ForeignChain::Ethereum), 0);
+			assert!(!MissedWitnessCount::<Test>::contains_key(epoch, (99, ForeignChain::Ethereum)));
 		});
 }
 
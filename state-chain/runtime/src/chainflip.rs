@@ -11,9 +11,9 @@ mod signer_nomination;
 use crate::{
 	AccountId, AccountRoles, Authorship, BitcoinChainTracking, BitcoinIngressEgress,
 	BitcoinThresholdSigner, BlockNumber, Emissions, Environment, EthereumBroadcaster,
-	EthereumChainTracking, EthereumIngressEgress, Flip, FlipBalance, Hash, PolkadotBroadcaster,
-	PolkadotChainTracking, PolkadotIngressEgress, PolkadotThresholdSigner, Runtime, RuntimeCall,
-	System, Validator, YEAR,
+	EthereumChainTracking, EthereumIngressEgress, Flip, FlipBalance, Funding, Hash,
+	PolkadotBroadcaster, PolkadotChainTracking, PolkadotIngressEgress, PolkadotThresholdSigner,
+	Runtime, RuntimeCall, System, Validator, YEAR,
 };
 use backup_node_rewards::calculate_backup_rewards;
 use cf_chains::{
@@ -24,7 +24,7 @@ use cf_chains::{
 	assets::any::ForeignChainAndAsset,
 	btc::{
 		api::{BitcoinApi, SelectedUtxosAndChangeAmount, UtxoSelectionType},
-		Bitcoin, BitcoinCrypto, BitcoinFeeInfo, BitcoinTransactionData, UtxoId,
+		Bitcoin, BitcoinCrypto, BitcoinFeeInfo, BitcoinTransactionData, Utxo, UtxoId,
 	},
 	dot::{
 		api::PolkadotApi, Polkadot, PolkadotAccountId, PolkadotCrypto, PolkadotReplayProtection,
@@ -41,25 +41,28 @@ use cf_chains::{
 		EvmCrypto, Transaction,
 	},
 	AnyChain, ApiCall, CcmChannelMetadata, CcmDepositMetadata, Chain, ChainCrypto,
-	ChainEnvironment, ChainState, DepositChannel, ForeignChain, ReplayProtectionProvider,
-	SetCommKeyWithAggKey, SetGovKeyWithAggKey, TransactionBuilder,
+	ChainEnvironment, ChainState, DcaParameters, DepositChannel, ForeignChain,
+	ReplayProtectionProvider, SetCommKeyWithAggKey, SetGovKeyWithAggKey, SwapRefundParameters,
+	TransactionBuilder,
 };
 use cf_primitives::{chains::assets, AccountRole, Asset, BasisPoints, ChannelId};
 use cf_traits::{
 	AccountInfo, AccountRoleRegistry, BackupRewardsNotifier, BlockEmissions,
 	BroadcastAnyChainGovKey, Broadcaster, Chainflip, CommKeyBroadcaster, DepositApi,
 	DepositHandler, EgressApi, EpochInfo, Heartbeat, Issuance, KeyProvider, OnBroadcastReady,
-	QualifyNode, RewardsDistribution, RuntimeUpgrade, ScheduledEgressDetails,
+	QualifyNode, RewardsDistribution, RuntimeUpgrade, ScheduledEgressDetails, Slashing,
 };
+use chain_instances::{BitcoinInstance, EthereumInstance, PolkadotInstance};
 use codec::{Decode, Encode};
 use frame_support::{
-	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
-	pallet_prelude::DispatchError,
+	dispatch::{DispatchErrorWithPostInfo, GetDispatchInfo, PostDispatchInfo},
+	pallet_prelude::{BoundedVec, ConstU32, DispatchError, DispatchResult},
 	sp_runtime::{
 		traits::{BlockNumberProvider, One, UniqueSaturatedFrom, UniqueSaturatedInto},
-		FixedPointNumber, FixedU64,
+		FixedPointNumber, FixedU64, RuntimeDebug,
 	},
-	traits::{Defensive, Get},
+	traits::{Defensive, Get, Imbalance, UnfilteredDispatchable},
+	weights::Weight,
 };
 pub use missed_authorship_slots::MissedAuraSlots;
 pub use offences::*;
@@ -107,7 +110,7 @@ impl RewardsDistribution for BackupNodeEmissions {
 			Emissions::current_authority_emission_per_block(),
 			Self::Balance::unique_saturated_from(Validator::current_authority_count()),
 		) {
-			Flip::settle(&validator_id, Self::Issuance::mint(reward).into());
+			mint_operator_reward(&validator_id, reward);
 			<Emissions as BackupRewardsNotifier>::emit_event(&validator_id, reward);
 		}
 	}
@@ -253,6 +256,26 @@ impl TransactionBuilder<Bitcoin, BitcoinApi<BtcEnvironment>> for BtcTransactionB
 		// to check anything here and just rebroadcast.
 		false
 	}
+	fn build_fee_bump_call(
+		signed_call: &BitcoinApi<BtcEnvironment>,
+	) -> Option<BitcoinApi<BtcEnvironment>> {
+		// A replacement transaction would have a different txid, which would invalidate the change
+		// utxo that subsequent transactions may already be spending. Instead, we spend the change
+		// output in a child transaction that pays for its parent.
+		BitcoinApi::child_pays_for_parent(signed_call)
+	}
+}
+
+/// Mints a reward earned by a validator operator. The operator is paid its share straight away,
+/// while its delegators' share is set aside in a reserve until each of them claims it.
+fn mint_operator_reward(operator: &AccountId, reward: FlipBalance) {
+	let (operator_share, delegators_share) = Funding::record_reward(operator, reward);
+	Flip::settle(operator, pallet_cf_flip::FlipIssuance::<Runtime>::mint(operator_share).into());
+	if delegators_share > 0 {
+		let _ =
+			Flip::deposit_reserves(pallet_cf_flip::DELEGATION_REWARDS_RESERVE, delegators_share)
+				.offset(pallet_cf_flip::FlipIssuance::<Runtime>::mint(delegators_share));
+	}
 }
 
 pub struct BlockAuthorRewardDistribution;
@@ -265,13 +288,44 @@ impl RewardsDistribution for BlockAuthorRewardDistribution {
 		let reward_amount = Emissions::current_authority_emission_per_block();
 		if reward_amount != 0 {
 			if let Some(current_block_author) = Authorship::author() {
-				Flip::settle(&current_block_author, Self::Issuance::mint(reward_amount).into());
+				mint_operator_reward(&current_block_author, reward_amount);
 			} else {
 				log::warn!("No block author for block {}.", System::current_block_number());
 			}
 		}
 	}
 }
+
+/// Slashes validators through [pallet_cf_flip::FlipSlasher], sharing each slash with the
+/// validator's delegators in proportion to their stake.
+pub struct DelegationAwareSlasher;
+
+impl Slashing for DelegationAwareSlasher {
+	type AccountId = AccountId;
+	type BlockNumber = BlockNumber;
+	type Balance = FlipBalance;
+
+	fn slash(validator_id: &Self::AccountId, blocks_offline: Self::BlockNumber) {
+		Self::slash_balance(
+			validator_id,
+			Self::calculate_slash_amount(validator_id, blocks_offline),
+		)
+	}
+
+	fn slash_balance(account_id: &Self::AccountId, slash_amount: FlipBalance) {
+		for (account_id, share) in Funding::record_slash(account_id, slash_amount) {
+			pallet_cf_flip::FlipSlasher::<Runtime>::slash_balance(&account_id, share);
+		}
+	}
+
+	fn calculate_slash_amount(
+		account_id: &Self::AccountId,
+		blocks: Self::BlockNumber,
+	) -> Self::Balance {
+		pallet_cf_flip::FlipSlasher::<Runtime>::calculate_slash_amount(account_id, blocks)
+	}
+}
+
 pub struct RuntimeUpgradeManager;
 
 impl RuntimeUpgrade for RuntimeUpgradeManager {
@@ -279,6 +333,64 @@ impl RuntimeUpgrade for RuntimeUpgradeManager {
 		System::set_code(frame_system::RawOrigin::Root.into(), code)
 	}
 }
+
+pub struct GovernanceCallCategoriser;
+
+impl pallet_cf_governance::CategoriseCall<RuntimeCall> for GovernanceCallCategoriser {
+	fn categorise(call: &RuntimeCall) -> pallet_cf_governance::CallCategory {
+		use pallet_cf_governance::CallCategory;
+		match call {
+			RuntimeCall::Governance(pallet_cf_governance::Call::chainflip_runtime_upgrade {
+				..
+			}) |
+			RuntimeCall::System(
+				frame_system::Call::set_code { .. } |
+				frame_system::Call::set_code_without_checks { .. },
+			) => CallCategory::RuntimeUpgrade,
+			RuntimeCall::Environment(pallet_cf_environment::Call::update_safe_mode { .. }) =>
+				CallCategory::SafeMode,
+			RuntimeCall::Governance(pallet_cf_governance::Call::call_as_sudo { call }) =>
+				Self::categorise(call),
+			_ => CallCategory::Other,
+		}
+	}
+}
+
+impl pallet_cf_witnesser::WitnessedChain for RuntimeCall {
+	fn witnessed_chain(&self) -> Option<ForeignChain> {
+		match self {
+			RuntimeCall::EthereumChainTracking(..) |
+			RuntimeCall::EthereumVault(..) |
+			RuntimeCall::EthereumBroadcaster(..) |
+			RuntimeCall::EthereumIngressEgress(..) |
+			RuntimeCall::Funding(..) => Some(ForeignChain::Ethereum),
+			RuntimeCall::PolkadotChainTracking(..) |
+			RuntimeCall::PolkadotVault(..) |
+			RuntimeCall::PolkadotBroadcaster(..) |
+			RuntimeCall::PolkadotIngressEgress(..) |
+			RuntimeCall::Environment(
+				pallet_cf_environment::Call::witness_polkadot_vault_creation { .. },
+			) => Some(ForeignChain::Polkadot),
+			RuntimeCall::BitcoinChainTracking(..) |
+			RuntimeCall::BitcoinVault(..) |
+			RuntimeCall::BitcoinBroadcaster(..) |
+			RuntimeCall::BitcoinIngressEgress(..) |
+			RuntimeCall::Environment(
+				pallet_cf_environment::Call::witness_current_bitcoin_block_number_for_key {
+					..
+				},
+			) => Some(ForeignChain::Bitcoin),
+			RuntimeCall::Swapping(
+				pallet_cf_swapping::Call::schedule_swap_from_contract {
+					from: source_asset, ..
+				} |
+				pallet_cf_swapping::Call::ccm_deposit { source_asset, .. },
+			) => Some((*source_asset).into()),
+			_ => None,
+		}
+	}
+}
+
 pub struct EthEnvironment;
 
 impl ReplayProtectionProvider<Ethereum> for EthEnvironment {
@@ -362,6 +474,18 @@ impl ChainEnvironment<UtxoSelectionType, SelectedUtxosAndChangeAmount> for BtcEn
 	}
 }
 
+impl ChainEnvironment<UtxoId, Utxo> for BtcEnvironment {
+	fn lookup(utxo_id: UtxoId) -> Option<Utxo> {
+		Environment::take_bitcoin_utxo(&utxo_id)
+	}
+}
+
+impl ChainEnvironment<(), BitcoinFeeInfo> for BtcEnvironment {
+	fn lookup(_: ()) -> Option<BitcoinFeeInfo> {
+		BitcoinChainTracking::chain_state().map(|chain_state| chain_state.tracked_data.btc_fee_info)
+	}
+}
+
 impl ChainEnvironment<(), cf_chains::btc::AggKey> for BtcEnvironment {
 	fn lookup(_: ()) -> Option<cf_chains::btc::AggKey> {
 		<BitcoinThresholdSigner as KeyProvider<BitcoinCrypto>>::active_epoch_key()
@@ -430,6 +554,76 @@ impl CommKeyBroadcaster for TokenholderGovernanceBroadcaster {
 	}
 }
 
+type IngressEgressConfigUpdates<I> =
+	BoundedVec<pallet_cf_ingress_egress::PalletConfigUpdate<Runtime, I>, ConstU32<10>>;
+
+/// The parameter changes that tokenholders can propose. Each one is enacted by dispatching the
+/// equivalent governance call.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum TokenholderParameterUpdate {
+	Validator(pallet_cf_validator::PalletConfigUpdate),
+	Swapping(BoundedVec<pallet_cf_swapping::PalletConfigUpdate, ConstU32<10>>),
+	EthereumIngressEgress(IngressEgressConfigUpdates<EthereumInstance>),
+	PolkadotIngressEgress(IngressEgressConfigUpdates<PolkadotInstance>),
+	BitcoinIngressEgress(IngressEgressConfigUpdates<BitcoinInstance>),
+	/// Inflation for current authorities, in parts per billion.
+	CurrentAuthorityEmissionInflation(u32),
+	/// Inflation for backup nodes, in parts per billion.
+	BackupNodeEmissionInflation(u32),
+}
+
+impl TokenholderParameterUpdate {
+	fn call(self) -> RuntimeCall {
+		match self {
+			Self::Validator(update) =>
+				pallet_cf_validator::Call::update_pallet_config { update }.into(),
+			Self::Swapping(updates) =>
+				pallet_cf_swapping::Call::update_pallet_config { updates }.into(),
+			Self::EthereumIngressEgress(updates) =>
+				pallet_cf_ingress_egress::Call::<Runtime, EthereumInstance>::update_pallet_config {
+					updates,
+				}
+				.into(),
+			Self::PolkadotIngressEgress(updates) =>
+				pallet_cf_ingress_egress::Call::<Runtime, PolkadotInstance>::update_pallet_config {
+					updates,
+				}
+				.into(),
+			Self::BitcoinIngressEgress(updates) =>
+				pallet_cf_ingress_egress::Call::<Runtime, BitcoinInstance>::update_pallet_config {
+					updates,
+				}
+				.into(),
+			Self::CurrentAuthorityEmissionInflation(inflation) =>
+				pallet_cf_emissions::Call::update_current_authority_emission_inflation { inflation }
+					.into(),
+			Self::BackupNodeEmissionInflation(inflation) =>
+				pallet_cf_emissions::Call::update_backup_node_emission_inflation { inflation }
+					.into(),
+		}
+	}
+}
+
+impl cf_traits::TokenholderParameterChange for TokenholderParameterUpdate {
+	fn enact(self) -> DispatchResult {
+		self.call()
+			.dispatch_bypass_filter(pallet_cf_governance::RawOrigin::GovernanceApproval.into())
+			.map(|_| ())
+			.map_err(|e| e.error)
+	}
+
+	fn weight(&self) -> Weight {
+		self.clone().call().get_dispatch_info().weight
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl cf_chains::benchmarking_value::BenchmarkValue for TokenholderParameterUpdate {
+	fn benchmark_value() -> Self {
+		Self::CurrentAuthorityEmissionInflation(Default::default())
+	}
+}
+
 #[macro_export]
 macro_rules! impl_deposit_api_for_anychain {
 	( $t: ident, $(($chain: ident, $pallet: ident)),+ ) => {
@@ -460,7 +654,9 @@ macro_rules! impl_deposit_api_for_anychain {
 				broker_commission_bps: BasisPoints,
 				broker_id: Self::AccountId,
 				channel_metadata: Option<CcmChannelMetadata>,
-				boost_fee: BasisPoints
+				boost_fee: BasisPoints,
+				refund_parameters: Option<SwapRefundParameters>,
+				dca_parameters: Option<DcaParameters>,
 			) -> Result<(ChannelId, ForeignChainAddress, <AnyChain as cf_chains::Chain>::ChainBlockNumber), DispatchError> {
 				match source_asset.into() {
 					$(
@@ -471,7 +667,9 @@ macro_rules! impl_deposit_api_for_anychain {
 							broker_commission_bps,
 							broker_id,
 							channel_metadata,
-							boost_fee
+							boost_fee,
+							refund_parameters,
+							dca_parameters,
 						).map(|(channel, address, block_number)| (channel, address, block_number.into())),
 					)+
 				}
@@ -570,16 +768,14 @@ impl OnBroadcastReady<Bitcoin> for BroadcastReadyProvider {
 	fn on_broadcast_ready(api_call: &Self::ApiCall) {
 		match api_call {
 			BitcoinApi::BatchTransfer(batch_transfer) => {
-				let tx_id = batch_transfer.bitcoin_transaction.txid();
-				let outputs = batch_transfer.bitcoin_transaction.outputs.clone();
-				let output_len = outputs.len();
-				let vout = output_len - 1;
-				let change_output = outputs.get(vout).unwrap();
-				Environment::add_bitcoin_change_utxo(
-					change_output.amount,
-					UtxoId { tx_id, vout: vout as u32 },
-					batch_transfer.change_utxo_key,
-				);
+				// Changeless transactions don't return anything to the vault.
+				if let Some((vout, change_output)) = batch_transfer.change_output() {
+					Environment::add_bitcoin_change_utxo(
+						change_output.amount,
+						UtxoId { tx_id: batch_transfer.bitcoin_transaction.txid(), vout },
+						batch_transfer.change_utxo_key,
+					);
+				}
 			},
 			_ => unreachable!(),
 		}
@@ -1,12 +1,20 @@
 use cf_primitives::EpochIndex;
 use cf_traits::EpochTransitionHandler;
 
-use crate::Witnesser;
+use crate::{
+	BitcoinThresholdSigner, EthereumThresholdSigner, Flip, PolkadotThresholdSigner, Reputation,
+	Witnesser,
+};
 
 pub struct ChainflipEpochTransitions;
 
 impl EpochTransitionHandler for ChainflipEpochTransitions {
 	fn on_expired_epoch(expired: EpochIndex) {
 		<Witnesser as EpochTransitionHandler>::on_expired_epoch(expired);
+		<Reputation as EpochTransitionHandler>::on_expired_epoch(expired);
+		<EthereumThresholdSigner as EpochTransitionHandler>::on_expired_epoch(expired);
+		<PolkadotThresholdSigner as EpochTransitionHandler>::on_expired_epoch(expired);
+		<BitcoinThresholdSigner as EpochTransitionHandler>::on_expired_epoch(expired);
+		<Flip as EpochTransitionHandler>::on_expired_epoch(expired);
 	}
 }
@@ -12,8 +12,8 @@ mod weights;
 use crate::{
 	chainflip::{calculate_account_apy, Offence},
 	runtime_apis::{
-		AuctionState, DispatchErrorWithMessage, FailingWitnessValidators, LiquidityProviderInfo,
-		RuntimeApiAccountInfoV2, RuntimeApiPenalty,
+		AuctionState, ChainPerformance, DispatchErrorWithMessage, FailingWitnessValidators,
+		LiquidityProviderInfo, RuntimeApiAccountInfoV2, RuntimeApiPenalty, ValidatorPerformance,
 	},
 };
 use cf_amm::{
@@ -26,10 +26,10 @@ use cf_chains::{
 	dot::{self, PolkadotCrypto},
 	eth::{self, api::EthereumApi, Address as EthereumAddress, Ethereum},
 	evm::EvmCrypto,
-	Bitcoin, CcmChannelMetadata, DefaultRetryPolicy, FeeEstimationApi, ForeignChain, Polkadot,
-	TransactionBuilder,
+	Bitcoin, CcmChannelMetadata, DefaultRetryPolicy, FeeEstimationApi, ForeignChain,
+	ForeignChainAddress, Polkadot, TransactionBuilder,
 };
-use cf_primitives::{BroadcastId, NetworkEnvironment};
+use cf_primitives::{BroadcastId, ChannelId, EpochIndex, NetworkEnvironment};
 use cf_traits::{AssetConverter, GetTrackedData, LpBalanceApi};
 use core::ops::Range;
 pub use frame_system::Call as SystemCall;
@@ -111,7 +111,7 @@ use chainflip::{
 use safe_mode::{RuntimeSafeMode, WitnesserCallPermission};
 
 use constants::common::*;
-use pallet_cf_flip::{Bonder, FlipSlasher};
+use pallet_cf_flip::Bonder;
 pub use pallet_transaction_payment::ChargeTransactionPayment;
 
 // Make the WASM binary available.
@@ -186,6 +186,7 @@ impl pallet_cf_validator::Config for Runtime {
 		cons_key_rotator!(EthereumThresholdSigner, PolkadotThresholdSigner, BitcoinThresholdSigner);
 	type MissedAuthorshipSlots = chainflip::MissedAuraSlots;
 	type BidderProvider = pallet_cf_funding::Pallet<Self>;
+	type DelegationInfo = pallet_cf_funding::Pallet<Self>;
 	type KeygenQualification = (
 		Reputation,
 		(
@@ -547,6 +548,7 @@ impl pallet_cf_funding::Config for Runtime {
 	type RegisterRedemption = EthereumApi<EthEnvironment>;
 	type TimeSource = Timestamp;
 	type SafeMode = RuntimeSafeMode;
+	type MaxDelegatorsPerOperator = ConstU32<100>;
 	type WeightInfo = pallet_cf_funding::weights::PalletWeight<Runtime>;
 }
 
@@ -559,6 +561,7 @@ impl pallet_cf_tokenholder_governance::Config for Runtime {
 	type CommKeyBroadcaster = TokenholderGovernanceBroadcaster;
 	type ProposalFee = ConstU128<{ 1_000 * FLIPPERINOS_PER_FLIP }>;
 	type EnactmentDelay = ConstU32<{ 7 * DAYS }>;
+	type ParameterChange = chainflip::TokenholderParameterUpdate;
 }
 
 impl pallet_cf_governance::Config for Runtime {
@@ -571,6 +574,7 @@ impl pallet_cf_governance::Config for Runtime {
 	type RuntimeUpgrade = chainflip::RuntimeUpgradeManager;
 	type CompatibleCfeVersions = Environment;
 	type AuthoritiesCfeVersions = Validator;
+	type CallCategoriser = chainflip::GovernanceCallCategoriser;
 }
 
 impl pallet_cf_emissions::Config for Runtime {
@@ -618,7 +622,7 @@ impl pallet_cf_reputation::Config for Runtime {
 	type Heartbeat = ChainflipHeartbeat;
 	type HeartbeatBlockInterval = ConstU32<HEARTBEAT_BLOCK_INTERVAL>;
 	type ReputationPointFloorAndCeiling = ReputationPointFloorAndCeiling;
-	type Slasher = FlipSlasher<Self>;
+	type Slasher = chainflip::DelegationAwareSlasher;
 	type WeightInfo = pallet_cf_reputation::weights::PalletWeight<Runtime>;
 	type MaximumAccruableReputation = MaximumAccruableReputation;
 	type SafeMode = RuntimeSafeMode;
@@ -635,7 +639,7 @@ impl pallet_cf_threshold_signature::Config<EthereumInstance> for Runtime {
 	type OffenceReporter = Reputation;
 	type CeremonyRetryDelay = ConstU32<1>;
 	type SafeMode = RuntimeSafeMode;
-	type Slasher = FlipSlasher<Self>;
+	type Slasher = chainflip::DelegationAwareSlasher;
 	type CfeMultisigRequest = CfeInterface;
 	type Weights = pallet_cf_threshold_signature::weights::PalletWeight<Self>;
 }
@@ -651,7 +655,7 @@ impl pallet_cf_threshold_signature::Config<PolkadotInstance> for Runtime {
 	type OffenceReporter = Reputation;
 	type CeremonyRetryDelay = ConstU32<1>;
 	type SafeMode = RuntimeSafeMode;
-	type Slasher = FlipSlasher<Self>;
+	type Slasher = chainflip::DelegationAwareSlasher;
 	type CfeMultisigRequest = CfeInterface;
 	type Weights = pallet_cf_threshold_signature::weights::PalletWeight<Self>;
 }
@@ -667,7 +671,7 @@ impl pallet_cf_threshold_signature::Config<BitcoinInstance> for Runtime {
 	type OffenceReporter = Reputation;
 	type CeremonyRetryDelay = ConstU32<1>;
 	type SafeMode = RuntimeSafeMode;
-	type Slasher = FlipSlasher<Self>;
+	type Slasher = chainflip::DelegationAwareSlasher;
 	type CfeMultisigRequest = CfeInterface;
 	type Weights = pallet_cf_threshold_signature::weights::PalletWeight<Self>;
 }
@@ -922,7 +926,7 @@ type PalletMigrations = (
 	pallet_cf_ingress_egress::migrations::PalletMigration<Runtime, Instance1>,
 	pallet_cf_ingress_egress::migrations::PalletMigration<Runtime, Instance2>,
 	pallet_cf_ingress_egress::migrations::PalletMigration<Runtime, Instance3>,
-	// pallet_cf_pools::migrations::PalletMigration<Runtime>,
+	pallet_cf_pools::migrations::PalletMigration<Runtime>,
 );
 
 pub struct ThresholdSignatureRefactorMigration;
@@ -1463,6 +1467,57 @@ impl_runtime_apis! {
 				ForeignChain::Bitcoin => pallet_cf_ingress_egress::Pallet::<Runtime, BitcoinInstance>::channel_opening_fee(),
 			}
 		}
+
+		fn cf_unrecovered_dust(chain: ForeignChain) -> Vec<(ForeignChainAddress, Asset, ChannelId, AssetAmount)> {
+			match chain {
+				ForeignChain::Ethereum => pallet_cf_ingress_egress::Pallet::<Runtime, EthereumInstance>::unrecovered_dust(),
+				ForeignChain::Polkadot => pallet_cf_ingress_egress::Pallet::<Runtime, PolkadotInstance>::unrecovered_dust(),
+				ForeignChain::Bitcoin => pallet_cf_ingress_egress::Pallet::<Runtime, BitcoinInstance>::unrecovered_dust(),
+			}
+		}
+
+		fn cf_tokenholder_governance_proposals() -> Vec<(pallet_cf_tokenholder_governance::Proposal<Runtime>, pallet_cf_tokenholder_governance::ProposalStatus<BlockNumber, FlipBalance>)> {
+			TokenholderGovernance::proposal_statuses()
+		}
+
+		fn cf_validator_performance(account_id: AccountId, epoch_index: EpochIndex) -> ValidatorPerformance {
+			// Heartbeats and witnessing are only tracked for the authorities of the epoch.
+			let is_authority = <Runtime as Chainflip>::EpochInfo::authorities_at_epoch(epoch_index).contains(&account_id);
+			let (heartbeat_intervals, missed_heartbeat_intervals) = if is_authority {
+				(
+					pallet_cf_reputation::HeartbeatIntervals::<Runtime>::get(epoch_index),
+					pallet_cf_reputation::MissedHeartbeatIntervals::<Runtime>::get(epoch_index, &account_id),
+				)
+			} else {
+				(0, 0)
+			};
+			let (slash_count, slashed_amount) = pallet_cf_flip::SlashesApplied::<Runtime>::get(epoch_index, &account_id);
+			let chains = ForeignChain::iter().map(|chain| {
+				let ceremonies = match chain {
+					ForeignChain::Ethereum => pallet_cf_threshold_signature::CeremonyParticipationStats::<Runtime, EthereumInstance>::get(epoch_index, &account_id),
+					ForeignChain::Polkadot => pallet_cf_threshold_signature::CeremonyParticipationStats::<Runtime, PolkadotInstance>::get(epoch_index, &account_id),
+					ForeignChain::Bitcoin => pallet_cf_threshold_signature::CeremonyParticipationStats::<Runtime, BitcoinInstance>::get(epoch_index, &account_id),
+				};
+				ChainPerformance {
+					chain,
+					witnessed_calls: if is_authority { pallet_cf_witnesser::WitnessedCallCount::<Runtime>::get(epoch_index, chain) } else { 0 },
+					missed_witnesses: pallet_cf_witnesser::MissedWitnessCount::<Runtime>::get(epoch_index, (account_id.clone(), chain)),
+					keygen_ceremonies: ceremonies.keygen_ceremonies,
+					signing_ceremonies: ceremonies.signing_ceremonies,
+					times_blamed: ceremonies.times_blamed,
+				}
+			}).collect();
+
+			ValidatorPerformance {
+				epoch_index,
+				is_authority,
+				heartbeat_intervals,
+				missed_heartbeat_intervals,
+				slash_count,
+				slashed_amount,
+				chains,
+			}
+		}
 	}
 
 	// END custom runtime APIs
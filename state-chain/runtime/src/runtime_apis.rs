@@ -7,7 +7,7 @@ use cf_chains::{
 	assets::any::AssetMap, eth::Address as EthereumAddress, Chain, ForeignChainAddress,
 };
 use cf_primitives::{
-	AccountRole, Asset, AssetAmount, BroadcastId, EpochIndex, FlipBalance, ForeignChain,
+	AccountRole, Asset, AssetAmount, BroadcastId, ChannelId, EpochIndex, FlipBalance, ForeignChain,
 	NetworkEnvironment, SemVer, SwapOutput,
 };
 use codec::{Decode, Encode};
@@ -18,6 +18,7 @@ use pallet_cf_pools::{
 	AskBidMap, PoolInfo, PoolLiquidity, PoolOrderbook, PoolOrders, PoolPriceV1, PoolPriceV2,
 	UnidirectionalPoolDepth,
 };
+use pallet_cf_tokenholder_governance::{Proposal, ProposalStatus};
 use pallet_cf_witnesser::CallHash;
 use scale_info::{prelude::string::String, TypeInfo};
 use serde::{Deserialize, Serialize};
@@ -100,6 +101,30 @@ pub struct FailingWitnessValidators {
 	pub validators: Vec<(cf_primitives::AccountId, String, bool)>,
 }
 
+#[derive(Serialize, Deserialize, Encode, Decode, Eq, PartialEq, TypeInfo, Debug)]
+pub struct ChainPerformance {
+	pub chain: ForeignChain,
+	/// Calls on this chain that were witnessed and whose witnessing deadline has passed.
+	pub witnessed_calls: u32,
+	/// Calls on this chain that the validator failed to witness before the deadline.
+	pub missed_witnesses: u32,
+	pub keygen_ceremonies: u32,
+	pub signing_ceremonies: u32,
+	pub times_blamed: u32,
+}
+
+/// Performance statistics of a validator over a single (unexpired) epoch.
+#[derive(Serialize, Deserialize, Encode, Decode, Eq, PartialEq, TypeInfo, Debug)]
+pub struct ValidatorPerformance {
+	pub epoch_index: EpochIndex,
+	pub is_authority: bool,
+	pub heartbeat_intervals: u32,
+	pub missed_heartbeat_intervals: u32,
+	pub slash_count: u32,
+	pub slashed_amount: FlipBalance,
+	pub chains: Vec<ChainPerformance>,
+}
+
 decl_runtime_apis!(
 	/// Definition for all runtime API interfaces.
 	pub trait CustomRuntimeApi {
@@ -196,5 +221,14 @@ decl_runtime_apis!(
 		fn cf_witness_count(hash: CallHash) -> Option<FailingWitnessValidators>;
 		fn cf_witness_safety_margin(chain: ForeignChain) -> Option<u64>;
 		fn cf_channel_opening_fee(chain: ForeignChain) -> FlipBalance;
+		fn cf_unrecovered_dust(
+			chain: ForeignChain,
+		) -> Vec<(ForeignChainAddress, Asset, ChannelId, AssetAmount)>;
+		fn cf_tokenholder_governance_proposals(
+		) -> Vec<(Proposal<crate::Runtime>, ProposalStatus<u32, FlipBalance>)>;
+		fn cf_validator_performance(
+			account_id: AccountId32,
+			epoch_index: EpochIndex,
+		) -> ValidatorPerformance;
 	}
 );
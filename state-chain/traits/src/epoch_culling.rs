@@ -0,0 +1,46 @@
+use cf_primitives::EpochIndex;
+use codec::{FullCodec, FullEncode};
+use frame_support::{
+	storage::{StorageDoubleMap, StorageValue},
+	weights::Weight,
+};
+use sp_std::vec::Vec;
+
+/// Removes the entries of `Storage` for the last epoch in `EpochsToCull`, as far as the
+/// `remaining_weight` allows. The epoch is dropped from `EpochsToCull` once all of its entries
+/// have been removed, so that larger maps are culled over several blocks. Meant to be called from
+/// `on_idle`.
+///
+/// `idle_weight` is the weight of checking for epochs to cull, and `removal_weight` returns the
+/// weight of removing the given number of entries. Returns the weight used.
+pub fn cull_expired_epoch<EpochsToCull, Storage, K, V>(
+	remaining_weight: Weight,
+	idle_weight: Weight,
+	removal_weight: impl Fn(u32) -> Weight,
+) -> Weight
+where
+	EpochsToCull: StorageValue<Vec<EpochIndex>, Query = Vec<EpochIndex>>,
+	Storage: StorageDoubleMap<EpochIndex, K, V>,
+	K: FullEncode,
+	V: FullCodec,
+{
+	let mut epochs_to_cull = EpochsToCull::get();
+	let Some(epoch) = epochs_to_cull.pop() else { return idle_weight };
+
+	let max_deletions: u32 = remaining_weight
+		.saturating_sub(idle_weight)
+		.ref_time()
+		.checked_div(removal_weight(1).ref_time())
+		.unwrap_or_default()
+		.try_into()
+		.unwrap_or(u32::MAX);
+	if max_deletions == 0 {
+		return idle_weight
+	}
+
+	let remove_result = Storage::clear_prefix(epoch, max_deletions, None);
+	if remove_result.maybe_cursor.is_none() {
+		EpochsToCull::put(epochs_to_cull);
+	}
+	idle_weight.saturating_add(removal_weight(remove_result.backend))
+}
@@ -7,6 +7,8 @@ pub use liquidity::*;
 pub mod safe_mode;
 pub use safe_mode::*;
 
+mod epoch_culling;
+pub use epoch_culling::cull_expired_epoch;
 pub mod mocks;
 pub mod offence_reporting;
 
@@ -15,8 +17,9 @@ use core::fmt::Debug;
 pub use async_result::AsyncResult;
 
 use cf_chains::{
-	address::ForeignChainAddress, ApiCall, CcmChannelMetadata, CcmDepositMetadata, Chain,
-	ChainCrypto, DepositChannel, Ethereum, SwapOrigin,
+	address::ForeignChainAddress, benchmarking_value::BenchmarkValue, ApiCall, CcmChannelMetadata,
+	CcmDepositMetadata, Chain, ChainCrypto, DcaParameters, DepositChannel, Ethereum, SwapOrigin,
+	SwapRefundParameters,
 };
 use cf_primitives::{
 	AccountRole, Asset, AssetAmount, AuthorityCount, BasisPoints, BroadcastId, ChannelId,
@@ -32,6 +35,7 @@ use frame_support::{
 		DispatchError, DispatchResult, FixedPointOperand, Percent, RuntimeDebug,
 	},
 	traits::{EnsureOrigin, Get, Imbalance, IsType, UnfilteredDispatchable},
+	weights::Weight,
 	CloneNoBound, EqNoBound, Hashable, Parameter, PartialEqNoBound,
 };
 use scale_info::TypeInfo;
@@ -265,6 +269,10 @@ pub trait Funding {
 
 	/// Reverts a pending redemption in the case of an expiry or cancellation.
 	fn revert_redemption(account_id: &Self::AccountId) -> Result<(), DispatchError>;
+
+	/// Pays out rewards to a delegator from the rewards that were set aside for delegators as
+	/// they were earned.
+	fn credit_delegation_rewards(account_id: &Self::AccountId, amount: Self::Balance);
 }
 
 pub trait AccountInfo<T: Chainflip> {
@@ -663,6 +671,13 @@ pub trait FundingInfo {
 	fn total_onchain_funds() -> Self::Balance;
 }
 
+/// Provides information about funds delegated to validator operators.
+pub trait DelegationInfo {
+	type AccountId;
+	/// Returns true if the account has delegated funds to an operator.
+	fn is_delegating(account_id: &Self::AccountId) -> bool;
+}
+
 /// Allow pallets to open and expire deposit addresses.
 pub trait DepositApi<C: Chain> {
 	type AccountId;
@@ -675,6 +690,7 @@ pub trait DepositApi<C: Chain> {
 	) -> Result<(ChannelId, ForeignChainAddress, C::ChainBlockNumber), DispatchError>;
 
 	/// Issues a channel id and deposit address for a new swap.
+	#[allow(clippy::too_many_arguments)]
 	fn request_swap_deposit_address(
 		source_asset: C::ChainAsset,
 		destination_asset: Asset,
@@ -683,6 +699,8 @@ pub trait DepositApi<C: Chain> {
 		broker_id: Self::AccountId,
 		channel_metadata: Option<CcmChannelMetadata>,
 		boost_fee: BasisPoints,
+		refund_parameters: Option<SwapRefundParameters>,
+		dca_parameters: Option<DcaParameters>,
 	) -> Result<(ChannelId, ForeignChainAddress, C::ChainBlockNumber), DispatchError>;
 }
 
@@ -802,6 +820,15 @@ pub trait CommKeyBroadcaster {
 	fn broadcast(new_key: <<Ethereum as Chain>::ChainCrypto as ChainCrypto>::GovKey);
 }
 
+/// A whitelisted runtime parameter change that can be proposed and voted on by tokenholders.
+pub trait TokenholderParameterChange: Member + Parameter + BenchmarkValue {
+	/// Applies the change.
+	fn enact(self) -> DispatchResult;
+
+	/// The weight of applying the change.
+	fn weight(&self) -> Weight;
+}
+
 /// Provides an interface to access the amount of Flip that is ready to be burned.
 pub trait FlipBurnInfo {
 	/// Takes the available Flip and returns it.
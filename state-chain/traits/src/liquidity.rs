@@ -1,4 +1,4 @@
-use cf_chains::address::ForeignChainAddress;
+use cf_chains::{address::ForeignChainAddress, DcaParameters, SwapRefundParameters};
 use cf_primitives::{Asset, AssetAmount, BasisPoints, ChannelId, SwapId};
 use frame_support::pallet_prelude::{DispatchError, DispatchResult};
 use sp_std::vec::Vec;
@@ -17,6 +17,8 @@ pub trait SwapDepositHandler {
 		broker_id: Self::AccountId,
 		broker_commission_bps: BasisPoints,
 		channel_id: ChannelId,
+		refund_parameters: Option<SwapRefundParameters>,
+		dca_parameters: Option<DcaParameters>,
 	) -> SwapId;
 }
 
@@ -1,6 +1,7 @@
 use crate::{Chainflip, DepositApi};
 use cf_chains::{
-	address::ForeignChainAddress, dot::PolkadotAccountId, CcmChannelMetadata, Chain, ForeignChain,
+	address::ForeignChainAddress, dot::PolkadotAccountId, CcmChannelMetadata, Chain, DcaParameters,
+	ForeignChain, SwapRefundParameters,
 };
 use cf_primitives::{chains::assets::any, BasisPoints, ChannelId};
 use codec::{Decode, Encode};
@@ -30,6 +31,8 @@ pub struct SwapChannel<C: Chain, T: Chainflip> {
 	pub broker_id: <T as frame_system::Config>::AccountId,
 	pub channel_metadata: Option<CcmChannelMetadata>,
 	pub boost_fee: BasisPoints,
+	pub refund_parameters: Option<SwapRefundParameters>,
+	pub dca_parameters: Option<DcaParameters>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
@@ -114,6 +117,8 @@ impl<C: Chain, T: Chainflip> DepositApi<C> for MockDepositHandler<C, T> {
 		broker_id: Self::AccountId,
 		channel_metadata: Option<CcmChannelMetadata>,
 		boost_fee: BasisPoints,
+		refund_parameters: Option<SwapRefundParameters>,
+		dca_parameters: Option<DcaParameters>,
 	) -> Result<
 		(cf_primitives::ChannelId, ForeignChainAddress, C::ChainBlockNumber),
 		sp_runtime::DispatchError,
@@ -134,6 +139,8 @@ impl<C: Chain, T: Chainflip> DepositApi<C> for MockDepositHandler<C, T> {
 					broker_id,
 					channel_metadata,
 					boost_fee,
+					refund_parameters,
+					dca_parameters,
 				});
 			};
 		});
@@ -1,13 +1,34 @@
 use crate::{LpBalanceApi, LpDepositHandler};
 use cf_chains::assets::any::Asset;
 use cf_primitives::AssetAmount;
-use sp_runtime::DispatchResult;
+use sp_runtime::{DispatchError, DispatchResult};
 
 #[cfg(feature = "runtime-benchmarks")]
 use cf_chains::ForeignChainAddress;
 
+use super::{MockPallet, MockPalletStorage};
+
 pub struct MockBalance;
 
+impl MockPallet for MockBalance {
+	const PREFIX: &'static [u8] = b"MockBalance";
+}
+
+const FREE_BALANCES: &[u8] = b"FREE_BALANCES";
+const ENFORCE_BALANCES: &[u8] = b"ENFORCE_BALANCES";
+
+impl MockBalance {
+	/// Makes `try_debit_account` fail when the account's balance is insufficient. By default,
+	/// debits always succeed.
+	pub fn enforce_balances() {
+		<Self as MockPalletStorage>::put_value(ENFORCE_BALANCES, true);
+	}
+
+	pub fn get_balance(who: &<Self as LpBalanceApi>::AccountId, asset: Asset) -> AssetAmount {
+		<Self as MockPalletStorage>::get_storage(FREE_BALANCES, (*who, asset)).unwrap_or_default()
+	}
+}
+
 impl LpDepositHandler for MockBalance {
 	type AccountId = u64;
 
@@ -35,19 +56,42 @@ impl LpBalanceApi for MockBalance {
 	}
 
 	fn try_credit_account(
-		_who: &Self::AccountId,
-		_asset: Asset,
-		_amount: AssetAmount,
+		who: &Self::AccountId,
+		asset: Asset,
+		amount: AssetAmount,
 	) -> DispatchResult {
+		<Self as MockPalletStorage>::mutate_storage::<(u64, Asset), _, _, _, _>(
+			FREE_BALANCES,
+			&(*who, asset),
+			|balance: &mut Option<AssetAmount>| {
+				*balance = Some(balance.unwrap_or_default().saturating_add(amount));
+			},
+		);
 		Ok(())
 	}
 
 	fn try_debit_account(
-		_who: &Self::AccountId,
-		_asset: Asset,
-		_amount: AssetAmount,
+		who: &Self::AccountId,
+		asset: Asset,
+		amount: AssetAmount,
 	) -> DispatchResult {
-		Ok(())
+		<Self as MockPalletStorage>::mutate_storage::<(u64, Asset), _, _, _, _>(
+			FREE_BALANCES,
+			&(*who, asset),
+			|balance: &mut Option<AssetAmount>| {
+				let current = balance.unwrap_or_default();
+				*balance = Some(
+					if <Self as MockPalletStorage>::get_value(ENFORCE_BALANCES).unwrap_or(false) {
+						current
+							.checked_sub(amount)
+							.ok_or(DispatchError::Other("Insufficient balance"))?
+					} else {
+						current.saturating_sub(amount)
+					},
+				);
+				Ok(())
+			},
+		)
 	}
 
 	fn record_fees(_who: &Self::AccountId, _amount: AssetAmount, _asset: Asset) {}
@@ -22,6 +22,8 @@ where
 		_broker_id: Self::AccountId,
 		_broker_commission_bps: cf_primitives::BasisPoints,
 		_channel_id: cf_primitives::ChannelId,
+		_refund_parameters: Option<cf_chains::SwapRefundParameters>,
+		_dca_parameters: Option<cf_chains::DcaParameters>,
 	) -> SwapId {
 		let _ = E::schedule_egress(
 			to.try_into().unwrap_or_else(|_| panic!("Unable to convert")),
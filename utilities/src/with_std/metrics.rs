@@ -78,6 +78,10 @@ impl IntGaugeWrapper {
 			Err(e) => tracing::error!("Conversion to i64 failed: {:?}", e),
 		}
 	}
+
+	pub fn get(&self) -> i64 {
+		self.prom_metric.get()
+	}
 }
 
 /// wrapper used to enforce the correct number of labels when interacting with an IntGaugeVec
@@ -128,6 +132,16 @@ impl<const N: usize> IntGaugeVecWrapper<N> {
 			Err(e) => tracing::error!("Conversion to i64 failed: {:?}", e),
 		}
 	}
+
+	pub fn get(&self, labels: &[&str; N]) -> i64 {
+		match self.prom_metric.get_metric_with_label_values(labels) {
+			Ok(m) => m.get(),
+			Err(e) => {
+				tracing::error!("Failed to get the metric: {}", e);
+				0
+			},
+		}
+	}
 }
 
 #[derive(Clone)]
@@ -416,6 +430,43 @@ build_gauge_vec!(
 	"Gauge keeping track of the latest block number the engine reported to the state chain",
 	["chain"]
 );
+build_gauge_vec!(
+	EXTERNAL_CHAIN_HEAD,
+	"cfe_external_chain_head",
+	"Gauge keeping track of the latest block number the engine has seen on the external chain",
+	["chain"]
+);
+build_gauge_vec!(
+	WITNESSED_BLOCK,
+	"cfe_witnessed_block",
+	"Gauge keeping track of the latest block number fully processed by each witnessing source",
+	["chain", "source"]
+);
+build_gauge_vec!(
+	MONITORED_DEPOSIT_ADDRESSES,
+	"cfe_monitored_deposit_addresses",
+	"Gauge keeping track of the number of deposit addresses the engine is witnessing for each epoch",
+	["chain", "epoch"]
+);
+build_counter_vec!(
+	WITNESS_EXTRINSICS_SUBMITTED,
+	"cfe_witness_extrinsics_submitted",
+	"Count the witness extrinsics submitted by the engine",
+	["chain"]
+);
+build_counter_vec!(
+	WITNESS_EXTRINSICS_FAILED,
+	"cfe_witness_extrinsics_failed",
+	"Count the witness extrinsics which failed or were not included in a finalized block",
+	["chain"]
+);
+build_histogram_vec!(
+	WITNESSING_DELAY,
+	"cfe_witnessing_delay",
+	"Measure the time in seconds from a block being produced, according to its timestamp, to it being fully processed by a witnessing source",
+	["chain", "source"],
+	(vec![1.0, 2.0, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1200.0, 2400.0, 3600.0, 7200.0])
+);
 build_gauge_vec!(
 	AUTHORIZED_CEREMONIES,
 	"cfe_authorized_ceremonies",